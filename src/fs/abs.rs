@@ -7,12 +7,7 @@
     target_os = "wasi"
 )))]
 use crate::fs::StatFs;
-#[cfg(not(any(
-    target_os = "illumos",
-    target_os = "netbsd",
-    target_os = "redox",
-    target_os = "wasi"
-)))]
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
 use crate::{imp, io, path};
 
 /// `statfs`—Queries filesystem metadata.
@@ -31,3 +26,21 @@ use crate::{imp, io, path};
 pub fn statfs<P: path::Arg>(path: P) -> io::Result<StatFs> {
     path.into_with_z_str(imp::fs::syscalls::statfs)
 }
+
+/// `truncate(path, length)`—Sets the length of a file.
+///
+/// If the file is larger than `length`, the extra data is discarded. If
+/// it's smaller, it's extended with zeros. This requires permission to
+/// write to the file, and fails with [`io::Errno::ACCESS`] without it.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/truncate.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/truncate.2.html
+#[cfg(not(any(target_os = "fuchsia", target_os = "redox", target_os = "wasi")))]
+#[inline]
+pub fn truncate<P: path::Arg>(path: P, length: u64) -> io::Result<()> {
+    path.into_with_z_str(|path| imp::fs::syscalls::truncate(path, length))
+}
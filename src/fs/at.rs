@@ -10,7 +10,7 @@ use crate::ffi::{ZStr, ZString};
 use crate::fs::Access;
 #[cfg(any(target_os = "ios", target_os = "macos"))]
 use crate::fs::CloneFlags;
-#[cfg(not(any(target_os = "ios", target_os = "macos", target_os = "wasi")))]
+#[cfg(not(target_os = "wasi"))]
 use crate::fs::FileType;
 #[cfg(any(target_os = "android", target_os = "linux"))]
 use crate::fs::RenameFlags;
@@ -21,6 +21,8 @@ use crate::path::SMALL_PATH_BUFFER_SIZE;
 use crate::process::{Gid, Uid};
 use crate::{imp, path};
 use alloc::vec::Vec;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+use imp::fd::AsRawFd;
 use imp::fd::{AsFd, BorrowedFd};
 use imp::time::types::Nsecs;
 
@@ -104,6 +106,10 @@ fn _readlinkat(dirfd: BorrowedFd<'_>, path: &ZStr, mut buffer: Vec<u8>) -> io::R
 
 /// `mkdirat(fd, path, mode)`—Creates a directory.
 ///
+/// The new directory's permission bits are `mode & !umask`, so bits which
+/// are set in the process umask are cleared. Use `fchmod` after creating
+/// the directory to set them regardless of the umask.
+///
 /// # References
 ///  - [POSIX]
 ///  - [Linux]
@@ -118,6 +124,10 @@ pub fn mkdirat<P: path::Arg, Fd: AsFd>(dirfd: Fd, path: P, mode: Mode) -> io::Re
 /// `linkat(old_dirfd, old_path, new_dirfd, new_path, flags)`—Creates a hard
 /// link.
 ///
+/// If `old_path` is a symlink, this links the symlink itself, unless
+/// `flags` contains [`AtFlags::SYMLINK_FOLLOW`], in which case it links the
+/// file the symlink refers to.
+///
 /// # References
 ///  - [POSIX]
 ///  - [Linux]
@@ -148,7 +158,8 @@ pub fn linkat<P: path::Arg, Q: path::Arg, PFd: AsFd, QFd: AsFd>(
 /// `unlinkat(fd, path, flags)`—Unlinks a file or remove a directory.
 ///
 /// With the [`REMOVEDIR`] flag, this removes a directory. This is in place
-/// of a `rmdirat` function.
+/// of a `rmdirat` function. Removing a directory which isn't empty fails
+/// with [`io::Errno::NOTEMPTY`].
 ///
 /// # References
 ///  - [POSIX]
@@ -215,7 +226,13 @@ pub fn renameat_with<P: path::Arg, Q: path::Arg, PFd: AsFd, QFd: AsFd>(
     })
 }
 
-/// `symlinkat(old_dirfd, old_path, new_dirfd, new_path)`—Creates a symlink.
+/// `symlinkat(old_path, new_dirfd, new_path)`—Creates a symlink.
+///
+/// `old_path` is stored in the symlink verbatim. It isn't resolved or
+/// validated, so it may be relative, and it may name something which
+/// doesn't exist. A relative `old_path` is resolved relative to the
+/// directory containing the symlink when the symlink is followed, not
+/// relative to `new_dirfd`.
 ///
 /// # References
 ///  - [POSIX]
@@ -255,9 +272,54 @@ pub fn statat<P: path::Arg, Fd: AsFd>(dirfd: Fd, path: P, flags: AtFlags) -> io:
     path.into_with_z_str(|path| imp::fs::syscalls::statat(dirfd.as_fd(), path, flags))
 }
 
+/// `fstatat(fd, "", AT_EMPTY_PATH)`—Queries metadata for the file `fd`
+/// refers to.
+///
+/// Unlike [`fstat`], this works on `OFlags::PATH` file descriptors on all
+/// Linux versions that support them. With `OFlags::PATH | OFlags::NOFOLLOW`,
+/// `fd` may refer to a symlink itself, in which case this returns the
+/// metadata of the symlink.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/fstatat.2.html
+/// [`fstat`]: crate::fs::fstat
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg(feature = "fs")]
+#[inline]
+pub fn statat_empty<Fd: AsFd>(fd: Fd) -> io::Result<Stat> {
+    imp::fs::syscalls::statat(fd.as_fd(), zstr!(""), AtFlags::EMPTY_PATH)
+}
+
+/// `readlinkat(fd, "")`—Reads the contents of the symlink `fd` refers to.
+///
+/// `fd` must have been opened with `OFlags::PATH | OFlags::NOFOLLOW` on a
+/// symlink, and this fails with `NOENT` otherwise. Together with
+/// [`statat_empty`], this allows inspecting a symlink through a handle,
+/// without looking up its path again.
+///
+/// If `reuse` is non-empty, reuse its buffer to store the result if possible.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/readlinkat.2.html
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg(feature = "fs")]
+#[inline]
+pub fn readlinkat_empty<Fd: AsFd, B: Into<Vec<u8>>>(fd: Fd, reuse: B) -> io::Result<ZString> {
+    _readlinkat(fd.as_fd(), zstr!(""), reuse.into())
+}
+
 /// `faccessat(dirfd, path, access, flags)`—Tests permissions for a file or
 /// directory.
 ///
+/// On Linux, `faccessat` has no flags argument, so flags are passed to
+/// `faccessat2`, which requires Linux 5.8 or later. The exception is
+/// [`AtFlags::EACCESS`] in a process whose real and effective IDs are the
+/// same, which can use `faccessat` since the two checks are equivalent.
+///
 /// # References
 ///  - [POSIX]
 ///  - [Linux]
@@ -294,13 +356,16 @@ pub fn utimensat<P: path::Arg, Fd: AsFd>(
     path.into_with_z_str(|path| imp::fs::syscalls::utimensat(dirfd.as_fd(), path, times, flags))
 }
 
-/// `fchmodat(dirfd, path, mode, 0)`—Sets file or directory permissions.
+/// `fchmodat(dirfd, path, mode, flags)`—Sets file or directory permissions.
 ///
-/// The flags argument is fixed to 0, so `AT_SYMLINK_NOFOLLOW` is not
-/// supported. <details>Platform support for this flag varies widely.</details>
+/// Linux's `fchmodat` has no flags argument, so on Linux,
+/// `AT_SYMLINK_NOFOLLOW` is emulated by opening `path` with `O_PATH` and
+/// changing the permissions through `/proc/self/fd`, which requires procfs to
+/// be mounted. Linux doesn't support permissions on symlinks, so if `path`
+/// names a symlink, this fails with [`io::Errno::OPNOTSUPP`].
 ///
-/// This implementation does not support `O_PATH` file descriptors, even on
-/// platforms where the host libc emulates it.
+/// This implementation does not support `O_PATH` file descriptors as
+/// `dirfd`, even on platforms where the host libc emulates it.
 ///
 /// # References
 ///  - [POSIX]
@@ -311,8 +376,48 @@ pub fn utimensat<P: path::Arg, Fd: AsFd>(
 #[cfg(not(target_os = "wasi"))]
 #[inline]
 #[doc(alias = "fchmodat")]
-pub fn chmodat<P: path::Arg, Fd: AsFd>(dirfd: Fd, path: P, mode: Mode) -> io::Result<()> {
-    path.into_with_z_str(|path| imp::fs::syscalls::chmodat(dirfd.as_fd(), path, mode))
+pub fn chmodat<P: path::Arg, Fd: AsFd>(
+    dirfd: Fd,
+    path: P,
+    mode: Mode,
+    flags: AtFlags,
+) -> io::Result<()> {
+    path.into_with_z_str(|path| _chmodat(dirfd.as_fd(), path, mode, flags))
+}
+
+#[cfg(not(any(target_os = "android", target_os = "linux", target_os = "wasi")))]
+fn _chmodat(dirfd: BorrowedFd<'_>, path: &ZStr, mode: Mode, flags: AtFlags) -> io::Result<()> {
+    imp::fs::syscalls::chmodat(dirfd, path, mode, flags)
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+fn _chmodat(dirfd: BorrowedFd<'_>, path: &ZStr, mode: Mode, flags: AtFlags) -> io::Result<()> {
+    if flags.is_empty() {
+        return imp::fs::syscalls::chmodat(dirfd, path, mode);
+    }
+    if flags != AtFlags::SYMLINK_NOFOLLOW {
+        return Err(io::Errno::INVAL);
+    }
+
+    // Emulate `AT_SYMLINK_NOFOLLOW` in the same way that libc
+    // implementations do.
+    let fd = imp::fs::syscalls::openat(
+        dirfd,
+        path,
+        OFlags::PATH | OFlags::NOFOLLOW | OFlags::CLOEXEC,
+        Mode::empty(),
+    )?;
+    let stat = imp::fs::syscalls::fstat(fd.as_fd())?;
+    if FileType::from_raw_mode(stat.st_mode) == FileType::Symlink {
+        return Err(io::Errno::OPNOTSUPP);
+    }
+
+    // `fchmod` doesn't work on `O_PATH` file descriptors, but `chmod` on
+    // their `/proc/self/fd` entry does.
+    let proc_path = alloc::format!("/proc/self/fd/{}", fd.as_raw_fd());
+    path::Arg::into_with_z_str(proc_path, |proc_path| {
+        imp::fs::syscalls::chmodat(crate::fs::cwd(), proc_path, mode)
+    })
 }
 
 /// `fclonefileat(src, dst_dir, dst, flags)`—Efficiently copies between files.
@@ -342,7 +447,12 @@ pub fn fclonefileat<Fd: AsFd, DstFd: AsFd, P: path::Arg>(
 ///
 /// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/mknodat.html
 /// [Linux]: https://man7.org/linux/man-pages/man2/mknodat.2.html
-#[cfg(not(any(target_os = "ios", target_os = "macos", target_os = "wasi")))]
+#[cfg(not(any(
+    target_os = "ios",
+    target_os = "macos",
+    target_os = "redox",
+    target_os = "wasi",
+)))]
 #[inline]
 pub fn mknodat<P: path::Arg, Fd: AsFd>(
     dirfd: Fd,
@@ -356,9 +466,37 @@ pub fn mknodat<P: path::Arg, Fd: AsFd>(
     })
 }
 
+/// `mkfifoat(dirfd, path, mode)`—Creates a FIFO.
+///
+/// This is equivalent to calling [`mknodat`] with [`FileType::Fifo`]. The
+/// resulting FIFO must then be opened for reading and for writing before data
+/// can be passed through it; by default, opening either end blocks until the
+/// other end is also opened.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/mkfifoat.html
+/// [Linux]: https://man7.org/linux/man-pages/man3/mkfifoat.3.html
+#[cfg(not(any(
+    target_os = "ios",
+    target_os = "macos",
+    target_os = "redox",
+    target_os = "wasi",
+)))]
+#[inline]
+pub fn mkfifoat<P: path::Arg, Fd: AsFd>(dirfd: Fd, path: P, mode: Mode) -> io::Result<()> {
+    mknodat(dirfd, path, FileType::Fifo, mode, 0)
+}
+
 /// `fchownat(dirfd, path, owner, group, flags)`—Sets file or directory
 /// ownership.
 ///
+/// A `None` for `owner` or `group` leaves that id unchanged. With
+/// [`AtFlags::SYMLINK_NOFOLLOW`], a symlink itself is changed rather than the
+/// file it refers to, as with `lchown`.
+///
 /// # References
 ///  - [POSIX]
 ///  - [Linux]
@@ -370,8 +508,8 @@ pub fn mknodat<P: path::Arg, Fd: AsFd>(
 pub fn chownat<P: path::Arg, Fd: AsFd>(
     dirfd: Fd,
     path: P,
-    owner: Uid,
-    group: Gid,
+    owner: Option<Uid>,
+    group: Option<Gid>,
     flags: AtFlags,
 ) -> io::Result<()> {
     path.into_with_z_str(|path| {
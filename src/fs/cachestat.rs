@@ -0,0 +1,51 @@
+use crate::fd::AsFd;
+use crate::{imp, io};
+
+/// `struct cachestat_range`—A byte range for use with [`cachestat`].
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash)]
+pub struct CachestatRange {
+    /// The offset of the start of the range, in bytes.
+    pub off: u64,
+
+    /// The length of the range, in bytes, or 0 for the rest of the file.
+    pub len: u64,
+}
+
+/// `struct cachestat`—Page cache statistics returned by [`cachestat`].
+///
+/// All counts are in pages.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash)]
+pub struct Cachestat {
+    /// The number of cached pages.
+    pub nr_cache: u64,
+
+    /// The number of dirty pages.
+    pub nr_dirty: u64,
+
+    /// The number of pages marked for writeback.
+    pub nr_writeback: u64,
+
+    /// The number of pages evicted from the cache.
+    pub nr_evicted: u64,
+
+    /// The number of pages evicted from the cache recently enough that
+    /// accessing them again would count as thrashing.
+    pub nr_recently_evicted: u64,
+}
+
+/// `cachestat(fd, range, &cstat, 0)`—Return page cache statistics for a
+/// range of a file.
+///
+/// This fails with [`io::Errno::NOSYS`] before Linux 6.5, and with
+/// [`io::Errno::BADF`] if `fd` refers to a hugetlbfs file.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/cachestat.2.html
+#[inline]
+pub fn cachestat<Fd: AsFd>(fd: Fd, range: &CachestatRange) -> io::Result<Cachestat> {
+    imp::fs::syscalls::cachestat(fd.as_fd(), range)
+}
@@ -0,0 +1,215 @@
+//! A recursive directory copy, built on the `*at` functions.
+
+use crate::fd::{AsFd, BorrowedFd};
+use crate::ffi::ZStr;
+use crate::fs::{
+    copy_file_range, fchmod, futimens, mkdirat, mknodat, openat, readlinkat, statat, symlinkat,
+    utimensat, AtFlags, Dir, FileType, Mode, OFlags, Stat, Timespec, Timestamps,
+};
+use crate::io::{self, read, write, OwnedFd};
+use crate::path;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// The number of bytes to ask `copy_file_range` to copy at a time.
+const COPY_CHUNK: u64 = 1 << 30;
+
+/// The size of the buffer used when falling back to `read` and `write`.
+const BUFFER_SIZE: usize = 64 * 1024;
+
+/// Recursively copy the directory at `src_path` to a new directory at
+/// `dst_path`.
+///
+/// Directories are created with `mkdirat` and read with [`Dir`]. Regular
+/// files are copied with [`copy_file_range`], falling back to `read` and
+/// `write` where it isn't supported, such as across filesystems on older
+/// kernels. Symlinks are recreated with `symlinkat` rather than followed,
+/// and other special files are recreated with `mknodat`. The permission bits
+/// and the access and modification times of everything copied are
+/// preserved; ownership, extended attributes, and hard links are not.
+///
+/// `dst_path` must not exist. If it's inside the tree being copied, the
+/// copy skips over it. If the source tree contains a loop, such as from a
+/// bind mount, this fails with [`io::Errno::LOOP`].
+///
+/// On failure, whatever was copied before the error is left in place.
+pub fn copy_tree<SrcFd: AsFd, SrcP: path::Arg, DstFd: AsFd, DstP: path::Arg>(
+    src_dirfd: SrcFd,
+    src_path: SrcP,
+    dst_dirfd: DstFd,
+    dst_path: DstP,
+) -> io::Result<()> {
+    src_path.into_with_z_str(|src_path| {
+        dst_path.into_with_z_str(|dst_path| {
+            _copy_tree(src_dirfd.as_fd(), src_path, dst_dirfd.as_fd(), dst_path)
+        })
+    })
+}
+
+fn _copy_tree(
+    src_dirfd: BorrowedFd<'_>,
+    src_path: &ZStr,
+    dst_dirfd: BorrowedFd<'_>,
+    dst_path: &ZStr,
+) -> io::Result<()> {
+    let src = open_dir(src_dirfd, src_path)?;
+    let src_stat = statat(&src, zstr!(""), AtFlags::EMPTY_PATH)?;
+    mkdirat(dst_dirfd, dst_path, Mode::RWXU)?;
+    let dst = open_dir(dst_dirfd, dst_path)?;
+    let dst_stat = statat(&dst, zstr!(""), AtFlags::EMPTY_PATH)?;
+
+    let mut copier = Copier {
+        ancestors: vec![file_id(&src_stat)],
+        dst_root: file_id(&dst_stat),
+        buf: Vec::new(),
+    };
+    copier.copy_dir_contents(src.as_fd(), dst.as_fd())?;
+    copy_metadata(&dst, &src_stat)
+}
+
+struct Copier {
+    /// The `(st_dev, st_ino)` of each source directory being copied.
+    ancestors: Vec<(u64, u64)>,
+
+    /// The `(st_dev, st_ino)` of the destination directory, to skip it if
+    /// it's inside the source tree.
+    dst_root: (u64, u64),
+
+    /// The buffer for the `read` and `write` fallback, allocated on first
+    /// use.
+    buf: Vec<u8>,
+}
+
+impl Copier {
+    fn copy_dir_contents(&mut self, src: BorrowedFd<'_>, dst: BorrowedFd<'_>) -> io::Result<()> {
+        for entry in Dir::read_from(src)? {
+            let entry = entry?;
+            let name = entry.file_name();
+            if name == zstr!(".") || name == zstr!("..") {
+                continue;
+            }
+
+            let stat = statat(src, name, AtFlags::SYMLINK_NOFOLLOW)?;
+            match FileType::from_raw_mode(stat.st_mode as _) {
+                FileType::Directory => {
+                    let id = file_id(&stat);
+                    if id == self.dst_root {
+                        continue;
+                    }
+                    if self.ancestors.contains(&id) {
+                        return Err(io::Errno::LOOP);
+                    }
+
+                    let src_child = open_dir(src, name)?;
+                    mkdirat(dst, name, Mode::RWXU)?;
+                    let dst_child = open_dir(dst, name)?;
+
+                    self.ancestors.push(id);
+                    self.copy_dir_contents(src_child.as_fd(), dst_child.as_fd())?;
+                    self.ancestors.pop();
+
+                    // Set the mode last, in case it doesn't permit writing.
+                    copy_metadata(&dst_child, &stat)?;
+                }
+                FileType::RegularFile => {
+                    let src_file = openat(
+                        src,
+                        name,
+                        OFlags::RDONLY | OFlags::NOFOLLOW | OFlags::CLOEXEC,
+                        Mode::empty(),
+                    )?;
+                    let dst_file = openat(
+                        dst,
+                        name,
+                        OFlags::WRONLY | OFlags::CREATE | OFlags::EXCL | OFlags::CLOEXEC,
+                        Mode::RUSR | Mode::WUSR,
+                    )?;
+                    self.copy_file_data(src_file.as_fd(), dst_file.as_fd())?;
+                    copy_metadata(&dst_file, &stat)?;
+                }
+                FileType::Symlink => {
+                    let target = readlinkat(src, name, Vec::new())?;
+                    symlinkat(&*target, dst, name)?;
+                    utimensat(dst, name, &timestamps(&stat), AtFlags::SYMLINK_NOFOLLOW)?;
+                }
+                file_type => {
+                    let mode = Mode::from_raw_mode(stat.st_mode as _);
+                    mknodat(dst, name, file_type, mode, stat.st_rdev as _)?;
+                    utimensat(dst, name, &timestamps(&stat), AtFlags::SYMLINK_NOFOLLOW)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn copy_file_data(&mut self, src: BorrowedFd<'_>, dst: BorrowedFd<'_>) -> io::Result<()> {
+        // Try `copy_file_range` first, which lets the filesystem share or
+        // offload the copy. It uses and updates the file offsets, so if it
+        // fails partway, the fallback picks up where it left off.
+        loop {
+            match copy_file_range(src, None, dst, None, COPY_CHUNK) {
+                Ok(0) => return Ok(()),
+                Ok(_) => (),
+                Err(io::Errno::INTR) => (),
+                Err(io::Errno::XDEV)
+                | Err(io::Errno::NOSYS)
+                | Err(io::Errno::INVAL)
+                | Err(io::Errno::OPNOTSUPP)
+                | Err(io::Errno::PERM) => break,
+                Err(err) => return Err(err),
+            }
+        }
+
+        if self.buf.is_empty() {
+            self.buf.resize(BUFFER_SIZE, 0);
+        }
+        loop {
+            let nread = match read(src, &mut self.buf) {
+                Ok(0) => return Ok(()),
+                Ok(nread) => nread,
+                Err(io::Errno::INTR) => continue,
+                Err(err) => return Err(err),
+            };
+            let mut data = &self.buf[..nread];
+            while !data.is_empty() {
+                match write(dst, data) {
+                    Ok(nwritten) => data = &data[nwritten..],
+                    Err(io::Errno::INTR) => (),
+                    Err(err) => return Err(err),
+                }
+            }
+        }
+    }
+}
+
+fn open_dir(dirfd: BorrowedFd<'_>, path: &ZStr) -> io::Result<OwnedFd> {
+    openat(
+        dirfd,
+        path,
+        OFlags::RDONLY | OFlags::DIRECTORY | OFlags::NOFOLLOW | OFlags::CLOEXEC,
+        Mode::empty(),
+    )
+}
+
+/// Set the permission bits and timestamps of `fd` to those in `stat`.
+fn copy_metadata(fd: &OwnedFd, stat: &Stat) -> io::Result<()> {
+    fchmod(fd, Mode::from_raw_mode(stat.st_mode as _))?;
+    futimens(fd, &timestamps(stat))
+}
+
+fn timestamps(stat: &Stat) -> Timestamps {
+    Timestamps {
+        last_access: Timespec {
+            tv_sec: stat.st_atime as _,
+            tv_nsec: stat.st_atime_nsec as _,
+        },
+        last_modification: Timespec {
+            tv_sec: stat.st_mtime as _,
+            tv_nsec: stat.st_mtime_nsec as _,
+        },
+    }
+}
+
+fn file_id(stat: &Stat) -> (u64, u64) {
+    (stat.st_dev as _, stat.st_ino as _)
+}
@@ -0,0 +1,209 @@
+//! fanotify support for monitoring filesystem events.
+//!
+//! Most fanotify operations require the `CAP_SYS_ADMIN` capability; without
+//! it, [`init`] fails with [`io::Errno::PERM`].
+//!
+//! # Safety
+//!
+//! Each event read from a fanotify instance carries a newly opened file
+//! descriptor, which [`Reader`] takes ownership of.
+//!
+//! # References
+//!  - [Linux]
+//!
+//! [Linux]: https://man7.org/linux/man-pages/man7/fanotify.7.html
+
+#![allow(unsafe_code)]
+
+use crate::fd::{AsFd, AsRawFd, BorrowedFd, FromRawFd};
+use crate::fs::OFlags;
+use crate::io::{self, OwnedFd};
+use crate::{imp, path};
+use core::convert::TryInto;
+
+pub use imp::fs::fanotify::{EventFlags, FanotifyResponse, InitFlags, MarkFlags};
+
+/// `fanotify_init(flags, event_flags)`—Create a new fanotify instance.
+///
+/// `event_flags` are the file status flags for the file descriptors
+/// which are opened for events.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/fanotify_init.2.html
+#[inline]
+#[doc(alias = "fanotify_init")]
+pub fn init(flags: InitFlags, event_flags: OFlags) -> io::Result<OwnedFd> {
+    imp::fs::syscalls::fanotify_init(flags, event_flags)
+}
+
+/// `fanotify_mark(fd, flags, mask, dirfd, path)`—Add, remove, or modify a
+/// mark on a filesystem object.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/fanotify_mark.2.html
+#[inline]
+#[doc(alias = "fanotify_mark")]
+pub fn mark<Fd: AsFd, DirFd: AsFd, P: path::Arg>(
+    fd: Fd,
+    flags: MarkFlags,
+    mask: EventFlags,
+    dirfd: DirFd,
+    path: P,
+) -> io::Result<()> {
+    let (fd, dirfd) = (fd.as_fd(), dirfd.as_fd());
+    path.into_with_z_str(|path| {
+        imp::fs::syscalls::fanotify_mark(fd, flags, mask, dirfd, Some(path))
+    })
+}
+
+/// `fanotify_mark(fd, flags, mask, target, NULL)`—Add, remove, or modify a
+/// mark on the filesystem object referred to by `target`.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/fanotify_mark.2.html
+#[inline]
+pub fn mark_fd<Fd: AsFd, TargetFd: AsFd>(
+    fd: Fd,
+    flags: MarkFlags,
+    mask: EventFlags,
+    target: TargetFd,
+) -> io::Result<()> {
+    imp::fs::syscalls::fanotify_mark(fd.as_fd(), flags, mask, target.as_fd(), None)
+}
+
+/// Respond to a permission event.
+///
+/// `event_fd` is the file descriptor from the [`FanotifyEvent`] being
+/// responded to.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man7/fanotify.7.html
+pub fn respond<Fd: AsFd, EventFd: AsFd>(
+    fd: Fd,
+    event_fd: EventFd,
+    response: FanotifyResponse,
+) -> io::Result<()> {
+    // Encode a `struct fanotify_response`.
+    let mut buf = [0_u8; 8];
+    buf[..4].copy_from_slice(&event_fd.as_fd().as_raw_fd().to_ne_bytes());
+    buf[4..].copy_from_slice(&(response as u32).to_ne_bytes());
+    io::write(fd, &buf).map(|_| ())
+}
+
+/// The size of a `struct fanotify_event_metadata`.
+const EVENT_METADATA_SIZE: usize = 24;
+
+/// An event read from a fanotify instance by a [`Reader`].
+#[derive(Debug)]
+pub struct FanotifyEvent {
+    /// The events that occurred.
+    pub mask: EventFlags,
+
+    /// A file descriptor for the object the event is about, or `None` if
+    /// the event queue overflowed.
+    pub fd: Option<OwnedFd>,
+
+    /// The ID of the process which caused the event.
+    pub pid: i32,
+}
+
+/// A reader which decodes the events read from a fanotify instance.
+///
+/// Events remaining in the buffer when a `Reader` is dropped are discarded,
+/// and their file descriptors are closed.
+pub struct Reader<'buf, Fd: AsFd> {
+    fd: Fd,
+    buf: &'buf mut [u8],
+    len: usize,
+    offset: usize,
+}
+
+impl<'buf, Fd: AsFd> Reader<'buf, Fd> {
+    /// Create a new `Reader` which reads from `fd` into `buf`.
+    ///
+    /// `buf` must be large enough to hold at least one event; otherwise reads
+    /// fail with [`io::Errno::INVAL`].
+    #[inline]
+    pub fn new(fd: Fd, buf: &'buf mut [u8]) -> Self {
+        Self {
+            fd,
+            buf,
+            len: 0,
+            offset: 0,
+        }
+    }
+
+    /// Return the next event, reading more events from the file descriptor
+    /// when the buffer is exhausted.
+    ///
+    /// If the fanotify instance is non-blocking and no events are pending,
+    /// this fails with [`io::Errno::AGAIN`].
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> io::Result<FanotifyEvent> {
+        if self.is_buffer_empty() {
+            self.len = io::read(self.fd.as_fd(), self.buf)?;
+            self.offset = 0;
+        }
+
+        Ok(self.take_event())
+    }
+
+    /// Test whether all events in the buffer have been returned, so that the
+    /// next call to [`Reader::next`] will read from the file descriptor.
+    #[inline]
+    pub fn is_buffer_empty(&self) -> bool {
+        self.offset >= self.len
+    }
+
+    /// Return the file descriptor this reader reads from.
+    #[inline]
+    pub fn fd(&self) -> BorrowedFd<'_> {
+        self.fd.as_fd()
+    }
+
+    /// Decode the next event in the buffer, which must not be empty.
+    fn take_event(&mut self) -> FanotifyEvent {
+        let event = &self.buf[self.offset..self.len];
+        let event_len = u32::from_ne_bytes(event[0..4].try_into().unwrap()) as usize;
+        let mask = u64::from_ne_bytes(event[8..16].try_into().unwrap());
+        let fd = i32::from_ne_bytes(event[16..20].try_into().unwrap());
+        let pid = i32::from_ne_bytes(event[20..24].try_into().unwrap());
+
+        // Skip any information records following the metadata.
+        self.offset += event_len.max(EVENT_METADATA_SIZE);
+
+        // Negative values, such as `FAN_NOFD`, mean there's no file
+        // descriptor.
+        let fd = if fd < 0 {
+            None
+        } else {
+            // Safety: The kernel opened this file descriptor for the event,
+            // and we only return each event once.
+            Some(unsafe { OwnedFd::from_raw_fd(fd) })
+        };
+
+        FanotifyEvent {
+            mask: EventFlags::from_bits_truncate(mask),
+            fd,
+            pid,
+        }
+    }
+}
+
+impl<'buf, Fd: AsFd> Drop for Reader<'buf, Fd> {
+    fn drop(&mut self) {
+        // Close the file descriptors of any events that were read but not
+        // returned.
+        while !self.is_buffer_empty() {
+            drop(self.take_event());
+        }
+    }
+}
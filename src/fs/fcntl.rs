@@ -5,6 +5,8 @@
 
 use crate::imp;
 use crate::io::{self, OwnedFd};
+#[cfg(any(target_os = "android", target_os = "linux"))]
+use crate::process::{Pid, Signal};
 use imp::fd::{AsFd, RawFd};
 use imp::fs::types::{FdFlags, OFlags};
 
@@ -64,6 +66,98 @@ pub fn fcntl_setfl<Fd: AsFd>(fd: Fd, flags: OFlags) -> io::Result<()> {
     imp::fs::syscalls::fcntl_setfl(fd.as_fd(), flags)
 }
 
+/// `fcntl(fd, F_SETFL, fcntl(fd, F_GETFL) | O_NONBLOCK)`—Enables or
+/// disables non-blocking mode.
+///
+/// This reads the current status flags and changes only `O_NONBLOCK`,
+/// preserving other flags such as `O_APPEND`. If the flag is already in the
+/// requested state, the flags are left unmodified.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/fcntl.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/fcntl.2.html
+#[inline]
+#[doc(alias = "O_NONBLOCK")]
+pub fn set_nonblocking<Fd: AsFd>(fd: Fd, nonblocking: bool) -> io::Result<()> {
+    let fd = fd.as_fd();
+    let flags = imp::fs::syscalls::fcntl_getfl(fd)?;
+    if flags.contains(OFlags::NONBLOCK) == nonblocking {
+        return Ok(());
+    }
+    let mut new_flags = flags;
+    new_flags.set(OFlags::NONBLOCK, nonblocking);
+    imp::fs::syscalls::fcntl_setfl(fd, new_flags)
+}
+
+/// The owner of a file descriptor, which receives its I/O signals, for use
+/// with [`fcntl_setown`] and [`fcntl_getown`].
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Owner {
+    /// `F_OWNER_PID`—A process.
+    Pid(Pid),
+
+    /// `F_OWNER_PGRP`—Every process in a process group.
+    ProcessGroup(Pid),
+
+    /// `F_OWNER_TID`—A single thread.
+    Thread(Pid),
+}
+
+/// `fcntl(fd, F_SETOWN_EX, owner)`—Sets the owner of a file descriptor.
+///
+/// With `O_ASYNC` set on the file description using [`fcntl_setfl`], the
+/// owner is sent a signal when I/O becomes possible on `fd`. This is `SIGIO`
+/// unless another signal is chosen with [`fcntl_setsig`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/fcntl.2.html
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "F_SETOWN")]
+#[doc(alias = "F_SETOWN_EX")]
+pub fn fcntl_setown<Fd: AsFd>(fd: Fd, owner: Owner) -> io::Result<()> {
+    imp::fs::syscalls::fcntl_setown_ex(fd.as_fd(), owner)
+}
+
+/// `fcntl(fd, F_GETOWN_EX)`—Returns the owner of a file descriptor, or
+/// `None` if it doesn't have one.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/fcntl.2.html
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "F_GETOWN")]
+#[doc(alias = "F_GETOWN_EX")]
+pub fn fcntl_getown<Fd: AsFd>(fd: Fd) -> io::Result<Option<Owner>> {
+    imp::fs::syscalls::fcntl_getown_ex(fd.as_fd())
+}
+
+/// `fcntl(fd, F_SETSIG, sig)`—Sets the signal sent to the owner of a file
+/// descriptor when I/O becomes possible.
+///
+/// `None` restores the default, `SIGIO`. Choosing any signal, even `SIGIO`,
+/// makes a handler installed with `SA_SIGINFO` receive the file descriptor
+/// in `si_fd`.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/fcntl.2.html
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "F_SETSIG")]
+pub fn fcntl_setsig<Fd: AsFd>(fd: Fd, sig: Option<Signal>) -> io::Result<()> {
+    imp::fs::syscalls::fcntl_setsig(fd.as_fd(), sig)
+}
+
 /// `fcntl(fd, F_GET_SEALS)`
 ///
 /// # References
@@ -117,10 +211,15 @@ pub fn fcntl_add_seals<Fd: AsFd>(fd: Fd, seals: SealFlags) -> io::Result<()> {
 /// rely on this, as file descriptors may be unexpectedly allocated on other
 /// threads or in libraries.
 ///
+/// Unlike [`dup`], this sets `O_CLOEXEC` and never returns a file descriptor
+/// lower than `min`, which makes it useful for moving file descriptors out of
+/// the range reserved for stdio.
+///
 /// # References
 ///  - [POSIX]
 ///  - [Linux]
 ///
+/// [`dup`]: crate::io::dup
 /// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/fcntl.html
 /// [Linux]: https://man7.org/linux/man-pages/man2/fcntl.2.html
 #[cfg(not(target_os = "wasi"))]
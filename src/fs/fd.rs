@@ -2,7 +2,7 @@
 
 #[cfg(not(target_os = "wasi"))]
 use crate::fs::Mode;
-use crate::io::SeekFrom;
+use crate::fs::SeekFrom;
 #[cfg(not(target_os = "wasi"))]
 use crate::process::{Gid, Uid};
 use crate::{imp, io};
@@ -69,6 +69,10 @@ pub const NFS_SUPER_MAGIC: FsWord = imp::fs::types::NFS_SUPER_MAGIC;
 
 /// `lseek(fd, offset, whence)`—Repositions a file descriptor within a file.
 ///
+/// `SeekFrom::Data` and `SeekFrom::Hole` may be used to skip over holes in
+/// sparse files. Seeking with `SeekFrom::Data` past the last region of data
+/// fails with [`io::Errno::NXIO`].
+///
 /// # References
 ///  - [POSIX]
 ///  - [Linux]
@@ -102,6 +106,11 @@ pub fn tell<Fd: AsFd>(fd: Fd) -> io::Result<u64> {
 /// This implementation does not support `O_PATH` file descriptors, even on
 /// platforms where the host libc emulates it.
 ///
+/// Unlike `chmodat`, this doesn't need a path, so it works on files which
+/// don't have one, such as memfds and files opened with `OFlags::TMPFILE`.
+/// This makes it possible to set a file's final permissions before it's
+/// linked into place with `linkat`.
+///
 /// # References
 ///  - [POSIX]
 ///  - [Linux]
@@ -114,7 +123,9 @@ pub fn fchmod<Fd: AsFd>(fd: Fd, mode: Mode) -> io::Result<()> {
     imp::fs::syscalls::fchmod(fd.as_fd(), mode)
 }
 
-/// `fchown(fd)`—Sets open file or directory ownership.
+/// `fchown(fd, owner, group)`—Sets open file or directory ownership.
+///
+/// A `None` for `owner` or `group` leaves that id unchanged.
 ///
 /// # References
 ///  - [POSIX]
@@ -124,7 +135,7 @@ pub fn fchmod<Fd: AsFd>(fd: Fd, mode: Mode) -> io::Result<()> {
 /// [Linux]: https://man7.org/linux/man-pages/man2/fchown.2.html
 #[cfg(not(target_os = "wasi"))]
 #[inline]
-pub fn fchown<Fd: AsFd>(fd: Fd, owner: Uid, group: Gid) -> io::Result<()> {
+pub fn fchown<Fd: AsFd>(fd: Fd, owner: Option<Uid>, group: Option<Gid>) -> io::Result<()> {
     imp::fs::syscalls::fchown(fd.as_fd(), owner, group)
 }
 
@@ -204,6 +215,36 @@ pub fn fallocate<Fd: AsFd>(fd: Fd, mode: FallocateFlags, offset: u64, len: u64)
     imp::fs::syscalls::fallocate(fd.as_fd(), mode, offset, len)
 }
 
+/// `fallocate(fd, 0, offset, len)`—Allocates storage for a range of a file.
+///
+/// This has the semantics of `posix_fallocate`: it ensures that storage is
+/// allocated for the bytes in `offset..offset + len`, extending the file if
+/// it's shorter than `offset + len`. Unlike [`fallocate`], it takes no
+/// flags, so it never punches holes, zeroes, or collapses ranges, and never
+/// shrinks the file.
+///
+/// Unlike glibc's `posix_fallocate`, this doesn't emulate allocation by
+/// writing zeros when the filesystem doesn't support it; it fails with
+/// [`io::Errno::OPNOTSUPP`] instead, so that callers can decide what to do.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux `fallocate`]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/posix_fallocate.html
+/// [Linux `fallocate`]: https://man7.org/linux/man-pages/man2/fallocate.2.html
+#[cfg(not(any(
+    target_os = "dragonfly",
+    target_os = "illumos",
+    target_os = "netbsd",
+    target_os = "openbsd",
+    target_os = "redox"
+)))] // not implemented in libc for netbsd yet
+#[inline]
+pub fn fallocate_reserve<Fd: AsFd>(fd: Fd, offset: u64, len: u64) -> io::Result<()> {
+    imp::fs::syscalls::fallocate(fd.as_fd(), FallocateFlags::empty(), offset, len)
+}
+
 /// `fcntl(fd, F_GETFL) & O_ACCMODE`
 ///
 /// Returns a pair of booleans indicating whether the file descriptor is
@@ -260,6 +301,11 @@ pub fn fsync<Fd: AsFd>(fd: Fd) -> io::Result<()> {
 /// `fdatasync(fd)`—Ensures that file data is written to the underlying
 /// storage device.
 ///
+/// Unlike [`fsync`], this only writes metadata which is needed to read the
+/// data back, such as the file size, and skips the rest, such as
+/// timestamps. This can make it much faster for workloads which overwrite
+/// or append to files.
+///
 /// # References
 ///  - [POSIX]
 ///  - [Linux]
@@ -277,8 +323,33 @@ pub fn fdatasync<Fd: AsFd>(fd: Fd) -> io::Result<()> {
     imp::fs::syscalls::fdatasync(fd.as_fd())
 }
 
+/// `fsync(dirfd)`—Ensures that the entries of a directory are written to
+/// the underlying storage device.
+///
+/// Call this on the directory containing a file after creating, renaming,
+/// linking, or unlinking it, to durably persist the directory entry.
+/// [`fsync`] or `fdatasync` on the file itself persists the file's
+/// contents, but not necessarily its name; after a crash, a newly created
+/// file may otherwise be missing, or a rename may be undone.
+///
+/// On Linux, `dirfd` must not be opened with `O_PATH`.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/fsync.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/fsync.2.html
+#[inline]
+#[doc(alias = "fsync")]
+pub fn fsync_parent_dir<Fd: AsFd>(dirfd: Fd) -> io::Result<()> {
+    imp::fs::syscalls::fsync(dirfd.as_fd())
+}
+
 /// `ftruncate(fd, length)`—Sets the length of a file.
 ///
+/// If `fd` isn't open for writing, this fails with [`io::Errno::INVAL`].
+///
 /// # References
 ///  - [POSIX]
 ///  - [Linux]
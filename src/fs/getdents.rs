@@ -0,0 +1,229 @@
+//! The Linux `getdents64` syscall, and a decoder for its output.
+//!
+//! Unlike [`Dir`], this leaves buffering to the caller, which is useful
+//! when driving directory reads from an event loop.
+//!
+//! [`Dir`]: crate::fs::Dir
+
+use crate::fd::{AsFd, BorrowedFd};
+use crate::ffi::ZStr;
+use crate::fs::{seek, Dir, FileType, SeekFrom};
+use crate::{imp, io};
+use core::fmt;
+
+/// Offsets of the fields in `struct linux_dirent64`, which has the same
+/// layout on all architectures.
+const OFFSETOF_D_INO: usize = 0;
+const OFFSETOF_D_OFF: usize = 8;
+const OFFSETOF_D_RECLEN: usize = 16;
+const OFFSETOF_D_TYPE: usize = 18;
+const OFFSETOF_D_NAME: usize = 19;
+
+/// `getdents64(fd, buf, buf.len())`—Reads directory entries from `fd`
+/// into `buf`.
+///
+/// `buf` is filled with packed `struct linux_dirent64` records, which can
+/// be decoded with [`RawDirEntries`]. Returns the number of bytes written,
+/// or 0 at the end of the directory. If `buf` is too small to hold the
+/// next entry, this fails with [`io::Errno::INVAL`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/getdents64.2.html
+#[inline]
+#[doc(alias = "getdents64")]
+pub fn getdents<Fd: AsFd>(fd: Fd, buf: &mut [u8]) -> io::Result<usize> {
+    imp::fs::syscalls::getdents(fd.as_fd(), buf)
+}
+
+/// An iterator over the `struct linux_dirent64` records in a buffer filled
+/// by [`getdents`].
+///
+/// Iteration stops at the end of the buffer, or at the first record that
+/// doesn't fit within it.
+#[derive(Clone, Debug)]
+pub struct RawDirEntries<'a> {
+    buf: &'a [u8],
+}
+
+impl<'a> RawDirEntries<'a> {
+    /// Construct a `RawDirEntries` which decodes the records in `buf`.
+    ///
+    /// `buf` should be the prefix of a buffer passed to [`getdents`], with
+    /// the length it returned.
+    #[inline]
+    pub const fn new(buf: &'a [u8]) -> Self {
+        Self { buf }
+    }
+}
+
+impl<'a> Iterator for RawDirEntries<'a> {
+    type Item = RawDirEntry<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let buf = self.buf;
+        if buf.len() <= OFFSETOF_D_NAME {
+            return None;
+        }
+
+        // Do an unaligned u16 load.
+        let d_reclen = u16::from_ne_bytes([buf[OFFSETOF_D_RECLEN], buf[OFFSETOF_D_RECLEN + 1]]);
+        let reclen = d_reclen as usize;
+        if reclen <= OFFSETOF_D_NAME || reclen > buf.len() {
+            self.buf = &[];
+            return None;
+        }
+        let (record, rest) = buf.split_at(reclen);
+        self.buf = rest;
+
+        // The name is NUL-terminated, and followed by padding.
+        let name = &record[OFFSETOF_D_NAME..];
+        let name_len = match name.iter().position(|x| *x == b'\0') {
+            Some(name_len) => name_len,
+            None => {
+                self.buf = &[];
+                return None;
+            }
+        };
+        let name = ZStr::from_bytes_with_nul(&name[..=name_len]).unwrap();
+
+        // Do unaligned u64 loads.
+        let mut d_ino = [0_u8; 8];
+        d_ino.copy_from_slice(&record[OFFSETOF_D_INO..OFFSETOF_D_INO + 8]);
+        let mut d_off = [0_u8; 8];
+        d_off.copy_from_slice(&record[OFFSETOF_D_OFF..OFFSETOF_D_OFF + 8]);
+
+        Some(RawDirEntry {
+            d_ino: u64::from_ne_bytes(d_ino),
+            d_off: i64::from_ne_bytes(d_off),
+            d_reclen,
+            d_type: record[OFFSETOF_D_TYPE],
+            name,
+        })
+    }
+}
+
+/// A `struct linux_dirent64` record, borrowed from a buffer filled by
+/// [`getdents`].
+#[derive(Clone, Copy, Debug)]
+pub struct RawDirEntry<'a> {
+    d_ino: u64,
+    d_off: i64,
+    d_reclen: u16,
+    d_type: u8,
+    name: &'a ZStr,
+}
+
+impl<'a> RawDirEntry<'a> {
+    /// Returns the file name of this directory entry.
+    #[inline]
+    pub fn file_name(&self) -> &'a ZStr {
+        self.name
+    }
+
+    /// Returns the type of this directory entry.
+    #[inline]
+    pub fn file_type(&self) -> FileType {
+        FileType::from_dirent_d_type(self.d_type)
+    }
+
+    /// Returns the raw `d_type` of this directory entry.
+    #[inline]
+    pub fn d_type(&self) -> u8 {
+        self.d_type
+    }
+
+    /// Return the inode number of this directory entry.
+    #[inline]
+    pub fn ino(&self) -> u64 {
+        self.d_ino
+    }
+
+    /// Returns the `d_off` of this directory entry, an opaque position
+    /// which can be passed to `seek` with `SeekFrom::Start` to resume
+    /// reading after this entry.
+    #[inline]
+    pub fn next_offset(&self) -> i64 {
+        self.d_off
+    }
+
+    /// Returns the length of this record in the buffer, including padding.
+    #[inline]
+    pub fn reclen(&self) -> u16 {
+        self.d_reclen
+    }
+}
+
+impl Dir {
+    /// Construct a [`DirBorrow`] that reads entries from the given directory
+    /// file descriptor into `buf`, without allocating.
+    ///
+    /// Unlike [`Dir::read_from`], this reads from `fd` itself, so it shares
+    /// `fd`'s position. `buf` must be large enough to hold at least one
+    /// entry; a few hundred bytes suffices for any file name.
+    #[inline]
+    pub fn new_in<'a>(fd: BorrowedFd<'a>, buf: &'a mut [u8]) -> DirBorrow<'a> {
+        DirBorrow {
+            fd,
+            buf,
+            pos: 0,
+            len: 0,
+        }
+    }
+}
+
+/// A directory reader which reads entries into a caller-provided buffer.
+///
+/// This is created by [`Dir::new_in`]. Entries borrow from the buffer, so
+/// each one is only valid until the next call to [`DirBorrow::read`].
+pub struct DirBorrow<'a> {
+    fd: BorrowedFd<'a>,
+    buf: &'a mut [u8],
+    pos: usize,
+    len: usize,
+}
+
+impl<'a> DirBorrow<'a> {
+    /// `readdir(self)`, where `None` means the end of the directory.
+    pub fn read(&mut self) -> Option<io::Result<RawDirEntry<'_>>> {
+        // Test if we've consumed all the entries, and if so, read more.
+        if self.pos >= self.len {
+            match getdents(self.fd, self.buf) {
+                Ok(0) => return None,
+                Ok(nread) => {
+                    self.pos = 0;
+                    self.len = nread;
+                }
+                Err(err) => return Some(Err(err)),
+            }
+        }
+
+        let entry = RawDirEntries::new(&self.buf[self.pos..self.len]).next();
+        match entry {
+            Some(entry) => {
+                self.pos += entry.reclen() as usize;
+                Some(Ok(entry))
+            }
+            None => {
+                self.pos = self.len;
+                Some(Err(io::Errno::IO))
+            }
+        }
+    }
+
+    /// `rewinddir(self)`
+    #[inline]
+    pub fn rewind(&mut self) -> io::Result<()> {
+        seek(self.fd, SeekFrom::Start(0))?;
+        self.pos = 0;
+        self.len = 0;
+        Ok(())
+    }
+}
+
+impl<'a> fmt::Debug for DirBorrow<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DirBorrow").field("fd", &self.fd).finish()
+    }
+}
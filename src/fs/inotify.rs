@@ -0,0 +1,155 @@
+//! inotify support for watching for filesystem events.
+//!
+//! # References
+//!  - [Linux]
+//!
+//! [Linux]: https://man7.org/linux/man-pages/man7/inotify.7.html
+
+use crate::fd::{AsFd, BorrowedFd};
+use crate::ffi::ZStr;
+use crate::io::{self, OwnedFd};
+use crate::{imp, path};
+use core::convert::TryInto;
+
+pub use imp::fs::inotify::{CreateFlags, ReadFlags, WatchFlags};
+
+/// A watch descriptor, as returned by [`add_watch`].
+pub type WatchDescriptor = i32;
+
+/// `inotify_init1(flags)`—Create a new inotify instance.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/inotify_init1.2.html
+#[inline]
+#[doc(alias = "inotify_init1")]
+pub fn init(flags: CreateFlags) -> io::Result<OwnedFd> {
+    imp::fs::syscalls::inotify_init1(flags)
+}
+
+/// `inotify_add_watch(fd, path, flags)`—Add a watch to an inotify instance.
+///
+/// If `path` is already watched by `fd`, its watch is modified according to
+/// `flags` and the existing watch descriptor is returned.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/inotify_add_watch.2.html
+#[inline]
+#[doc(alias = "inotify_add_watch")]
+pub fn add_watch<Fd: AsFd, P: path::Arg>(
+    fd: Fd,
+    path: P,
+    flags: WatchFlags,
+) -> io::Result<WatchDescriptor> {
+    let fd = fd.as_fd();
+    path.into_with_z_str(|path| imp::fs::syscalls::inotify_add_watch(fd, path, flags))
+}
+
+/// `inotify_rm_watch(fd, wd)`—Remove a watch from an inotify instance.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/inotify_rm_watch.2.html
+#[inline]
+#[doc(alias = "inotify_rm_watch")]
+pub fn remove_watch<Fd: AsFd>(fd: Fd, wd: WatchDescriptor) -> io::Result<()> {
+    imp::fs::syscalls::inotify_rm_watch(fd.as_fd(), wd)
+}
+
+/// The size of the fixed part of a `struct inotify_event`.
+const EVENT_HEADER_SIZE: usize = 16;
+
+/// An event read from an inotify instance by a [`Reader`].
+#[derive(Debug, Clone, Copy)]
+pub struct InotifyEvent<'a> {
+    /// The watch descriptor the event is for.
+    pub wd: WatchDescriptor,
+
+    /// The events that occurred.
+    pub mask: ReadFlags,
+
+    /// A cookie which associates related `MOVED_FROM` and `MOVED_TO` events.
+    pub cookie: u32,
+
+    /// The name of the file within a watched directory, if any.
+    pub name: Option<&'a ZStr>,
+}
+
+/// A reader which decodes the variable-length events read from an inotify
+/// instance.
+pub struct Reader<'buf, Fd: AsFd> {
+    fd: Fd,
+    buf: &'buf mut [u8],
+    len: usize,
+    offset: usize,
+}
+
+impl<'buf, Fd: AsFd> Reader<'buf, Fd> {
+    /// Create a new `Reader` which reads from `fd` into `buf`.
+    ///
+    /// `buf` must be large enough to hold at least one event, including a
+    /// file name of up to `NAME_MAX` bytes; otherwise reads fail with
+    /// [`io::Errno::INVAL`].
+    #[inline]
+    pub fn new(fd: Fd, buf: &'buf mut [u8]) -> Self {
+        Self {
+            fd,
+            buf,
+            len: 0,
+            offset: 0,
+        }
+    }
+
+    /// Return the next event, reading more events from the file descriptor
+    /// when the buffer is exhausted.
+    ///
+    /// If the inotify instance is non-blocking and no events are pending,
+    /// this fails with [`io::Errno::AGAIN`].
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> io::Result<InotifyEvent<'_>> {
+        if self.is_buffer_empty() {
+            self.len = io::read(self.fd.as_fd(), self.buf)?;
+            self.offset = 0;
+        }
+
+        let event = &self.buf[self.offset..self.len];
+        let field = |i: usize| event[i * 4..i * 4 + 4].try_into().unwrap();
+        let wd = i32::from_ne_bytes(field(0));
+        let mask = u32::from_ne_bytes(field(1));
+        let cookie = u32::from_ne_bytes(field(2));
+        let name_len = u32::from_ne_bytes(field(3)) as usize;
+
+        // The name is NUL-terminated and padded with further NULs.
+        let name = &event[EVENT_HEADER_SIZE..EVENT_HEADER_SIZE + name_len];
+        let name = name
+            .iter()
+            .position(|b| *b == b'\0')
+            .map(|nul| ZStr::from_bytes_with_nul(&name[..=nul]).unwrap());
+
+        self.offset += EVENT_HEADER_SIZE + name_len;
+
+        Ok(InotifyEvent {
+            wd,
+            mask: ReadFlags::from_bits_truncate(mask),
+            cookie,
+            name,
+        })
+    }
+
+    /// Test whether all events in the buffer have been returned, so that the
+    /// next call to [`Reader::next`] will read from the file descriptor.
+    #[inline]
+    pub fn is_buffer_empty(&self) -> bool {
+        self.offset >= self.len
+    }
+
+    /// Return the file descriptor this reader reads from.
+    #[inline]
+    pub fn fd(&self) -> BorrowedFd<'_> {
+        self.fd.as_fd()
+    }
+}
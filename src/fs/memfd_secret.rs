@@ -0,0 +1,24 @@
+use crate::imp;
+use crate::io::{self, OwnedFd};
+
+pub use imp::fs::types::MemfdSecretFlags;
+
+/// `memfd_secret(flags)`—Creates an anonymous file for secret memory.
+///
+/// The file is sized with `ftruncate` and mapped with `mmap` like a memfd,
+/// but its pages are removed from the kernel's direct map, so they're only
+/// accessible through mappings of this file. They're also locked into
+/// memory, so they count against `RLIMIT_MEMLOCK`.
+///
+/// This fails with [`io::Errno::NOSYS`] before Linux 5.14, on architectures
+/// which don't support it, or if it's disabled, which it is by default
+/// before Linux 6.5 unless the `secretmem.enable=1` boot parameter is set.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/memfd_secret.2.html
+#[inline]
+pub fn memfd_secret(flags: MemfdSecretFlags) -> io::Result<OwnedFd> {
+    imp::fs::syscalls::memfd_secret(flags)
+}
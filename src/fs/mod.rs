@@ -5,9 +5,15 @@ mod abs;
 #[cfg(not(target_os = "redox"))]
 #[cfg(any(feature = "fs", feature = "procfs"))]
 mod at;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg(feature = "fs")]
+mod cachestat;
 mod constants;
 #[cfg(any(target_os = "android", target_os = "linux"))]
 mod copy_file_range;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg(feature = "fs")]
+mod copy_tree;
 #[cfg(not(target_os = "redox"))]
 mod cwd;
 #[cfg(not(target_os = "redox"))]
@@ -23,6 +29,9 @@ mod dir;
     target_os = "redox"
 )))]
 mod fadvise;
+#[cfg(target_os = "linux")]
+#[cfg(feature = "fs")]
+pub mod fanotify;
 pub(crate) mod fcntl;
 #[cfg(any(target_os = "ios", target_os = "macos"))]
 mod fcntl_darwin;
@@ -30,8 +39,14 @@ mod fcntl_darwin;
 mod fcopyfile;
 pub(crate) mod fd;
 mod file_type;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg(feature = "fs")]
+mod getdents;
 #[cfg(any(target_os = "ios", target_os = "macos"))]
 mod getpath;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg(feature = "fs")]
+pub mod inotify;
 #[cfg(not(any(
     target_os = "dragonfly",
     target_os = "freebsd",
@@ -48,11 +63,27 @@ mod makedev;
 mod memfd_create;
 #[cfg(any(target_os = "android", target_os = "linux"))]
 #[cfg(feature = "fs")]
+mod memfd_secret;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg(feature = "fs")]
+mod mount;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg(feature = "fs")]
 mod openat2;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg(feature = "fs")]
+mod pivot_root;
+#[cfg(target_os = "linux")]
+#[cfg(feature = "fs")]
+mod readahead;
+mod seek_from;
 #[cfg(target_os = "linux")]
 mod sendfile;
 #[cfg(any(target_os = "android", target_os = "linux"))]
 mod statx;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg(feature = "fs")]
+mod swap;
 
 #[cfg(not(any(
     target_os = "illumos",
@@ -62,20 +93,15 @@ mod statx;
 )))]
 #[cfg(feature = "fs")]
 pub use abs::statfs;
+#[cfg(not(any(target_os = "fuchsia", target_os = "redox", target_os = "wasi")))]
+#[cfg(feature = "fs")]
+pub use abs::truncate;
 #[cfg(not(any(target_os = "illumos", target_os = "redox")))]
 #[cfg(feature = "fs")]
 pub use at::accessat;
 #[cfg(any(target_os = "ios", target_os = "macos"))]
 #[cfg(feature = "fs")]
 pub use at::fclonefileat;
-#[cfg(not(any(
-    target_os = "ios",
-    target_os = "macos",
-    target_os = "redox",
-    target_os = "wasi",
-)))]
-#[cfg(feature = "fs")]
-pub use at::mknodat;
 #[cfg(any(target_os = "android", target_os = "linux"))]
 #[cfg(feature = "fs")]
 pub use at::renameat_with;
@@ -88,6 +114,20 @@ pub use at::{
     linkat, mkdirat, openat, readlinkat, renameat, statat, symlinkat, unlinkat, utimensat, RawMode,
     UTIME_NOW, UTIME_OMIT,
 };
+#[cfg(not(any(
+    target_os = "ios",
+    target_os = "macos",
+    target_os = "redox",
+    target_os = "wasi",
+)))]
+#[cfg(feature = "fs")]
+pub use at::{mkfifoat, mknodat};
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg(feature = "fs")]
+pub use at::{readlinkat_empty, statat_empty};
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg(feature = "fs")]
+pub use cachestat::{cachestat, Cachestat, CachestatRange};
 #[cfg(any(target_os = "ios", target_os = "macos"))]
 pub use constants::CloneFlags;
 /// `copyfile_flags_t`
@@ -102,6 +142,9 @@ pub use constants::{Access, FdFlags, Mode, Nsecs, OFlags, Secs, Timespec};
 pub use constants::{AtFlags, Dev};
 #[cfg(any(target_os = "android", target_os = "linux"))]
 pub use copy_file_range::copy_file_range;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg(feature = "fs")]
+pub use copy_tree::copy_tree;
 #[cfg(not(target_os = "redox"))]
 pub use cwd::cwd;
 #[cfg(not(target_os = "redox"))]
@@ -127,6 +170,8 @@ pub use fcntl::fcntl_dupfd_cloexec;
 ))]
 pub use fcntl::{fcntl_add_seals, fcntl_get_seals, SealFlags};
 pub use fcntl::{fcntl_getfd, fcntl_getfl, fcntl_setfd, fcntl_setfl};
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub use fcntl::{fcntl_getown, fcntl_setown, fcntl_setsig, Owner};
 #[cfg(any(target_os = "ios", target_os = "macos"))]
 pub use fcntl_darwin::{fcntl_fullfsync, fcntl_rdadvise};
 #[cfg(any(target_os = "ios", target_os = "macos"))]
@@ -141,7 +186,7 @@ pub use fcopyfile::{
     target_os = "openbsd",
     target_os = "redox"
 )))]
-pub use fd::{fallocate, FallocateFlags};
+pub use fd::{fallocate, fallocate_reserve, FallocateFlags};
 
 #[cfg(not(any(
     target_os = "dragonfly",
@@ -152,7 +197,10 @@ pub use fd::{fallocate, FallocateFlags};
 pub use fd::fdatasync;
 #[cfg(not(target_os = "wasi"))]
 pub use fd::{fchmod, fchown, flock, FlockOperation};
-pub use fd::{fstat, fsync, ftruncate, futimens, is_file_read_write, seek, tell, Stat, Timestamps};
+pub use fd::{
+    fstat, fsync, fsync_parent_dir, ftruncate, futimens, is_file_read_write, seek, tell, Stat,
+    Timestamps,
+};
 #[cfg(not(any(
     target_os = "illumos",
     target_os = "netbsd",
@@ -164,6 +212,9 @@ pub use fd::{fstatfs, StatFs};
 #[cfg(any(target_os = "android", target_os = "linux"))]
 pub use fd::{FsWord, NFS_SUPER_MAGIC, PROC_SUPER_MAGIC};
 pub use file_type::FileType;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg(feature = "fs")]
+pub use getdents::{getdents, DirBorrow, RawDirEntries, RawDirEntry};
 #[cfg(any(target_os = "ios", target_os = "macos"))]
 pub use getpath::getpath;
 #[cfg(not(any(
@@ -182,11 +233,30 @@ pub use makedev::{major, makedev, minor};
 pub use memfd_create::{memfd_create, MemfdFlags};
 #[cfg(any(target_os = "android", target_os = "linux"))]
 #[cfg(feature = "fs")]
+pub use memfd_secret::{memfd_secret, MemfdSecretFlags};
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg(feature = "fs")]
+pub use mount::{
+    fspick, mount_setattr, open_tree, FspickFlags, MountAttr, MountAttrFlags,
+    MountPropagationFlags, OpenTreeFlags,
+};
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg(feature = "fs")]
 pub use openat2::openat2;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg(feature = "fs")]
+pub use pivot_root::pivot_root;
+#[cfg(target_os = "linux")]
+#[cfg(feature = "fs")]
+pub use readahead::readahead;
+pub use seek_from::SeekFrom;
 #[cfg(target_os = "linux")]
 pub use sendfile::sendfile;
 #[cfg(any(target_os = "android", target_os = "linux"))]
 pub use statx::{statx, Statx, StatxFlags, StatxTimestamp};
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg(feature = "fs")]
+pub use swap::{swapoff, swapon, SwapFlags};
 
 /// Re-export types common to POSIX-ish platforms.
 #[cfg(feature = "std")]
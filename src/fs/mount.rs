@@ -0,0 +1,191 @@
+//! Linux's new mount API.
+
+use crate::fd::{AsFd, BorrowedFd};
+use crate::fs::{AtFlags, OFlags};
+use crate::io::OwnedFd;
+use crate::{imp, io, path};
+
+bitflags::bitflags! {
+    /// `MOUNT_ATTR_*` constants for use with [`MountAttr`].
+    #[derive(Default)]
+    pub struct MountAttrFlags: u64 {
+        /// `MOUNT_ATTR_RDONLY`
+        const RDONLY = 0x0000_0001;
+
+        /// `MOUNT_ATTR_NOSUID`
+        const NOSUID = 0x0000_0002;
+
+        /// `MOUNT_ATTR_NODEV`
+        const NODEV = 0x0000_0004;
+
+        /// `MOUNT_ATTR_NOEXEC`
+        const NOEXEC = 0x0000_0008;
+
+        /// `MOUNT_ATTR__ATIME`—The bits which select the atime behavior.
+        ///
+        /// To change the atime behavior, include this in
+        /// [`MountAttr::attr_clr`], and the new behavior in
+        /// [`MountAttr::attr_set`].
+        const ATIME_MASK = 0x0000_0070;
+
+        /// `MOUNT_ATTR_RELATIME`
+        const RELATIME = 0x0000_0000;
+
+        /// `MOUNT_ATTR_NOATIME`
+        const NOATIME = 0x0000_0010;
+
+        /// `MOUNT_ATTR_STRICTATIME`
+        const STRICTATIME = 0x0000_0020;
+
+        /// `MOUNT_ATTR_NODIRATIME`
+        const NODIRATIME = 0x0000_0080;
+
+        /// `MOUNT_ATTR_IDMAP`—Idmap the mount with the user namespace
+        /// [`MountAttr::userns_fd`].
+        const IDMAP = 0x0010_0000;
+
+        /// `MOUNT_ATTR_NOSYMFOLLOW` (since Linux 5.14)
+        const NOSYMFOLLOW = 0x0020_0000;
+    }
+}
+
+bitflags::bitflags! {
+    /// `MS_*` propagation types for use with [`MountAttr`].
+    ///
+    /// At most one of these may be set.
+    #[derive(Default)]
+    pub struct MountPropagationFlags: u64 {
+        /// `MS_UNBINDABLE`
+        const UNBINDABLE = 0x0002_0000;
+
+        /// `MS_PRIVATE`
+        const PRIVATE = 0x0004_0000;
+
+        /// `MS_SLAVE`
+        const SLAVE = 0x0008_0000;
+
+        /// `MS_SHARED`
+        const SHARED = 0x0010_0000;
+    }
+}
+
+bitflags::bitflags! {
+    /// `OPEN_TREE_*` and `AT_*` constants for use with [`open_tree`].
+    pub struct OpenTreeFlags: u32 {
+        /// `OPEN_TREE_CLONE`—Clone the mount, or with `RECURSIVE`, the
+        /// mount tree, into a detached mount.
+        const CLONE = 0x0000_0001;
+
+        /// `OPEN_TREE_CLOEXEC`
+        const CLOEXEC = OFlags::CLOEXEC.bits() as _;
+
+        /// `AT_RECURSIVE`
+        const RECURSIVE = AtFlags::RECURSIVE.bits() as _;
+
+        /// `AT_EMPTY_PATH`
+        const EMPTY_PATH = AtFlags::EMPTY_PATH.bits() as _;
+
+        /// `AT_SYMLINK_NOFOLLOW`
+        const SYMLINK_NOFOLLOW = AtFlags::SYMLINK_NOFOLLOW.bits() as _;
+
+        /// `AT_NO_AUTOMOUNT`
+        const NO_AUTOMOUNT = AtFlags::NO_AUTOMOUNT.bits() as _;
+    }
+}
+
+bitflags::bitflags! {
+    /// `FSPICK_*` constants for use with [`fspick`].
+    pub struct FspickFlags: u32 {
+        /// `FSPICK_CLOEXEC`
+        const CLOEXEC = 0x0000_0001;
+
+        /// `FSPICK_SYMLINK_NOFOLLOW`
+        const SYMLINK_NOFOLLOW = 0x0000_0002;
+
+        /// `FSPICK_NO_AUTOMOUNT`
+        const NO_AUTOMOUNT = 0x0000_0004;
+
+        /// `FSPICK_EMPTY_PATH`
+        const EMPTY_PATH = 0x0000_0008;
+    }
+}
+
+/// `struct mount_attr`—Changes to make with [`mount_setattr`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MountAttr<'a> {
+    /// Attributes to set.
+    pub attr_set: MountAttrFlags,
+
+    /// Attributes to clear.
+    pub attr_clr: MountAttrFlags,
+
+    /// The new propagation type, or empty to leave it unchanged.
+    pub propagation: MountPropagationFlags,
+
+    /// The user namespace to idmap the mount with, when `attr_set` contains
+    /// [`MountAttrFlags::IDMAP`].
+    pub userns_fd: Option<BorrowedFd<'a>>,
+}
+
+/// `mount_setattr(dirfd, path, flags, attr, sizeof(attr))`—Change the
+/// attributes of a mount, or with `AtFlags::RECURSIVE`, of a mount tree.
+///
+/// This requires the `CAP_SYS_ADMIN` capability in the mount namespace.
+/// Before Linux 5.12, this fails with [`io::Errno::NOSYS`]. If `attr`
+/// requests [`MountAttrFlags::IDMAP`] without a `userns_fd`, this fails
+/// with [`io::Errno::INVAL`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/mount_setattr.2.html
+#[inline]
+pub fn mount_setattr<Fd: AsFd, P: path::Arg>(
+    dirfd: Fd,
+    path: P,
+    flags: AtFlags,
+    attr: &MountAttr<'_>,
+) -> io::Result<()> {
+    path.into_with_z_str(|path| imp::fs::syscalls::mount_setattr(dirfd.as_fd(), path, flags, attr))
+}
+
+/// `open_tree(dirfd, path, flags)`—Open a mount, or with
+/// [`OpenTreeFlags::CLONE`], a detached copy of a mount or mount tree.
+///
+/// Cloning requires the `CAP_SYS_ADMIN` capability in the mount namespace.
+/// A detached mount is unmounted when its last file descriptor is closed,
+/// unless it's first attached somewhere with `move_mount`. Before Linux 5.2,
+/// this fails with [`io::Errno::NOSYS`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/open_tree.2.html
+#[inline]
+pub fn open_tree<Fd: AsFd, P: path::Arg>(
+    dirfd: Fd,
+    path: P,
+    flags: OpenTreeFlags,
+) -> io::Result<OwnedFd> {
+    path.into_with_z_str(|path| imp::fs::syscalls::open_tree(dirfd.as_fd(), path, flags))
+}
+
+/// `fspick(dirfd, path, flags)`—Open a filesystem context for the
+/// filesystem mounted at `path`, to reconfigure it.
+///
+/// `path` must be the root of a mount. This requires the `CAP_SYS_ADMIN`
+/// capability in the filesystem's user namespace. Before Linux 5.2, this
+/// fails with [`io::Errno::NOSYS`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/fspick.2.html
+#[inline]
+pub fn fspick<Fd: AsFd, P: path::Arg>(
+    dirfd: Fd,
+    path: P,
+    flags: FspickFlags,
+) -> io::Result<OwnedFd> {
+    path.into_with_z_str(|path| imp::fs::syscalls::fspick(dirfd.as_fd(), path, flags))
+}
@@ -0,0 +1,27 @@
+use crate::{imp, io, path};
+
+/// `pivot_root(new_root, put_old)`—Changes the root mount of the calling
+/// process' mount namespace.
+///
+/// This moves the root mount to `put_old` and makes `new_root` the new root
+/// mount. `new_root` and `put_old` must both be directories, and must not be
+/// on the same mount as the current root, or the kernel fails with `BUSY`.
+/// Otherwise, it fails with `INVAL` unless all of the following hold:
+///  - `put_old` is at or underneath `new_root`.
+///  - `new_root` is a mount point, and is not the current root.
+///  - The current root is a mount point, and its propagation type, and that
+///    of the parent mount of `new_root`, is not `MS_SHARED`.
+///
+/// This requires the `CAP_SYS_ADMIN` capability in the mount namespace, and
+/// fails with `PERM` otherwise.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/pivot_root.2.html
+#[inline]
+pub fn pivot_root<P: path::Arg, Q: path::Arg>(new_root: P, put_old: Q) -> io::Result<()> {
+    new_root.into_with_z_str(|new_root| {
+        put_old.into_with_z_str(|put_old| imp::fs::syscalls::pivot_root(new_root, put_old))
+    })
+}
@@ -0,0 +1,17 @@
+use crate::{imp, io};
+use imp::fd::AsFd;
+
+/// `readahead(fd, offset, count)`—Starts reading a range of a file into the
+/// page cache.
+///
+/// This fails with [`io::Errno::INVAL`] if `fd` doesn't refer to a file type
+/// that supports readahead.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/readahead.2.html
+#[inline]
+pub fn readahead<Fd: AsFd>(fd: Fd, offset: u64, count: usize) -> io::Result<()> {
+    imp::fs::syscalls::readahead(fd.as_fd(), offset, count)
+}
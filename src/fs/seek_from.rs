@@ -0,0 +1,77 @@
+//! The following is derived from Rust's
+//! library/std/src/io/mod.rs at revision
+//! dca3f1b786efd27be3b325ed1e01e247aa589c3b.
+
+/// Enumeration of possible methods to seek within an I/O object.
+///
+/// This is like [`std::io::SeekFrom`], with the addition of the `SEEK_DATA`
+/// and `SEEK_HOLE` whence values on platforms that support them.
+///
+/// It is used by the [`seek`] function.
+///
+/// [`std::io::SeekFrom`]: https://doc.rust-lang.org/std/io/enum.SeekFrom.html
+/// [`seek`]: crate::fs::seek
+#[derive(Copy, PartialEq, Eq, Clone, Debug)]
+pub enum SeekFrom {
+    /// Sets the offset to the provided number of bytes.
+    Start(u64),
+
+    /// Sets the offset to the size of this object plus the specified number of
+    /// bytes.
+    ///
+    /// It is possible to seek beyond the end of an object, but it's an error
+    /// to seek before byte 0.
+    End(i64),
+
+    /// Sets the offset to the current position plus the specified number of
+    /// bytes.
+    ///
+    /// It is possible to seek beyond the end of an object, but it's an error
+    /// to seek before byte 0.
+    Current(i64),
+
+    /// `SEEK_DATA`—Sets the offset to the start of the next region containing
+    /// data at or after the specified offset.
+    ///
+    /// If there is no data at or after the offset, the seek fails with
+    /// [`io::Errno::NXIO`].
+    ///
+    /// [`io::Errno::NXIO`]: crate::io::Errno::NXIO
+    #[cfg(any(
+        target_os = "android",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "illumos",
+        target_os = "linux",
+        target_os = "solaris",
+    ))]
+    Data(u64),
+
+    /// `SEEK_HOLE`—Sets the offset to the start of the next hole at or after
+    /// the specified offset.
+    ///
+    /// The end of the file counts as a hole, so this only fails with
+    /// [`io::Errno::NXIO`] if the offset is beyond the end of the file.
+    ///
+    /// [`io::Errno::NXIO`]: crate::io::Errno::NXIO
+    #[cfg(any(
+        target_os = "android",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "illumos",
+        target_os = "linux",
+        target_os = "solaris",
+    ))]
+    Hole(u64),
+}
+
+impl From<crate::io::SeekFrom> for SeekFrom {
+    #[inline]
+    fn from(pos: crate::io::SeekFrom) -> Self {
+        match pos {
+            crate::io::SeekFrom::Start(pos) => Self::Start(pos),
+            crate::io::SeekFrom::End(offset) => Self::End(offset),
+            crate::io::SeekFrom::Current(offset) => Self::Current(offset),
+        }
+    }
+}
@@ -0,0 +1,80 @@
+//! The Linux `swapon` and `swapoff` functions.
+
+use crate::{imp, io, path};
+
+bitflags::bitflags! {
+    /// `SWAP_FLAG_*` constants for use with [`swapon`].
+    ///
+    /// Use [`SwapFlags::prefer`] to set a priority.
+    pub struct SwapFlags: u32 {
+        /// `SWAP_FLAG_PREFER`—Use the priority in [`SwapFlags::PRIO_MASK`]
+        /// instead of a default one.
+        const PREFER = 0x8000;
+
+        /// `SWAP_FLAG_PRIO_MASK`—The bits which hold the priority.
+        const PRIO_MASK = 0x7fff;
+
+        /// `SWAP_FLAG_DISCARD`—Discard freed swap pages, if the device
+        /// supports it.
+        const DISCARD = 0x1_0000;
+
+        /// `SWAP_FLAG_DISCARD_ONCE`—With `DISCARD`, only discard the whole
+        /// area once, when it's activated.
+        const DISCARD_ONCE = 0x2_0000;
+
+        /// `SWAP_FLAG_DISCARD_PAGES`—With `DISCARD`, only discard pages as
+        /// they're freed.
+        const DISCARD_PAGES = 0x4_0000;
+    }
+}
+
+impl SwapFlags {
+    /// `SWAP_FLAG_PREFER | (priority & SWAP_FLAG_PRIO_MASK)`—Flags which
+    /// request the given priority.
+    ///
+    /// Priorities range from 0 to 32767; higher priorities are used first.
+    /// Bits above the range are ignored.
+    #[inline]
+    pub const fn prefer(priority: u16) -> Self {
+        Self::from_bits_truncate(Self::PREFER.bits() | (priority as u32 & Self::PRIO_MASK.bits()))
+    }
+
+    /// Returns the priority requested by these flags, if they contain
+    /// [`SwapFlags::PREFER`].
+    #[inline]
+    pub const fn priority(self) -> Option<u16> {
+        if self.contains(Self::PREFER) {
+            Some((self.bits() & Self::PRIO_MASK.bits()) as u16)
+        } else {
+            None
+        }
+    }
+}
+
+/// `swapon(path, flags)`—Starts swapping to a file or block device.
+///
+/// This requires the `CAP_SYS_ADMIN` capability, and fails with `PERM`
+/// otherwise.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/swapon.2.html
+#[inline]
+pub fn swapon<P: path::Arg>(path: P, flags: SwapFlags) -> io::Result<()> {
+    path.into_with_z_str(|path| imp::fs::syscalls::swapon(path, flags.bits()))
+}
+
+/// `swapoff(path)`—Stops swapping to a file or block device.
+///
+/// This requires the `CAP_SYS_ADMIN` capability, and fails with `PERM`
+/// otherwise.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/swapoff.2.html
+#[inline]
+pub fn swapoff<P: path::Arg>(path: P) -> io::Result<()> {
+    path.into_with_z_str(imp::fs::syscalls::swapoff)
+}
@@ -0,0 +1,161 @@
+use super::super::c;
+use bitflags::bitflags;
+
+bitflags! {
+    /// `FAN_*` flags for use with [`fanotify::init`].
+    ///
+    /// [`fanotify::init`]: crate::fs::fanotify::init
+    pub struct InitFlags: c::c_uint {
+        /// `FAN_CLOEXEC`
+        const CLOEXEC = c::FAN_CLOEXEC;
+
+        /// `FAN_NONBLOCK`
+        const NONBLOCK = c::FAN_NONBLOCK;
+
+        /// `FAN_CLASS_NOTIF`—Only receive notification events.
+        const CLASS_NOTIF = c::FAN_CLASS_NOTIF;
+
+        /// `FAN_CLASS_CONTENT`—Receive permission events for final content.
+        const CLASS_CONTENT = c::FAN_CLASS_CONTENT;
+
+        /// `FAN_CLASS_PRE_CONTENT`—Receive permission events before content
+        /// is final.
+        const CLASS_PRE_CONTENT = c::FAN_CLASS_PRE_CONTENT;
+
+        /// `FAN_UNLIMITED_QUEUE`—Don't limit the size of the event queue.
+        const UNLIMITED_QUEUE = c::FAN_UNLIMITED_QUEUE;
+
+        /// `FAN_UNLIMITED_MARKS`—Don't limit the number of marks.
+        const UNLIMITED_MARKS = c::FAN_UNLIMITED_MARKS;
+
+        /// `FAN_ENABLE_AUDIT`—Allow responses to request audit records.
+        const ENABLE_AUDIT = c::FAN_ENABLE_AUDIT;
+
+        /// `FAN_REPORT_TID`—Report thread IDs instead of process IDs.
+        const REPORT_TID = c::FAN_REPORT_TID;
+    }
+}
+
+bitflags! {
+    /// `FAN_MARK_*` flags for use with [`fanotify::mark`].
+    ///
+    /// [`fanotify::mark`]: crate::fs::fanotify::mark
+    pub struct MarkFlags: c::c_uint {
+        /// `FAN_MARK_ADD`—Add the events in the mask to the mark.
+        const ADD = c::FAN_MARK_ADD;
+
+        /// `FAN_MARK_REMOVE`—Remove the events in the mask from the mark.
+        const REMOVE = c::FAN_MARK_REMOVE;
+
+        /// `FAN_MARK_DONT_FOLLOW`—Don't follow a trailing symlink.
+        const DONT_FOLLOW = c::FAN_MARK_DONT_FOLLOW;
+
+        /// `FAN_MARK_ONLYDIR`—Fail if the path is not a directory.
+        const ONLYDIR = c::FAN_MARK_ONLYDIR;
+
+        /// `FAN_MARK_MOUNT`—Mark the mount containing the path.
+        const MOUNT = c::FAN_MARK_MOUNT;
+
+        /// `FAN_MARK_IGNORED_MASK`—Update the ignore mask, not the event mask.
+        const IGNORED_MASK = c::FAN_MARK_IGNORED_MASK;
+
+        /// `FAN_MARK_IGNORED_SURV_MODIFY`—Keep the ignore mask when the file
+        /// is modified.
+        const IGNORED_SURV_MODIFY = c::FAN_MARK_IGNORED_SURV_MODIFY;
+
+        /// `FAN_MARK_FLUSH`—Remove all marks of the type given by the other
+        /// flags.
+        const FLUSH = c::FAN_MARK_FLUSH;
+
+        /// `FAN_MARK_FILESYSTEM`—Mark the filesystem containing the path.
+        const FILESYSTEM = c::FAN_MARK_FILESYSTEM;
+    }
+}
+
+bitflags! {
+    /// `FAN_*` event flags for use with [`fanotify::mark`] and reported in
+    /// [`FanotifyEvent`].
+    ///
+    /// [`fanotify::mark`]: crate::fs::fanotify::mark
+    /// [`FanotifyEvent`]: crate::fs::fanotify::FanotifyEvent
+    pub struct EventFlags: u64 {
+        /// `FAN_ACCESS`—A file was accessed.
+        const ACCESS = c::FAN_ACCESS;
+
+        /// `FAN_MODIFY`—A file was modified.
+        const MODIFY = c::FAN_MODIFY;
+
+        /// `FAN_ATTRIB`—Metadata changed.
+        const ATTRIB = c::FAN_ATTRIB;
+
+        /// `FAN_CLOSE_WRITE`—A file opened for writing was closed.
+        const CLOSE_WRITE = c::FAN_CLOSE_WRITE;
+
+        /// `FAN_CLOSE_NOWRITE`—A file not opened for writing was closed.
+        const CLOSE_NOWRITE = c::FAN_CLOSE_NOWRITE;
+
+        /// `FAN_OPEN`—A file was opened.
+        const OPEN = c::FAN_OPEN;
+
+        /// `FAN_MOVED_FROM`—A file was moved out of a marked directory.
+        const MOVED_FROM = c::FAN_MOVED_FROM;
+
+        /// `FAN_MOVED_TO`—A file was moved into a marked directory.
+        const MOVED_TO = c::FAN_MOVED_TO;
+
+        /// `FAN_CREATE`—A file was created in a marked directory.
+        const CREATE = c::FAN_CREATE;
+
+        /// `FAN_DELETE`—A file was deleted from a marked directory.
+        const DELETE = c::FAN_DELETE;
+
+        /// `FAN_DELETE_SELF`—A marked file or directory was deleted.
+        const DELETE_SELF = c::FAN_DELETE_SELF;
+
+        /// `FAN_MOVE_SELF`—A marked file or directory was moved.
+        const MOVE_SELF = c::FAN_MOVE_SELF;
+
+        /// `FAN_OPEN_EXEC`—A file was opened for execution.
+        const OPEN_EXEC = c::FAN_OPEN_EXEC;
+
+        /// `FAN_Q_OVERFLOW`—The event queue overflowed, and events were
+        /// lost.
+        const Q_OVERFLOW = c::FAN_Q_OVERFLOW;
+
+        /// `FAN_OPEN_PERM`—Permission to open a file was requested.
+        const OPEN_PERM = c::FAN_OPEN_PERM;
+
+        /// `FAN_ACCESS_PERM`—Permission to read a file was requested.
+        const ACCESS_PERM = c::FAN_ACCESS_PERM;
+
+        /// `FAN_OPEN_EXEC_PERM`—Permission to open a file for execution was
+        /// requested.
+        const OPEN_EXEC_PERM = c::FAN_OPEN_EXEC_PERM;
+
+        /// `FAN_EVENT_ON_CHILD`—Report events for the immediate children of
+        /// marked directories.
+        const EVENT_ON_CHILD = c::FAN_EVENT_ON_CHILD;
+
+        /// `FAN_ONDIR`—Report events for directories too.
+        const ONDIR = c::FAN_ONDIR;
+
+        /// `FAN_CLOSE`
+        const CLOSE = c::FAN_CLOSE;
+
+        /// `FAN_MOVE`
+        const MOVE = c::FAN_MOVE;
+    }
+}
+
+/// A response to a permission event, for use with [`fanotify::respond`].
+///
+/// [`fanotify::respond`]: crate::fs::fanotify::respond
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[repr(u32)]
+pub enum FanotifyResponse {
+    /// `FAN_ALLOW`
+    Allow = c::FAN_ALLOW,
+
+    /// `FAN_DENY`
+    Deny = c::FAN_DENY,
+}
@@ -0,0 +1,142 @@
+//! inotify types.
+
+use super::super::c;
+use bitflags::bitflags;
+
+bitflags! {
+    /// `IN_*` flags for use with [`inotify::init`].
+    ///
+    /// [`inotify::init`]: crate::fs::inotify::init
+    pub struct CreateFlags: c::c_int {
+        /// `IN_CLOEXEC`
+        const CLOEXEC = c::IN_CLOEXEC;
+
+        /// `IN_NONBLOCK`
+        const NONBLOCK = c::IN_NONBLOCK;
+    }
+}
+
+bitflags! {
+    /// `IN_*` flags for use with [`inotify::add_watch`].
+    ///
+    /// [`inotify::add_watch`]: crate::fs::inotify::add_watch
+    pub struct WatchFlags: u32 {
+        /// `IN_ACCESS`—File was accessed.
+        const ACCESS = c::IN_ACCESS;
+
+        /// `IN_MODIFY`—File was modified.
+        const MODIFY = c::IN_MODIFY;
+
+        /// `IN_ATTRIB`—Metadata changed.
+        const ATTRIB = c::IN_ATTRIB;
+
+        /// `IN_CLOSE_WRITE`—A file opened for writing was closed.
+        const CLOSE_WRITE = c::IN_CLOSE_WRITE;
+
+        /// `IN_CLOSE_NOWRITE`—A file not opened for writing was closed.
+        const CLOSE_NOWRITE = c::IN_CLOSE_NOWRITE;
+
+        /// `IN_OPEN`—File was opened.
+        const OPEN = c::IN_OPEN;
+
+        /// `IN_MOVED_FROM`—A file was moved out of a watched directory.
+        const MOVED_FROM = c::IN_MOVED_FROM;
+
+        /// `IN_MOVED_TO`—A file was moved into a watched directory.
+        const MOVED_TO = c::IN_MOVED_TO;
+
+        /// `IN_CREATE`—A file was created in a watched directory.
+        const CREATE = c::IN_CREATE;
+
+        /// `IN_DELETE`—A file was deleted from a watched directory.
+        const DELETE = c::IN_DELETE;
+
+        /// `IN_DELETE_SELF`—The watched file or directory was deleted.
+        const DELETE_SELF = c::IN_DELETE_SELF;
+
+        /// `IN_MOVE_SELF`—The watched file or directory was moved.
+        const MOVE_SELF = c::IN_MOVE_SELF;
+
+        /// `IN_CLOSE`
+        const CLOSE = c::IN_CLOSE;
+
+        /// `IN_MOVE`
+        const MOVE = c::IN_MOVE;
+
+        /// `IN_ALL_EVENTS`
+        const ALL_EVENTS = c::IN_ALL_EVENTS;
+
+        /// `IN_ONLYDIR`—Only watch the path if it is a directory.
+        const ONLYDIR = c::IN_ONLYDIR;
+
+        /// `IN_DONT_FOLLOW`—Don't follow a trailing symlink.
+        const DONT_FOLLOW = c::IN_DONT_FOLLOW;
+
+        /// `IN_EXCL_UNLINK`—Ignore events for children after they are unlinked.
+        const EXCL_UNLINK = c::IN_EXCL_UNLINK;
+
+        /// `IN_MASK_CREATE`—Fail with `EEXIST` if the path is already watched.
+        const MASK_CREATE = c::IN_MASK_CREATE;
+
+        /// `IN_MASK_ADD`—Add to an existing watch's mask, don't replace it.
+        const MASK_ADD = c::IN_MASK_ADD;
+
+        /// `IN_ONESHOT`—Remove the watch after one event.
+        const ONESHOT = c::IN_ONESHOT;
+    }
+}
+
+bitflags! {
+    /// `IN_*` flags reported in the `mask` field of [`InotifyEvent`].
+    ///
+    /// [`InotifyEvent`]: crate::fs::inotify::InotifyEvent
+    pub struct ReadFlags: u32 {
+        /// `IN_ACCESS`—File was accessed.
+        const ACCESS = c::IN_ACCESS;
+
+        /// `IN_MODIFY`—File was modified.
+        const MODIFY = c::IN_MODIFY;
+
+        /// `IN_ATTRIB`—Metadata changed.
+        const ATTRIB = c::IN_ATTRIB;
+
+        /// `IN_CLOSE_WRITE`—A file opened for writing was closed.
+        const CLOSE_WRITE = c::IN_CLOSE_WRITE;
+
+        /// `IN_CLOSE_NOWRITE`—A file not opened for writing was closed.
+        const CLOSE_NOWRITE = c::IN_CLOSE_NOWRITE;
+
+        /// `IN_OPEN`—File was opened.
+        const OPEN = c::IN_OPEN;
+
+        /// `IN_MOVED_FROM`—A file was moved out of a watched directory.
+        const MOVED_FROM = c::IN_MOVED_FROM;
+
+        /// `IN_MOVED_TO`—A file was moved into a watched directory.
+        const MOVED_TO = c::IN_MOVED_TO;
+
+        /// `IN_CREATE`—A file was created in a watched directory.
+        const CREATE = c::IN_CREATE;
+
+        /// `IN_DELETE`—A file was deleted from a watched directory.
+        const DELETE = c::IN_DELETE;
+
+        /// `IN_DELETE_SELF`—The watched file or directory was deleted.
+        const DELETE_SELF = c::IN_DELETE_SELF;
+
+        /// `IN_MOVE_SELF`—The watched file or directory was moved.
+        const MOVE_SELF = c::IN_MOVE_SELF;
+
+        /// `IN_UNMOUNT`—The watched object's filesystem was unmounted.
+        const UNMOUNT = c::IN_UNMOUNT;
+
+        /// `IN_Q_OVERFLOW`—The event queue overflowed, and events were lost.
+        const Q_OVERFLOW = c::IN_Q_OVERFLOW;
+
+        /// `IN_IGNORED`—The watch was removed.
+        const IGNORED = c::IN_IGNORED;
+
+        /// `IN_ISDIR`—The subject of the event is a directory.
+        const ISDIR = c::IN_ISDIR;
+    }
+}
@@ -1,6 +1,12 @@
 #[cfg(not(target_os = "redox"))]
 #[cfg(any(feature = "fs", feature = "procfs"))]
 pub(crate) mod dir;
+#[cfg(target_os = "linux")]
+#[cfg(feature = "fs")]
+pub(crate) mod fanotify;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg(feature = "fs")]
+pub(crate) mod inotify;
 #[cfg(not(any(
     target_os = "dragonfly",
     target_os = "freebsd",
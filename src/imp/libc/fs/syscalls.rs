@@ -31,6 +31,8 @@ use super::super::offset::libc_posix_fadvise;
     target_os = "redox",
 )))]
 use super::super::offset::libc_posix_fallocate;
+#[cfg(not(any(target_os = "fuchsia", target_os = "redox", target_os = "wasi")))]
+use super::super::offset::libc_truncate;
 use super::super::offset::{libc_fstat, libc_fstatat, libc_ftruncate, libc_lseek, libc_off_t};
 #[cfg(not(any(
     target_os = "illumos",
@@ -44,6 +46,12 @@ use super::super::offset::{libc_fstatfs, libc_statfs};
     target_env = "gnu"
 ))]
 use super::super::time::types::LibcTimespec;
+#[cfg(target_os = "linux")]
+#[cfg(feature = "fs")]
+use super::fanotify::{EventFlags, InitFlags, MarkFlags};
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg(feature = "fs")]
+use super::inotify::{CreateFlags, WatchFlags};
 use crate::fd::BorrowedFd;
 #[cfg(not(target_os = "wasi"))]
 use crate::fd::RawFd;
@@ -89,6 +97,9 @@ use crate::fs::SealFlags;
 )))]
 // not implemented in libc for netbsd yet
 use crate::fs::StatFs;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg(feature = "fs")]
+use crate::fs::{Cachestat, CachestatRange, MemfdSecretFlags};
 #[cfg(not(any(
     target_os = "ios",
     target_os = "macos",
@@ -96,12 +107,17 @@ use crate::fs::StatFs;
     target_os = "wasi",
 )))]
 use crate::fs::{Dev, FileType};
-use crate::fs::{FdFlags, Mode, OFlags, Stat, Timestamps};
+use crate::fs::{FdFlags, Mode, OFlags, SeekFrom, Stat, Timestamps};
 #[cfg(any(target_os = "android", target_os = "linux"))]
-use crate::fs::{RenameFlags, ResolveFlags, Statx, StatxFlags};
-use crate::io::{self, OwnedFd, SeekFrom};
+#[cfg(feature = "fs")]
+use crate::fs::{FspickFlags, MountAttr, OpenTreeFlags};
+#[cfg(any(target_os = "android", target_os = "linux"))]
+use crate::fs::{Owner, RenameFlags, ResolveFlags, Statx, StatxFlags};
+use crate::io::{self, OwnedFd};
 #[cfg(not(target_os = "wasi"))]
 use crate::process::{Gid, Uid};
+#[cfg(any(target_os = "android", target_os = "linux"))]
+use crate::process::{Pid, Signal};
 #[cfg(not(all(
     any(target_arch = "arm", target_arch = "mips", target_arch = "x86"),
     target_env = "gnu"
@@ -633,8 +649,20 @@ unsafe fn utimensat_old(
     target_os = "redox",
     target_os = "wasi",
 )))]
-pub(crate) fn chmodat(dirfd: BorrowedFd<'_>, path: &ZStr, mode: Mode) -> io::Result<()> {
-    unsafe { ret(c::fchmodat(borrowed_fd(dirfd), c_str(path), mode.bits(), 0)) }
+pub(crate) fn chmodat(
+    dirfd: BorrowedFd<'_>,
+    path: &ZStr,
+    mode: Mode,
+    flags: AtFlags,
+) -> io::Result<()> {
+    unsafe {
+        ret(c::fchmodat(
+            borrowed_fd(dirfd),
+            c_str(path),
+            mode.bits(),
+            flags.bits(),
+        ))
+    }
 }
 
 #[cfg(any(target_os = "android", target_os = "linux"))]
@@ -676,16 +704,17 @@ pub(crate) fn fclonefileat(
 pub(crate) fn chownat(
     dirfd: BorrowedFd<'_>,
     path: &ZStr,
-    owner: Uid,
-    group: Gid,
+    owner: Option<Uid>,
+    group: Option<Gid>,
     flags: AtFlags,
 ) -> io::Result<()> {
     unsafe {
+        let (ow, gr) = crate::process::translate_fchown_args(owner, group);
         ret(c::fchownat(
             borrowed_fd(dirfd),
             c_str(path),
-            owner.as_raw(),
-            group.as_raw(),
+            ow,
+            gr,
             flags.bits(),
         ))
     }
@@ -838,6 +867,75 @@ pub(crate) fn fcntl_add_seals(fd: BorrowedFd<'_>, seals: SealFlags) -> io::Resul
     unsafe { ret(c::fcntl(borrowed_fd(fd), c::F_ADD_SEALS, seals.bits())) }
 }
 
+/// `F_SETSIG`, `F_SETOWN_EX`, `F_GETOWN_EX`, and `F_OWNER_*`, which
+/// aren't in the libc crate for most targets. These values are the same on
+/// all Linux architectures.
+#[cfg(any(target_os = "android", target_os = "linux"))]
+const F_SETSIG: c::c_int = 10;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+const F_SETOWN_EX: c::c_int = 15;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+const F_GETOWN_EX: c::c_int = 16;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+const F_OWNER_TID: c::c_int = 0;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+const F_OWNER_PID: c::c_int = 1;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+const F_OWNER_PGRP: c::c_int = 2;
+
+/// `struct f_owner_ex`
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[repr(C)]
+struct f_owner_ex {
+    type_: c::c_int,
+    pid: c::pid_t,
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn fcntl_setown_ex(fd: BorrowedFd<'_>, owner: Owner) -> io::Result<()> {
+    let (type_, pid) = match owner {
+        Owner::Pid(pid) => (F_OWNER_PID, pid),
+        Owner::ProcessGroup(pid) => (F_OWNER_PGRP, pid),
+        Owner::Thread(pid) => (F_OWNER_TID, pid),
+    };
+    let owner = f_owner_ex {
+        type_,
+        pid: pid.as_raw_nonzero().get(),
+    };
+    unsafe {
+        ret(c::fcntl(
+            borrowed_fd(fd),
+            F_SETOWN_EX,
+            &owner as *const f_owner_ex,
+        ))
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn fcntl_getown_ex(fd: BorrowedFd<'_>) -> io::Result<Option<Owner>> {
+    let mut owner = MaybeUninit::<f_owner_ex>::uninit();
+    let owner = unsafe {
+        ret(c::fcntl(borrowed_fd(fd), F_GETOWN_EX, owner.as_mut_ptr()))?;
+        owner.assume_init()
+    };
+    let pid = match unsafe { Pid::from_raw(owner.pid) } {
+        Some(pid) => pid,
+        None => return Ok(None),
+    };
+    match owner.type_ {
+        F_OWNER_PID => Ok(Some(Owner::Pid(pid))),
+        F_OWNER_PGRP => Ok(Some(Owner::ProcessGroup(pid))),
+        F_OWNER_TID => Ok(Some(Owner::Thread(pid))),
+        _ => Err(io::Errno::INVAL),
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn fcntl_setsig(fd: BorrowedFd<'_>, sig: Option<Signal>) -> io::Result<()> {
+    let sig = sig.map_or(0, |sig| sig as c::c_int);
+    unsafe { ret(c::fcntl(borrowed_fd(fd), F_SETSIG, sig)) }
+}
+
 #[cfg(not(target_os = "wasi"))]
 pub(crate) fn fcntl_dupfd_cloexec(fd: BorrowedFd<'_>, min: RawFd) -> io::Result<OwnedFd> {
     unsafe { ret_owned_fd(c::fcntl(borrowed_fd(fd), c::F_DUPFD_CLOEXEC, min)) }
@@ -852,6 +950,24 @@ pub(crate) fn seek(fd: BorrowedFd<'_>, pos: SeekFrom) -> io::Result<u64> {
         }
         SeekFrom::End(offset) => (c::SEEK_END, offset),
         SeekFrom::Current(offset) => (c::SEEK_CUR, offset),
+        #[cfg(any(
+            target_os = "android",
+            target_os = "dragonfly",
+            target_os = "freebsd",
+            target_os = "illumos",
+            target_os = "linux",
+            target_os = "solaris",
+        ))]
+        SeekFrom::Data(offset) => (c::SEEK_DATA, offset as i64),
+        #[cfg(any(
+            target_os = "android",
+            target_os = "dragonfly",
+            target_os = "freebsd",
+            target_os = "illumos",
+            target_os = "linux",
+            target_os = "solaris",
+        ))]
+        SeekFrom::Hole(offset) => (c::SEEK_HOLE, offset as i64),
     };
     let offset = unsafe { ret_off_t(libc_lseek(borrowed_fd(fd), offset, whence))? };
     Ok(offset as u64)
@@ -883,20 +999,19 @@ pub(crate) fn fchmod(fd: BorrowedFd<'_>, mode: Mode) -> io::Result<()> {
 }
 
 #[cfg(any(target_os = "android", target_os = "linux"))]
-pub(crate) fn fchown(fd: BorrowedFd<'_>, owner: Uid, group: Gid) -> io::Result<()> {
+pub(crate) fn fchown(fd: BorrowedFd<'_>, owner: Option<Uid>, group: Option<Gid>) -> io::Result<()> {
     unsafe {
-        syscall_ret(c::syscall(
-            c::SYS_fchown,
-            borrowed_fd(fd),
-            owner.as_raw(),
-            group.as_raw(),
-        ))
+        let (ow, gr) = crate::process::translate_fchown_args(owner, group);
+        syscall_ret(c::syscall(c::SYS_fchown, borrowed_fd(fd), ow, gr))
     }
 }
 
 #[cfg(not(any(target_os = "android", target_os = "linux", target_os = "wasi")))]
-pub(crate) fn fchown(fd: BorrowedFd<'_>, owner: Uid, group: Gid) -> io::Result<()> {
-    unsafe { ret(c::fchown(borrowed_fd(fd), owner.as_raw(), group.as_raw())) }
+pub(crate) fn fchown(fd: BorrowedFd<'_>, owner: Option<Uid>, group: Option<Gid>) -> io::Result<()> {
+    unsafe {
+        let (ow, gr) = crate::process::translate_fchown_args(owner, group);
+        ret(c::fchown(borrowed_fd(fd), ow, gr))
+    }
 }
 
 #[cfg(not(target_os = "wasi"))]
@@ -1132,7 +1247,15 @@ pub(crate) fn fallocate(
             store.fst_flags = c::F_ALLOCATEALL;
             let _ = ret_c_int(c::fcntl(borrowed_fd(fd), c::F_PREALLOCATE, &store))?;
         }
-        ret(c::ftruncate(borrowed_fd(fd), new_len))
+
+        // Like `fallocate` on other platforms, extend the file to cover the
+        // range, but never shrink it.
+        let mut stat = MaybeUninit::<c::stat>::uninit();
+        ret(libc_fstat(borrowed_fd(fd), stat.as_mut_ptr()))?;
+        if stat.assume_init().st_size < new_len {
+            ret(c::ftruncate(borrowed_fd(fd), new_len))?;
+        }
+        Ok(())
     }
 }
 
@@ -1155,6 +1278,12 @@ pub(crate) fn ftruncate(fd: BorrowedFd<'_>, length: u64) -> io::Result<()> {
     unsafe { ret(libc_ftruncate(borrowed_fd(fd), length)) }
 }
 
+#[cfg(not(any(target_os = "fuchsia", target_os = "redox", target_os = "wasi")))]
+pub(crate) fn truncate(path: &ZStr, length: u64) -> io::Result<()> {
+    let length = length.try_into().map_err(|_overflow_err| io::Errno::FBIG)?;
+    unsafe { ret(libc_truncate(c_str(path), length)) }
+}
+
 #[cfg(any(target_os = "android", target_os = "freebsd", target_os = "linux"))]
 pub(crate) fn memfd_create(path: &ZStr, flags: MemfdFlags) -> io::Result<OwnedFd> {
     #[cfg(target_os = "freebsd")]
@@ -1176,6 +1305,58 @@ pub(crate) fn memfd_create(path: &ZStr, flags: MemfdFlags) -> io::Result<OwnedFd
     unsafe { ret_owned_fd(memfd_create(c_str(path), flags.bits())) }
 }
 
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg(feature = "fs")]
+pub(crate) fn memfd_secret(flags: MemfdSecretFlags) -> io::Result<OwnedFd> {
+    #[cfg(any(target_arch = "aarch64", target_arch = "x86", target_arch = "x86_64"))]
+    {
+        syscall! {
+            fn memfd_secret(
+                flags: c::c_uint
+            ) via SYS_memfd_secret -> c::c_int
+        }
+
+        unsafe { ret_owned_fd(memfd_secret(flags.bits())) }
+    }
+
+    // `memfd_secret` isn't available on this architecture.
+    #[cfg(not(any(target_arch = "aarch64", target_arch = "x86", target_arch = "x86_64")))]
+    {
+        let _ = flags;
+        Err(io::Errno::NOSYS)
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg(feature = "fs")]
+pub(crate) fn cachestat(fd: BorrowedFd<'_>, range: &CachestatRange) -> io::Result<Cachestat> {
+    // libc doesn't have `SYS_cachestat` yet.
+    #[cfg(target_arch = "mips")]
+    const SYS_CACHESTAT: c::c_long = 4451;
+    #[cfg(target_arch = "mips64")]
+    const SYS_CACHESTAT: c::c_long = 5451;
+    #[cfg(all(target_arch = "x86_64", target_pointer_width = "32"))]
+    const SYS_CACHESTAT: c::c_long = 0x4000_0000 | 451; // `__X32_SYSCALL_BIT | 451`
+    #[cfg(not(any(
+        target_arch = "mips",
+        target_arch = "mips64",
+        all(target_arch = "x86_64", target_pointer_width = "32")
+    )))]
+    const SYS_CACHESTAT: c::c_long = 451;
+
+    let mut cstat = Cachestat::default();
+    unsafe {
+        syscall_ret(c::syscall(
+            SYS_CACHESTAT,
+            borrowed_fd(fd),
+            range as *const CachestatRange,
+            &mut cstat as *mut Cachestat,
+            0 as c::c_uint,
+        ))?;
+    }
+    Ok(cstat)
+}
+
 #[cfg(any(target_os = "android", target_os = "linux"))]
 pub(crate) fn openat2(
     dirfd: BorrowedFd<'_>,
@@ -1244,6 +1425,128 @@ pub(crate) fn sendfile(
     }
 }
 
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg(feature = "fs")]
+pub(crate) fn getdents(fd: BorrowedFd<'_>, dirent: &mut [u8]) -> io::Result<usize> {
+    // libc doesn't provide a `getdents64` wrapper, so use `syscall`.
+    unsafe {
+        syscall_ret_ssize_t(c::syscall(
+            c::SYS_getdents64,
+            borrowed_fd(fd),
+            dirent.as_mut_ptr(),
+            dirent.len(),
+        ))
+        .map(|nread| nread as usize)
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg(feature = "fs")]
+pub(crate) fn fspick(
+    dirfd: BorrowedFd<'_>,
+    path: &ZStr,
+    flags: FspickFlags,
+) -> io::Result<OwnedFd> {
+    // libc doesn't provide an `fspick` wrapper, so use `syscall`.
+    unsafe {
+        syscall_ret_owned_fd(c::syscall(
+            c::SYS_fspick,
+            borrowed_fd(dirfd),
+            c_str(path),
+            flags.bits(),
+        ))
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg(feature = "fs")]
+pub(crate) fn open_tree(
+    dirfd: BorrowedFd<'_>,
+    path: &ZStr,
+    flags: OpenTreeFlags,
+) -> io::Result<OwnedFd> {
+    // libc doesn't provide an `open_tree` wrapper, so use `syscall`.
+    unsafe {
+        syscall_ret_owned_fd(c::syscall(
+            c::SYS_open_tree,
+            borrowed_fd(dirfd),
+            c_str(path),
+            flags.bits(),
+        ))
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg(feature = "fs")]
+pub(crate) fn mount_setattr(
+    dirfd: BorrowedFd<'_>,
+    path: &ZStr,
+    flags: AtFlags,
+    attr: &MountAttr<'_>,
+) -> io::Result<()> {
+    // A `struct mount_attr`.
+    #[repr(C)]
+    struct RawMountAttr {
+        attr_set: u64,
+        attr_clr: u64,
+        propagation: u64,
+        userns_fd: u64,
+    }
+
+    let attr = RawMountAttr {
+        attr_set: attr.attr_set.bits(),
+        attr_clr: attr.attr_clr.bits(),
+        propagation: attr.propagation.bits(),
+        // Linux only reads this with `MOUNT_ATTR_IDMAP`, and rejects values
+        // greater than `INT_MAX`.
+        userns_fd: attr.userns_fd.map_or(u64::MAX, |fd| borrowed_fd(fd) as u64),
+    };
+
+    // libc doesn't provide a `mount_setattr` wrapper, so use `syscall`.
+    unsafe {
+        syscall_ret(c::syscall(
+            c::SYS_mount_setattr,
+            borrowed_fd(dirfd),
+            c_str(path),
+            flags.bits(),
+            &attr,
+            core::mem::size_of::<RawMountAttr>(),
+        ))
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn pivot_root(new_root: &ZStr, put_old: &ZStr) -> io::Result<()> {
+    // libc doesn't provide a `pivot_root` wrapper, so use `syscall`.
+    unsafe {
+        syscall_ret(c::syscall(
+            c::SYS_pivot_root,
+            c_str(new_root),
+            c_str(put_old),
+        ))
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn swapon(path: &ZStr, flags: u32) -> io::Result<()> {
+    unsafe { ret(c::swapon(c_str(path), flags as c::c_int)) }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn swapoff(path: &ZStr) -> io::Result<()> {
+    unsafe { ret(c::swapoff(c_str(path))) }
+}
+
+#[cfg(target_os = "linux")]
+pub(crate) fn readahead(fd: BorrowedFd<'_>, offset: u64, count: usize) -> io::Result<()> {
+    // Silently cast; we'll get `EINVAL` if the value is negative.
+    let offset = offset as i64;
+    unsafe {
+        ret_ssize_t(c::readahead(borrowed_fd(fd), offset, count))?;
+    }
+    Ok(())
+}
+
 /// Convert from a Linux `statx` value to rustix's `Stat`.
 #[cfg(all(
     any(target_os = "android", target_os = "linux"),
@@ -1664,3 +1967,63 @@ struct Attrlist {
     fileattr: Attrgroup,
     forkattr: Attrgroup,
 }
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg(feature = "fs")]
+pub(crate) fn inotify_init1(flags: CreateFlags) -> io::Result<OwnedFd> {
+    unsafe { ret_owned_fd(c::inotify_init1(flags.bits())) }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg(feature = "fs")]
+pub(crate) fn inotify_add_watch(
+    infd: BorrowedFd<'_>,
+    path: &ZStr,
+    flags: WatchFlags,
+) -> io::Result<i32> {
+    unsafe {
+        ret_c_int(c::inotify_add_watch(
+            borrowed_fd(infd),
+            c_str(path),
+            flags.bits(),
+        ))
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg(feature = "fs")]
+pub(crate) fn inotify_rm_watch(infd: BorrowedFd<'_>, wd: i32) -> io::Result<()> {
+    // Android's `inotify_rm_watch` takes the watch descriptor as a `u32`.
+    unsafe { ret(c::inotify_rm_watch(borrowed_fd(infd), wd as _)) }
+}
+
+#[cfg(target_os = "linux")]
+#[cfg(feature = "fs")]
+pub(crate) fn fanotify_init(flags: InitFlags, event_flags: OFlags) -> io::Result<OwnedFd> {
+    unsafe {
+        ret_owned_fd(c::fanotify_init(
+            flags.bits(),
+            event_flags.bits() as c::c_uint,
+        ))
+    }
+}
+
+#[cfg(target_os = "linux")]
+#[cfg(feature = "fs")]
+pub(crate) fn fanotify_mark(
+    fanotify_fd: BorrowedFd<'_>,
+    flags: MarkFlags,
+    mask: EventFlags,
+    dirfd: BorrowedFd<'_>,
+    path: Option<&ZStr>,
+) -> io::Result<()> {
+    unsafe {
+        ret(c::fanotify_mark(
+            borrowed_fd(fanotify_fd),
+            flags.bits(),
+            mask.bits(),
+            borrowed_fd(dirfd),
+            path.map_or(core::ptr::null(), c_str),
+        ))
+    }
+}
@@ -69,6 +69,14 @@ bitflags! {
         /// `AT_STATX_DONT_SYNC`
         #[cfg(all(target_os = "linux", target_env = "gnu"))]
         const STATX_DONT_SYNC = c::AT_STATX_DONT_SYNC;
+
+        /// `AT_NO_AUTOMOUNT`
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        const NO_AUTOMOUNT = c::AT_NO_AUTOMOUNT;
+
+        /// `AT_RECURSIVE`
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        const RECURSIVE = c::AT_RECURSIVE;
     }
 }
 
@@ -236,7 +244,16 @@ bitflags! {
         /// `O_TRUNC`
         const TRUNC = c::O_TRUNC;
 
-        /// `O_PATH`
+        /// `O_PATH`—Open a handle which refers to a file without opening
+        /// the file itself.
+        ///
+        /// The handle can be used as the `dirfd` of `*at` functions, with
+        /// [`statat_empty`] and [`readlinkat_empty`], and with `fchdir` and
+        /// `close`, but reading and writing fail with `BADF`. With
+        /// `NOFOLLOW`, it can refer to a symlink itself.
+        ///
+        /// [`statat_empty`]: crate::fs::statat_empty
+        /// [`readlinkat_empty`]: crate::fs::readlinkat_empty
         #[cfg(any(target_os = "android",
                   target_os = "emscripten",
                   target_os = "fuchsia",
@@ -499,6 +516,17 @@ pub enum Advice {
     DontNeed = c::POSIX_FADV_DONTNEED as c::c_uint,
 }
 
+#[cfg(any(target_os = "android", target_os = "linux"))]
+bitflags! {
+    /// `O_*` constants for use with [`memfd_secret`].
+    ///
+    /// [`memfd_secret`]: crate::fs::memfd_secret
+    pub struct MemfdSecretFlags: c::c_uint {
+        /// `O_CLOEXEC`
+        const CLOEXEC = c::O_CLOEXEC as c::c_uint;
+    }
+}
+
 #[cfg(any(target_os = "android", target_os = "freebsd", target_os = "linux"))]
 bitflags! {
     /// `MFD_*` constants for use with [`memfd_create`].
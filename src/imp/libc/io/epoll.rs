@@ -62,7 +62,9 @@ use super::super::conv::{ret, ret_owned_fd, ret_u32};
 use crate::fd::{AsFd, AsRawFd, BorrowedFd, RawFd};
 #[cfg(not(feature = "rustc-dep-of-std"))]
 use crate::fd::{FromFd, FromRawFd, IntoFd, IntoRawFd};
+use crate::imp::time::types::Timespec;
 use crate::io::{self, OwnedFd};
+use crate::process::SigSet;
 use alloc::vec::Vec;
 use bitflags::bitflags;
 use core::convert::TryInto;
@@ -319,6 +321,35 @@ impl<Context: self::Context> Epoll<Context> {
         }
     }
 
+    /// `epoll_ctl(self, EPOLL_CTL_ADD, data, event)`—Adds several elements
+    /// to an `Epoll`.
+    ///
+    /// This is equivalent to calling [`Epoll::add`] on each `(data, events)`
+    /// pair in `items`, in order. If adding an element fails, the elements
+    /// which were already added by this call are removed again and their
+    /// `Data` is dropped, so that the set of registered file descriptors is
+    /// unchanged, and the index of the element which failed is returned along
+    /// with the error.
+    #[doc(alias = "epoll_ctl")]
+    pub fn add_many<I>(&self, items: I) -> Result<(), (usize, io::Errno)>
+    where
+        I: IntoIterator<Item = (Context::Data, EventFlags)>,
+    {
+        let mut added = Vec::new();
+        for (index, (data, event_flags)) in items.into_iter().enumerate() {
+            match self.add(data, event_flags) {
+                Ok(target) => added.push(target),
+                Err(err) => {
+                    for target in added.into_iter().rev() {
+                        let _ = self.del(target);
+                    }
+                    return Err((index, err));
+                }
+            }
+        }
+        Ok(())
+    }
+
     /// `epoll_ctl(self, EPOLL_CTL_MOD, target, event)`—Modifies an element in
     /// this `Epoll`.
     ///
@@ -393,6 +424,101 @@ impl<Context: self::Context> Epoll<Context> {
 
         Ok(())
     }
+
+    /// `epoll_pwait2(self, events, timeout, sigmask)`—Waits for registered
+    /// events of interest, with a nanosecond-precision timeout.
+    ///
+    /// For each event of interest, an element is written to `events`. On
+    /// success, this returns the number of written elements.
+    ///
+    /// If `timeout` is `None`, this waits indefinitely. If `sigmask` is
+    /// `Some`, the calling thread's signal mask is replaced by it for the
+    /// duration of the wait.
+    ///
+    /// `epoll_pwait2` requires Linux 5.11. On older kernels, this falls back
+    /// to `epoll_pwait`, with the timeout rounded up to whole milliseconds.
+    #[doc(alias = "epoll_pwait2")]
+    #[doc(alias = "epoll_pwait")]
+    pub fn wait_timeout<'context>(
+        &'context self,
+        event_list: &mut EventVec<'context, Context>,
+        timeout: Option<&Timespec>,
+        sigmask: Option<&SigSet>,
+    ) -> io::Result<usize> {
+        // `epoll_pwait2` always takes a `__kernel_timespec`, which may differ
+        // from libc's `timespec`.
+        #[repr(C)]
+        struct KernelTimespec {
+            tv_sec: i64,
+            tv_nsec: i64,
+        }
+
+        syscall! {
+            fn epoll_pwait2(
+                epfd: c::c_int,
+                events: *mut c::epoll_event,
+                maxevents: c::c_int,
+                timeout: *const KernelTimespec,
+                sigmask: *const c::sigset_t,
+                sigsetsize: c::size_t
+            ) via SYS_epoll_pwait2 -> c::c_int
+        }
+
+        // The kernel's `sigset_t` is smaller than libc's.
+        #[cfg(any(target_arch = "mips", target_arch = "mips64"))]
+        const KERNEL_SIGSET_SIZE: usize = 16;
+        #[cfg(not(any(target_arch = "mips", target_arch = "mips64")))]
+        const KERNEL_SIGSET_SIZE: usize = 8;
+
+        let epfd = self.epoll_fd.as_fd().as_raw_fd();
+        let maxevents = event_list.events.capacity().try_into().unwrap_or(i32::MAX);
+        let sigmask = sigmask.map_or_else(null, |sigmask| &sigmask.sigset);
+
+        // Safety: We're calling `epoll_pwait2` via FFI and we know how it
+        // behaves.
+        unsafe {
+            event_list.events.set_len(0);
+            let events = event_list.events.as_mut_ptr().cast::<c::epoll_event>();
+            #[allow(clippy::useless_conversion)]
+            let timeout = timeout.map(|timeout| KernelTimespec {
+                tv_sec: timeout.tv_sec.into(),
+                tv_nsec: timeout.tv_nsec.into(),
+            });
+            let timeout_ptr: *const KernelTimespec = match &timeout {
+                Some(timeout) => timeout,
+                None => null(),
+            };
+            let nfds = match ret_u32(epoll_pwait2(
+                epfd,
+                events,
+                maxevents,
+                timeout_ptr,
+                sigmask,
+                KERNEL_SIGSET_SIZE,
+            )) {
+                Err(io::Errno::NOSYS) => {
+                    // `epoll_pwait2` was introduced in Linux 5.11; fall back
+                    // to `epoll_pwait`, rounding the timeout up to whole
+                    // milliseconds.
+                    let timeout = match timeout {
+                        Some(timeout) => {
+                            let millis = timeout
+                                .tv_sec
+                                .saturating_mul(1000)
+                                .saturating_add((timeout.tv_nsec + 999_999) / 1_000_000);
+                            millis.max(0).min(i64::from(c::c_int::MAX)) as c::c_int
+                        }
+                        None => -1,
+                    };
+                    ret_u32(c::epoll_pwait(epfd, events, maxevents, timeout, sigmask))?
+                }
+                otherwise => otherwise?,
+            };
+            event_list.events.set_len(nfds as usize);
+            event_list.context = &self.context;
+            Ok(nfds as usize)
+        }
+    }
 }
 
 #[cfg(not(feature = "rustc-dep-of-std"))]
@@ -974,9 +974,7 @@ impl Errno {
     #[cfg(feature = "std")]
     #[inline]
     pub fn from_io_error(io_err: &std::io::Error) -> Option<Self> {
-        io_err
-            .raw_os_error()
-            .and_then(|raw| if raw != 0 { Some(Self(raw)) } else { None })
+        io_err.raw_os_error().and_then(Self::try_from_raw_os_error)
     }
 
     /// Extract the raw OS error number from this error.
@@ -991,6 +989,23 @@ impl Errno {
         Self(raw)
     }
 
+    /// Construct an `Errno` from a raw OS error number, or return `None` if
+    /// it isn't a positive error number, or on Linux, if it isn't in Linux's
+    /// range of error numbers, `1..4096`.
+    #[inline]
+    pub fn try_from_raw_os_error(raw: i32) -> Option<Self> {
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        let valid = (1..4096).contains(&raw);
+        #[cfg(not(any(target_os = "android", target_os = "linux")))]
+        let valid = raw > 0;
+
+        if valid {
+            Some(Self(raw))
+        } else {
+            None
+        }
+    }
+
     pub(crate) fn last_os_error() -> Self {
         Self(errno().0)
     }
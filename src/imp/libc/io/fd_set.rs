@@ -0,0 +1,26 @@
+#![allow(non_snake_case)]
+
+use super::super::c;
+use super::types::RawFdSet;
+use crate::fd::RawFd;
+
+#[inline]
+pub(crate) fn FD_SET(fd: RawFd, set: &mut RawFdSet) {
+    unsafe { c::FD_SET(fd, set) }
+}
+
+#[inline]
+pub(crate) fn FD_CLR(fd: RawFd, set: &mut RawFdSet) {
+    unsafe { c::FD_CLR(fd, set) }
+}
+
+#[inline]
+pub(crate) fn FD_ISSET(fd: RawFd, set: &RawFdSet) -> bool {
+    // Some versions of libc take a `*mut fd_set` here.
+    unsafe { c::FD_ISSET(fd, set as *const RawFdSet as *mut RawFdSet) }
+}
+
+#[inline]
+pub(crate) fn FD_ZERO(set: &mut RawFdSet) {
+    unsafe { c::FD_ZERO(set) }
+}
@@ -1,4 +1,6 @@
 pub(crate) mod errno;
+#[cfg(not(any(windows, target_os = "redox", target_os = "wasi")))]
+pub(crate) mod fd_set;
 #[cfg(not(windows))]
 #[cfg(not(feature = "std"))]
 pub(crate) mod io_slice;
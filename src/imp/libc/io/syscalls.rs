@@ -24,6 +24,13 @@ use core::convert::TryInto;
 use core::mem::MaybeUninit;
 #[cfg(feature = "net")]
 use libc_errno::errno;
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+use {
+    super::super::process::types::RawSigSet,
+    super::super::time::types::{Timespec, Timeval},
+    super::types::RawFdSet,
+    core::ptr::{null, null_mut},
+};
 
 pub(crate) fn read(fd: BorrowedFd<'_>, buf: &mut [u8]) -> io::Result<usize> {
     let nread = unsafe {
@@ -36,6 +43,17 @@ pub(crate) fn read(fd: BorrowedFd<'_>, buf: &mut [u8]) -> io::Result<usize> {
     Ok(nread as usize)
 }
 
+pub(crate) fn read_uninit(fd: BorrowedFd<'_>, buf: &mut [MaybeUninit<u8>]) -> io::Result<usize> {
+    let nread = unsafe {
+        ret_ssize_t(c::read(
+            borrowed_fd(fd),
+            buf.as_mut_ptr().cast(),
+            min(buf.len(), READ_LIMIT),
+        ))?
+    };
+    Ok(nread as usize)
+}
+
 pub(crate) fn write(fd: BorrowedFd<'_>, buf: &[u8]) -> io::Result<usize> {
     let nwritten = unsafe {
         ret_ssize_t(c::write(
@@ -86,7 +104,7 @@ pub(crate) fn readv(fd: BorrowedFd<'_>, bufs: &mut [IoSliceMut]) -> io::Result<u
         ret_ssize_t(c::readv(
             borrowed_fd(fd),
             bufs.as_ptr().cast::<c::iovec>(),
-            min(bufs.len(), max_iov()) as c::c_int,
+            iov_len(bufs.len()),
         ))?
     };
     Ok(nread as usize)
@@ -97,7 +115,7 @@ pub(crate) fn writev(fd: BorrowedFd<'_>, bufs: &[IoSlice]) -> io::Result<usize>
         ret_ssize_t(c::writev(
             borrowed_fd(fd),
             bufs.as_ptr().cast::<c::iovec>(),
-            min(bufs.len(), max_iov()) as c::c_int,
+            iov_len(bufs.len()),
         ))?
     };
     Ok(nwritten as usize)
@@ -115,7 +133,7 @@ pub(crate) fn preadv(
         ret_ssize_t(libc_preadv(
             borrowed_fd(fd),
             bufs.as_ptr().cast::<c::iovec>(),
-            min(bufs.len(), max_iov()) as c::c_int,
+            iov_len(bufs.len()),
             offset,
         ))?
     };
@@ -130,7 +148,7 @@ pub(crate) fn pwritev(fd: BorrowedFd<'_>, bufs: &[IoSlice], offset: u64) -> io::
         ret_ssize_t(libc_pwritev(
             borrowed_fd(fd),
             bufs.as_ptr().cast::<c::iovec>(),
-            min(bufs.len(), max_iov()) as c::c_int,
+            iov_len(bufs.len()),
             offset,
         ))?
     };
@@ -150,7 +168,7 @@ pub(crate) fn preadv2(
         ret_ssize_t(libc_preadv2(
             borrowed_fd(fd),
             bufs.as_ptr().cast::<c::iovec>(),
-            min(bufs.len(), max_iov()) as c::c_int,
+            iov_len(bufs.len()),
             offset,
             flags.bits(),
         ))?
@@ -188,7 +206,7 @@ pub(crate) fn pwritev2(
         ret_ssize_t(libc_pwritev2(
             borrowed_fd(fd),
             bufs.as_ptr().cast::<c::iovec>(),
-            min(bufs.len(), max_iov()) as c::c_int,
+            iov_len(bufs.len()),
             offset,
             flags.bits(),
         ))?
@@ -229,36 +247,12 @@ const READ_LIMIT: usize = c::c_int::MAX as usize - 1;
 #[cfg(not(target_os = "macos"))]
 const READ_LIMIT: usize = c::ssize_t::MAX as usize;
 
-#[cfg(any(
-    target_os = "dragonfly",
-    target_os = "freebsd",
-    target_os = "ios",
-    target_os = "macos",
-    target_os = "netbsd",
-    target_os = "openbsd",
-))]
-const fn max_iov() -> usize {
-    c::IOV_MAX as usize
-}
-
-#[cfg(any(target_os = "android", target_os = "emscripten", target_os = "linux"))]
-const fn max_iov() -> usize {
-    c::UIO_MAXIOV as usize
-}
-
-#[cfg(not(any(
-    target_os = "android",
-    target_os = "dragonfly",
-    target_os = "emscripten",
-    target_os = "freebsd",
-    target_os = "ios",
-    target_os = "linux",
-    target_os = "macos",
-    target_os = "netbsd",
-    target_os = "openbsd",
-)))]
-const fn max_iov() -> usize {
-    16 // The minimum value required by POSIX.
+/// The number of buffers passed to a vectored I/O function, which takes the
+/// count as an `int`. Counts over `IOV_MAX` are passed through, so that they
+/// fail with `EINVAL` rather than silently doing a short read or write.
+#[inline]
+fn iov_len(len: usize) -> c::c_int {
+    min(len, c::c_int::MAX as usize) as c::c_int
 }
 
 pub(crate) unsafe fn close(raw_fd: RawFd) {
@@ -450,6 +444,74 @@ pub(crate) fn pipe_with(flags: PipeFlags) -> io::Result<(OwnedFd, OwnedFd)> {
     }
 }
 
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+#[allow(clippy::useless_conversion)]
+pub(crate) fn select(
+    nfds: c::c_int,
+    readfds: Option<&mut RawFdSet>,
+    writefds: Option<&mut RawFdSet>,
+    exceptfds: Option<&mut RawFdSet>,
+    timeout: Option<&Timeval>,
+) -> io::Result<usize> {
+    // Some platforms write the remaining time back to the timeout, so pass a
+    // copy.
+    let mut timeout = match timeout {
+        Some(timeout) => Some(c::timeval {
+            tv_sec: timeout.tv_sec.try_into().map_err(|_| io::Errno::INVAL)?,
+            tv_usec: timeout.tv_usec.try_into().map_err(|_| io::Errno::INVAL)?,
+        }),
+        None => None,
+    };
+
+    unsafe {
+        ret_c_int(c::select(
+            nfds,
+            opt_mut_ptr(readfds),
+            opt_mut_ptr(writefds),
+            opt_mut_ptr(exceptfds),
+            opt_mut_ptr(timeout.as_mut()),
+        ))
+        .map(|nready| nready as usize)
+    }
+}
+
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+#[allow(clippy::useless_conversion)]
+pub(crate) fn pselect(
+    nfds: c::c_int,
+    readfds: Option<&mut RawFdSet>,
+    writefds: Option<&mut RawFdSet>,
+    exceptfds: Option<&mut RawFdSet>,
+    timeout: Option<&Timespec>,
+    sigmask: Option<&RawSigSet>,
+) -> io::Result<usize> {
+    let timeout = match timeout {
+        Some(timeout) => Some(c::timespec {
+            tv_sec: timeout.tv_sec.try_into().map_err(|_| io::Errno::INVAL)?,
+            tv_nsec: timeout.tv_nsec as _,
+        }),
+        None => None,
+    };
+
+    unsafe {
+        ret_c_int(c::pselect(
+            nfds,
+            opt_mut_ptr(readfds),
+            opt_mut_ptr(writefds),
+            opt_mut_ptr(exceptfds),
+            timeout.as_ref().map_or(null(), |timeout| timeout),
+            sigmask.map_or(null(), |sigmask| sigmask),
+        ))
+        .map(|nready| nready as usize)
+    }
+}
+
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+#[inline]
+fn opt_mut_ptr<T>(t: Option<&mut T>) -> *mut T {
+    t.map_or(null_mut(), |t| t)
+}
+
 #[inline]
 pub(crate) fn poll(fds: &mut [PollFd<'_>], timeout: c::c_int) -> io::Result<usize> {
     let nfds = fds
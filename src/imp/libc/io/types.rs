@@ -82,3 +82,18 @@ pub(crate) const STDIN_FILENO: c::c_int = c::STDIN_FILENO;
 pub(crate) const STDOUT_FILENO: c::c_int = c::STDOUT_FILENO;
 #[cfg(not(windows))]
 pub(crate) const STDERR_FILENO: c::c_int = c::STDERR_FILENO;
+
+#[cfg(not(any(windows, target_os = "redox", target_os = "wasi")))]
+pub(crate) type RawFdSet = c::fd_set;
+
+#[cfg(not(any(windows, target_os = "redox", target_os = "wasi")))]
+#[inline]
+pub(crate) fn raw_fd_set_new() -> RawFdSet {
+    let mut set = unsafe { core::mem::zeroed() };
+    super::fd_set::FD_ZERO(&mut set);
+    set
+}
+
+#[cfg(not(any(windows, target_os = "redox", target_os = "wasi")))]
+#[allow(clippy::unnecessary_cast)]
+pub(crate) const FD_SETSIZE: usize = c::FD_SETSIZE as usize;
@@ -1,9 +1,23 @@
 //! libc syscalls supporting `rustix::mm`.
 
 use super::super::c;
-#[cfg(any(target_os = "android", target_os = "linux"))]
-use super::super::conv::syscall_ret_owned_fd;
 use super::super::conv::{borrowed_fd, no_fd, ret};
+#[cfg(any(target_os = "android", target_os = "linux"))]
+use super::super::conv::{syscall_ret, syscall_ret_owned_fd};
+// libc only defines the `SYS_pkey_*` constants on some architectures.
+#[cfg(all(
+    any(target_os = "android", target_os = "linux"),
+    any(
+        target_arch = "aarch64",
+        target_arch = "arm",
+        target_arch = "mips",
+        target_arch = "mips64",
+        target_arch = "riscv64",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    )
+))]
+use super::super::conv::syscall_ret_u32;
 #[cfg(not(target_os = "wasi"))]
 use super::super::offset::libc_mmap;
 #[cfg(not(any(target_os = "redox", target_os = "wasi")))]
@@ -18,6 +32,8 @@ use crate::fd::BorrowedFd;
 use crate::io;
 #[cfg(any(target_os = "android", target_os = "linux"))]
 use crate::io::OwnedFd;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+use core::{mem::MaybeUninit, ptr::null_mut};
 
 #[cfg(not(any(target_os = "redox", target_os = "wasi")))]
 pub(crate) fn madvise(addr: *mut c::c_void, len: usize, advice: Advice) -> io::Result<()> {
@@ -128,6 +144,20 @@ pub(crate) unsafe fn mprotect(
     ret(c::mprotect(ptr, len, flags.bits()))
 }
 
+#[cfg(any(
+    target_os = "android",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "ios",
+    target_os = "linux",
+    target_os = "macos",
+    target_os = "netbsd",
+    target_os = "openbsd",
+))]
+pub(crate) unsafe fn mincore(addr: *mut c::c_void, len: usize, vec: *mut u8) -> io::Result<()> {
+    ret(c::mincore(addr, len, vec.cast()))
+}
+
 #[cfg(not(target_os = "wasi"))]
 pub(crate) unsafe fn munmap(ptr: *mut c::c_void, len: usize) -> io::Result<()> {
     ret(c::munmap(ptr, len))
@@ -225,3 +255,125 @@ pub(crate) unsafe fn munlock(addr: *mut c::c_void, length: usize) -> io::Result<
 pub(crate) unsafe fn userfaultfd(flags: UserfaultfdFlags) -> io::Result<OwnedFd> {
     syscall_ret_owned_fd(c::syscall(c::SYS_userfaultfd, flags.bits()))
 }
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) unsafe fn set_mempolicy(
+    mode: u32,
+    nodemask: *const usize,
+    maxnode: usize,
+) -> io::Result<()> {
+    // glibc's `set_mempolicy` wrapper lives in libnuma, so use `syscall`.
+    syscall_ret(c::syscall(c::SYS_set_mempolicy, mode, nodemask, maxnode))
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) unsafe fn get_mempolicy(
+    nodemask: *mut usize,
+    maxnode: usize,
+    addr: *mut c::c_void,
+    flags: u32,
+) -> io::Result<u32> {
+    let mut mode = MaybeUninit::<c::c_int>::uninit();
+    syscall_ret(c::syscall(
+        c::SYS_get_mempolicy,
+        mode.as_mut_ptr(),
+        nodemask,
+        maxnode,
+        addr,
+        flags,
+    ))?;
+    Ok(mode.assume_init() as u32)
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) unsafe fn mbind(
+    addr: *mut c::c_void,
+    len: usize,
+    mode: u32,
+    nodemask: *const usize,
+    maxnode: usize,
+    flags: u32,
+) -> io::Result<()> {
+    syscall_ret(c::syscall(
+        c::SYS_mbind,
+        addr,
+        len,
+        mode,
+        nodemask,
+        maxnode,
+        flags,
+    ))
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn getcpu_node() -> io::Result<u32> {
+    let mut node = MaybeUninit::<c::c_uint>::uninit();
+    unsafe {
+        syscall_ret(c::syscall(
+            c::SYS_getcpu,
+            null_mut::<c::c_uint>(),
+            node.as_mut_ptr(),
+            null_mut::<c::c_void>(),
+        ))?;
+        Ok(node.assume_init())
+    }
+}
+
+#[cfg(all(
+    any(target_os = "android", target_os = "linux"),
+    any(
+        target_arch = "aarch64",
+        target_arch = "arm",
+        target_arch = "mips",
+        target_arch = "mips64",
+        target_arch = "riscv64",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    )
+))]
+pub(crate) fn pkey_alloc(flags: u32, access_rights: u32) -> io::Result<u32> {
+    unsafe { syscall_ret_u32(c::syscall(c::SYS_pkey_alloc, flags, access_rights)) }
+}
+
+#[cfg(all(
+    any(target_os = "android", target_os = "linux"),
+    any(
+        target_arch = "aarch64",
+        target_arch = "arm",
+        target_arch = "mips",
+        target_arch = "mips64",
+        target_arch = "riscv64",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    )
+))]
+pub(crate) fn pkey_free(pkey: u32) -> io::Result<()> {
+    unsafe { syscall_ret(c::syscall(c::SYS_pkey_free, pkey as c::c_int)) }
+}
+
+#[cfg(all(
+    any(target_os = "android", target_os = "linux"),
+    any(
+        target_arch = "aarch64",
+        target_arch = "arm",
+        target_arch = "mips",
+        target_arch = "mips64",
+        target_arch = "riscv64",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    )
+))]
+pub(crate) unsafe fn pkey_mprotect(
+    ptr: *mut c::c_void,
+    len: usize,
+    prot: ProtFlags,
+    pkey: u32,
+) -> io::Result<()> {
+    syscall_ret(c::syscall(
+        c::SYS_pkey_mprotect,
+        ptr,
+        len,
+        prot.bits(),
+        pkey as c::c_int,
+    ))
+}
@@ -68,6 +68,9 @@ pub(crate) mod mm;
 pub(crate) mod net;
 #[cfg(not(windows))]
 pub(crate) mod process;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg(feature = "pty")]
+pub(crate) mod pty;
 #[cfg(not(windows))]
 #[cfg(feature = "rand")]
 pub(crate) mod rand;
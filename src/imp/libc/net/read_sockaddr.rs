@@ -2,8 +2,6 @@ use super::super::c;
 #[cfg(unix)]
 use super::addr::SocketAddrUnix;
 use super::ext::{in6_addr_s6_addr, in_addr_s_addr, sockaddr_in6_sin6_scope_id};
-#[cfg(not(windows))]
-use crate::ffi::ZStr;
 use crate::io;
 use crate::net::{Ipv4Addr, Ipv6Addr, SocketAddrAny, SocketAddrV4, SocketAddrV6};
 #[cfg(not(windows))]
@@ -128,11 +126,11 @@ pub(crate) unsafe fn read_sockaddr(
         }
         #[cfg(unix)]
         c::AF_UNIX => {
-            if len < offsetof_sun_path {
+            if len < offsetof_sun_path || len > size_of::<c::sockaddr_un>() {
                 return Err(io::Errno::INVAL);
             }
             if len == offsetof_sun_path {
-                Ok(SocketAddrAny::Unix(SocketAddrUnix::new(&[][..]).unwrap()))
+                Ok(SocketAddrAny::Unix(SocketAddrUnix::new(&[][..])?))
             } else {
                 let decode = *storage.cast::<c::sockaddr_un>();
 
@@ -141,26 +139,22 @@ pub(crate) unsafe fn read_sockaddr(
                     // FreeBSD sometimes sets the length to longer than the length
                     // of the NUL-terminated string. Find the NUL and truncate the
                     // string accordingly.
-                    &decode.sun_path[..decode.sun_path.iter().position(|b| *b == 0).unwrap()]
+                    match decode.sun_path.iter().position(|b| *b == 0) {
+                        Some(nul) => &decode.sun_path[..nul],
+                        None => return Err(io::Errno::INVAL),
+                    }
                 } else {
                     // Otherwise, use the provided length.
                     let provided_len = len - 1 - offsetof_sun_path;
                     if decode.sun_path[provided_len] != b'\0' as c::c_char {
                         return Err(io::Errno::INVAL);
                     }
-                    debug_assert_eq!(
-                        ZStr::from_ptr(decode.sun_path.as_ptr().cast())
-                            .to_bytes()
-                            .len(),
-                        provided_len
-                    );
                     &decode.sun_path[..provided_len]
                 };
 
-                Ok(SocketAddrAny::Unix(
-                    SocketAddrUnix::new(path_bytes.iter().map(|c| *c as u8).collect::<Vec<u8>>())
-                        .unwrap(),
-                ))
+                Ok(SocketAddrAny::Unix(SocketAddrUnix::new(
+                    path_bytes.iter().map(|c| *c as u8).collect::<Vec<u8>>(),
+                )?))
             }
         }
         _ => Err(io::Errno::INVAL),
@@ -238,7 +232,10 @@ unsafe fn inner_read_sockaddr_os(
                 // of the NUL-terminated string. Find the NUL and truncate the
                 // string accordingly.
                 #[cfg(target_os = "freebsd")]
-                let path_bytes = &path_bytes[..path_bytes.iter().position(|b| *b == 0).unwrap()];
+                let path_bytes = &path_bytes[..path_bytes
+                    .iter()
+                    .position(|b| *b == 0)
+                    .unwrap_or(path_bytes.len())];
 
                 SocketAddrAny::Unix(
                     SocketAddrUnix::new(path_bytes.iter().map(|c| *c as u8).collect::<Vec<u8>>())
@@ -73,5 +73,8 @@ bitflags! {
         const TRUNC = c::MSG_TRUNC as c::c_int;
         /// `MSG_WAITALL`
         const WAITALL = c::MSG_WAITALL;
+        /// `MSG_WAITFORONE`
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        const WAITFORONE = c::MSG_WAITFORONE;
     }
 }
@@ -2,6 +2,10 @@
 
 use super::super::c;
 use super::super::conv::{borrowed_fd, ret, ret_owned_fd, ret_send_recv, send_recv_len};
+#[cfg(any(target_os = "android", target_os = "linux"))]
+use super::super::conv::{c_str, ret_c_int};
+#[cfg(any(target_os = "android", target_os = "linux"))]
+use super::super::time::types::{Timespec, Timeval};
 #[cfg(unix)]
 use super::addr::SocketAddrUnix;
 use super::ext::{in6_addr_new, in_addr_new};
@@ -11,14 +15,24 @@ use super::read_sockaddr::initialize_family_to_unspec;
 use super::read_sockaddr::{maybe_read_sockaddr_os, read_sockaddr_os};
 #[cfg(not(any(target_os = "redox", target_os = "wasi")))]
 use super::send_recv::{RecvFlags, SendFlags};
+#[cfg(any(target_os = "android", target_os = "linux"))]
+use super::types::SockExtendedErr;
 #[cfg(not(any(target_os = "redox", target_os = "wasi")))]
 use super::types::{AcceptFlags, AddressFamily, Protocol, Shutdown, SocketFlags, SocketType};
 #[cfg(not(any(target_os = "redox", target_os = "wasi")))]
 use super::write_sockaddr::{encode_sockaddr_v4, encode_sockaddr_v6};
 use crate::fd::BorrowedFd;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+use crate::ffi::{ZStr, ZString};
+#[cfg(any(target_os = "android", target_os = "linux"))]
+use crate::io::IoSliceMut;
 use crate::io::{self, OwnedFd};
+#[cfg(any(target_os = "android", target_os = "linux"))]
+use crate::net::{ControlMessage, RecvMsg};
 use crate::net::{SocketAddrAny, SocketAddrV4, SocketAddrV6};
 use crate::utils::as_ptr;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+use alloc::vec::Vec;
 use core::convert::TryInto;
 use core::mem::{size_of, MaybeUninit};
 #[cfg(not(any(target_os = "redox", target_os = "wasi")))]
@@ -349,6 +363,172 @@ pub(crate) fn getsockname(sockfd: BorrowedFd<'_>) -> io::Result<SocketAddrAny> {
     }
 }
 
+/// The layout of Linux's `struct sock_extended_err`.
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct sock_extended_err {
+    ee_errno: u32,
+    ee_origin: u8,
+    ee_type: u8,
+    ee_code: u8,
+    ee_pad: u8,
+    ee_info: u32,
+    ee_data: u32,
+}
+
+/// `recvmsg(fd, msg, MSG_ERRQUEUE | MSG_DONTWAIT)`—Receives one message from
+/// a socket's error queue, and returns the `IP_RECVERR` or `IPV6_RECVERR`
+/// error it contains, if any.
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn recv_error_queue(fd: BorrowedFd<'_>) -> io::Result<Option<SockExtendedErr>> {
+    // Space for a `sock_extended_err` followed by the offending address,
+    // aligned for `cmsghdr`.
+    let mut control = [0_usize; 32];
+    let flags = RecvFlags::ERRQUEUE | RecvFlags::DONTWAIT;
+
+    unsafe {
+        // Some libc implementations have padding fields in `msghdr`, so
+        // zero-initialize it and then set the fields we use.
+        let mut msg: c::msghdr = core::mem::zeroed();
+        msg.msg_control = control.as_mut_ptr().cast();
+        msg.msg_controllen = core::mem::size_of_val(&control) as _;
+
+        ret_send_recv(c::recvmsg(borrowed_fd(fd), &mut msg, flags.bits()))?;
+
+        let mut cmsg = c::CMSG_FIRSTHDR(&msg);
+        while !cmsg.is_null() {
+            let (level, type_) = ((*cmsg).cmsg_level, (*cmsg).cmsg_type);
+            if ((level == c::SOL_IP && type_ == c::IP_RECVERR)
+                || (level == c::SOL_IPV6 && type_ == c::IPV6_RECVERR))
+                && (*cmsg).cmsg_len as usize
+                    >= c::CMSG_LEN(core::mem::size_of::<sock_extended_err>() as _) as usize
+            {
+                let ee = c::CMSG_DATA(cmsg)
+                    .cast::<sock_extended_err>()
+                    .read_unaligned();
+                return Ok(Some(SockExtendedErr {
+                    errno: io::Errno::from_raw_os_error(ee.ee_errno as i32),
+                    origin: ee.ee_origin,
+                    type_: ee.ee_type,
+                    code: ee.ee_code,
+                    info: ee.ee_info,
+                    data: ee.ee_data,
+                }));
+            }
+            cmsg = c::CMSG_NXTHDR(&msg, cmsg);
+        }
+    }
+
+    Ok(None)
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[allow(clippy::useless_conversion, clippy::unnecessary_cast)]
+pub(crate) fn recvmsg(
+    fd: BorrowedFd<'_>,
+    bufs: &mut [IoSliceMut<'_>],
+    flags: RecvFlags,
+) -> io::Result<RecvMsg> {
+    // Space for several control messages, aligned for `cmsghdr`.
+    let mut control = [0_usize; 32];
+    let mut control_messages = Vec::new();
+
+    unsafe {
+        // Some libc implementations have padding fields in `msghdr`, so
+        // zero-initialize it and then set the fields we use.
+        let mut msg: c::msghdr = core::mem::zeroed();
+        msg.msg_iov = bufs.as_mut_ptr().cast();
+        msg.msg_iovlen = bufs.len() as _;
+        msg.msg_control = control.as_mut_ptr().cast();
+        msg.msg_controllen = core::mem::size_of_val(&control) as _;
+
+        let bytes = ret_send_recv(c::recvmsg(borrowed_fd(fd), &mut msg, flags.bits()))?;
+
+        let mut cmsg = c::CMSG_FIRSTHDR(&msg);
+        while !cmsg.is_null() {
+            let (level, type_) = ((*cmsg).cmsg_level, (*cmsg).cmsg_type);
+            let len = (*cmsg).cmsg_len as usize;
+            if level == c::SOL_SOCKET
+                && type_ == c::SCM_TIMESTAMP
+                && len >= c::CMSG_LEN(size_of::<c::timeval>() as _) as usize
+            {
+                let tv = c::CMSG_DATA(cmsg).cast::<c::timeval>().read_unaligned();
+                control_messages.push(ControlMessage::ScmTimestamp(Timeval {
+                    tv_sec: tv.tv_sec.into(),
+                    tv_usec: tv.tv_usec.into(),
+                }));
+            } else if level == c::SOL_SOCKET
+                && type_ == c::SCM_TIMESTAMPNS
+                && len >= c::CMSG_LEN(size_of::<c::timespec>() as _) as usize
+            {
+                let ts = c::CMSG_DATA(cmsg).cast::<c::timespec>().read_unaligned();
+                control_messages.push(ControlMessage::ScmTimestampNs(Timespec {
+                    tv_sec: ts.tv_sec.into(),
+                    tv_nsec: ts.tv_nsec.into(),
+                }));
+            }
+            cmsg = c::CMSG_NXTHDR(&msg, cmsg);
+        }
+
+        Ok(RecvMsg {
+            bytes: bytes as usize,
+            control_messages,
+        })
+    }
+}
+
+/// The maximum number of messages passed to `recvmmsg` and `sendmmsg`, which
+/// take the count as an `unsigned int`.
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+fn mmsg_len(len: usize) -> c::c_uint {
+    core::cmp::min(len, c::c_uint::MAX as usize) as c::c_uint
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+pub(crate) unsafe fn recvmmsg(
+    fd: BorrowedFd<'_>,
+    msgs: *mut c::mmsghdr,
+    len: usize,
+    flags: RecvFlags,
+    timeout: Option<&Timespec>,
+) -> io::Result<usize> {
+    // The kernel writes the remaining time back to `timeout`, so pass it a
+    // copy.
+    let mut timeout = timeout.copied();
+    let timeout = match &mut timeout {
+        Some(timeout) => timeout,
+        None => null_mut(),
+    };
+    let nrecv = ret_c_int(c::recvmmsg(
+        borrowed_fd(fd),
+        msgs,
+        mmsg_len(len),
+        flags.bits() as _,
+        timeout,
+    ))?;
+    Ok(nrecv as usize)
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+pub(crate) unsafe fn sendmmsg(
+    fd: BorrowedFd<'_>,
+    msgs: *mut c::mmsghdr,
+    len: usize,
+    flags: SendFlags,
+) -> io::Result<usize> {
+    let nsent = ret_c_int(c::sendmmsg(
+        borrowed_fd(fd),
+        msgs,
+        mmsg_len(len),
+        flags.bits() as _,
+    ))?;
+    Ok(nsent as usize)
+}
+
 #[cfg(not(any(target_os = "redox", target_os = "wasi")))]
 pub(crate) fn getpeername(sockfd: BorrowedFd<'_>) -> io::Result<Option<SocketAddrAny>> {
     unsafe {
@@ -387,18 +567,95 @@ pub(crate) fn socketpair(
     }
 }
 
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn if_nametoindex(name: &ZStr) -> io::Result<u32> {
+    match unsafe { c::if_nametoindex(c_str(name)) } {
+        0 => Err(io::Errno::last_os_error()),
+        index => Ok(index),
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn if_indextoname(index: u32) -> io::Result<ZString> {
+    let mut name = [0_u8; c::IF_NAMESIZE];
+    if unsafe { c::if_indextoname(index, name.as_mut_ptr().cast()) }.is_null() {
+        return Err(io::Errno::last_os_error());
+    }
+    let len = name
+        .iter()
+        .position(|b| *b == b'\0')
+        .unwrap_or(c::IF_NAMESIZE);
+    Ok(ZString::new(&name[..len]).unwrap())
+}
+
 #[cfg(not(any(target_os = "redox", target_os = "wasi")))]
 pub(crate) mod sockopt {
     use super::{c, in6_addr_new, in_addr_new, BorrowedFd};
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    use crate::ffi::{ZStr, ZString};
     use crate::io;
     use crate::net::sockopt::Timeout;
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    use crate::net::sockopt::{TcpInfo, TcpState, UCred};
     use crate::net::{Ipv4Addr, Ipv6Addr, SocketType};
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    use crate::process::{Gid, Pid, Uid};
     use crate::utils::as_mut_ptr;
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    use core::convert::TryFrom;
     use core::convert::TryInto;
     use core::time::Duration;
     #[cfg(windows)]
     use windows_sys::Win32::Foundation::BOOL;
 
+    /// `TCP_CA_NAME_MAX`—The size of a TCP congestion control algorithm name,
+    /// including the NUL.
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    const TCP_CA_NAME_MAX: usize = 16;
+
+    // libc doesn't define `SO_SNDBUFFORCE` and `SO_RCVBUFFORCE` on Linux, so
+    // use the values from the kernel's <asm/socket.h>.
+
+    /// `SO_SNDBUFFORCE`
+    #[cfg(all(
+        any(target_os = "android", target_os = "linux"),
+        not(any(
+            target_arch = "mips",
+            target_arch = "mips64",
+            target_arch = "sparc",
+            target_arch = "sparc64"
+        ))
+    ))]
+    const SO_SNDBUFFORCE: i32 = 32;
+
+    /// `SO_SNDBUFFORCE`
+    #[cfg(all(
+        any(target_os = "android", target_os = "linux"),
+        any(target_arch = "mips", target_arch = "mips64")
+    ))]
+    const SO_SNDBUFFORCE: i32 = 31;
+
+    /// `SO_SNDBUFFORCE`
+    #[cfg(all(
+        any(target_os = "android", target_os = "linux"),
+        any(target_arch = "sparc", target_arch = "sparc64")
+    ))]
+    const SO_SNDBUFFORCE: i32 = 0x100a;
+
+    /// `SO_RCVBUFFORCE`
+    #[cfg(all(
+        any(target_os = "android", target_os = "linux"),
+        not(any(target_arch = "sparc", target_arch = "sparc64"))
+    ))]
+    const SO_RCVBUFFORCE: i32 = 33;
+
+    /// `SO_RCVBUFFORCE`
+    #[cfg(all(
+        any(target_os = "android", target_os = "linux"),
+        any(target_arch = "sparc", target_arch = "sparc64")
+    ))]
+    const SO_RCVBUFFORCE: i32 = 0x100b;
+
     // TODO: With Rust 1.53 we can use `Duration::ZERO` instead.
     const DURATION_ZERO: Duration = Duration::from_secs(0);
 
@@ -449,15 +706,27 @@ pub(crate) mod sockopt {
             "Socket APIs don't ever use `bool` directly"
         );
 
-        unsafe {
-            ret(c::setsockopt(
-                borrowed_fd(fd),
-                level,
-                optname,
-                as_ptr(&value) as _,
-                optlen,
-            ))
-        }
+        unsafe { setsockopt_raw(fd, level, optname, as_ptr(&value).cast(), optlen) }
+    }
+
+    /// Like `setsockopt`, but for variable-size values such as strings.
+    #[inline]
+    unsafe fn setsockopt_raw(
+        fd: BorrowedFd<'_>,
+        level: i32,
+        optname: i32,
+        value: *const u8,
+        optlen: c::socklen_t,
+    ) -> io::Result<()> {
+        use super::*;
+
+        ret(c::setsockopt(
+            borrowed_fd(fd),
+            level,
+            optname,
+            value as _,
+            optlen,
+        ))
     }
 
     #[inline]
@@ -490,6 +759,65 @@ pub(crate) mod sockopt {
         getsockopt(fd, c::SOL_SOCKET as _, c::SO_BROADCAST).map(to_bool)
     }
 
+    #[inline]
+    pub(crate) fn set_socket_keepalive(fd: BorrowedFd<'_>, keepalive: bool) -> io::Result<()> {
+        setsockopt(
+            fd,
+            c::SOL_SOCKET as _,
+            c::SO_KEEPALIVE,
+            from_bool(keepalive),
+        )
+    }
+
+    #[inline]
+    pub(crate) fn get_socket_keepalive(fd: BorrowedFd<'_>) -> io::Result<bool> {
+        getsockopt(fd, c::SOL_SOCKET as _, c::SO_KEEPALIVE).map(to_bool)
+    }
+
+    #[inline]
+    pub(crate) fn set_socket_recv_buffer_size(fd: BorrowedFd<'_>, size: usize) -> io::Result<()> {
+        let size: c::c_int = size.try_into().map_err(|_| io::Errno::INVAL)?;
+        setsockopt(fd, c::SOL_SOCKET as _, c::SO_RCVBUF, size)
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[inline]
+    pub(crate) fn set_socket_recv_buffer_size_force(
+        fd: BorrowedFd<'_>,
+        size: usize,
+    ) -> io::Result<()> {
+        let size: c::c_int = size.try_into().map_err(|_| io::Errno::INVAL)?;
+        setsockopt(fd, c::SOL_SOCKET as _, SO_RCVBUFFORCE, size)
+    }
+
+    #[inline]
+    pub(crate) fn get_socket_recv_buffer_size(fd: BorrowedFd<'_>) -> io::Result<usize> {
+        let size: c::c_int = getsockopt(fd, c::SOL_SOCKET as _, c::SO_RCVBUF)?;
+        Ok(size as usize)
+    }
+
+    #[inline]
+    pub(crate) fn set_socket_send_buffer_size(fd: BorrowedFd<'_>, size: usize) -> io::Result<()> {
+        let size: c::c_int = size.try_into().map_err(|_| io::Errno::INVAL)?;
+        setsockopt(fd, c::SOL_SOCKET as _, c::SO_SNDBUF, size)
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[inline]
+    pub(crate) fn set_socket_send_buffer_size_force(
+        fd: BorrowedFd<'_>,
+        size: usize,
+    ) -> io::Result<()> {
+        let size: c::c_int = size.try_into().map_err(|_| io::Errno::INVAL)?;
+        setsockopt(fd, c::SOL_SOCKET as _, SO_SNDBUFFORCE, size)
+    }
+
+    #[inline]
+    pub(crate) fn get_socket_send_buffer_size(fd: BorrowedFd<'_>) -> io::Result<usize> {
+        let size: c::c_int = getsockopt(fd, c::SOL_SOCKET as _, c::SO_SNDBUF)?;
+        Ok(size as usize)
+    }
+
     #[inline]
     pub(crate) fn set_socket_linger(
         fd: BorrowedFd<'_>,
@@ -535,6 +863,66 @@ pub(crate) mod sockopt {
         getsockopt(fd, c::SOL_SOCKET as _, c::SO_PASSCRED).map(to_bool)
     }
 
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[inline]
+    pub(crate) fn set_socket_timestamp(fd: BorrowedFd<'_>, value: bool) -> io::Result<()> {
+        setsockopt(fd, c::SOL_SOCKET as _, c::SO_TIMESTAMP, from_bool(value))
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[inline]
+    pub(crate) fn get_socket_timestamp(fd: BorrowedFd<'_>) -> io::Result<bool> {
+        getsockopt(fd, c::SOL_SOCKET as _, c::SO_TIMESTAMP).map(to_bool)
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[inline]
+    pub(crate) fn set_socket_timestampns(fd: BorrowedFd<'_>, value: bool) -> io::Result<()> {
+        setsockopt(fd, c::SOL_SOCKET as _, c::SO_TIMESTAMPNS, from_bool(value))
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[inline]
+    pub(crate) fn get_socket_timestampns(fd: BorrowedFd<'_>) -> io::Result<bool> {
+        getsockopt(fd, c::SOL_SOCKET as _, c::SO_TIMESTAMPNS).map(to_bool)
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[inline]
+    pub(crate) fn set_socket_bindtodevice(fd: BorrowedFd<'_>, name: &ZStr) -> io::Result<()> {
+        let name = name.to_bytes();
+        unsafe {
+            setsockopt_raw(
+                fd,
+                c::SOL_SOCKET as _,
+                c::SO_BINDTODEVICE,
+                name.as_ptr(),
+                name.len().try_into().map_err(|_| io::Errno::INVAL)?,
+            )
+        }
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[inline]
+    pub(crate) fn get_socket_bindtodevice(fd: BorrowedFd<'_>) -> io::Result<ZString> {
+        let name: [u8; c::IFNAMSIZ] = getsockopt(fd, c::SOL_SOCKET as _, c::SO_BINDTODEVICE)?;
+        let len = name.iter().position(|b| *b == b'\0').unwrap_or(c::IFNAMSIZ);
+        Ok(ZString::new(&name[..len]).unwrap())
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[inline]
+    pub(crate) fn get_socket_peercred(fd: BorrowedFd<'_>) -> io::Result<UCred> {
+        let cred: c::ucred = getsockopt(fd, c::SOL_SOCKET as _, c::SO_PEERCRED)?;
+        unsafe {
+            Ok(UCred {
+                pid: Pid::from_raw(cred.pid as _),
+                uid: Uid::from_raw(cred.uid),
+                gid: Gid::from_raw(cred.gid),
+            })
+        }
+    }
+
     #[inline]
     pub(crate) fn set_socket_timeout(
         fd: BorrowedFd<'_>,
@@ -633,6 +1021,16 @@ pub(crate) mod sockopt {
         }
     }
 
+    #[inline]
+    pub(crate) fn get_socket_error(fd: BorrowedFd<'_>) -> io::Result<Result<(), io::Errno>> {
+        let err: c::c_int = getsockopt(fd, c::SOL_SOCKET as _, c::SO_ERROR)?;
+        Ok(if err == 0 {
+            Ok(())
+        } else {
+            Err(io::Errno::from_raw_os_error(err))
+        })
+    }
+
     #[inline]
     pub(crate) fn set_ip_ttl(fd: BorrowedFd<'_>, ttl: u32) -> io::Result<()> {
         setsockopt(fd, c::IPPROTO_IP as _, c::IP_TTL, ttl)
@@ -643,6 +1041,32 @@ pub(crate) mod sockopt {
         getsockopt(fd, c::IPPROTO_IP as _, c::IP_TTL)
     }
 
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[inline]
+    pub(crate) fn set_ip_recverr(fd: BorrowedFd<'_>, value: bool) -> io::Result<()> {
+        setsockopt(fd, c::IPPROTO_IP as _, c::IP_RECVERR, from_bool(value))
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[inline]
+    pub(crate) fn get_ip_recverr(fd: BorrowedFd<'_>) -> io::Result<bool> {
+        getsockopt(fd, c::IPPROTO_IP as _, c::IP_RECVERR).map(to_bool)
+    }
+
+    #[cfg(not(windows))]
+    #[inline]
+    pub(crate) fn set_ip_tos(fd: BorrowedFd<'_>, value: u8) -> io::Result<()> {
+        let value: c::c_int = value.into();
+        setsockopt(fd, c::IPPROTO_IP as _, c::IP_TOS, value)
+    }
+
+    #[cfg(not(windows))]
+    #[inline]
+    pub(crate) fn get_ip_tos(fd: BorrowedFd<'_>) -> io::Result<u8> {
+        let value: c::c_int = getsockopt(fd, c::IPPROTO_IP as _, c::IP_TOS)?;
+        Ok(value as u8)
+    }
+
     #[inline]
     pub(crate) fn set_ipv6_v6only(fd: BorrowedFd<'_>, only_v6: bool) -> io::Result<()> {
         setsockopt(fd, c::IPPROTO_IPV6 as _, c::IPV6_V6ONLY, from_bool(only_v6))
@@ -653,6 +1077,42 @@ pub(crate) mod sockopt {
         getsockopt(fd, c::IPPROTO_IPV6 as _, c::IPV6_V6ONLY).map(to_bool)
     }
 
+    #[cfg(any(
+        target_os = "android",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "illumos",
+        target_os = "ios",
+        target_os = "linux",
+        target_os = "macos",
+        target_os = "netbsd",
+        target_os = "openbsd",
+        target_os = "solaris",
+    ))]
+    #[inline]
+    pub(crate) fn set_ipv6_tclass(fd: BorrowedFd<'_>, value: u32) -> io::Result<()> {
+        let value: c::c_int = value.try_into().map_err(|_| io::Errno::INVAL)?;
+        setsockopt(fd, c::IPPROTO_IPV6 as _, c::IPV6_TCLASS, value)
+    }
+
+    #[cfg(any(
+        target_os = "android",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "illumos",
+        target_os = "ios",
+        target_os = "linux",
+        target_os = "macos",
+        target_os = "netbsd",
+        target_os = "openbsd",
+        target_os = "solaris",
+    ))]
+    #[inline]
+    pub(crate) fn get_ipv6_tclass(fd: BorrowedFd<'_>) -> io::Result<u32> {
+        let value: c::c_int = getsockopt(fd, c::IPPROTO_IPV6 as _, c::IPV6_TCLASS)?;
+        Ok(value as u32)
+    }
+
     #[inline]
     pub(crate) fn set_ip_multicast_loop(
         fd: BorrowedFd<'_>,
@@ -803,6 +1263,142 @@ pub(crate) mod sockopt {
         getsockopt(fd, c::IPPROTO_TCP as _, c::TCP_NODELAY).map(to_bool)
     }
 
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[inline]
+    pub(crate) fn set_tcp_keepidle(fd: BorrowedFd<'_>, duration: Duration) -> io::Result<()> {
+        let secs: c::c_uint = duration_to_secs(duration)?;
+        setsockopt(fd, c::IPPROTO_TCP as _, c::TCP_KEEPIDLE, secs)
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[inline]
+    pub(crate) fn get_tcp_keepidle(fd: BorrowedFd<'_>) -> io::Result<Duration> {
+        let secs: c::c_uint = getsockopt(fd, c::IPPROTO_TCP as _, c::TCP_KEEPIDLE)?;
+        Ok(Duration::from_secs(secs.into()))
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[inline]
+    pub(crate) fn set_tcp_keepintvl(fd: BorrowedFd<'_>, duration: Duration) -> io::Result<()> {
+        let secs: c::c_uint = duration_to_secs(duration)?;
+        setsockopt(fd, c::IPPROTO_TCP as _, c::TCP_KEEPINTVL, secs)
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[inline]
+    pub(crate) fn get_tcp_keepintvl(fd: BorrowedFd<'_>) -> io::Result<Duration> {
+        let secs: c::c_uint = getsockopt(fd, c::IPPROTO_TCP as _, c::TCP_KEEPINTVL)?;
+        Ok(Duration::from_secs(secs.into()))
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[inline]
+    pub(crate) fn set_tcp_keepcnt(fd: BorrowedFd<'_>, count: u32) -> io::Result<()> {
+        setsockopt(fd, c::IPPROTO_TCP as _, c::TCP_KEEPCNT, count)
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[inline]
+    pub(crate) fn get_tcp_keepcnt(fd: BorrowedFd<'_>) -> io::Result<u32> {
+        getsockopt(fd, c::IPPROTO_TCP as _, c::TCP_KEEPCNT)
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[inline]
+    pub(crate) fn set_tcp_user_timeout(fd: BorrowedFd<'_>, timeout: Duration) -> io::Result<()> {
+        let millis: c::c_uint = duration_to_millis(timeout)?;
+        setsockopt(fd, c::IPPROTO_TCP as _, c::TCP_USER_TIMEOUT, millis)
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[inline]
+    pub(crate) fn get_tcp_user_timeout(fd: BorrowedFd<'_>) -> io::Result<Duration> {
+        let millis: c::c_uint = getsockopt(fd, c::IPPROTO_TCP as _, c::TCP_USER_TIMEOUT)?;
+        Ok(Duration::from_millis(millis.into()))
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[inline]
+    pub(crate) fn set_tcp_congestion(fd: BorrowedFd<'_>, name: &ZStr) -> io::Result<()> {
+        let name = name.to_bytes();
+        unsafe {
+            setsockopt_raw(
+                fd,
+                c::IPPROTO_TCP as _,
+                c::TCP_CONGESTION,
+                name.as_ptr(),
+                name.len().try_into().map_err(|_| io::Errno::INVAL)?,
+            )
+        }
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[inline]
+    pub(crate) fn get_tcp_congestion(fd: BorrowedFd<'_>) -> io::Result<ZString> {
+        let name: [u8; TCP_CA_NAME_MAX] = getsockopt(fd, c::IPPROTO_TCP as _, c::TCP_CONGESTION)?;
+        let len = name
+            .iter()
+            .position(|b| *b == b'\0')
+            .unwrap_or(TCP_CA_NAME_MAX);
+        Ok(ZString::new(&name[..len]).unwrap())
+    }
+
+    /// The leading fields of Linux's `struct tcp_info`, which have been present
+    /// since Linux 2.6. Newer kernels append fields after these.
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    struct tcp_info {
+        tcpi_state: u8,
+        tcpi_ca_state: u8,
+        tcpi_retransmits: u8,
+        tcpi_probes: u8,
+        tcpi_backoff: u8,
+        tcpi_options: u8,
+        tcpi_wscale: u8,
+        tcpi_flags: u8,
+        tcpi_rto: u32,
+        tcpi_ato: u32,
+        tcpi_snd_mss: u32,
+        tcpi_rcv_mss: u32,
+        tcpi_unacked: u32,
+        tcpi_sacked: u32,
+        tcpi_lost: u32,
+        tcpi_retrans: u32,
+        tcpi_fackets: u32,
+        tcpi_last_data_sent: u32,
+        tcpi_last_ack_sent: u32,
+        tcpi_last_data_recv: u32,
+        tcpi_last_ack_recv: u32,
+        tcpi_pmtu: u32,
+        tcpi_rcv_ssthresh: u32,
+        tcpi_rtt: u32,
+        tcpi_rttvar: u32,
+        tcpi_snd_ssthresh: u32,
+        tcpi_snd_cwnd: u32,
+        tcpi_advmss: u32,
+        tcpi_reordering: u32,
+        tcpi_rcv_rtt: u32,
+        tcpi_rcv_space: u32,
+        tcpi_total_retrans: u32,
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[inline]
+    pub(crate) fn get_tcp_info(fd: BorrowedFd<'_>) -> io::Result<TcpInfo> {
+        // `getsockopt` permits the kernel to write fewer bytes than we ask for,
+        // leaving the remaining fields zeroed.
+        let info: tcp_info = getsockopt(fd, c::IPPROTO_TCP as _, c::TCP_INFO)?;
+        Ok(TcpInfo {
+            state: TcpState::from_raw(info.tcpi_state),
+            retransmits: info.tcpi_retransmits,
+            rtt: info.tcpi_rtt,
+            rttvar: info.tcpi_rttvar,
+            snd_cwnd: info.tcpi_snd_cwnd,
+            total_retrans: info.tcpi_total_retrans,
+        })
+    }
+
     #[inline]
     fn to_imr(multiaddr: &Ipv4Addr, interface: &Ipv4Addr) -> c::ip_mreq {
         c::ip_mreq {
@@ -841,6 +1437,32 @@ pub(crate) mod sockopt {
         interface as c::c_uint
     }
 
+    /// Convert a `Duration` to a whole number of seconds, rounding up.
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[inline]
+    fn duration_to_secs<T: TryFrom<u64>>(duration: Duration) -> io::Result<T> {
+        let mut secs = duration.as_secs();
+        if duration.subsec_nanos() != 0 {
+            secs = secs.checked_add(1).ok_or(io::Errno::INVAL)?;
+        }
+        secs.try_into().map_err(|_e| io::Errno::INVAL)
+    }
+
+    /// Convert a `Duration` to a whole number of milliseconds, rounding up.
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[inline]
+    fn duration_to_millis<T: TryFrom<u64>>(duration: Duration) -> io::Result<T> {
+        let mut millis = duration
+            .as_secs()
+            .checked_mul(1000)
+            .and_then(|millis| millis.checked_add(duration.subsec_millis().into()))
+            .ok_or(io::Errno::INVAL)?;
+        if duration.subsec_nanos() != duration.subsec_millis() * 1_000_000 {
+            millis = millis.checked_add(1).ok_or(io::Errno::INVAL)?;
+        }
+        millis.try_into().map_err(|_e| io::Errno::INVAL)
+    }
+
     // `getsockopt` and `setsockopt` represent boolean values as integers.
     #[cfg(not(windows))]
     type RawSocketBool = c::c_int;
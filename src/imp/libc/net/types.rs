@@ -572,9 +572,10 @@ bitflags! {
 }
 
 bitflags! {
-    /// `SOCK_*` constants for use with [`socket`].
+    /// `SOCK_*` constants for use with [`socket_with`] and [`socketpair`].
     ///
-    /// [`socket`]: crate::net::socket
+    /// [`socket_with`]: crate::net::socket_with
+    /// [`socketpair`]: crate::net::socketpair
     pub struct SocketFlags: c::c_int {
         /// `SOCK_NONBLOCK`
         #[cfg(not(any(windows, target_os = "ios", target_os = "macos")))]
@@ -600,3 +601,139 @@ pub enum Timeout {
     /// `SO_SNDTIMEO`—Timeout for sending.
     Send = c::SO_SNDTIMEO,
 }
+
+/// `TCP_*` connection states, as reported in [`TcpInfo::state`].
+///
+/// These values come from Linux's `include/net/tcp_states.h`, which isn't
+/// exported to userspace headers.
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[repr(transparent)]
+pub struct TcpState(pub(crate) u8);
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[rustfmt::skip]
+impl TcpState {
+    /// `TCP_ESTABLISHED`
+    pub const ESTABLISHED: Self = Self(1);
+
+    /// `TCP_SYN_SENT`
+    pub const SYN_SENT: Self = Self(2);
+
+    /// `TCP_SYN_RECV`
+    pub const SYN_RECV: Self = Self(3);
+
+    /// `TCP_FIN_WAIT1`
+    pub const FIN_WAIT1: Self = Self(4);
+
+    /// `TCP_FIN_WAIT2`
+    pub const FIN_WAIT2: Self = Self(5);
+
+    /// `TCP_TIME_WAIT`
+    pub const TIME_WAIT: Self = Self(6);
+
+    /// `TCP_CLOSE`
+    pub const CLOSE: Self = Self(7);
+
+    /// `TCP_CLOSE_WAIT`
+    pub const CLOSE_WAIT: Self = Self(8);
+
+    /// `TCP_LAST_ACK`
+    pub const LAST_ACK: Self = Self(9);
+
+    /// `TCP_LISTEN`
+    pub const LISTEN: Self = Self(10);
+
+    /// `TCP_CLOSING`
+    pub const CLOSING: Self = Self(11);
+
+    /// Constructs a `TcpState` from a raw integer.
+    #[inline]
+    pub const fn from_raw(raw: u8) -> Self {
+        Self(raw)
+    }
+
+    /// Returns the raw integer for this `TcpState`.
+    #[inline]
+    pub const fn as_raw(self) -> u8 {
+        self.0
+    }
+}
+
+/// A subset of the fields of Linux's `struct tcp_info`, for use with
+/// [`get_tcp_info`].
+///
+/// [`get_tcp_info`]: crate::net::sockopt::get_tcp_info
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct TcpInfo {
+    /// `tcpi_state`—The state of the connection.
+    pub state: TcpState,
+
+    /// `tcpi_retransmits`—The number of unrecovered timeouts.
+    pub retransmits: u8,
+
+    /// `tcpi_rtt`—The smoothed round-trip time, in microseconds.
+    pub rtt: u32,
+
+    /// `tcpi_rttvar`—The round-trip time variance, in microseconds.
+    pub rttvar: u32,
+
+    /// `tcpi_snd_cwnd`—The sending congestion window, in segments.
+    pub snd_cwnd: u32,
+
+    /// `tcpi_total_retrans`—The total number of retransmitted segments.
+    pub total_retrans: u32,
+}
+
+/// An error received from a socket's error queue, from Linux's
+/// `struct sock_extended_err`, for use with [`drain_error_queue`].
+///
+/// [`drain_error_queue`]: crate::net::drain_error_queue
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct SockExtendedErr {
+    /// `ee_errno`—The error number.
+    pub errno: crate::io::Errno,
+
+    /// `ee_origin`—Where the error originated; one of the `ORIGIN_*`
+    /// constants.
+    pub origin: u8,
+
+    /// `ee_type`—For ICMP errors, the ICMP type.
+    pub type_: u8,
+
+    /// `ee_code`—For ICMP errors, the ICMP code.
+    pub code: u8,
+
+    /// `ee_info`—Extra information, such as the MTU for `EMSGSIZE` errors.
+    pub info: u32,
+
+    /// `ee_data`—Extra data, depending on the origin.
+    pub data: u32,
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[rustfmt::skip]
+impl SockExtendedErr {
+    /// `SO_EE_ORIGIN_NONE`
+    pub const ORIGIN_NONE: u8 = 0;
+
+    /// `SO_EE_ORIGIN_LOCAL`
+    pub const ORIGIN_LOCAL: u8 = 1;
+
+    /// `SO_EE_ORIGIN_ICMP`
+    pub const ORIGIN_ICMP: u8 = 2;
+
+    /// `SO_EE_ORIGIN_ICMP6`
+    pub const ORIGIN_ICMP6: u8 = 3;
+
+    /// `SO_EE_ORIGIN_TXSTATUS`
+    pub const ORIGIN_TXSTATUS: u8 = 4;
+
+    /// `SO_EE_ORIGIN_ZEROCOPY`
+    pub const ORIGIN_ZEROCOPY: u8 = 5;
+
+    /// `SO_EE_ORIGIN_TXTIME`
+    pub const ORIGIN_TXTIME: u8 = 6;
+}
@@ -15,6 +15,18 @@ pub(super) use c::{
     off_t as libc_off_t,
 };
 
+#[cfg(not(any(
+    windows,
+    target_os = "android",
+    target_os = "linux",
+    target_os = "emscripten",
+    target_os = "fuchsia",
+    target_os = "l4re",
+    target_os = "redox",
+    target_os = "wasi",
+)))]
+pub(super) use c::truncate as libc_truncate;
+
 #[cfg(any(
     target_os = "android",
     target_os = "linux",
@@ -24,6 +36,7 @@ pub(super) use c::{
 pub(super) use c::{
     fstat64 as libc_fstat, fstatat64 as libc_fstatat, ftruncate64 as libc_ftruncate,
     lseek64 as libc_lseek, off64_t as libc_off_t, rlimit64 as libc_rlimit,
+    truncate64 as libc_truncate,
 };
 
 #[cfg(not(any(
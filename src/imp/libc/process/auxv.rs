@@ -1,9 +1,19 @@
 use super::super::c;
+#[cfg(all(
+    any(feature = "param", feature = "process"),
+    any(
+        all(target_os = "android", target_pointer_width = "64"),
+        target_os = "linux"
+    )
+))]
+use super::types::AuxvKind;
 #[cfg(any(
     all(target_os = "android", target_pointer_width = "64"),
     target_os = "linux"
 ))]
 use crate::ffi::ZStr;
+use core::sync::atomic::AtomicUsize;
+use core::sync::atomic::Ordering::Relaxed;
 
 // `getauxval` wasn't supported in glibc until 2.16.
 #[cfg(any(
@@ -14,7 +24,17 @@ weak!(fn getauxval(c::c_ulong) -> *mut c::c_void);
 
 #[inline]
 pub(crate) fn page_size() -> usize {
-    unsafe { c::sysconf(c::_SC_PAGESIZE) as usize }
+    // The page size never changes, so only call `sysconf` once.
+    static PAGE_SIZE: AtomicUsize = AtomicUsize::new(0);
+
+    match PAGE_SIZE.load(Relaxed) {
+        0 => {
+            let size = unsafe { c::sysconf(c::_SC_PAGESIZE) as usize };
+            PAGE_SIZE.store(size, Relaxed);
+            size
+        }
+        size => size,
+    }
 }
 
 #[cfg(not(target_os = "wasi"))]
@@ -40,6 +60,22 @@ pub(crate) fn linux_hwcap() -> (usize, usize) {
     }
 }
 
+#[cfg(all(
+    any(feature = "param", feature = "process"),
+    any(
+        all(target_os = "android", target_pointer_width = "64"),
+        target_os = "linux"
+    )
+))]
+#[inline]
+pub(crate) fn linux_auxv(kind: AuxvKind) -> usize {
+    if let Some(libc_getauxval) = getauxval.get() {
+        unsafe { libc_getauxval(kind as c::c_ulong) as usize }
+    } else {
+        0
+    }
+}
+
 #[cfg(any(
     all(target_os = "android", target_pointer_width = "64"),
     target_os = "linux"
@@ -6,6 +6,8 @@ pub(crate) mod auxv;
     target_os = "dragonfly"
 ))]
 pub(crate) mod cpu_set;
+#[cfg(not(target_os = "wasi"))]
+pub(crate) mod sig_set;
 #[cfg(not(windows))]
 pub(crate) mod syscalls;
 pub(crate) mod types;
@@ -0,0 +1,21 @@
+use super::super::c;
+use super::types::{RawSigSet, Signal};
+
+#[inline]
+pub(crate) fn sigaddset(sigset: &mut RawSigSet, sig: Signal) {
+    unsafe {
+        let _ = c::sigaddset(sigset, sig as c::c_int);
+    }
+}
+
+#[inline]
+pub(crate) fn sigdelset(sigset: &mut RawSigSet, sig: Signal) {
+    unsafe {
+        let _ = c::sigdelset(sigset, sig as c::c_int);
+    }
+}
+
+#[inline]
+pub(crate) fn sigismember(sigset: &RawSigSet, sig: Signal) -> bool {
+    unsafe { c::sigismember(sigset, sig as c::c_int) == 1 }
+}
@@ -16,6 +16,9 @@ use super::types::RawCpuSet;
 #[cfg(not(any(target_os = "wasi", target_os = "fuchsia")))]
 use crate::fd::BorrowedFd;
 use crate::ffi::ZStr;
+#[cfg(not(target_os = "wasi"))]
+#[cfg(feature = "fs")]
+use crate::fs::Mode;
 use crate::io;
 use core::mem::MaybeUninit;
 #[cfg(not(any(target_os = "fuchsia", target_os = "redox", target_os = "wasi")))]
@@ -26,6 +29,16 @@ use {
     core::convert::TryInto,
 };
 #[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg(feature = "process")]
+use {
+    super::super::conv::{ret_u32, syscall_ret_owned_fd, syscall_ret_ssize_t},
+    super::types::{PtraceRequest, RawSysinfo},
+    crate::fd::RawFd,
+    crate::io::{IoSlice, IoSliceMut, OwnedFd},
+    crate::process::{Personality, RemoteIoVec, Sysinfo},
+    core::cmp::min,
+};
+#[cfg(any(target_os = "android", target_os = "linux"))]
 use {
     super::super::offset::libc_prlimit,
     crate::process::{Cpuid, MembarrierCommand, MembarrierQuery},
@@ -35,12 +48,24 @@ use {
     super::types::RawUname,
     crate::process::{Gid, Pid, RawNonZeroPid, RawPid, Signal, Uid, WaitOptions, WaitStatus},
 };
+#[cfg(not(any(target_os = "fuchsia", target_os = "redox", target_os = "wasi")))]
+#[cfg(feature = "process")]
+use {
+    crate::imp::time::types::Timeval,
+    crate::process::{Rusage, RusageWho},
+};
 
 #[cfg(not(target_os = "wasi"))]
 pub(crate) fn chdir(path: &ZStr) -> io::Result<()> {
     unsafe { ret(c::chdir(c_str(path))) }
 }
 
+#[cfg(not(any(target_os = "fuchsia", target_os = "wasi")))]
+#[cfg(feature = "process")]
+pub(crate) fn chroot(path: &ZStr) -> io::Result<()> {
+    unsafe { ret(c::chroot(c_str(path))) }
+}
+
 #[cfg(not(any(target_os = "wasi", target_os = "fuchsia")))]
 pub(crate) fn fchdir(dirfd: BorrowedFd<'_>) -> io::Result<()> {
     unsafe { ret(c::fchdir(borrowed_fd(dirfd))) }
@@ -51,6 +76,13 @@ pub(crate) fn getcwd(buf: &mut [u8]) -> io::Result<()> {
     unsafe { ret_discarded_char_ptr(c::getcwd(buf.as_mut_ptr().cast(), buf.len())) }
 }
 
+#[cfg(not(target_os = "wasi"))]
+#[cfg(feature = "fs")]
+#[inline]
+pub(crate) fn umask(mask: Mode) -> Mode {
+    Mode::from_bits_truncate(unsafe { c::umask(mask.bits() as c::mode_t) } as _)
+}
+
 #[cfg(any(target_os = "android", target_os = "linux"))]
 pub(crate) fn membarrier_query() -> MembarrierQuery {
     // GLIBC does not have a wrapper for `membarrier`; [the documentation]
@@ -196,6 +228,89 @@ pub(crate) fn uname() -> RawUname {
     }
 }
 
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg(feature = "process")]
+pub(crate) fn pidfd_open(pid: Pid, flags: u32) -> io::Result<OwnedFd> {
+    unsafe { syscall_ret_owned_fd(c::syscall(c::SYS_pidfd_open, Pid::as_raw(Some(pid)), flags)) }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg(feature = "process")]
+pub(crate) fn pidfd_getfd(
+    pidfd: BorrowedFd<'_>,
+    targetfd: RawFd,
+    flags: u32,
+) -> io::Result<OwnedFd> {
+    unsafe {
+        syscall_ret_owned_fd(c::syscall(
+            c::SYS_pidfd_getfd,
+            borrowed_fd(pidfd),
+            targetfd,
+            flags,
+        ))
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg(feature = "process")]
+pub(crate) unsafe fn klogctl(action: u32, buf: *mut u8, len: u32) -> io::Result<usize> {
+    ret_c_int(c::klogctl(action as c::c_int, buf.cast(), len as c::c_int)).map(|n| n as usize)
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg(feature = "process")]
+pub(crate) fn reboot(cmd: u32, arg: Option<&ZStr>) -> io::Result<()> {
+    // libc's `reboot` wrapper doesn't take an argument, which
+    // `LINUX_REBOOT_CMD_RESTART2` needs, so use `syscall`.
+    let arg = arg.map_or(core::ptr::null(), c_str);
+    unsafe {
+        syscall_ret(c::syscall(
+            c::SYS_reboot,
+            c::LINUX_REBOOT_MAGIC1,
+            c::LINUX_REBOOT_MAGIC2,
+            cmd as c::c_int,
+            arg,
+        ))
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg(feature = "process")]
+#[inline]
+#[allow(clippy::useless_conversion)]
+pub(crate) fn sysinfo() -> io::Result<Sysinfo> {
+    let mut info = MaybeUninit::<RawSysinfo>::uninit();
+    unsafe {
+        ret(c::sysinfo(info.as_mut_ptr()))?;
+        let info = info.assume_init();
+        Ok(Sysinfo {
+            uptime: info.uptime.into(),
+            loads: [
+                info.loads[0].into(),
+                info.loads[1].into(),
+                info.loads[2].into(),
+            ],
+            totalram: info.totalram.into(),
+            freeram: info.freeram.into(),
+            sharedram: info.sharedram.into(),
+            bufferram: info.bufferram.into(),
+            totalswap: info.totalswap.into(),
+            freeswap: info.freeswap.into(),
+            procs: info.procs,
+            totalhigh: info.totalhigh.into(),
+            freehigh: info.freehigh.into(),
+            mem_unit: info.mem_unit,
+        })
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg(feature = "process")]
+#[inline]
+pub(crate) fn sethostname(name: &[u8]) -> io::Result<()> {
+    unsafe { ret(c::sethostname(name.as_ptr().cast(), name.len())) }
+}
+
 #[cfg(not(any(target_os = "fuchsia", target_os = "wasi")))]
 #[inline]
 pub(crate) fn nice(inc: i32) -> io::Result<i32> {
@@ -284,6 +399,47 @@ pub(crate) fn getrlimit(limit: Resource) -> Rlimit {
     }
 }
 
+#[cfg(not(any(target_os = "fuchsia", target_os = "redox", target_os = "wasi")))]
+#[cfg(feature = "process")]
+#[inline]
+pub(crate) fn getrusage(who: RusageWho) -> Rusage {
+    let mut usage = MaybeUninit::<c::rusage>::uninit();
+    unsafe {
+        ret_infallible(c::getrusage(who as c::c_int, usage.as_mut_ptr()));
+        rusage_from_raw(usage.assume_init())
+    }
+}
+
+#[cfg(not(any(target_os = "fuchsia", target_os = "redox", target_os = "wasi")))]
+#[cfg(feature = "process")]
+#[allow(clippy::useless_conversion)]
+fn rusage_from_raw(usage: c::rusage) -> Rusage {
+    Rusage {
+        ru_utime: Timeval {
+            tv_sec: usage.ru_utime.tv_sec.into(),
+            tv_usec: usage.ru_utime.tv_usec.into(),
+        },
+        ru_stime: Timeval {
+            tv_sec: usage.ru_stime.tv_sec.into(),
+            tv_usec: usage.ru_stime.tv_usec.into(),
+        },
+        ru_maxrss: usage.ru_maxrss as u64,
+        ru_ixrss: usage.ru_ixrss as u64,
+        ru_idrss: usage.ru_idrss as u64,
+        ru_isrss: usage.ru_isrss as u64,
+        ru_minflt: usage.ru_minflt as u64,
+        ru_majflt: usage.ru_majflt as u64,
+        ru_nswap: usage.ru_nswap as u64,
+        ru_inblock: usage.ru_inblock as u64,
+        ru_oublock: usage.ru_oublock as u64,
+        ru_msgsnd: usage.ru_msgsnd as u64,
+        ru_msgrcv: usage.ru_msgrcv as u64,
+        ru_nsignals: usage.ru_nsignals as u64,
+        ru_nvcsw: usage.ru_nvcsw as u64,
+        ru_nivcsw: usage.ru_nivcsw as u64,
+    }
+}
+
 #[cfg(not(any(target_os = "fuchsia", target_os = "redox", target_os = "wasi")))]
 #[inline]
 pub(crate) fn setrlimit(limit: Resource, new: Rlimit) -> io::Result<()> {
@@ -371,6 +527,36 @@ pub(crate) fn _waitpid(
     }
 }
 
+#[cfg(not(any(
+    target_os = "fuchsia",
+    target_os = "illumos",
+    target_os = "redox",
+    target_os = "wasi"
+)))]
+#[cfg(feature = "process")]
+#[inline]
+pub(crate) fn wait4(
+    pid: Option<Pid>,
+    waitopts: WaitOptions,
+) -> io::Result<Option<(WaitStatus, Rusage)>> {
+    unsafe {
+        let mut status: c::c_int = 0;
+        let mut usage = MaybeUninit::<c::rusage>::uninit();
+        let pid = ret_c_int(c::wait4(
+            Pid::as_raw(pid) as _,
+            &mut status,
+            waitopts.bits() as _,
+            usage.as_mut_ptr(),
+        ))?;
+        Ok(RawNonZeroPid::new(pid).map(|_| {
+            (
+                WaitStatus::new(status as _),
+                rusage_from_raw(usage.assume_init()),
+            )
+        }))
+    }
+}
+
 #[inline]
 pub(crate) fn exit_group(code: c::c_int) -> ! {
     // `_exit` and `_Exit` are the same; it's just a matter of which ones
@@ -395,6 +581,30 @@ pub(crate) fn setsid() -> io::Result<Pid> {
     }
 }
 
+#[cfg(not(target_os = "wasi"))]
+#[inline]
+pub(crate) fn getsid(pid: Option<Pid>) -> io::Result<Option<Pid>> {
+    unsafe {
+        let sid = ret_c_int(c::getsid(Pid::as_raw(pid) as _))?;
+        Ok(Pid::from_raw(sid))
+    }
+}
+
+#[cfg(not(target_os = "wasi"))]
+#[inline]
+pub(crate) fn getpgid(pid: Option<Pid>) -> io::Result<Option<Pid>> {
+    unsafe {
+        let pgid = ret_c_int(c::getpgid(Pid::as_raw(pid) as _))?;
+        Ok(Pid::from_raw(pgid))
+    }
+}
+
+#[cfg(not(target_os = "wasi"))]
+#[inline]
+pub(crate) fn setpgid(pid: Option<Pid>, pgid: Option<Pid>) -> io::Result<()> {
+    unsafe { ret(c::setpgid(Pid::as_raw(pid) as _, Pid::as_raw(pgid) as _)) }
+}
+
 #[cfg(not(target_os = "wasi"))]
 #[inline]
 pub(crate) fn kill_process(pid: Pid, sig: Signal) -> io::Result<()> {
@@ -417,3 +627,91 @@ pub(crate) fn kill_process_group(pid: Pid, sig: Signal) -> io::Result<()> {
 pub(crate) fn kill_current_process_group(sig: Signal) -> io::Result<()> {
     unsafe { ret(c::kill(0, sig as i32)) }
 }
+
+#[cfg(not(target_os = "wasi"))]
+#[inline]
+pub(crate) fn test_kill_process(pid: Pid) -> io::Result<()> {
+    unsafe { ret(c::kill(pid.as_raw_nonzero().get(), 0)) }
+}
+
+#[cfg(not(target_os = "wasi"))]
+#[inline]
+pub(crate) fn test_kill_process_group(pid: Pid) -> io::Result<()> {
+    unsafe { ret(c::kill(pid.as_raw_nonzero().get().wrapping_neg(), 0)) }
+}
+
+#[cfg(not(target_os = "wasi"))]
+#[inline]
+pub(crate) fn test_kill_current_process_group() -> io::Result<()> {
+    unsafe { ret(c::kill(0, 0)) }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg(feature = "process")]
+pub(crate) fn process_vm_readv(
+    pid: Pid,
+    local: &mut [IoSliceMut<'_>],
+    remote: &[RemoteIoVec],
+) -> io::Result<usize> {
+    unsafe {
+        let nread = syscall_ret_ssize_t(c::syscall(
+            c::SYS_process_vm_readv,
+            pid.as_raw_nonzero().get(),
+            local.as_ptr().cast::<c::iovec>(),
+            min(local.len(), c::UIO_MAXIOV as usize) as c::c_ulong,
+            remote.as_ptr().cast::<c::iovec>(),
+            min(remote.len(), c::UIO_MAXIOV as usize) as c::c_ulong,
+            0 as c::c_ulong,
+        ))?;
+        Ok(nread as usize)
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg(feature = "process")]
+pub(crate) unsafe fn process_vm_writev(
+    pid: Pid,
+    local: &[IoSlice<'_>],
+    remote: &[RemoteIoVec],
+) -> io::Result<usize> {
+    let nwritten = syscall_ret_ssize_t(c::syscall(
+        c::SYS_process_vm_writev,
+        pid.as_raw_nonzero().get(),
+        local.as_ptr().cast::<c::iovec>(),
+        min(local.len(), c::UIO_MAXIOV as usize) as c::c_ulong,
+        remote.as_ptr().cast::<c::iovec>(),
+        min(remote.len(), c::UIO_MAXIOV as usize) as c::c_ulong,
+        0 as c::c_ulong,
+    ))?;
+    Ok(nwritten as usize)
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg(feature = "process")]
+pub(crate) unsafe fn ptrace(
+    request: PtraceRequest,
+    pid: Option<Pid>,
+    addr: *mut c::c_void,
+    data: *mut c::c_void,
+) -> io::Result<()> {
+    // Use the raw syscall rather than `c::ptrace`, so that `PTRACE_PEEK*`
+    // requests use the kernel convention of storing the result in `data`.
+    syscall_ret(c::syscall(
+        c::SYS_ptrace,
+        request as c::c_long,
+        Pid::as_raw(pid) as c::c_long,
+        addr,
+        data,
+    ))
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg(feature = "process")]
+pub(crate) fn personality(persona: u32) -> io::Result<Personality> {
+    unsafe {
+        let persona = ret_u32(c::personality(persona as _))?;
+        // Safety: The persona value is coming from Linux, and unnamed bits
+        // are preserved so that the previous persona can be restored.
+        Ok(Personality::from_bits_unchecked(persona))
+    }
+}
@@ -146,9 +146,101 @@ impl Resource {
     pub const Rss: Self = Self::As;
 }
 
+/// A `who` value for use with [`getrusage`].
+///
+/// [`getrusage`]: crate::process::getrusage
+#[cfg(not(any(target_os = "fuchsia", target_os = "redox", target_os = "wasi")))]
+#[cfg(feature = "process")]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(i32)]
+pub enum RusageWho {
+    /// `RUSAGE_SELF`
+    Self_ = c::RUSAGE_SELF as c::c_int,
+    /// `RUSAGE_CHILDREN`
+    Children = c::RUSAGE_CHILDREN as c::c_int,
+    /// `RUSAGE_THREAD`
+    #[cfg(target_os = "linux")]
+    Thread = c::RUSAGE_THREAD as c::c_int,
+    /// `RUSAGE_THREAD`
+    #[cfg(target_os = "android")]
+    Thread = 1,
+}
+
+/// A key for use with [`auxv`].
+///
+/// [`auxv`]: crate::param::auxv
+#[cfg(all(
+    any(feature = "param", feature = "process"),
+    any(
+        all(target_os = "android", target_pointer_width = "64"),
+        target_os = "linux"
+    )
+))]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum AuxvKind {
+    /// `AT_PHDR`—The address of the program headers of the executable.
+    Phdr = c::AT_PHDR as u32,
+    /// `AT_PHNUM`—The number of program headers of the executable.
+    Phnum = c::AT_PHNUM as u32,
+    /// `AT_PAGESZ`—The page size.
+    Pagesz = c::AT_PAGESZ as u32,
+    /// `AT_HWCAP`—Architecture-specific hardware capability bits.
+    Hwcap = c::AT_HWCAP as u32,
+    /// `AT_CLKTCK`—The number of clock ticks per second.
+    Clktck = c::AT_CLKTCK as u32,
+    /// `AT_HWCAP2`—More architecture-specific hardware capability bits.
+    Hwcap2 = c::AT_HWCAP2 as u32,
+    /// `AT_EXECFN`—The address of the filesystem path of the executable.
+    Execfn = c::AT_EXECFN as u32,
+    /// `AT_SYSINFO_EHDR`—The address of the vDSO.
+    SysinfoEhdr = c::AT_SYSINFO_EHDR as u32,
+}
+
+/// A `PTRACE_*` request for use with [`ptrace`].
+///
+/// [`ptrace`]: crate::process::ptrace
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg(feature = "process")]
+#[allow(clippy::unnecessary_cast)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub(crate) enum PtraceRequest {
+    TraceMe = c::PTRACE_TRACEME as u32,
+    PeekData = c::PTRACE_PEEKDATA as u32,
+    PokeData = c::PTRACE_POKEDATA as u32,
+    Cont = c::PTRACE_CONT as u32,
+    SingleStep = c::PTRACE_SINGLESTEP as u32,
+    Attach = c::PTRACE_ATTACH as u32,
+    Detach = c::PTRACE_DETACH as u32,
+    Syscall = c::PTRACE_SYSCALL as u32,
+    SetOptions = c::PTRACE_SETOPTIONS as u32,
+    GetRegSet = c::PTRACE_GETREGSET as u32,
+    SetRegSet = c::PTRACE_SETREGSET as u32,
+    #[cfg(target_os = "linux")]
+    Seize = c::PTRACE_SEIZE as u32,
+    // TODO: libc doesn't define `PTRACE_SEIZE` on Android yet.
+    #[cfg(target_os = "android")]
+    Seize = 0x4206,
+}
+
+/// The `NT_PRSTATUS` register set, for use with `PTRACE_GETREGSET` and
+/// `PTRACE_SETREGSET`.
+// TODO: This is not yet exposed through libc, so we define the constant
+// ourselves.
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg(feature = "process")]
+pub(crate) const NT_PRSTATUS: usize = 1;
+
 /// A signal number for use with [`kill_process`], [`kill_process_group`],
 /// and [`kill_current_process_group`].
 ///
+/// On Linux and Android, the real-time signals `SIGRTMIN` through
+/// `SIGRTMIN+32`, using the kernel's `SIGRTMIN` of 32, are represented by
+/// [`Signal::Rtmin`] through [`Signal::Rt32`]; see also [`Signal::rt`]. The
+/// C library reserves the lowest few of these for its own use, so
+/// `libc::SIGRTMIN()` may be greater than 32.
+///
 /// [`kill_process`]: crate::process::kill_process
 /// [`kill_process_group`]: crate::process::kill_process_group
 /// [`kill_current_process_group`]: crate::process::kill_current_process_group
@@ -245,6 +337,105 @@ pub enum Signal {
     /// `SIGSYS`, aka `SIGUNUSED`
     #[doc(alias = "Unused")]
     Sys = c::SIGSYS,
+    /// `SIGRTMIN`, using the kernel's value
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    Rtmin = 32,
+    /// `SIGRTMIN+1`, using the kernel's `SIGRTMIN`
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    Rt1 = 32 + 1,
+    /// `SIGRTMIN+2`, using the kernel's `SIGRTMIN`
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    Rt2 = 32 + 2,
+    /// `SIGRTMIN+3`, using the kernel's `SIGRTMIN`
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    Rt3 = 32 + 3,
+    /// `SIGRTMIN+4`, using the kernel's `SIGRTMIN`
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    Rt4 = 32 + 4,
+    /// `SIGRTMIN+5`, using the kernel's `SIGRTMIN`
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    Rt5 = 32 + 5,
+    /// `SIGRTMIN+6`, using the kernel's `SIGRTMIN`
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    Rt6 = 32 + 6,
+    /// `SIGRTMIN+7`, using the kernel's `SIGRTMIN`
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    Rt7 = 32 + 7,
+    /// `SIGRTMIN+8`, using the kernel's `SIGRTMIN`
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    Rt8 = 32 + 8,
+    /// `SIGRTMIN+9`, using the kernel's `SIGRTMIN`
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    Rt9 = 32 + 9,
+    /// `SIGRTMIN+10`, using the kernel's `SIGRTMIN`
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    Rt10 = 32 + 10,
+    /// `SIGRTMIN+11`, using the kernel's `SIGRTMIN`
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    Rt11 = 32 + 11,
+    /// `SIGRTMIN+12`, using the kernel's `SIGRTMIN`
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    Rt12 = 32 + 12,
+    /// `SIGRTMIN+13`, using the kernel's `SIGRTMIN`
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    Rt13 = 32 + 13,
+    /// `SIGRTMIN+14`, using the kernel's `SIGRTMIN`
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    Rt14 = 32 + 14,
+    /// `SIGRTMIN+15`, using the kernel's `SIGRTMIN`
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    Rt15 = 32 + 15,
+    /// `SIGRTMIN+16`, using the kernel's `SIGRTMIN`
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    Rt16 = 32 + 16,
+    /// `SIGRTMIN+17`, using the kernel's `SIGRTMIN`
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    Rt17 = 32 + 17,
+    /// `SIGRTMIN+18`, using the kernel's `SIGRTMIN`
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    Rt18 = 32 + 18,
+    /// `SIGRTMIN+19`, using the kernel's `SIGRTMIN`
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    Rt19 = 32 + 19,
+    /// `SIGRTMIN+20`, using the kernel's `SIGRTMIN`
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    Rt20 = 32 + 20,
+    /// `SIGRTMIN+21`, using the kernel's `SIGRTMIN`
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    Rt21 = 32 + 21,
+    /// `SIGRTMIN+22`, using the kernel's `SIGRTMIN`
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    Rt22 = 32 + 22,
+    /// `SIGRTMIN+23`, using the kernel's `SIGRTMIN`
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    Rt23 = 32 + 23,
+    /// `SIGRTMIN+24`, using the kernel's `SIGRTMIN`
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    Rt24 = 32 + 24,
+    /// `SIGRTMIN+25`, using the kernel's `SIGRTMIN`
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    Rt25 = 32 + 25,
+    /// `SIGRTMIN+26`, using the kernel's `SIGRTMIN`
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    Rt26 = 32 + 26,
+    /// `SIGRTMIN+27`, using the kernel's `SIGRTMIN`
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    Rt27 = 32 + 27,
+    /// `SIGRTMIN+28`, using the kernel's `SIGRTMIN`
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    Rt28 = 32 + 28,
+    /// `SIGRTMIN+29`, using the kernel's `SIGRTMIN`
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    Rt29 = 32 + 29,
+    /// `SIGRTMIN+30`, using the kernel's `SIGRTMIN`
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    Rt30 = 32 + 30,
+    /// `SIGRTMIN+31`, using the kernel's `SIGRTMIN`
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    Rt31 = 32 + 31,
+    /// `SIGRTMIN+32`, using the kernel's `SIGRTMIN`
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    Rt32 = 32 + 32,
 }
 
 #[cfg(not(target_os = "wasi"))]
@@ -302,9 +493,85 @@ impl Signal {
             )))]
             c::SIGPWR => Some(Self::Power),
             c::SIGSYS => Some(Self::Sys),
+            #[cfg(any(target_os = "android", target_os = "linux"))]
+            32 => Some(Self::Rtmin),
+            #[cfg(any(target_os = "android", target_os = "linux"))]
+            33 => Some(Self::Rt1),
+            #[cfg(any(target_os = "android", target_os = "linux"))]
+            34 => Some(Self::Rt2),
+            #[cfg(any(target_os = "android", target_os = "linux"))]
+            35 => Some(Self::Rt3),
+            #[cfg(any(target_os = "android", target_os = "linux"))]
+            36 => Some(Self::Rt4),
+            #[cfg(any(target_os = "android", target_os = "linux"))]
+            37 => Some(Self::Rt5),
+            #[cfg(any(target_os = "android", target_os = "linux"))]
+            38 => Some(Self::Rt6),
+            #[cfg(any(target_os = "android", target_os = "linux"))]
+            39 => Some(Self::Rt7),
+            #[cfg(any(target_os = "android", target_os = "linux"))]
+            40 => Some(Self::Rt8),
+            #[cfg(any(target_os = "android", target_os = "linux"))]
+            41 => Some(Self::Rt9),
+            #[cfg(any(target_os = "android", target_os = "linux"))]
+            42 => Some(Self::Rt10),
+            #[cfg(any(target_os = "android", target_os = "linux"))]
+            43 => Some(Self::Rt11),
+            #[cfg(any(target_os = "android", target_os = "linux"))]
+            44 => Some(Self::Rt12),
+            #[cfg(any(target_os = "android", target_os = "linux"))]
+            45 => Some(Self::Rt13),
+            #[cfg(any(target_os = "android", target_os = "linux"))]
+            46 => Some(Self::Rt14),
+            #[cfg(any(target_os = "android", target_os = "linux"))]
+            47 => Some(Self::Rt15),
+            #[cfg(any(target_os = "android", target_os = "linux"))]
+            48 => Some(Self::Rt16),
+            #[cfg(any(target_os = "android", target_os = "linux"))]
+            49 => Some(Self::Rt17),
+            #[cfg(any(target_os = "android", target_os = "linux"))]
+            50 => Some(Self::Rt18),
+            #[cfg(any(target_os = "android", target_os = "linux"))]
+            51 => Some(Self::Rt19),
+            #[cfg(any(target_os = "android", target_os = "linux"))]
+            52 => Some(Self::Rt20),
+            #[cfg(any(target_os = "android", target_os = "linux"))]
+            53 => Some(Self::Rt21),
+            #[cfg(any(target_os = "android", target_os = "linux"))]
+            54 => Some(Self::Rt22),
+            #[cfg(any(target_os = "android", target_os = "linux"))]
+            55 => Some(Self::Rt23),
+            #[cfg(any(target_os = "android", target_os = "linux"))]
+            56 => Some(Self::Rt24),
+            #[cfg(any(target_os = "android", target_os = "linux"))]
+            57 => Some(Self::Rt25),
+            #[cfg(any(target_os = "android", target_os = "linux"))]
+            58 => Some(Self::Rt26),
+            #[cfg(any(target_os = "android", target_os = "linux"))]
+            59 => Some(Self::Rt27),
+            #[cfg(any(target_os = "android", target_os = "linux"))]
+            60 => Some(Self::Rt28),
+            #[cfg(any(target_os = "android", target_os = "linux"))]
+            61 => Some(Self::Rt29),
+            #[cfg(any(target_os = "android", target_os = "linux"))]
+            62 => Some(Self::Rt30),
+            #[cfg(any(target_os = "android", target_os = "linux"))]
+            63 => Some(Self::Rt31),
+            #[cfg(any(target_os = "android", target_os = "linux"))]
+            64 => Some(Self::Rt32),
             _ => None,
         }
     }
+
+    /// Return the real-time signal `SIGRTMIN+n`, using the kernel's
+    /// `SIGRTMIN`, if `n` is at most 32.
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    pub fn rt(n: u32) -> Option<Self> {
+        if n > 32 {
+            return None;
+        }
+        Self::from_raw(32 + n as i32)
+    }
 }
 
 pub const EXIT_SUCCESS: c::c_int = c::EXIT_SUCCESS;
@@ -331,6 +598,9 @@ pub type RawCpuid = u32;
 #[cfg(not(target_os = "wasi"))]
 pub(crate) type RawUname = c::utsname;
 
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) type RawSysinfo = c::sysinfo;
+
 #[cfg(any(
     target_os = "linux",
     target_os = "android",
@@ -359,3 +629,19 @@ pub(crate) fn raw_cpu_set_new() -> RawCpuSet {
     target_os = "dragonfly"
 ))]
 pub(crate) const CPU_SETSIZE: usize = c::CPU_SETSIZE as usize;
+
+#[cfg(not(target_os = "wasi"))]
+pub(crate) type RawSigSet = c::sigset_t;
+
+#[cfg(not(target_os = "wasi"))]
+#[inline]
+pub(crate) fn raw_sig_set_new() -> RawSigSet {
+    // Zero the whole set, rather than leaving it to `sigemptyset`, because
+    // some libc implementations only initialize the bits they use, and we
+    // compare and hash the entire value.
+    let mut set = unsafe { core::mem::zeroed() };
+    unsafe {
+        let _ = c::sigemptyset(&mut set);
+    }
+    set
+}
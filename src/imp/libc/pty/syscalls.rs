@@ -0,0 +1,36 @@
+//! libc syscalls supporting `rustix::pty`.
+//!
+//! # Safety
+//!
+//! See the `rustix::imp::syscalls` module documentation for details.
+
+#![allow(unsafe_code)]
+
+use super::super::c;
+use super::super::conv::{borrowed_fd, ret};
+use crate::fd::BorrowedFd;
+use crate::io;
+use core::mem::MaybeUninit;
+
+// Android's libc bindings don't define `TIOCGPTN`; Android only runs on
+// architectures which use the generic ioctl numbering.
+#[cfg(target_os = "android")]
+const TIOCGPTN: c::c_int = 0x8004_5430_u32 as c::c_int;
+#[cfg(not(target_os = "android"))]
+use c::TIOCGPTN;
+
+pub(crate) fn ioctl_tiocgptn(fd: BorrowedFd) -> io::Result<u32> {
+    unsafe {
+        let mut result = MaybeUninit::<c::c_uint>::uninit();
+        ret(c::ioctl(
+            borrowed_fd(fd),
+            TIOCGPTN as _,
+            result.as_mut_ptr(),
+        ))?;
+        Ok(result.assume_init())
+    }
+}
+
+pub(crate) fn unlockpt(fd: BorrowedFd) -> io::Result<()> {
+    unsafe { ret(c::unlockpt(borrowed_fd(fd))) }
+}
@@ -0,0 +1,18 @@
+use super::super::c;
+use bitflags::bitflags;
+
+bitflags! {
+    /// `O_*` flags for use with [`openpt`].
+    ///
+    /// [`openpt`]: crate::pty::openpt
+    pub struct OpenptFlags: c::c_int {
+        /// `O_RDWR`
+        const RDWR = c::O_RDWR;
+
+        /// `O_NOCTTY`
+        const NOCTTY = c::O_NOCTTY;
+
+        /// `O_CLOEXEC`
+        const CLOEXEC = c::O_CLOEXEC;
+    }
+}
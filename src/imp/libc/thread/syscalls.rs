@@ -2,13 +2,24 @@
 
 use super::super::c;
 use super::super::conv::ret;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+use super::super::conv::syscall_ret;
 use super::super::time::types::LibcTimespec;
 use crate::io;
 #[cfg(any(target_os = "android", target_os = "linux"))]
-use crate::process::{Pid, RawNonZeroPid};
+use crate::process::{Pid, RawNonZeroPid, Signal};
 #[cfg(not(target_os = "redox"))]
 use crate::thread::{NanosleepRelativeResult, Timespec};
 use core::mem::MaybeUninit;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+use {
+    super::super::conv::{borrowed_fd, ret_c_int, syscall_ret_owned_fd, syscall_ret_u32},
+    crate::fd::{AsRawFd, BorrowedFd},
+    crate::io::OwnedFd,
+    crate::thread::{SeccompNotif, SockFilter},
+    core::mem::size_of,
+    core::ptr::null,
+};
 #[cfg(not(any(
     target_os = "dragonfly",
     target_os = "freebsd",
@@ -274,3 +285,296 @@ pub(crate) fn gettid() -> Pid {
         Pid::from_raw_nonzero(RawNonZeroPid::new_unchecked(tid))
     }
 }
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+pub(crate) fn tgkill(tgid: Pid, tid: Pid, sig: Signal) -> io::Result<()> {
+    // `tgkill` wasn't supported in glibc until 2.30, so use `syscall`.
+    unsafe {
+        syscall_ret(c::syscall(
+            c::SYS_tgkill,
+            tgid.as_raw_nonzero().get(),
+            tid.as_raw_nonzero().get(),
+            sig as c::c_int,
+        ))
+    }
+}
+
+/// `SECCOMP_MODE_FILTER`
+#[cfg(any(target_os = "android", target_os = "linux"))]
+const SECCOMP_MODE_FILTER: c::c_ulong = 2;
+
+/// `SECCOMP_SET_MODE_FILTER`
+#[cfg(any(target_os = "android", target_os = "linux"))]
+const SECCOMP_SET_MODE_FILTER: c::c_uint = 1;
+
+/// `SECCOMP_IOCTL_NOTIF_RECV`
+#[cfg(any(target_os = "android", target_os = "linux"))]
+const SECCOMP_IOCTL_NOTIF_RECV: u32 = 0xc050_2100;
+
+/// `SECCOMP_IOCTL_NOTIF_SEND`
+#[cfg(any(target_os = "android", target_os = "linux"))]
+const SECCOMP_IOCTL_NOTIF_SEND: u32 = 0xc018_2101;
+
+/// `SECCOMP_IOCTL_NOTIF_ID_VALID`
+#[cfg(all(
+    any(target_os = "android", target_os = "linux"),
+    not(any(
+        target_arch = "mips",
+        target_arch = "mips64",
+        target_arch = "powerpc",
+        target_arch = "powerpc64",
+        target_arch = "sparc",
+        target_arch = "sparc64",
+    ))
+))]
+const SECCOMP_IOCTL_NOTIF_ID_VALID: u32 = 0x4008_2102;
+
+/// `SECCOMP_IOCTL_NOTIF_ID_VALID`
+#[cfg(all(
+    any(target_os = "android", target_os = "linux"),
+    any(
+        target_arch = "mips",
+        target_arch = "mips64",
+        target_arch = "powerpc",
+        target_arch = "powerpc64",
+        target_arch = "sparc",
+        target_arch = "sparc64",
+    )
+))]
+const SECCOMP_IOCTL_NOTIF_ID_VALID: u32 = 0x8008_2102;
+
+/// `struct seccomp_notif_resp`
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[repr(C)]
+struct seccomp_notif_resp {
+    id: u64,
+    val: i64,
+    error: i32,
+    flags: u32,
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn seccomp_set_mode_filter(
+    flags: u32,
+    len: u16,
+    filter: *const SockFilter,
+) -> io::Result<u32> {
+    let prog = c::sock_fprog {
+        len,
+        filter: filter as *mut c::sock_filter,
+    };
+    unsafe {
+        match syscall_ret_u32(c::syscall(
+            c::SYS_seccomp,
+            SECCOMP_SET_MODE_FILTER,
+            flags,
+            &prog as *const c::sock_fprog,
+        )) {
+            // `seccomp` was added in Linux 3.17; before that, filters without
+            // flags can be installed with `prctl`.
+            Err(io::Errno::NOSYS) if flags == 0 => {
+                ret(c::prctl(
+                    c::PR_SET_SECCOMP,
+                    SECCOMP_MODE_FILTER,
+                    &prog as *const c::sock_fprog,
+                ))?;
+                Ok(0)
+            }
+            result => result,
+        }
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn set_no_new_privs() -> io::Result<()> {
+    unsafe {
+        ret(c::prctl(
+            c::PR_SET_NO_NEW_PRIVS,
+            1 as c::c_ulong,
+            0 as c::c_ulong,
+            0 as c::c_ulong,
+            0 as c::c_ulong,
+        ))
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn no_new_privs() -> io::Result<bool> {
+    unsafe {
+        ret_c_int(c::prctl(
+            c::PR_GET_NO_NEW_PRIVS,
+            0 as c::c_ulong,
+            0 as c::c_ulong,
+            0 as c::c_ulong,
+            0 as c::c_ulong,
+        ))
+        .map(|value| value != 0)
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn seccomp_notif_recv(fd: BorrowedFd<'_>) -> io::Result<SeccompNotif> {
+    // The kernel requires the struct to be zeroed.
+    let mut notif = SeccompNotif::default();
+    unsafe {
+        ret(c::ioctl(
+            borrowed_fd(fd),
+            SECCOMP_IOCTL_NOTIF_RECV as _,
+            &mut notif as *mut SeccompNotif,
+        ))?;
+    }
+    Ok(notif)
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn seccomp_notif_send(
+    fd: BorrowedFd<'_>,
+    id: u64,
+    val: i64,
+    error: i32,
+    flags: u32,
+) -> io::Result<()> {
+    let resp = seccomp_notif_resp {
+        id,
+        val,
+        error,
+        flags,
+    };
+    unsafe {
+        ret(c::ioctl(
+            borrowed_fd(fd),
+            SECCOMP_IOCTL_NOTIF_SEND as _,
+            &resp as *const seccomp_notif_resp,
+        ))
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn seccomp_notif_id_valid(fd: BorrowedFd<'_>, id: u64) -> io::Result<()> {
+    unsafe {
+        ret(c::ioctl(
+            borrowed_fd(fd),
+            SECCOMP_IOCTL_NOTIF_ID_VALID as _,
+            &id as *const u64,
+        ))
+    }
+}
+
+// The oldest libc we support, 0.2.118, doesn't have `SYS_landlock_*`, so
+// define the syscall numbers ourselves.
+
+/// `SYS_landlock_create_ruleset`
+#[cfg(all(any(target_os = "android", target_os = "linux"), target_arch = "mips"))]
+const SYS_LANDLOCK_CREATE_RULESET: c::c_long = 4444;
+/// `SYS_landlock_create_ruleset`
+#[cfg(all(
+    any(target_os = "android", target_os = "linux"),
+    target_arch = "mips64"
+))]
+const SYS_LANDLOCK_CREATE_RULESET: c::c_long = 5444;
+/// `SYS_landlock_create_ruleset`
+#[cfg(all(
+    any(target_os = "android", target_os = "linux"),
+    target_arch = "x86_64",
+    target_pointer_width = "32"
+))]
+const SYS_LANDLOCK_CREATE_RULESET: c::c_long = 0x4000_0000 | 444; // `__X32_SYSCALL_BIT | 444`
+/// `SYS_landlock_create_ruleset`
+#[cfg(all(
+    any(target_os = "android", target_os = "linux"),
+    not(any(
+        target_arch = "mips",
+        target_arch = "mips64",
+        all(target_arch = "x86_64", target_pointer_width = "32")
+    ))
+))]
+const SYS_LANDLOCK_CREATE_RULESET: c::c_long = 444;
+
+/// `SYS_landlock_add_rule`
+#[cfg(any(target_os = "android", target_os = "linux"))]
+const SYS_LANDLOCK_ADD_RULE: c::c_long = SYS_LANDLOCK_CREATE_RULESET + 1;
+
+/// `SYS_landlock_restrict_self`
+#[cfg(any(target_os = "android", target_os = "linux"))]
+const SYS_LANDLOCK_RESTRICT_SELF: c::c_long = SYS_LANDLOCK_CREATE_RULESET + 2;
+
+/// `LANDLOCK_CREATE_RULESET_VERSION`
+#[cfg(any(target_os = "android", target_os = "linux"))]
+const LANDLOCK_CREATE_RULESET_VERSION: c::c_uint = 1 << 0;
+
+/// `LANDLOCK_RULE_PATH_BENEATH`
+#[cfg(any(target_os = "android", target_os = "linux"))]
+const LANDLOCK_RULE_PATH_BENEATH: c::c_uint = 1;
+
+/// `struct landlock_ruleset_attr`
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[repr(C)]
+struct landlock_ruleset_attr {
+    handled_access_fs: u64,
+}
+
+/// `struct landlock_path_beneath_attr`
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[repr(C, packed)]
+struct landlock_path_beneath_attr {
+    allowed_access: u64,
+    parent_fd: i32,
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn landlock_create_ruleset(handled_access_fs: u64, flags: u32) -> io::Result<OwnedFd> {
+    let attr = landlock_ruleset_attr { handled_access_fs };
+    unsafe {
+        syscall_ret_owned_fd(c::syscall(
+            SYS_LANDLOCK_CREATE_RULESET,
+            &attr as *const landlock_ruleset_attr,
+            size_of::<landlock_ruleset_attr>(),
+            flags,
+        ))
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn landlock_abi_version() -> io::Result<u32> {
+    unsafe {
+        syscall_ret_u32(c::syscall(
+            SYS_LANDLOCK_CREATE_RULESET,
+            null::<landlock_ruleset_attr>(),
+            0_usize,
+            LANDLOCK_CREATE_RULESET_VERSION,
+        ))
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn landlock_add_rule_path_beneath(
+    ruleset_fd: BorrowedFd<'_>,
+    allowed_access: u64,
+    parent_fd: BorrowedFd<'_>,
+) -> io::Result<()> {
+    let attr = landlock_path_beneath_attr {
+        allowed_access,
+        parent_fd: parent_fd.as_raw_fd(),
+    };
+    unsafe {
+        syscall_ret(c::syscall(
+            SYS_LANDLOCK_ADD_RULE,
+            borrowed_fd(ruleset_fd),
+            LANDLOCK_RULE_PATH_BENEATH,
+            &attr as *const landlock_path_beneath_attr,
+            0 as c::c_uint,
+        ))
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn landlock_restrict_self(ruleset_fd: BorrowedFd<'_>, flags: u32) -> io::Result<()> {
+    unsafe {
+        syscall_ret(c::syscall(
+            SYS_LANDLOCK_RESTRICT_SELF,
+            borrowed_fd(ruleset_fd),
+            flags,
+        ))
+    }
+}
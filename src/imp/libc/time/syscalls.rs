@@ -10,7 +10,19 @@ use super::types::Timespec;
 #[cfg(not(target_os = "wasi"))]
 use super::types::{ClockId, DynamicClockId};
 use crate::io;
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+#[cfg(feature = "time")]
+use crate::time::Timeval;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg(feature = "time")]
+use core::convert::TryInto;
 use core::mem::MaybeUninit;
+#[cfg(target_os = "linux")]
+#[cfg(feature = "time")]
+use {
+    super::super::conv::ret_c_int,
+    crate::time::{Timex, TimexModes, TimexState},
+};
 #[cfg(any(target_os = "android", target_os = "fuchsia", target_os = "linux"))]
 #[cfg(feature = "time")]
 use {
@@ -155,34 +167,7 @@ unsafe fn clock_gettime_old(id: ClockId) -> Timespec {
 pub(crate) fn clock_gettime_dynamic(id: DynamicClockId<'_>) -> io::Result<Timespec> {
     let mut timespec = MaybeUninit::<LibcTimespec>::uninit();
     unsafe {
-        let id: c::clockid_t = match id {
-            DynamicClockId::Known(id) => id as c::clockid_t,
-
-            #[cfg(any(target_os = "android", target_os = "linux"))]
-            DynamicClockId::Dynamic(fd) => {
-                use crate::fd::AsRawFd;
-                const CLOCKFD: i32 = 3;
-                (!fd.as_raw_fd() << 3) | CLOCKFD
-            }
-
-            #[cfg(not(any(target_os = "android", target_os = "linux")))]
-            DynamicClockId::Dynamic(_fd) => {
-                // Dynamic clocks are not supported on this platform.
-                return Err(io::Errno::INVAL);
-            }
-
-            #[cfg(any(target_os = "android", target_os = "linux"))]
-            DynamicClockId::RealtimeAlarm => c::CLOCK_REALTIME_ALARM,
-
-            #[cfg(any(target_os = "android", target_os = "linux"))]
-            DynamicClockId::Tai => c::CLOCK_TAI,
-
-            #[cfg(any(target_os = "android", target_os = "linux"))]
-            DynamicClockId::Boottime => c::CLOCK_BOOTTIME,
-
-            #[cfg(any(target_os = "android", target_os = "linux"))]
-            DynamicClockId::BoottimeAlarm => c::CLOCK_BOOTTIME_ALARM,
-        };
+        let id = dynamic_clockid(id)?;
 
         #[cfg(all(
             any(target_arch = "arm", target_arch = "mips", target_arch = "x86"),
@@ -233,6 +218,121 @@ unsafe fn clock_gettime_dynamic_old(id: c::clockid_t) -> io::Result<Timespec> {
     })
 }
 
+#[cfg(not(target_os = "wasi"))]
+#[inline]
+fn dynamic_clockid(id: DynamicClockId<'_>) -> io::Result<c::clockid_t> {
+    Ok(match id {
+        DynamicClockId::Known(id) => id as c::clockid_t,
+
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        DynamicClockId::Dynamic(fd) => {
+            use crate::fd::AsRawFd;
+            const CLOCKFD: i32 = 3;
+            (!fd.as_raw_fd() << 3) | CLOCKFD
+        }
+
+        #[cfg(not(any(target_os = "android", target_os = "linux")))]
+        DynamicClockId::Dynamic(_fd) => {
+            // Dynamic clocks are not supported on this platform.
+            return Err(io::Errno::INVAL);
+        }
+
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        DynamicClockId::RealtimeAlarm => c::CLOCK_REALTIME_ALARM,
+
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        DynamicClockId::Tai => c::CLOCK_TAI,
+
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        DynamicClockId::Boottime => c::CLOCK_BOOTTIME,
+
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        DynamicClockId::BoottimeAlarm => c::CLOCK_BOOTTIME_ALARM,
+    })
+}
+
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+#[inline]
+pub(crate) fn clock_getres_dynamic(id: DynamicClockId<'_>) -> io::Result<Timespec> {
+    let mut timespec = MaybeUninit::<LibcTimespec>::uninit();
+    unsafe {
+        let id = dynamic_clockid(id)?;
+
+        #[cfg(all(
+            any(target_arch = "arm", target_arch = "mips", target_arch = "x86"),
+            target_env = "gnu"
+        ))]
+        {
+            if let Some(libc_clock_getres) = __clock_getres64.get() {
+                ret(libc_clock_getres(id, timespec.as_mut_ptr()))?;
+
+                Ok(timespec.assume_init().into())
+            } else {
+                clock_getres_dynamic_old(id)
+            }
+        }
+
+        #[cfg(not(all(
+            any(target_arch = "arm", target_arch = "mips", target_arch = "x86"),
+            target_env = "gnu"
+        )))]
+        {
+            ret(c::clock_getres(id, timespec.as_mut_ptr()))?;
+
+            Ok(timespec.assume_init())
+        }
+    }
+}
+
+#[cfg(all(
+    any(target_arch = "arm", target_arch = "mips", target_arch = "x86"),
+    target_env = "gnu"
+))]
+#[inline]
+unsafe fn clock_getres_dynamic_old(id: c::clockid_t) -> io::Result<Timespec> {
+    let mut old_timespec = MaybeUninit::<c::timespec>::uninit();
+
+    ret(c::clock_getres(id, old_timespec.as_mut_ptr()))?;
+
+    let old_timespec = old_timespec.assume_init();
+    Ok(Timespec {
+        tv_sec: old_timespec.tv_sec.into(),
+        tv_nsec: old_timespec.tv_nsec.into(),
+    })
+}
+
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+#[cfg(feature = "time")]
+#[allow(clippy::useless_conversion)]
+pub(crate) fn gettimeofday() -> Timeval {
+    let mut tv = MaybeUninit::<c::timeval>::uninit();
+    unsafe {
+        ret(c::gettimeofday(tv.as_mut_ptr(), core::ptr::null_mut())).unwrap();
+        let tv = tv.assume_init();
+        Timeval {
+            tv_sec: tv.tv_sec.into(),
+            tv_usec: tv.tv_usec.into(),
+        }
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg(feature = "time")]
+#[allow(clippy::useless_conversion)]
+pub(crate) fn settimeofday(tv: &Timeval) -> io::Result<()> {
+    // Like libc's own `settimeofday`, set the time with `clock_settime`.
+    if !(0..1_000_000).contains(&tv.tv_usec) {
+        return Err(io::Errno::INVAL);
+    }
+    let timespec = c::timespec {
+        tv_sec: tv.tv_sec.try_into().map_err(|_| io::Errno::OVERFLOW)?,
+        tv_nsec: (tv.tv_usec * 1000)
+            .try_into()
+            .map_err(|_| io::Errno::INVAL)?,
+    };
+    unsafe { ret(c::clock_settime(c::CLOCK_REALTIME, &timespec)) }
+}
+
 #[cfg(any(target_os = "android", target_os = "fuchsia", target_os = "linux"))]
 #[cfg(feature = "time")]
 pub(crate) fn timerfd_create(id: TimerfdClockId, flags: TimerfdFlags) -> io::Result<OwnedFd> {
@@ -402,3 +502,85 @@ unsafe fn timerfd_gettime_old(fd: BorrowedFd<'_>) -> io::Result<Itimerspec> {
         },
     })
 }
+
+#[cfg(target_os = "linux")]
+#[cfg(feature = "time")]
+pub(crate) fn clock_adjtime(id: ClockId, timex: &mut Timex) -> io::Result<TimexState> {
+    let mut raw = timex_to_libc(timex)?;
+    let state = unsafe { ret_c_int(c::clock_adjtime(id as c::clockid_t, &mut raw))? };
+    *timex = timex_from_libc(&raw);
+    Ok(match state {
+        c::TIME_OK => TimexState::Ok,
+        c::TIME_INS => TimexState::Ins,
+        c::TIME_DEL => TimexState::Del,
+        c::TIME_OOP => TimexState::Oop,
+        c::TIME_WAIT => TimexState::Wait,
+        _ => TimexState::Error,
+    })
+}
+
+#[cfg(target_os = "linux")]
+#[cfg(feature = "time")]
+#[allow(clippy::useless_conversion)]
+fn timex_to_libc(timex: &Timex) -> io::Result<c::timex> {
+    fn long<T: TryInto<U>, U>(x: T) -> io::Result<U> {
+        x.try_into().map_err(|_| io::Errno::OVERFLOW)
+    }
+
+    // Safety: `timex` is a plain C struct, and all-zeros is a valid value;
+    // zeroing also clears the private padding fields.
+    let mut raw: c::timex = unsafe { core::mem::zeroed() };
+    raw.modes = timex.modes.bits();
+    raw.offset = long(timex.offset)?;
+    raw.freq = long(timex.freq)?;
+    raw.maxerror = long(timex.maxerror)?;
+    raw.esterror = long(timex.esterror)?;
+    raw.status = timex.status;
+    raw.constant = long(timex.constant)?;
+    raw.precision = long(timex.precision)?;
+    raw.tolerance = long(timex.tolerance)?;
+    raw.time.tv_sec = long(timex.time.tv_sec)?;
+    raw.time.tv_usec = long(timex.time.tv_usec)?;
+    raw.tick = long(timex.tick)?;
+    raw.ppsfreq = long(timex.ppsfreq)?;
+    raw.jitter = long(timex.jitter)?;
+    raw.shift = timex.shift;
+    raw.stabil = long(timex.stabil)?;
+    raw.jitcnt = long(timex.jitcnt)?;
+    raw.calcnt = long(timex.calcnt)?;
+    raw.errcnt = long(timex.errcnt)?;
+    raw.stbcnt = long(timex.stbcnt)?;
+    raw.tai = timex.tai;
+    Ok(raw)
+}
+
+#[cfg(target_os = "linux")]
+#[cfg(feature = "time")]
+#[allow(clippy::useless_conversion)]
+fn timex_from_libc(raw: &c::timex) -> Timex {
+    Timex {
+        modes: TimexModes::from_bits_truncate(raw.modes),
+        offset: raw.offset.into(),
+        freq: raw.freq.into(),
+        maxerror: raw.maxerror.into(),
+        esterror: raw.esterror.into(),
+        status: raw.status,
+        constant: raw.constant.into(),
+        precision: raw.precision.into(),
+        tolerance: raw.tolerance.into(),
+        time: Timeval {
+            tv_sec: raw.time.tv_sec.into(),
+            tv_usec: raw.time.tv_usec.into(),
+        },
+        tick: raw.tick.into(),
+        ppsfreq: raw.ppsfreq.into(),
+        jitter: raw.jitter.into(),
+        shift: raw.shift,
+        stabil: raw.stabil.into(),
+        jitcnt: raw.jitcnt.into(),
+        calcnt: raw.calcnt.into(),
+        errcnt: raw.errcnt.into(),
+        stbcnt: raw.stbcnt.into(),
+        tai: raw.tai,
+    }
+}
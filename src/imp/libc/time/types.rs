@@ -49,6 +49,16 @@ pub type Nsecs = i64;
 #[cfg(not(all(target_arch = "x86_64", target_pointer_width = "32")))]
 pub type Nsecs = c::c_long;
 
+/// `struct timeval`—A time value with microsecond precision.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+pub struct Timeval {
+    /// Seconds.
+    pub tv_sec: Secs,
+
+    /// Microseconds. Must be less than 1_000_000.
+    pub tv_usec: i64,
+}
+
 /// On most platforms, `LibcTimespec` is just `Timespec`.
 #[cfg(not(all(
     any(target_arch = "arm", target_arch = "mips", target_arch = "x86"),
@@ -360,3 +370,78 @@ pub enum TimerfdClockId {
     /// Use of this clock requires the `CAP_WAKE_ALARM` Linux capability.
     BoottimeAlarm = c::CLOCK_BOOTTIME_ALARM,
 }
+
+#[cfg(target_os = "linux")]
+bitflags! {
+    /// `ADJ_*` flags for use with [`clock_adjtime`].
+    ///
+    /// [`clock_adjtime`]: crate::time::clock_adjtime
+    #[derive(Default)]
+    pub struct TimexModes: c::c_uint {
+        /// `ADJ_OFFSET`—Set the time offset from `offset`.
+        const OFFSET = c::ADJ_OFFSET;
+
+        /// `ADJ_FREQUENCY`—Set the frequency offset from `freq`.
+        const FREQUENCY = c::ADJ_FREQUENCY;
+
+        /// `ADJ_MAXERROR`—Set the maximum error from `maxerror`.
+        const MAXERROR = c::ADJ_MAXERROR;
+
+        /// `ADJ_ESTERROR`—Set the estimated error from `esterror`.
+        const ESTERROR = c::ADJ_ESTERROR;
+
+        /// `ADJ_STATUS`—Set the clock status bits from `status`.
+        const STATUS = c::ADJ_STATUS;
+
+        /// `ADJ_TIMECONST`—Set the PLL time constant from `constant`.
+        const TIMECONST = c::ADJ_TIMECONST;
+
+        /// `ADJ_TAI`—Set the TAI offset from `constant`.
+        const TAI = c::ADJ_TAI;
+
+        /// `ADJ_SETOFFSET`—Add `time` to the current time.
+        const SETOFFSET = c::ADJ_SETOFFSET;
+
+        /// `ADJ_MICRO`—Select microsecond resolution.
+        const MICRO = c::ADJ_MICRO;
+
+        /// `ADJ_NANO`—Select nanosecond resolution.
+        const NANO = c::ADJ_NANO;
+
+        /// `ADJ_TICK`—Set the tick value from `tick`.
+        const TICK = c::ADJ_TICK;
+
+        /// `ADJ_OFFSET_SINGLESHOT`—Old-fashioned `adjtime` offset adjustment.
+        const OFFSET_SINGLESHOT = c::ADJ_OFFSET_SINGLESHOT;
+
+        /// `ADJ_OFFSET_SS_READ`—Read the remaining old-fashioned `adjtime` offset.
+        const OFFSET_SS_READ = c::ADJ_OFFSET_SS_READ;
+    }
+}
+
+/// `TIME_*` clock states returned by [`clock_adjtime`].
+///
+/// [`clock_adjtime`]: crate::time::clock_adjtime
+#[cfg(target_os = "linux")]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[repr(i32)]
+#[non_exhaustive]
+pub enum TimexState {
+    /// `TIME_OK`—Clock synchronized, no leap second adjustment pending.
+    Ok = c::TIME_OK,
+
+    /// `TIME_INS`—A leap second will be inserted at the end of the UTC day.
+    Ins = c::TIME_INS,
+
+    /// `TIME_DEL`—A leap second will be deleted at the end of the UTC day.
+    Del = c::TIME_DEL,
+
+    /// `TIME_OOP`—A leap second insertion is in progress.
+    Oop = c::TIME_OOP,
+
+    /// `TIME_WAIT`—A leap second insertion or deletion has completed.
+    Wait = c::TIME_WAIT,
+
+    /// `TIME_ERROR`—The clock is not synchronized.
+    Error = c::TIME_ERROR,
+}
@@ -26,7 +26,13 @@ use super::io::errno::{
     try_decode_void_star,
 };
 use super::reg::{raw_arg, ArgNumber, ArgReg, RetReg, R0};
-#[cfg(any(feature = "thread", feature = "time", target_arch = "x86"))]
+#[cfg(any(
+    feature = "param",
+    feature = "process",
+    feature = "thread",
+    feature = "time",
+    target_arch = "x86"
+))]
 use super::time::types::ClockId;
 #[cfg(feature = "time")]
 use super::time::types::TimerfdClockId;
@@ -37,7 +43,13 @@ use crate::process::{Pid, Resource, Signal};
 use crate::utils::{as_mut_ptr, as_ptr};
 use core::mem::MaybeUninit;
 use core::ptr::null_mut;
-#[cfg(any(feature = "thread", feature = "time", target_arch = "x86"))]
+#[cfg(any(
+    feature = "param",
+    feature = "process",
+    feature = "thread",
+    feature = "time",
+    target_arch = "x86"
+))]
 use linux_raw_sys::general::__kernel_clockid_t;
 #[cfg(target_pointer_width = "64")]
 use linux_raw_sys::general::__kernel_loff_t;
@@ -224,7 +236,6 @@ pub(super) fn opt_mut<T: Sized, Num: ArgNumber>(t: Option<&mut T>) -> ArgReg<Num
 
 /// Convert an optional immutable reference into a `usize` for passing to a
 /// syscall.
-#[cfg(any(target_arch = "aarch64", target_arch = "riscv64"))]
 #[inline]
 pub(super) fn opt_ref<'a, T: Sized, Num: ArgNumber>(t: Option<&'a T>) -> ArgReg<'a, Num> {
     // This optimizes into the equivalent of `transmute(t)`, and has the
@@ -263,7 +274,13 @@ pub(super) fn loff_t_from_u64<'a, Num: ArgNumber>(i: u64) -> ArgReg<'a, Num> {
     pass_usize(i as usize)
 }
 
-#[cfg(any(feature = "thread", feature = "time", target_arch = "x86"))]
+#[cfg(any(
+    feature = "param",
+    feature = "process",
+    feature = "thread",
+    feature = "time",
+    target_arch = "x86"
+))]
 impl<'a, Num: ArgNumber> From<ClockId> for ArgReg<'a, Num> {
     #[inline]
     fn from(i: ClockId) -> Self {
@@ -369,7 +386,13 @@ impl<'a, Num: ArgNumber> From<crate::io::epoll::CreateFlags> for ArgReg<'a, Num>
     }
 }
 
-#[cfg(any(feature = "mm", feature = "time", target_arch = "x86"))] // vdso.rs uses `madvise`
+#[cfg(any(
+    feature = "mm",
+    feature = "param",
+    feature = "process",
+    feature = "time",
+    target_arch = "x86"
+))] // vdso.rs uses `madvise`
 impl<'a, Num: ArgNumber> From<crate::imp::mm::types::ProtFlags> for ArgReg<'a, Num> {
     #[inline]
     fn from(flags: crate::imp::mm::types::ProtFlags) -> Self {
@@ -377,7 +400,13 @@ impl<'a, Num: ArgNumber> From<crate::imp::mm::types::ProtFlags> for ArgReg<'a, N
     }
 }
 
-#[cfg(any(feature = "mm", feature = "time", target_arch = "x86"))] // vdso.rs uses `madvise`
+#[cfg(any(
+    feature = "mm",
+    feature = "param",
+    feature = "process",
+    feature = "time",
+    target_arch = "x86"
+))] // vdso.rs uses `madvise`
 impl<'a, Num: ArgNumber> From<crate::imp::mm::types::MsyncFlags> for ArgReg<'a, Num> {
     #[inline]
     fn from(flags: crate::imp::mm::types::MsyncFlags) -> Self {
@@ -385,7 +414,13 @@ impl<'a, Num: ArgNumber> From<crate::imp::mm::types::MsyncFlags> for ArgReg<'a,
     }
 }
 
-#[cfg(any(feature = "mm", feature = "time", target_arch = "x86"))] // vdso.rs uses `madvise`
+#[cfg(any(
+    feature = "mm",
+    feature = "param",
+    feature = "process",
+    feature = "time",
+    target_arch = "x86"
+))] // vdso.rs uses `madvise`
 impl<'a, Num: ArgNumber> From<crate::imp::mm::types::MremapFlags> for ArgReg<'a, Num> {
     #[inline]
     fn from(flags: crate::imp::mm::types::MremapFlags) -> Self {
@@ -393,7 +428,13 @@ impl<'a, Num: ArgNumber> From<crate::imp::mm::types::MremapFlags> for ArgReg<'a,
     }
 }
 
-#[cfg(any(feature = "mm", feature = "time", target_arch = "x86"))] // vdso.rs uses `madvise`
+#[cfg(any(
+    feature = "mm",
+    feature = "param",
+    feature = "process",
+    feature = "time",
+    target_arch = "x86"
+))] // vdso.rs uses `madvise`
 impl<'a, Num: ArgNumber> From<crate::imp::mm::types::MlockFlags> for ArgReg<'a, Num> {
     #[inline]
     fn from(flags: crate::imp::mm::types::MlockFlags) -> Self {
@@ -401,7 +442,13 @@ impl<'a, Num: ArgNumber> From<crate::imp::mm::types::MlockFlags> for ArgReg<'a,
     }
 }
 
-#[cfg(any(feature = "mm", feature = "time", target_arch = "x86"))] // vdso.rs uses `madvise`
+#[cfg(any(
+    feature = "mm",
+    feature = "param",
+    feature = "process",
+    feature = "time",
+    target_arch = "x86"
+))] // vdso.rs uses `madvise`
 impl<'a, Num: ArgNumber> From<crate::imp::mm::types::MapFlags> for ArgReg<'a, Num> {
     #[inline]
     fn from(flags: crate::imp::mm::types::MapFlags) -> Self {
@@ -409,7 +456,13 @@ impl<'a, Num: ArgNumber> From<crate::imp::mm::types::MapFlags> for ArgReg<'a, Nu
     }
 }
 
-#[cfg(any(feature = "mm", feature = "time", target_arch = "x86"))] // vdso.rs uses `madvise`
+#[cfg(any(
+    feature = "mm",
+    feature = "param",
+    feature = "process",
+    feature = "time",
+    target_arch = "x86"
+))] // vdso.rs uses `madvise`
 impl<'a, Num: ArgNumber> From<crate::imp::mm::types::MprotectFlags> for ArgReg<'a, Num> {
     #[inline]
     fn from(flags: crate::imp::mm::types::MprotectFlags) -> Self {
@@ -417,7 +470,13 @@ impl<'a, Num: ArgNumber> From<crate::imp::mm::types::MprotectFlags> for ArgReg<'
     }
 }
 
-#[cfg(any(feature = "mm", feature = "time", target_arch = "x86"))] // vdso.rs uses `madvise`
+#[cfg(any(
+    feature = "mm",
+    feature = "param",
+    feature = "process",
+    feature = "time",
+    target_arch = "x86"
+))] // vdso.rs uses `madvise`
 impl<'a, Num: ArgNumber> From<crate::imp::mm::types::UserfaultfdFlags> for ArgReg<'a, Num> {
     #[inline]
     fn from(flags: crate::imp::mm::types::UserfaultfdFlags) -> Self {
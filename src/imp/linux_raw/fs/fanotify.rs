@@ -0,0 +1,161 @@
+use super::super::c;
+use bitflags::bitflags;
+
+bitflags! {
+    /// `FAN_*` flags for use with [`fanotify::init`].
+    ///
+    /// [`fanotify::init`]: crate::fs::fanotify::init
+    pub struct InitFlags: c::c_uint {
+        /// `FAN_CLOEXEC`
+        const CLOEXEC = 0x0000_0001;
+
+        /// `FAN_NONBLOCK`
+        const NONBLOCK = 0x0000_0002;
+
+        /// `FAN_CLASS_NOTIF`—Only receive notification events.
+        const CLASS_NOTIF = 0x0000_0000;
+
+        /// `FAN_CLASS_CONTENT`—Receive permission events for final content.
+        const CLASS_CONTENT = 0x0000_0004;
+
+        /// `FAN_CLASS_PRE_CONTENT`—Receive permission events before content
+        /// is final.
+        const CLASS_PRE_CONTENT = 0x0000_0008;
+
+        /// `FAN_UNLIMITED_QUEUE`—Don't limit the size of the event queue.
+        const UNLIMITED_QUEUE = 0x0000_0010;
+
+        /// `FAN_UNLIMITED_MARKS`—Don't limit the number of marks.
+        const UNLIMITED_MARKS = 0x0000_0020;
+
+        /// `FAN_ENABLE_AUDIT`—Allow responses to request audit records.
+        const ENABLE_AUDIT = 0x0000_0040;
+
+        /// `FAN_REPORT_TID`—Report thread IDs instead of process IDs.
+        const REPORT_TID = 0x0000_0100;
+    }
+}
+
+bitflags! {
+    /// `FAN_MARK_*` flags for use with [`fanotify::mark`].
+    ///
+    /// [`fanotify::mark`]: crate::fs::fanotify::mark
+    pub struct MarkFlags: c::c_uint {
+        /// `FAN_MARK_ADD`—Add the events in the mask to the mark.
+        const ADD = 0x0000_0001;
+
+        /// `FAN_MARK_REMOVE`—Remove the events in the mask from the mark.
+        const REMOVE = 0x0000_0002;
+
+        /// `FAN_MARK_DONT_FOLLOW`—Don't follow a trailing symlink.
+        const DONT_FOLLOW = 0x0000_0004;
+
+        /// `FAN_MARK_ONLYDIR`—Fail if the path is not a directory.
+        const ONLYDIR = 0x0000_0008;
+
+        /// `FAN_MARK_MOUNT`—Mark the mount containing the path.
+        const MOUNT = 0x0000_0010;
+
+        /// `FAN_MARK_IGNORED_MASK`—Update the ignore mask, not the event mask.
+        const IGNORED_MASK = 0x0000_0020;
+
+        /// `FAN_MARK_IGNORED_SURV_MODIFY`—Keep the ignore mask when the file
+        /// is modified.
+        const IGNORED_SURV_MODIFY = 0x0000_0040;
+
+        /// `FAN_MARK_FLUSH`—Remove all marks of the type given by the other
+        /// flags.
+        const FLUSH = 0x0000_0080;
+
+        /// `FAN_MARK_FILESYSTEM`—Mark the filesystem containing the path.
+        const FILESYSTEM = 0x0000_0100;
+    }
+}
+
+bitflags! {
+    /// `FAN_*` event flags for use with [`fanotify::mark`] and reported in
+    /// [`FanotifyEvent`].
+    ///
+    /// [`fanotify::mark`]: crate::fs::fanotify::mark
+    /// [`FanotifyEvent`]: crate::fs::fanotify::FanotifyEvent
+    pub struct EventFlags: u64 {
+        /// `FAN_ACCESS`—A file was accessed.
+        const ACCESS = 0x0000_0001;
+
+        /// `FAN_MODIFY`—A file was modified.
+        const MODIFY = 0x0000_0002;
+
+        /// `FAN_ATTRIB`—Metadata changed.
+        const ATTRIB = 0x0000_0004;
+
+        /// `FAN_CLOSE_WRITE`—A file opened for writing was closed.
+        const CLOSE_WRITE = 0x0000_0008;
+
+        /// `FAN_CLOSE_NOWRITE`—A file not opened for writing was closed.
+        const CLOSE_NOWRITE = 0x0000_0010;
+
+        /// `FAN_OPEN`—A file was opened.
+        const OPEN = 0x0000_0020;
+
+        /// `FAN_MOVED_FROM`—A file was moved out of a marked directory.
+        const MOVED_FROM = 0x0000_0040;
+
+        /// `FAN_MOVED_TO`—A file was moved into a marked directory.
+        const MOVED_TO = 0x0000_0080;
+
+        /// `FAN_CREATE`—A file was created in a marked directory.
+        const CREATE = 0x0000_0100;
+
+        /// `FAN_DELETE`—A file was deleted from a marked directory.
+        const DELETE = 0x0000_0200;
+
+        /// `FAN_DELETE_SELF`—A marked file or directory was deleted.
+        const DELETE_SELF = 0x0000_0400;
+
+        /// `FAN_MOVE_SELF`—A marked file or directory was moved.
+        const MOVE_SELF = 0x0000_0800;
+
+        /// `FAN_OPEN_EXEC`—A file was opened for execution.
+        const OPEN_EXEC = 0x0000_1000;
+
+        /// `FAN_Q_OVERFLOW`—The event queue overflowed, and events were
+        /// lost.
+        const Q_OVERFLOW = 0x0000_4000;
+
+        /// `FAN_OPEN_PERM`—Permission to open a file was requested.
+        const OPEN_PERM = 0x0001_0000;
+
+        /// `FAN_ACCESS_PERM`—Permission to read a file was requested.
+        const ACCESS_PERM = 0x0002_0000;
+
+        /// `FAN_OPEN_EXEC_PERM`—Permission to open a file for execution was
+        /// requested.
+        const OPEN_EXEC_PERM = 0x0004_0000;
+
+        /// `FAN_EVENT_ON_CHILD`—Report events for the immediate children of
+        /// marked directories.
+        const EVENT_ON_CHILD = 0x0800_0000;
+
+        /// `FAN_ONDIR`—Report events for directories too.
+        const ONDIR = 0x4000_0000;
+
+        /// `FAN_CLOSE`
+        const CLOSE = Self::CLOSE_WRITE.bits | Self::CLOSE_NOWRITE.bits;
+
+        /// `FAN_MOVE`
+        const MOVE = Self::MOVED_FROM.bits | Self::MOVED_TO.bits;
+    }
+}
+
+/// A response to a permission event, for use with [`fanotify::respond`].
+///
+/// [`fanotify::respond`]: crate::fs::fanotify::respond
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[repr(u32)]
+pub enum FanotifyResponse {
+    /// `FAN_ALLOW`
+    Allow = 0x01,
+
+    /// `FAN_DENY`
+    Deny = 0x02,
+}
@@ -0,0 +1,153 @@
+//! inotify types.
+
+use super::super::c;
+use bitflags::bitflags;
+
+bitflags! {
+    /// `IN_*` flags for use with [`inotify::init`].
+    ///
+    /// [`inotify::init`]: crate::fs::inotify::init
+    pub struct CreateFlags: c::c_uint {
+        /// `IN_CLOEXEC`
+        const CLOEXEC = linux_raw_sys::general::O_CLOEXEC;
+
+        /// `IN_NONBLOCK`
+        const NONBLOCK = linux_raw_sys::general::O_NONBLOCK;
+    }
+}
+
+bitflags! {
+    /// `IN_*` flags for use with [`inotify::add_watch`].
+    ///
+    /// [`inotify::add_watch`]: crate::fs::inotify::add_watch
+    pub struct WatchFlags: u32 {
+        /// `IN_ACCESS`—File was accessed.
+        const ACCESS = 0x0000_0001;
+
+        /// `IN_MODIFY`—File was modified.
+        const MODIFY = 0x0000_0002;
+
+        /// `IN_ATTRIB`—Metadata changed.
+        const ATTRIB = 0x0000_0004;
+
+        /// `IN_CLOSE_WRITE`—A file opened for writing was closed.
+        const CLOSE_WRITE = 0x0000_0008;
+
+        /// `IN_CLOSE_NOWRITE`—A file not opened for writing was closed.
+        const CLOSE_NOWRITE = 0x0000_0010;
+
+        /// `IN_OPEN`—File was opened.
+        const OPEN = 0x0000_0020;
+
+        /// `IN_MOVED_FROM`—A file was moved out of a watched directory.
+        const MOVED_FROM = 0x0000_0040;
+
+        /// `IN_MOVED_TO`—A file was moved into a watched directory.
+        const MOVED_TO = 0x0000_0080;
+
+        /// `IN_CREATE`—A file was created in a watched directory.
+        const CREATE = 0x0000_0100;
+
+        /// `IN_DELETE`—A file was deleted from a watched directory.
+        const DELETE = 0x0000_0200;
+
+        /// `IN_DELETE_SELF`—The watched file or directory was deleted.
+        const DELETE_SELF = 0x0000_0400;
+
+        /// `IN_MOVE_SELF`—The watched file or directory was moved.
+        const MOVE_SELF = 0x0000_0800;
+
+        /// `IN_CLOSE`
+        const CLOSE = Self::CLOSE_WRITE.bits | Self::CLOSE_NOWRITE.bits;
+
+        /// `IN_MOVE`
+        const MOVE = Self::MOVED_FROM.bits | Self::MOVED_TO.bits;
+
+        /// `IN_ALL_EVENTS`
+        const ALL_EVENTS = Self::ACCESS.bits
+            | Self::MODIFY.bits
+            | Self::ATTRIB.bits
+            | Self::CLOSE_WRITE.bits
+            | Self::CLOSE_NOWRITE.bits
+            | Self::OPEN.bits
+            | Self::MOVED_FROM.bits
+            | Self::MOVED_TO.bits
+            | Self::CREATE.bits
+            | Self::DELETE.bits
+            | Self::DELETE_SELF.bits
+            | Self::MOVE_SELF.bits;
+
+        /// `IN_ONLYDIR`—Only watch the path if it is a directory.
+        const ONLYDIR = 0x0100_0000;
+
+        /// `IN_DONT_FOLLOW`—Don't follow a trailing symlink.
+        const DONT_FOLLOW = 0x0200_0000;
+
+        /// `IN_EXCL_UNLINK`—Ignore events for children after they are unlinked.
+        const EXCL_UNLINK = 0x0400_0000;
+
+        /// `IN_MASK_CREATE`—Fail with `EEXIST` if the path is already watched.
+        const MASK_CREATE = 0x1000_0000;
+
+        /// `IN_MASK_ADD`—Add to an existing watch's mask, don't replace it.
+        const MASK_ADD = 0x2000_0000;
+
+        /// `IN_ONESHOT`—Remove the watch after one event.
+        const ONESHOT = 0x8000_0000;
+    }
+}
+
+bitflags! {
+    /// `IN_*` flags reported in the `mask` field of [`InotifyEvent`].
+    ///
+    /// [`InotifyEvent`]: crate::fs::inotify::InotifyEvent
+    pub struct ReadFlags: u32 {
+        /// `IN_ACCESS`—File was accessed.
+        const ACCESS = 0x0000_0001;
+
+        /// `IN_MODIFY`—File was modified.
+        const MODIFY = 0x0000_0002;
+
+        /// `IN_ATTRIB`—Metadata changed.
+        const ATTRIB = 0x0000_0004;
+
+        /// `IN_CLOSE_WRITE`—A file opened for writing was closed.
+        const CLOSE_WRITE = 0x0000_0008;
+
+        /// `IN_CLOSE_NOWRITE`—A file not opened for writing was closed.
+        const CLOSE_NOWRITE = 0x0000_0010;
+
+        /// `IN_OPEN`—File was opened.
+        const OPEN = 0x0000_0020;
+
+        /// `IN_MOVED_FROM`—A file was moved out of a watched directory.
+        const MOVED_FROM = 0x0000_0040;
+
+        /// `IN_MOVED_TO`—A file was moved into a watched directory.
+        const MOVED_TO = 0x0000_0080;
+
+        /// `IN_CREATE`—A file was created in a watched directory.
+        const CREATE = 0x0000_0100;
+
+        /// `IN_DELETE`—A file was deleted from a watched directory.
+        const DELETE = 0x0000_0200;
+
+        /// `IN_DELETE_SELF`—The watched file or directory was deleted.
+        const DELETE_SELF = 0x0000_0400;
+
+        /// `IN_MOVE_SELF`—The watched file or directory was moved.
+        const MOVE_SELF = 0x0000_0800;
+
+        /// `IN_UNMOUNT`—The watched object's filesystem was unmounted.
+        const UNMOUNT = 0x0000_2000;
+
+        /// `IN_Q_OVERFLOW`—The event queue overflowed, and events were lost.
+        const Q_OVERFLOW = 0x0000_4000;
+
+        /// `IN_IGNORED`—The watch was removed.
+        const IGNORED = 0x0000_8000;
+
+        /// `IN_ISDIR`—The subject of the event is a directory.
+        const ISDIR = 0x4000_0000;
+    }
+}
@@ -1,5 +1,9 @@
 #[cfg(any(feature = "fs", feature = "procfs"))]
 pub(crate) mod dir;
+#[cfg(feature = "fs")]
+pub(crate) mod fanotify;
+#[cfg(feature = "fs")]
+pub(crate) mod inotify;
 pub(crate) mod makedev;
 pub(crate) mod syscalls;
 pub(crate) mod types;
@@ -19,6 +19,10 @@ use super::super::conv::{
 };
 #[cfg(target_pointer_width = "64")]
 use super::super::conv::{loff_t, loff_t_from_u64, ret_u64};
+#[cfg(feature = "fs")]
+use super::fanotify::{EventFlags, InitFlags, MarkFlags};
+#[cfg(feature = "fs")]
+use super::inotify::{CreateFlags, WatchFlags};
 #[cfg(any(
     target_arch = "aarch64",
     target_arch = "riscv64",
@@ -30,10 +34,11 @@ use crate::fd::{BorrowedFd, RawFd};
 use crate::ffi::ZStr;
 use crate::fs::{
     Access, Advice, AtFlags, FallocateFlags, FdFlags, FileType, FlockOperation, MemfdFlags, Mode,
-    OFlags, RenameFlags, ResolveFlags, SealFlags, Stat, StatFs, StatxFlags, Timestamps,
+    OFlags, Owner, RenameFlags, ResolveFlags, SealFlags, SeekFrom, Stat, StatFs, StatxFlags,
+    Timestamps,
 };
-use crate::io::{self, OwnedFd, SeekFrom};
-use crate::process::{Gid, Uid};
+use crate::io::{self, OwnedFd};
+use crate::process::{Gid, Pid, Signal, Uid};
 use core::convert::TryInto;
 use core::mem::MaybeUninit;
 #[cfg(any(target_pointer_width = "32", target_arch = "mips64"))]
@@ -41,9 +46,19 @@ use core::sync::atomic::{AtomicBool, Ordering::Relaxed};
 #[cfg(target_arch = "mips64")]
 use linux_raw_sys::general::stat as linux_stat64;
 use linux_raw_sys::general::{
-    __kernel_timespec, open_how, statx, AT_FDCWD, AT_REMOVEDIR, AT_SYMLINK_NOFOLLOW, F_ADD_SEALS,
-    F_DUPFD, F_DUPFD_CLOEXEC, F_GETFD, F_GETFL, F_GETLEASE, F_GETOWN, F_GETPIPE_SZ, F_GETSIG,
-    F_GET_SEALS, F_SETFD, F_SETFL, F_SETPIPE_SZ,
+    __kernel_timespec, f_owner_ex, open_how, statx, AT_FDCWD, AT_REMOVEDIR, AT_SYMLINK_NOFOLLOW,
+    F_ADD_SEALS, F_DUPFD, F_DUPFD_CLOEXEC, F_GETFD, F_GETFL, F_GETLEASE, F_GETOWN, F_GETOWN_EX,
+    F_GETPIPE_SZ, F_GETSIG, F_GET_SEALS, F_OWNER_PGRP, F_OWNER_PID, F_OWNER_TID, F_SETFD, F_SETFL,
+    F_SETOWN_EX, F_SETPIPE_SZ, F_SETSIG,
+};
+#[cfg(feature = "fs")]
+use {
+    super::super::conv::by_mut,
+    crate::fd::AsRawFd,
+    crate::fs::{
+        Cachestat, CachestatRange, FspickFlags, MemfdSecretFlags, MountAttr, OpenTreeFlags,
+    },
+    linux_raw_sys::general::mount_attr,
 };
 #[cfg(target_pointer_width = "32")]
 use {
@@ -155,31 +170,28 @@ pub(crate) fn fchmod(fd: BorrowedFd<'_>, mode: Mode) -> io::Result<()> {
 pub(crate) fn chownat(
     dirfd: BorrowedFd<'_>,
     filename: &ZStr,
-    owner: Uid,
-    group: Gid,
+    owner: Option<Uid>,
+    group: Option<Gid>,
     flags: AtFlags,
 ) -> io::Result<()> {
     unsafe {
+        let (ow, gr) = crate::process::translate_fchown_args(owner, group);
         ret(syscall_readonly!(
             __NR_fchownat,
             dirfd,
             filename,
-            c_uint(owner.as_raw()),
-            c_uint(group.as_raw()),
+            c_uint(ow),
+            c_uint(gr),
             flags
         ))
     }
 }
 
 #[inline]
-pub(crate) fn fchown(fd: BorrowedFd<'_>, owner: Uid, group: Gid) -> io::Result<()> {
+pub(crate) fn fchown(fd: BorrowedFd<'_>, owner: Option<Uid>, group: Option<Gid>) -> io::Result<()> {
     unsafe {
-        ret(syscall_readonly!(
-            __NR_fchown,
-            fd,
-            c_uint(owner.as_raw()),
-            c_uint(group.as_raw())
-        ))
+        let (ow, gr) = crate::process::translate_fchown_args(owner, group);
+        ret(syscall_readonly!(__NR_fchown, fd, c_uint(ow), c_uint(gr)))
     }
 }
 
@@ -223,6 +235,8 @@ pub(crate) fn seek(fd: BorrowedFd<'_>, pos: SeekFrom) -> io::Result<u64> {
         }
         SeekFrom::End(offset) => (linux_raw_sys::general::SEEK_END, offset),
         SeekFrom::Current(offset) => (linux_raw_sys::general::SEEK_CUR, offset),
+        SeekFrom::Data(offset) => (linux_raw_sys::general::SEEK_DATA, offset as i64),
+        SeekFrom::Hole(offset) => (linux_raw_sys::general::SEEK_HOLE, offset as i64),
     };
     _seek(fd, offset, whence)
 }
@@ -298,6 +312,44 @@ pub(crate) fn ftruncate(fd: BorrowedFd<'_>, length: u64) -> io::Result<()> {
     }
 }
 
+#[inline]
+pub(crate) fn truncate(path: &ZStr, length: u64) -> io::Result<()> {
+    // <https://github.com/torvalds/linux/blob/fcadab740480e0e0e9fa9bd272acd409884d431a/arch/arm64/kernel/sys32.c#L76-L78>
+    #[cfg(all(
+        target_pointer_width = "32",
+        any(target_arch = "arm", target_arch = "mips", target_arch = "powerpc")
+    ))]
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_truncate64,
+            path,
+            zero(),
+            hi(length),
+            lo(length)
+        ))
+    }
+    #[cfg(all(
+        target_pointer_width = "32",
+        not(any(target_arch = "arm", target_arch = "mips", target_arch = "powerpc"))
+    ))]
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_truncate64,
+            path,
+            hi(length),
+            lo(length)
+        ))
+    }
+    #[cfg(target_pointer_width = "64")]
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_truncate,
+            path,
+            loff_t_from_u64(length)
+        ))
+    }
+}
+
 #[inline]
 pub(crate) fn fallocate(
     fd: BorrowedFd<'_>,
@@ -401,6 +453,122 @@ pub(crate) fn fadvise(fd: BorrowedFd<'_>, pos: u64, len: u64, advice: Advice) ->
     }
 }
 
+#[cfg(feature = "fs")]
+#[inline]
+pub(crate) fn mount_setattr(
+    dirfd: BorrowedFd<'_>,
+    path: &ZStr,
+    flags: AtFlags,
+    attr: &MountAttr<'_>,
+) -> io::Result<()> {
+    let attr = mount_attr {
+        attr_set: attr.attr_set.bits(),
+        attr_clr: attr.attr_clr.bits(),
+        propagation: attr.propagation.bits(),
+        // Linux only reads this with `MOUNT_ATTR_IDMAP`, and rejects values
+        // greater than `INT_MAX`.
+        userns_fd: attr.userns_fd.map_or(u64::MAX, |fd| fd.as_raw_fd() as u64),
+    };
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_mount_setattr,
+            dirfd,
+            path,
+            flags,
+            by_ref(&attr),
+            size_of::<mount_attr, _>()
+        ))
+    }
+}
+
+#[cfg(feature = "fs")]
+#[inline]
+pub(crate) fn fspick(
+    dirfd: BorrowedFd<'_>,
+    path: &ZStr,
+    flags: FspickFlags,
+) -> io::Result<OwnedFd> {
+    unsafe {
+        ret_owned_fd(syscall_readonly!(
+            __NR_fspick,
+            dirfd,
+            path,
+            c_uint(flags.bits())
+        ))
+    }
+}
+
+#[cfg(feature = "fs")]
+#[inline]
+pub(crate) fn open_tree(
+    dirfd: BorrowedFd<'_>,
+    path: &ZStr,
+    flags: OpenTreeFlags,
+) -> io::Result<OwnedFd> {
+    unsafe {
+        ret_owned_fd(syscall_readonly!(
+            __NR_open_tree,
+            dirfd,
+            path,
+            c_uint(flags.bits())
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn pivot_root(new_root: &ZStr, put_old: &ZStr) -> io::Result<()> {
+    unsafe { ret(syscall_readonly!(__NR_pivot_root, new_root, put_old)) }
+}
+
+#[inline]
+pub(crate) fn swapon(path: &ZStr, flags: u32) -> io::Result<()> {
+    unsafe { ret(syscall_readonly!(__NR_swapon, path, c_uint(flags))) }
+}
+
+#[inline]
+pub(crate) fn swapoff(path: &ZStr) -> io::Result<()> {
+    unsafe { ret(syscall_readonly!(__NR_swapoff, path)) }
+}
+
+#[inline]
+pub(crate) fn readahead(fd: BorrowedFd<'_>, offset: u64, count: usize) -> io::Result<()> {
+    // On ARM, mips, and powerpc, padding is inserted so that the 64-bit
+    // offset is passed in an aligned register pair.
+    #[cfg(any(target_arch = "arm", target_arch = "mips", target_arch = "powerpc"))]
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_readahead,
+            fd,
+            zero(),
+            hi(offset),
+            lo(offset),
+            pass_usize(count)
+        ))
+    }
+    #[cfg(all(
+        target_pointer_width = "32",
+        not(any(target_arch = "arm", target_arch = "mips", target_arch = "powerpc"))
+    ))]
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_readahead,
+            fd,
+            hi(offset),
+            lo(offset),
+            pass_usize(count)
+        ))
+    }
+    #[cfg(target_pointer_width = "64")]
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_readahead,
+            fd,
+            loff_t_from_u64(offset),
+            pass_usize(count)
+        ))
+    }
+}
+
 #[inline]
 pub(crate) fn fsync(fd: BorrowedFd<'_>) -> io::Result<()> {
     unsafe { ret(syscall_readonly!(__NR_fsync, fd)) }
@@ -960,6 +1128,82 @@ pub(crate) fn fcntl_getsig(fd: BorrowedFd<'_>) -> io::Result<c::c_int> {
     }
 }
 
+#[inline]
+pub(crate) fn fcntl_setown_ex(fd: BorrowedFd<'_>, owner: Owner) -> io::Result<()> {
+    let (type_, pid) = match owner {
+        Owner::Pid(pid) => (F_OWNER_PID, pid),
+        Owner::ProcessGroup(pid) => (F_OWNER_PGRP, pid),
+        Owner::Thread(pid) => (F_OWNER_TID, pid),
+    };
+    let owner = f_owner_ex {
+        type_: type_ as _,
+        pid: pid.as_raw_nonzero().get() as _,
+    };
+    #[cfg(target_pointer_width = "32")]
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_fcntl64,
+            fd,
+            c_uint(F_SETOWN_EX),
+            by_ref(&owner)
+        ))
+    }
+    #[cfg(target_pointer_width = "64")]
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_fcntl,
+            fd,
+            c_uint(F_SETOWN_EX),
+            by_ref(&owner)
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn fcntl_getown_ex(fd: BorrowedFd<'_>) -> io::Result<Option<Owner>> {
+    let mut owner = MaybeUninit::<f_owner_ex>::uninit();
+    let owner = unsafe {
+        #[cfg(target_pointer_width = "32")]
+        ret(syscall!(__NR_fcntl64, fd, c_uint(F_GETOWN_EX), &mut owner))?;
+        #[cfg(target_pointer_width = "64")]
+        ret(syscall!(__NR_fcntl, fd, c_uint(F_GETOWN_EX), &mut owner))?;
+        owner.assume_init()
+    };
+    let pid = match unsafe { Pid::from_raw(owner.pid as _) } {
+        Some(pid) => pid,
+        None => return Ok(None),
+    };
+    match owner.type_ as u32 {
+        F_OWNER_PID => Ok(Some(Owner::Pid(pid))),
+        F_OWNER_PGRP => Ok(Some(Owner::ProcessGroup(pid))),
+        F_OWNER_TID => Ok(Some(Owner::Thread(pid))),
+        _ => Err(io::Errno::INVAL),
+    }
+}
+
+#[inline]
+pub(crate) fn fcntl_setsig(fd: BorrowedFd<'_>, sig: Option<Signal>) -> io::Result<()> {
+    let sig = sig.map_or(0, |sig| sig as c::c_int);
+    #[cfg(target_pointer_width = "32")]
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_fcntl64,
+            fd,
+            c_uint(F_SETSIG),
+            c_int(sig)
+        ))
+    }
+    #[cfg(target_pointer_width = "64")]
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_fcntl,
+            fd,
+            c_uint(F_SETSIG),
+            c_int(sig)
+        ))
+    }
+}
+
 #[inline]
 pub(crate) fn fcntl_getpipe_sz(fd: BorrowedFd<'_>) -> io::Result<usize> {
     #[cfg(target_pointer_width = "32")]
@@ -1321,12 +1565,26 @@ pub(crate) fn accessat(
         return _accessat(dirfd, path, access.bits());
     }
 
-    if flags.bits() != linux_raw_sys::general::AT_EACCESS {
-        return Err(io::Errno::INVAL);
-    }
+    // Otherwise use `faccessat2`, which is available in Linux 5.8 and later.
+    _accessat2(dirfd, path, access.bits(), flags.bits())
+}
 
-    // TODO: Use faccessat2 in newer Linux versions.
-    Err(io::Errno::NOSYS)
+#[inline]
+fn _accessat2(
+    dirfd: BorrowedFd<'_>,
+    pathname: &ZStr,
+    mode: c::c_uint,
+    flags: c::c_uint,
+) -> io::Result<()> {
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_faccessat2,
+            dirfd,
+            pathname,
+            c_uint(mode),
+            c_uint(flags)
+        ))
+    }
 }
 
 #[inline]
@@ -1380,6 +1638,46 @@ pub(crate) fn memfd_create(name: &ZStr, flags: MemfdFlags) -> io::Result<OwnedFd
     unsafe { ret_owned_fd(syscall_readonly!(__NR_memfd_create, name, flags)) }
 }
 
+#[cfg(feature = "fs")]
+#[inline]
+pub(crate) fn memfd_secret(flags: MemfdSecretFlags) -> io::Result<OwnedFd> {
+    #[cfg(any(target_arch = "aarch64", target_arch = "x86", target_arch = "x86_64"))]
+    unsafe {
+        ret_owned_fd(syscall_readonly!(__NR_memfd_secret, c_uint(flags.bits())))
+    }
+
+    // `memfd_secret` isn't available on this architecture.
+    #[cfg(not(any(target_arch = "aarch64", target_arch = "x86", target_arch = "x86_64")))]
+    {
+        let _ = flags;
+        Err(io::Errno::NOSYS)
+    }
+}
+
+#[cfg(feature = "fs")]
+#[inline]
+pub(crate) fn cachestat(fd: BorrowedFd<'_>, range: &CachestatRange) -> io::Result<Cachestat> {
+    // linux-raw-sys doesn't have `__NR_cachestat` yet.
+    #[cfg(target_arch = "mips")]
+    const NR_CACHESTAT: u32 = 4451;
+    #[cfg(target_arch = "mips64")]
+    const NR_CACHESTAT: u32 = 5451;
+    #[cfg(not(any(target_arch = "mips", target_arch = "mips64")))]
+    const NR_CACHESTAT: u32 = 451;
+
+    let mut cstat = Cachestat::default();
+    unsafe {
+        ret(crate::imp::arch::choose::syscall4(
+            crate::imp::reg::nr(NR_CACHESTAT),
+            fd.into(),
+            by_ref(range),
+            by_mut(&mut cstat),
+            c_uint(0),
+        ))?;
+    }
+    Ok(cstat)
+}
+
 #[inline]
 pub(crate) fn sendfile(
     out_fd: BorrowedFd<'_>,
@@ -1408,3 +1706,78 @@ pub(crate) fn sendfile(
         ))
     }
 }
+
+#[cfg(feature = "fs")]
+#[inline]
+pub(crate) fn inotify_init1(flags: CreateFlags) -> io::Result<OwnedFd> {
+    unsafe { ret_owned_fd(syscall_readonly!(__NR_inotify_init1, c_uint(flags.bits()))) }
+}
+
+#[cfg(feature = "fs")]
+#[inline]
+pub(crate) fn inotify_add_watch(
+    infd: BorrowedFd<'_>,
+    path: &ZStr,
+    flags: WatchFlags,
+) -> io::Result<i32> {
+    unsafe {
+        ret_c_int(syscall_readonly!(
+            __NR_inotify_add_watch,
+            infd,
+            path,
+            c_uint(flags.bits())
+        ))
+    }
+}
+
+#[cfg(feature = "fs")]
+#[inline]
+pub(crate) fn inotify_rm_watch(infd: BorrowedFd<'_>, wd: i32) -> io::Result<()> {
+    unsafe { ret(syscall_readonly!(__NR_inotify_rm_watch, infd, c_int(wd))) }
+}
+
+#[cfg(feature = "fs")]
+#[inline]
+pub(crate) fn fanotify_init(flags: InitFlags, event_flags: OFlags) -> io::Result<OwnedFd> {
+    unsafe {
+        ret_owned_fd(syscall_readonly!(
+            __NR_fanotify_init,
+            c_uint(flags.bits()),
+            event_flags
+        ))
+    }
+}
+
+#[cfg(feature = "fs")]
+#[inline]
+pub(crate) fn fanotify_mark(
+    fanotify_fd: BorrowedFd<'_>,
+    flags: MarkFlags,
+    mask: EventFlags,
+    dirfd: BorrowedFd<'_>,
+    path: Option<&ZStr>,
+) -> io::Result<()> {
+    #[cfg(target_pointer_width = "32")]
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_fanotify_mark,
+            fanotify_fd,
+            c_uint(flags.bits()),
+            hi(mask.bits()),
+            lo(mask.bits()),
+            dirfd,
+            path
+        ))
+    }
+    #[cfg(target_pointer_width = "64")]
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_fanotify_mark,
+            fanotify_fd,
+            c_uint(flags.bits()),
+            pass_usize(mask.bits() as usize),
+            dirfd,
+            path
+        ))
+    }
+}
@@ -61,6 +61,12 @@ bitflags! {
 
         /// `AT_STATX_DONT_SYNC`
         const STATX_DONT_SYNC = linux_raw_sys::general::AT_STATX_DONT_SYNC;
+
+        /// `AT_NO_AUTOMOUNT`
+        const NO_AUTOMOUNT = linux_raw_sys::general::AT_NO_AUTOMOUNT;
+
+        /// `AT_RECURSIVE`
+        const RECURSIVE = linux_raw_sys::general::AT_RECURSIVE;
     }
 }
 
@@ -198,7 +204,16 @@ bitflags! {
         /// `O_TRUNC`
         const TRUNC = linux_raw_sys::general::O_TRUNC;
 
-        /// `O_PATH`
+        /// `O_PATH`—Open a handle which refers to a file without opening
+        /// the file itself.
+        ///
+        /// The handle can be used as the `dirfd` of `*at` functions, with
+        /// [`statat_empty`] and [`readlinkat_empty`], and with `fchdir` and
+        /// `close`, but reading and writing fail with `BADF`. With
+        /// `NOFOLLOW`, it can refer to a symlink itself.
+        ///
+        /// [`statat_empty`]: crate::fs::statat_empty
+        /// [`readlinkat_empty`]: crate::fs::readlinkat_empty
         const PATH = linux_raw_sys::general::O_PATH;
 
         /// `O_CLOEXEC`
@@ -400,6 +415,16 @@ bitflags! {
     }
 }
 
+bitflags! {
+    /// `O_*` constants for use with [`memfd_secret`].
+    ///
+    /// [`memfd_secret`]: crate::fs::memfd_secret
+    pub struct MemfdSecretFlags: c::c_uint {
+        /// `O_CLOEXEC`
+        const CLOEXEC = linux_raw_sys::general::O_CLOEXEC;
+    }
+}
+
 bitflags! {
     /// `F_SEAL_*` constants for use with [`fcntl_add_seals`] and
     /// [`fcntl_get_seals`].
@@ -63,8 +63,12 @@ use super::super::c;
 use crate::fd::{AsFd, AsRawFd, BorrowedFd, RawFd};
 #[cfg(feature = "std")]
 use crate::fd::{FromFd, FromRawFd, IntoFd, IntoRawFd};
-use crate::imp::io::syscalls::{epoll_add, epoll_create, epoll_del, epoll_mod, epoll_wait};
+use crate::imp::io::syscalls::{
+    epoll_add, epoll_create, epoll_del, epoll_mod, epoll_pwait2, epoll_wait,
+};
+use crate::imp::time::types::Timespec;
 use crate::io::{self, OwnedFd};
+use crate::process::SigSet;
 use alloc::vec::Vec;
 use bitflags::bitflags;
 use core::fmt;
@@ -317,6 +321,35 @@ impl<Context: self::Context> Epoll<Context> {
         }
     }
 
+    /// `epoll_ctl(self, EPOLL_CTL_ADD, data, event)`—Adds several elements
+    /// to an `Epoll`.
+    ///
+    /// This is equivalent to calling [`Epoll::add`] on each `(data, events)`
+    /// pair in `items`, in order. If adding an element fails, the elements
+    /// which were already added by this call are removed again and their
+    /// `Data` is dropped, so that the set of registered file descriptors is
+    /// unchanged, and the index of the element which failed is returned along
+    /// with the error.
+    #[doc(alias = "epoll_ctl")]
+    pub fn add_many<I>(&self, items: I) -> Result<(), (usize, io::Errno)>
+    where
+        I: IntoIterator<Item = (Context::Data, EventFlags)>,
+    {
+        let mut added = Vec::new();
+        for (index, (data, event_flags)) in items.into_iter().enumerate() {
+            match self.add(data, event_flags) {
+                Ok(target) => added.push(target),
+                Err(err) => {
+                    for target in added.into_iter().rev() {
+                        let _ = self.del(target);
+                    }
+                    return Err((index, err));
+                }
+            }
+        }
+        Ok(())
+    }
+
     /// `epoll_ctl(self, EPOLL_CTL_MOD, target, event)`—Modifies an element in
     /// this `Epoll`.
     ///
@@ -385,6 +418,43 @@ impl<Context: self::Context> Epoll<Context> {
 
         Ok(())
     }
+
+    /// `epoll_pwait2(self, events, timeout, sigmask)`—Waits for registered
+    /// events of interest, with a nanosecond-precision timeout.
+    ///
+    /// For each event of interest, an element is written to `events`. On
+    /// success, this returns the number of written elements.
+    ///
+    /// If `timeout` is `None`, this waits indefinitely. If `sigmask` is
+    /// `Some`, the calling thread's signal mask is replaced by it for the
+    /// duration of the wait.
+    ///
+    /// `epoll_pwait2` requires Linux 5.11. On older kernels, this falls back
+    /// to `epoll_pwait`, with the timeout rounded up to whole milliseconds.
+    #[doc(alias = "epoll_pwait2")]
+    #[doc(alias = "epoll_pwait")]
+    pub fn wait_timeout<'context>(
+        &'context self,
+        event_list: &mut EventVec<'context, Context>,
+        timeout: Option<&Timespec>,
+        sigmask: Option<&SigSet>,
+    ) -> io::Result<usize> {
+        // Safety: We're calling `epoll_pwait2` via FFI and we know how it
+        // behaves.
+        unsafe {
+            event_list.events.set_len(0);
+            let nfds = epoll_pwait2(
+                self.epoll_fd.as_fd(),
+                event_list.events[..].as_mut_ptr().cast(),
+                event_list.events.capacity(),
+                timeout,
+                sigmask.map(|sigmask| &sigmask.sigset),
+            )?;
+            event_list.events.set_len(nfds);
+            event_list.context = &self.context;
+            Ok(nfds)
+        }
+    }
 }
 
 #[cfg(feature = "std")]
@@ -37,15 +37,9 @@ impl Errno {
     #[cfg(feature = "std")]
     #[inline]
     pub fn from_io_error(io_err: &std::io::Error) -> Option<Self> {
-        io_err.raw_os_error().and_then(|raw| {
-            // `std::io::Error` could theoretically have arbitrary "OS error"
-            // values, so check that they're in Linux's range.
-            if (1..4096).contains(&raw) {
-                Some(Self::from_errno(raw as u32))
-            } else {
-                None
-            }
-        })
+        // `std::io::Error` could theoretically have arbitrary "OS error"
+        // values, so check that they're in Linux's range.
+        io_err.raw_os_error().and_then(Self::try_from_raw_os_error)
     }
 
     /// Extract the raw OS error number from this error.
@@ -60,6 +54,17 @@ impl Errno {
         Self::from_errno(raw as u32)
     }
 
+    /// Construct an `Errno` from a raw OS error number, or return `None` if
+    /// it isn't in Linux's range of error numbers, `1..4096`.
+    #[inline]
+    pub fn try_from_raw_os_error(raw: i32) -> Option<Self> {
+        if (1..4096).contains(&raw) {
+            Some(Self::from_errno(raw as u32))
+        } else {
+            None
+        }
+    }
+
     /// Convert from a C errno value (which is positive) to an `Errno`.
     const fn from_errno(raw: u32) -> Self {
         // We store error values in negated form, so that we don't have to negate
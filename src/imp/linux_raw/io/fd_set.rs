@@ -0,0 +1,38 @@
+#![allow(non_snake_case)]
+
+use super::types::RawFdSet;
+use crate::fd::RawFd;
+use core::mem::size_of_val;
+
+#[inline]
+fn index(fd: RawFd, set: &RawFdSet) -> (usize, usize) {
+    let size_in_bits = 8 * size_of_val(&set.bits[0]);
+    let fd = fd as usize;
+    (fd / size_in_bits, fd % size_in_bits)
+}
+
+#[inline]
+pub(crate) fn FD_SET(fd: RawFd, set: &mut RawFdSet) {
+    let (idx, offset) = index(fd, set);
+    set.bits[idx] |= 1 << offset
+}
+
+#[inline]
+pub(crate) fn FD_CLR(fd: RawFd, set: &mut RawFdSet) {
+    let (idx, offset) = index(fd, set);
+    set.bits[idx] &= !(1 << offset)
+}
+
+#[inline]
+pub(crate) fn FD_ISSET(fd: RawFd, set: &RawFdSet) -> bool {
+    let (idx, offset) = index(fd, set);
+    (set.bits[idx] & (1 << offset)) != 0
+}
+
+#[inline]
+pub(crate) fn FD_ZERO(set: &mut RawFdSet) {
+    // TODO: With, Rust 1.50, use `set.bits.fill(0)` instead.
+    for element in &mut set.bits {
+        *element = 0;
+    }
+}
@@ -1,5 +1,6 @@
 pub mod epoll;
 pub(crate) mod errno;
+pub(crate) mod fd_set;
 #[cfg(not(feature = "std"))]
 pub(crate) mod io_slice;
 pub(crate) mod poll_fd;
@@ -10,11 +10,14 @@ use super::super::c;
 #[cfg(target_pointer_width = "64")]
 use super::super::conv::loff_t_from_u64;
 use super::super::conv::{
-    by_ref, c_int, c_uint, pass_usize, raw_fd, ret, ret_discarded_fd, ret_owned_fd, ret_usize,
-    slice, slice_mut, zero,
+    by_ref, c_int, c_uint, opt_mut, opt_ref, pass_usize, raw_fd, ret, ret_discarded_fd,
+    ret_owned_fd, ret_usize, size_of, slice, slice_mut, zero,
 };
 #[cfg(target_pointer_width = "32")]
 use super::super::conv::{hi, lo};
+use super::super::process::types::RawSigSet;
+use super::super::time::types::Timeval;
+use super::types::RawFdSet;
 use crate::fd::{AsFd, BorrowedFd, RawFd};
 use crate::io::{
     self, epoll, DupFlags, EventfdFlags, IoSlice, IoSliceMut, OwnedFd, PipeFlags, PollFd,
@@ -22,15 +25,13 @@ use crate::io::{
 };
 #[cfg(feature = "net")]
 use crate::net::{RecvFlags, SendFlags};
-use core::cmp;
 use core::mem::MaybeUninit;
-use linux_raw_sys::general::{epoll_event, EPOLL_CTL_ADD, EPOLL_CTL_DEL, EPOLL_CTL_MOD};
-use linux_raw_sys::ioctl::{BLKPBSZGET, BLKSSZGET, FIONBIO, FIONREAD, TIOCEXCL, TIOCNXCL};
 #[cfg(any(target_arch = "aarch64", target_arch = "riscv64"))]
-use {
-    super::super::conv::{opt_ref, size_of},
-    linux_raw_sys::general::{__kernel_timespec, sigset_t},
+use linux_raw_sys::general::sigset_t;
+use linux_raw_sys::general::{
+    __kernel_timespec, epoll_event, EPOLL_CTL_ADD, EPOLL_CTL_DEL, EPOLL_CTL_MOD,
 };
+use linux_raw_sys::ioctl::{BLKPBSZGET, BLKSSZGET, FIONBIO, FIONREAD, TIOCEXCL, TIOCNXCL};
 
 #[inline]
 pub(crate) fn read(fd: BorrowedFd<'_>, buf: &mut [u8]) -> io::Result<usize> {
@@ -39,6 +40,13 @@ pub(crate) fn read(fd: BorrowedFd<'_>, buf: &mut [u8]) -> io::Result<usize> {
     unsafe { ret_usize(syscall!(__NR_read, fd, buf_addr_mut, buf_len)) }
 }
 
+#[inline]
+pub(crate) fn read_uninit(fd: BorrowedFd<'_>, buf: &mut [MaybeUninit<u8>]) -> io::Result<usize> {
+    let (buf_addr_mut, buf_len) = slice_mut(buf);
+
+    unsafe { ret_usize(syscall!(__NR_read, fd, buf_addr_mut, buf_len)) }
+}
+
 #[inline]
 pub(crate) fn pread(fd: BorrowedFd<'_>, buf: &mut [u8], pos: u64) -> io::Result<usize> {
     let (buf_addr_mut, buf_len) = slice_mut(buf);
@@ -46,7 +54,7 @@ pub(crate) fn pread(fd: BorrowedFd<'_>, buf: &mut [u8], pos: u64) -> io::Result<
     // <https://github.com/torvalds/linux/blob/fcadab740480e0e0e9fa9bd272acd409884d431a/arch/arm64/kernel/sys32.c#L75>
     #[cfg(all(
         target_pointer_width = "32",
-        any(target_arch = "arm", target_arch = "mips", target_arch = "power")
+        any(target_arch = "arm", target_arch = "mips", target_arch = "powerpc")
     ))]
     unsafe {
         ret_usize(syscall!(
@@ -61,7 +69,7 @@ pub(crate) fn pread(fd: BorrowedFd<'_>, buf: &mut [u8], pos: u64) -> io::Result<
     }
     #[cfg(all(
         target_pointer_width = "32",
-        not(any(target_arch = "arm", target_arch = "mips", target_arch = "power"))
+        not(any(target_arch = "arm", target_arch = "mips", target_arch = "powerpc"))
     ))]
     unsafe {
         ret_usize(syscall!(
@@ -87,7 +95,7 @@ pub(crate) fn pread(fd: BorrowedFd<'_>, buf: &mut [u8], pos: u64) -> io::Result<
 
 #[inline]
 pub(crate) fn readv(fd: BorrowedFd<'_>, bufs: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
-    let (bufs_addr, bufs_len) = slice(&bufs[..cmp::min(bufs.len(), max_iov())]);
+    let (bufs_addr, bufs_len) = slice(bufs);
 
     unsafe { ret_usize(syscall!(__NR_readv, fd, bufs_addr, bufs_len)) }
 }
@@ -98,7 +106,7 @@ pub(crate) fn preadv(
     bufs: &mut [IoSliceMut<'_>],
     pos: u64,
 ) -> io::Result<usize> {
-    let (bufs_addr, bufs_len) = slice(&bufs[..cmp::min(bufs.len(), max_iov())]);
+    let (bufs_addr, bufs_len) = slice(bufs);
 
     #[cfg(target_pointer_width = "32")]
     unsafe {
@@ -130,7 +138,7 @@ pub(crate) fn preadv2(
     pos: u64,
     flags: ReadWriteFlags,
 ) -> io::Result<usize> {
-    let (bufs_addr, bufs_len) = slice(&bufs[..cmp::min(bufs.len(), max_iov())]);
+    let (bufs_addr, bufs_len) = slice(bufs);
 
     #[cfg(target_pointer_width = "32")]
     unsafe {
@@ -171,7 +179,7 @@ pub(crate) fn pwrite(fd: BorrowedFd<'_>, buf: &[u8], pos: u64) -> io::Result<usi
     // <https://github.com/torvalds/linux/blob/fcadab740480e0e0e9fa9bd272acd409884d431a/arch/arm64/kernel/sys32.c#L81-L83>
     #[cfg(all(
         target_pointer_width = "32",
-        any(target_arch = "arm", target_arch = "mips", target_arch = "power")
+        any(target_arch = "arm", target_arch = "mips", target_arch = "powerpc")
     ))]
     unsafe {
         ret_usize(syscall_readonly!(
@@ -186,7 +194,7 @@ pub(crate) fn pwrite(fd: BorrowedFd<'_>, buf: &[u8], pos: u64) -> io::Result<usi
     }
     #[cfg(all(
         target_pointer_width = "32",
-        not(any(target_arch = "arm", target_arch = "mips", target_arch = "power"))
+        not(any(target_arch = "arm", target_arch = "mips", target_arch = "powerpc"))
     ))]
     unsafe {
         ret_usize(syscall_readonly!(
@@ -212,14 +220,14 @@ pub(crate) fn pwrite(fd: BorrowedFd<'_>, buf: &[u8], pos: u64) -> io::Result<usi
 
 #[inline]
 pub(crate) fn writev(fd: BorrowedFd<'_>, bufs: &[IoSlice<'_>]) -> io::Result<usize> {
-    let (bufs_addr, bufs_len) = slice(&bufs[..cmp::min(bufs.len(), max_iov())]);
+    let (bufs_addr, bufs_len) = slice(bufs);
 
     unsafe { ret_usize(syscall_readonly!(__NR_writev, fd, bufs_addr, bufs_len)) }
 }
 
 #[inline]
 pub(crate) fn pwritev(fd: BorrowedFd<'_>, bufs: &[IoSlice<'_>], pos: u64) -> io::Result<usize> {
-    let (bufs_addr, bufs_len) = slice(&bufs[..cmp::min(bufs.len(), max_iov())]);
+    let (bufs_addr, bufs_len) = slice(bufs);
 
     #[cfg(target_pointer_width = "32")]
     unsafe {
@@ -251,7 +259,7 @@ pub(crate) fn pwritev2(
     pos: u64,
     flags: ReadWriteFlags,
 ) -> io::Result<usize> {
-    let (bufs_addr, bufs_len) = slice(&bufs[..cmp::min(bufs.len(), max_iov())]);
+    let (bufs_addr, bufs_len) = slice(bufs);
 
     #[cfg(target_pointer_width = "32")]
     unsafe {
@@ -278,12 +286,6 @@ pub(crate) fn pwritev2(
     }
 }
 
-/// The maximum number of buffers that can be passed into a vectored I/O system
-/// call on the current platform.
-const fn max_iov() -> usize {
-    linux_raw_sys::general::UIO_MAXIOV as usize
-}
-
 #[inline]
 pub(crate) unsafe fn close(fd: RawFd) {
     // See the documentation for [`io::close`] for why errors are ignored.
@@ -484,6 +486,105 @@ pub(crate) fn poll(fds: &mut [PollFd<'_>], timeout: c::c_int) -> io::Result<usiz
     }
 }
 
+#[inline]
+pub(crate) fn select(
+    nfds: c::c_int,
+    readfds: Option<&mut RawFdSet>,
+    writefds: Option<&mut RawFdSet>,
+    exceptfds: Option<&mut RawFdSet>,
+    timeout: Option<&Timeval>,
+) -> io::Result<usize> {
+    // Not all architectures have `select`, so use `pselect6`.
+    let timeout = match timeout {
+        Some(timeout) => {
+            if timeout.tv_usec < 0 || timeout.tv_usec >= 1_000_000 {
+                return Err(io::Errno::INVAL);
+            }
+            Some(__kernel_timespec {
+                tv_sec: timeout.tv_sec,
+                tv_nsec: timeout.tv_usec * 1000,
+            })
+        }
+        None => None,
+    };
+    pselect(nfds, readfds, writefds, exceptfds, timeout.as_ref(), None)
+}
+
+/// The last argument to `pselect6`, which holds the signal mask and its size.
+#[repr(C)]
+struct pselect6_sigmask {
+    ss: *const RawSigSet,
+    ss_len: usize,
+}
+
+#[inline]
+pub(crate) fn pselect(
+    nfds: c::c_int,
+    readfds: Option<&mut RawFdSet>,
+    writefds: Option<&mut RawFdSet>,
+    exceptfds: Option<&mut RawFdSet>,
+    timeout: Option<&__kernel_timespec>,
+    sigmask: Option<&RawSigSet>,
+) -> io::Result<usize> {
+    let sigmask = sigmask.map(|sigmask| pselect6_sigmask {
+        ss: sigmask,
+        ss_len: core::mem::size_of::<RawSigSet>(),
+    });
+
+    // `pselect6` writes the remaining time back to the timeout, so pass it a
+    // copy.
+    let mut timeout = timeout.copied();
+
+    #[cfg(target_pointer_width = "32")]
+    unsafe {
+        use core::convert::TryInto;
+        let (mut readfds, mut writefds, mut exceptfds) = (readfds, writefds, exceptfds);
+        match ret_usize(syscall!(
+            __NR_pselect6_time64,
+            c_int(nfds),
+            opt_mut(readfds.as_deref_mut()),
+            opt_mut(writefds.as_deref_mut()),
+            opt_mut(exceptfds.as_deref_mut()),
+            opt_mut(timeout.as_mut()),
+            opt_ref(sigmask.as_ref())
+        )) {
+            // See the comments in `rustix_clock_gettime_via_syscall` about
+            // emulation.
+            Err(io::Errno::NOSYS) => {
+                let mut old_timeout = match timeout {
+                    Some(timeout) => Some(linux_raw_sys::general::timespec {
+                        tv_sec: timeout.tv_sec.try_into().map_err(|_| io::Errno::INVAL)?,
+                        tv_nsec: timeout.tv_nsec.try_into().map_err(|_| io::Errno::INVAL)?,
+                    }),
+                    None => None,
+                };
+                ret_usize(syscall!(
+                    __NR_pselect6,
+                    c_int(nfds),
+                    opt_mut(readfds),
+                    opt_mut(writefds),
+                    opt_mut(exceptfds),
+                    opt_mut(old_timeout.as_mut()),
+                    opt_ref(sigmask.as_ref())
+                ))
+            }
+            otherwise => otherwise,
+        }
+    }
+    #[cfg(target_pointer_width = "64")]
+    unsafe {
+        ret_usize(syscall!(
+            __NR_pselect6,
+            c_int(nfds),
+            opt_mut(readfds),
+            opt_mut(writefds),
+            opt_mut(exceptfds),
+            opt_mut(timeout.as_mut()),
+            opt_ref(sigmask.as_ref())
+        ))
+    }
+}
+
 #[inline]
 pub(crate) fn epoll_create(flags: epoll::CreateFlags) -> io::Result<OwnedFd> {
     unsafe { ret_owned_fd(syscall_readonly!(__NR_epoll_create1, flags)) }
@@ -559,3 +660,49 @@ pub(crate) fn epoll_wait(
         ))
     }
 }
+
+#[inline]
+pub(crate) fn epoll_pwait2(
+    epfd: BorrowedFd<'_>,
+    events: *mut epoll_event,
+    num_events: usize,
+    timeout: Option<&__kernel_timespec>,
+    sigmask: Option<&RawSigSet>,
+) -> io::Result<usize> {
+    unsafe {
+        match ret_usize(syscall!(
+            __NR_epoll_pwait2,
+            epfd,
+            events,
+            pass_usize(num_events),
+            opt_ref(timeout),
+            opt_ref(sigmask),
+            size_of::<RawSigSet, _>()
+        )) {
+            Err(io::Errno::NOSYS) => (),
+            otherwise => return otherwise,
+        }
+
+        // `epoll_pwait2` was introduced in Linux 5.11; fall back to
+        // `epoll_pwait`, rounding the timeout up to whole milliseconds.
+        let timeout = match timeout {
+            Some(timeout) => {
+                let millis = timeout
+                    .tv_sec
+                    .saturating_mul(1000)
+                    .saturating_add((timeout.tv_nsec + 999_999) / 1_000_000);
+                millis.max(0).min(i64::from(c::c_int::MAX)) as c::c_int
+            }
+            None => -1,
+        };
+        ret_usize(syscall!(
+            __NR_epoll_pwait,
+            epfd,
+            events,
+            pass_usize(num_events),
+            c_int(timeout),
+            opt_ref(sigmask),
+            size_of::<RawSigSet, _>()
+        ))
+    }
+}
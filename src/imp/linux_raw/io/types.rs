@@ -65,3 +65,22 @@ pub(crate) const AT_FDCWD: c::c_int = linux_raw_sys::general::AT_FDCWD;
 pub(crate) const STDIN_FILENO: c::c_uint = linux_raw_sys::general::STDIN_FILENO;
 pub(crate) const STDOUT_FILENO: c::c_uint = linux_raw_sys::general::STDOUT_FILENO;
 pub(crate) const STDERR_FILENO: c::c_uint = linux_raw_sys::general::STDERR_FILENO;
+
+/// The kernel's `fd_set`, for use with [`select`] and [`pselect`].
+///
+/// [`select`]: crate::io::select
+/// [`pselect`]: crate::io::pselect
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub(crate) struct RawFdSet {
+    pub(crate) bits: [usize; FD_SETSIZE / (8 * core::mem::size_of::<usize>())],
+}
+
+#[inline]
+pub(crate) const fn raw_fd_set_new() -> RawFdSet {
+    RawFdSet {
+        bits: [0; FD_SETSIZE / (8 * core::mem::size_of::<usize>())],
+    }
+}
+
+pub(crate) const FD_SETSIZE: usize = linux_raw_sys::general::__FD_SETSIZE as usize;
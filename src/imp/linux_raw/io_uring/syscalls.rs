@@ -5,7 +5,7 @@
 //! See the `rustix::imp::syscalls` module documentation for details.
 #![allow(unsafe_code)]
 
-use super::super::conv::{by_mut, c_uint, pass_usize, ret, ret_c_uint, ret_owned_fd};
+use super::super::conv::{by_mut, c_uint, pass_usize, ret_c_uint, ret_owned_fd};
 use crate::fd::BorrowedFd;
 use crate::io;
 use crate::io::OwnedFd;
@@ -29,8 +29,10 @@ pub(crate) unsafe fn io_uring_register(
     opcode: IoringRegisterOp,
     arg: *const c_void,
     nr_args: u32,
-) -> io::Result<()> {
-    ret(syscall_readonly!(
+) -> io::Result<u32> {
+    // This is not `_readonly` because some operations, such as
+    // `IORING_REGISTER_PROBE`, write to the memory `arg` points to.
+    ret_c_uint(syscall!(
         __NR_io_uring_register,
         fd,
         c_uint(opcode as u32),
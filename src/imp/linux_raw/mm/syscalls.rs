@@ -8,7 +8,9 @@
 use super::super::c;
 #[cfg(target_pointer_width = "64")]
 use super::super::conv::loff_t_from_u64;
-use super::super::conv::{c_uint, no_fd, pass_usize, ret, ret_owned_fd, ret_void_star};
+use super::super::conv::{
+    c_int, c_uint, no_fd, pass_usize, ret, ret_c_uint, ret_owned_fd, ret_void_star, zero,
+};
 use super::types::{
     Advice, MapFlags, MlockFlags, MprotectFlags, MremapFlags, MsyncFlags, ProtFlags,
     UserfaultfdFlags,
@@ -17,6 +19,7 @@ use crate::fd::BorrowedFd;
 use crate::io::{self, OwnedFd};
 #[cfg(target_pointer_width = "32")]
 use core::convert::TryInto;
+use core::mem::MaybeUninit;
 
 #[inline]
 pub(crate) fn madvise(addr: *mut c::c_void, len: usize, advice: Advice) -> io::Result<()> {
@@ -123,6 +126,11 @@ pub(crate) unsafe fn mprotect(
     ret(syscall!(__NR_mprotect, ptr, pass_usize(len), flags))
 }
 
+#[inline]
+pub(crate) unsafe fn mincore(addr: *mut c::c_void, len: usize, vec: *mut u8) -> io::Result<()> {
+    ret(syscall!(__NR_mincore, addr, pass_usize(len), vec))
+}
+
 /// # Safety
 ///
 /// `munmap` is primarily unsafe due to the `addr` parameter, as anything
@@ -210,3 +218,97 @@ pub(crate) unsafe fn munlock(addr: *mut c::c_void, length: usize) -> io::Result<
 pub(crate) unsafe fn userfaultfd(flags: UserfaultfdFlags) -> io::Result<OwnedFd> {
     ret_owned_fd(syscall_readonly!(__NR_userfaultfd, flags))
 }
+
+#[inline]
+pub(crate) unsafe fn set_mempolicy(
+    mode: u32,
+    nodemask: *const usize,
+    maxnode: usize,
+) -> io::Result<()> {
+    ret(syscall_readonly!(
+        __NR_set_mempolicy,
+        c_uint(mode),
+        nodemask,
+        pass_usize(maxnode)
+    ))
+}
+
+#[inline]
+pub(crate) unsafe fn get_mempolicy(
+    nodemask: *mut usize,
+    maxnode: usize,
+    addr: *mut c::c_void,
+    flags: u32,
+) -> io::Result<u32> {
+    let mut mode = MaybeUninit::<c::c_int>::uninit();
+    ret(syscall!(
+        __NR_get_mempolicy,
+        &mut mode,
+        nodemask,
+        pass_usize(maxnode),
+        addr,
+        c_uint(flags)
+    ))?;
+    Ok(mode.assume_init() as u32)
+}
+
+#[inline]
+pub(crate) unsafe fn mbind(
+    addr: *mut c::c_void,
+    len: usize,
+    mode: u32,
+    nodemask: *const usize,
+    maxnode: usize,
+    flags: u32,
+) -> io::Result<()> {
+    ret(syscall!(
+        __NR_mbind,
+        addr,
+        pass_usize(len),
+        c_uint(mode),
+        nodemask,
+        pass_usize(maxnode),
+        c_uint(flags)
+    ))
+}
+
+#[inline]
+pub(crate) fn getcpu_node() -> io::Result<u32> {
+    let mut node = MaybeUninit::<c::c_uint>::uninit();
+    unsafe {
+        ret(syscall!(__NR_getcpu, zero(), &mut node, zero()))?;
+        Ok(node.assume_init())
+    }
+}
+
+#[inline]
+pub(crate) fn pkey_alloc(flags: u32, access_rights: u32) -> io::Result<u32> {
+    unsafe {
+        ret_c_uint(syscall_readonly!(
+            __NR_pkey_alloc,
+            c_uint(flags),
+            c_uint(access_rights)
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn pkey_free(pkey: u32) -> io::Result<()> {
+    unsafe { ret(syscall_readonly!(__NR_pkey_free, c_int(pkey as _))) }
+}
+
+#[inline]
+pub(crate) unsafe fn pkey_mprotect(
+    ptr: *mut c::c_void,
+    len: usize,
+    prot: ProtFlags,
+    pkey: u32,
+) -> io::Result<()> {
+    ret(syscall!(
+        __NR_pkey_mprotect,
+        ptr,
+        pass_usize(len),
+        prot,
+        c_int(pkey as _)
+    ))
+}
@@ -19,7 +19,12 @@ mod arch;
 mod conv;
 mod elf;
 mod reg;
-#[cfg(any(feature = "time", target_arch = "x86"))]
+#[cfg(any(
+    feature = "param",
+    feature = "process",
+    feature = "time",
+    target_arch = "x86"
+))]
 mod vdso;
 #[cfg(any(feature = "time", target_arch = "x86"))]
 mod vdso_wrappers;
@@ -30,11 +35,19 @@ pub(crate) mod io;
 #[cfg(feature = "io_uring")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "io_uring")))]
 pub(crate) mod io_uring;
-#[cfg(any(feature = "mm", feature = "time", target_arch = "x86"))] // vdso.rs uses `madvise`
+#[cfg(any(
+    feature = "mm",
+    feature = "param",
+    feature = "process",
+    feature = "time",
+    target_arch = "x86"
+))] // vdso.rs uses `madvise`
 pub(crate) mod mm;
 #[cfg(feature = "net")]
 pub(crate) mod net;
 pub(crate) mod process;
+#[cfg(feature = "pty")]
+pub(crate) mod pty;
 #[cfg(feature = "rand")]
 pub(crate) mod rand;
 #[cfg(feature = "runtime")]
@@ -83,17 +83,16 @@ pub(crate) unsafe fn read_sockaddr(
             )))
         }
         c::AF_UNIX => {
-            if len < offsetof_sun_path {
+            if len < offsetof_sun_path || len > size_of::<c::sockaddr_un>() {
                 return Err(io::Errno::INVAL);
             }
             if len == offsetof_sun_path {
                 Ok(SocketAddrAny::Unix(SocketAddrUnix::new(&[][..])?))
             } else {
                 let decode = *storage.cast::<c::sockaddr_un>();
-                assert_eq!(
-                    decode.sun_path[len - 1 - offsetof_sun_path],
-                    b'\0' as c::c_char
-                );
+                if decode.sun_path[len - 1 - offsetof_sun_path] != b'\0' as c::c_char {
+                    return Err(io::Errno::INVAL);
+                }
                 Ok(SocketAddrAny::Unix(SocketAddrUnix::new(
                     decode.sun_path[..len - 1 - offsetof_sun_path]
                         .iter()
@@ -102,7 +101,7 @@ pub(crate) unsafe fn read_sockaddr(
                 )?))
             }
         }
-        _ => Err(io::Errno::NOTSUP),
+        _ => Err(io::Errno::INVAL),
     }
 }
 
@@ -38,5 +38,7 @@ bitflags! {
         const TRUNC = c::MSG_TRUNC;
         /// `MSG_WAITALL`
         const WAITALL = c::MSG_WAITALL;
+        /// `MSG_WAITFORONE`
+        const WAITFORONE = 0x1_0000;
     }
 }
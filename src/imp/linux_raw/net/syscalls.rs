@@ -8,28 +8,41 @@
 
 use super::super::c;
 use super::super::conv::{
-    by_mut, by_ref, c_int, c_uint, ret, ret_owned_fd, ret_usize, size_of, slice, slice_mut,
-    socklen_t, zero,
+    by_mut, by_ref, c_int, c_uint, opt_mut, ret, ret_owned_fd, ret_usize, size_of, slice,
+    slice_mut, socklen_t, zero,
 };
 use super::read_sockaddr::{initialize_family_to_unspec, maybe_read_sockaddr_os, read_sockaddr_os};
 use super::send_recv::{RecvFlags, SendFlags};
-use super::types::{AcceptFlags, AddressFamily, Protocol, Shutdown, SocketFlags, SocketType};
+use super::types::{
+    AcceptFlags, AddressFamily, Protocol, Shutdown, SockExtendedErr, SocketFlags, SocketType,
+};
 use super::write_sockaddr::{encode_sockaddr_v4, encode_sockaddr_v6};
-use crate::fd::BorrowedFd;
-use crate::io::{self, OwnedFd};
-use crate::net::{SocketAddrAny, SocketAddrUnix, SocketAddrV4, SocketAddrV6};
+use crate::fd::{AsFd, BorrowedFd};
+use crate::ffi::{ZStr, ZString};
+use crate::imp::time::types::{Timespec, Timeval};
+use crate::io::{self, IoSliceMut, OwnedFd};
+use crate::net::{
+    ControlMessage, RecvMsg, SocketAddrAny, SocketAddrUnix, SocketAddrV4, SocketAddrV6,
+};
+use alloc::vec::Vec;
 use c::{sockaddr_in, sockaddr_in6, socklen_t};
 use core::convert::TryInto;
 use core::mem::MaybeUninit;
-use linux_raw_sys::general::sockaddr;
+use core::ptr::null_mut;
+#[cfg(target_pointer_width = "32")]
+use linux_raw_sys::general::timespec as __kernel_old_timespec;
+use linux_raw_sys::general::{
+    __kernel_old_timeval, __kernel_sock_timeval, cmsghdr, msghdr, sockaddr,
+    timespec as old_timespec,
+};
 #[cfg(target_arch = "x86")]
 use {
     super::super::conv::{slice_just_addr, x86_sys},
     super::super::reg::{ArgReg, SocketArg},
     linux_raw_sys::general::{
         SYS_ACCEPT, SYS_ACCEPT4, SYS_BIND, SYS_CONNECT, SYS_GETPEERNAME, SYS_GETSOCKNAME,
-        SYS_GETSOCKOPT, SYS_LISTEN, SYS_RECV, SYS_RECVFROM, SYS_SEND, SYS_SENDTO, SYS_SETSOCKOPT,
-        SYS_SHUTDOWN, SYS_SOCKET, SYS_SOCKETPAIR,
+        SYS_GETSOCKOPT, SYS_LISTEN, SYS_RECV, SYS_RECVFROM, SYS_RECVMSG, SYS_SEND, SYS_SENDTO,
+        SYS_SETSOCKOPT, SYS_SHUTDOWN, SYS_SOCKET, SYS_SOCKETPAIR,
     },
 };
 
@@ -513,6 +526,277 @@ pub(crate) fn recvfrom(
     }
 }
 
+/// The layout of Linux's `struct sock_extended_err`.
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct sock_extended_err {
+    ee_errno: u32,
+    ee_origin: u8,
+    ee_type: u8,
+    ee_code: u8,
+    ee_pad: u8,
+    ee_info: u32,
+    ee_data: u32,
+}
+
+/// Rounds `len` up to the alignment of control message headers, like
+/// `CMSG_ALIGN`.
+#[inline]
+const fn cmsg_align(len: usize) -> usize {
+    (len + core::mem::size_of::<usize>() - 1) & !(core::mem::size_of::<usize>() - 1)
+}
+
+/// `recvmsg(fd, msg, MSG_ERRQUEUE | MSG_DONTWAIT)`—Receives one message from
+/// a socket's error queue, and returns the `IP_RECVERR` or `IPV6_RECVERR`
+/// error it contains, if any.
+pub(crate) fn recv_error_queue(fd: BorrowedFd<'_>) -> io::Result<Option<SockExtendedErr>> {
+    // Space for a `sock_extended_err` followed by the offending address,
+    // aligned for `cmsghdr`.
+    let mut control = [0_usize; 32];
+    let mut msg = msghdr {
+        msg_name: null_mut(),
+        msg_namelen: 0,
+        msg_iov: null_mut(),
+        msg_iovlen: 0,
+        msg_control: control.as_mut_ptr().cast(),
+        msg_controllen: core::mem::size_of_val(&control) as _,
+        msg_flags: 0,
+    };
+    let flags = RecvFlags::ERRQUEUE | RecvFlags::DONTWAIT;
+
+    unsafe {
+        #[cfg(not(target_arch = "x86"))]
+        ret_usize(syscall!(__NR_recvmsg, fd, by_mut(&mut msg), flags))?;
+        #[cfg(target_arch = "x86")]
+        ret_usize(syscall!(
+            __NR_socketcall,
+            x86_sys(SYS_RECVMSG),
+            slice_just_addr::<ArgReg<SocketArg>, _>(&[fd.into(), by_mut(&mut msg), flags.into(),])
+        ))?;
+    }
+
+    // Walk the control messages, as `CMSG_FIRSTHDR` and `CMSG_NXTHDR` would.
+    let control_len = msg.msg_controllen as usize;
+    let control = control.as_ptr().cast::<u8>();
+    let header_len = core::mem::size_of::<cmsghdr>();
+    let mut offset = 0;
+    while offset + header_len <= control_len {
+        let cmsg = unsafe { control.add(offset).cast::<cmsghdr>().read() };
+        let cmsg_len = cmsg.cmsg_len as usize;
+        if cmsg_len < header_len || offset + cmsg_len > control_len {
+            break;
+        }
+
+        let (level, type_) = (cmsg.cmsg_level as u32, cmsg.cmsg_type as u32);
+        if ((level == c::IPPROTO_IP as u32 && type_ == c::IP_RECVERR)
+            || (level == c::IPPROTO_IPV6 as u32 && type_ == c::IPV6_RECVERR))
+            && cmsg_len >= cmsg_align(header_len) + core::mem::size_of::<sock_extended_err>()
+        {
+            let ee = unsafe {
+                control
+                    .add(offset + cmsg_align(header_len))
+                    .cast::<sock_extended_err>()
+                    .read_unaligned()
+            };
+            return Ok(Some(SockExtendedErr {
+                errno: io::Errno::from_raw_os_error(ee.ee_errno as i32),
+                origin: ee.ee_origin,
+                type_: ee.ee_type,
+                code: ee.ee_code,
+                info: ee.ee_info,
+                data: ee.ee_data,
+            }));
+        }
+
+        offset += cmsg_align(cmsg_len);
+    }
+
+    Ok(None)
+}
+
+#[allow(clippy::useless_conversion)]
+pub(crate) fn recvmsg(
+    fd: BorrowedFd<'_>,
+    bufs: &mut [IoSliceMut<'_>],
+    flags: RecvFlags,
+) -> io::Result<RecvMsg> {
+    // Space for several control messages, aligned for `cmsghdr`.
+    let mut control = [0_usize; 32];
+    let mut msg = msghdr {
+        msg_name: null_mut(),
+        msg_namelen: 0,
+        msg_iov: bufs.as_mut_ptr().cast(),
+        msg_iovlen: bufs.len() as _,
+        msg_control: control.as_mut_ptr().cast(),
+        msg_controllen: core::mem::size_of_val(&control) as _,
+        msg_flags: 0,
+    };
+
+    let bytes = unsafe {
+        #[cfg(not(target_arch = "x86"))]
+        let bytes = ret_usize(syscall!(__NR_recvmsg, fd, by_mut(&mut msg), flags))?;
+        #[cfg(target_arch = "x86")]
+        let bytes = ret_usize(syscall!(
+            __NR_socketcall,
+            x86_sys(SYS_RECVMSG),
+            slice_just_addr::<ArgReg<SocketArg>, _>(&[fd.into(), by_mut(&mut msg), flags.into(),])
+        ))?;
+        bytes
+    };
+
+    // Walk the control messages, as `CMSG_FIRSTHDR` and `CMSG_NXTHDR` would.
+    let mut control_messages = Vec::new();
+    let control_len = msg.msg_controllen as usize;
+    let control = control.as_ptr().cast::<u8>();
+    let header_len = core::mem::size_of::<cmsghdr>();
+    let mut offset = 0;
+    while offset + header_len <= control_len {
+        let cmsg = unsafe { control.add(offset).cast::<cmsghdr>().read() };
+        let cmsg_len = cmsg.cmsg_len as usize;
+        if cmsg_len < header_len || offset + cmsg_len > control_len {
+            break;
+        }
+
+        let data = unsafe { control.add(offset + cmsg_align(header_len)) };
+        let data_len = cmsg_len - cmsg_align(header_len);
+        if cmsg.cmsg_level as u32 == c::SOL_SOCKET {
+            // Decode both the `_OLD` and `_NEW` layouts, since which one the
+            // kernel sends depends on which option was enabled.
+            match cmsg.cmsg_type as u32 {
+                c::SO_TIMESTAMP_NEW
+                    if data_len >= core::mem::size_of::<__kernel_sock_timeval>() =>
+                {
+                    let tv = unsafe { data.cast::<__kernel_sock_timeval>().read_unaligned() };
+                    control_messages.push(ControlMessage::ScmTimestamp(Timeval {
+                        tv_sec: tv.tv_sec,
+                        tv_usec: tv.tv_usec,
+                    }));
+                }
+                c::SO_TIMESTAMP_OLD if data_len >= core::mem::size_of::<__kernel_old_timeval>() => {
+                    let tv = unsafe { data.cast::<__kernel_old_timeval>().read_unaligned() };
+                    control_messages.push(ControlMessage::ScmTimestamp(Timeval {
+                        tv_sec: tv.tv_sec.into(),
+                        tv_usec: tv.tv_usec.into(),
+                    }));
+                }
+                c::SO_TIMESTAMPNS_NEW if data_len >= core::mem::size_of::<Timespec>() => {
+                    let ts = unsafe { data.cast::<Timespec>().read_unaligned() };
+                    control_messages.push(ControlMessage::ScmTimestampNs(ts));
+                }
+                c::SO_TIMESTAMPNS_OLD if data_len >= core::mem::size_of::<old_timespec>() => {
+                    let ts = unsafe { data.cast::<old_timespec>().read_unaligned() };
+                    control_messages.push(ControlMessage::ScmTimestampNs(Timespec {
+                        tv_sec: ts.tv_sec.into(),
+                        tv_nsec: ts.tv_nsec.into(),
+                    }));
+                }
+                _ => (),
+            }
+        }
+
+        offset += cmsg_align(cmsg_len);
+    }
+
+    Ok(RecvMsg {
+        bytes,
+        control_messages,
+    })
+}
+
+/// The maximum number of messages passed to `recvmmsg` and `sendmmsg`, which
+/// take the count as an `unsigned int`.
+#[inline]
+fn mmsg_len(len: usize) -> c::c_uint {
+    core::cmp::min(len, c::c_uint::MAX as usize) as c::c_uint
+}
+
+#[inline]
+pub(crate) unsafe fn recvmmsg(
+    fd: BorrowedFd<'_>,
+    msgs: *mut c::mmsghdr,
+    len: usize,
+    flags: RecvFlags,
+    timeout: Option<&Timespec>,
+) -> io::Result<usize> {
+    // The kernel writes the remaining time back to `timeout`, so pass it a
+    // copy.
+    let mut timeout = timeout.copied();
+
+    #[cfg(target_pointer_width = "32")]
+    {
+        ret_usize(syscall!(
+            __NR_recvmmsg_time64,
+            fd,
+            msgs,
+            c_uint(mmsg_len(len)),
+            flags,
+            opt_mut(timeout.as_mut())
+        ))
+        .or_else(|err| {
+            // See the comments in `rustix_clock_gettime_via_syscall` about
+            // emulation.
+            if err == io::Errno::NOSYS {
+                recvmmsg_old(fd, msgs, len, flags, timeout.as_ref())
+            } else {
+                Err(err)
+            }
+        })
+    }
+
+    #[cfg(target_pointer_width = "64")]
+    {
+        ret_usize(syscall!(
+            __NR_recvmmsg,
+            fd,
+            msgs,
+            c_uint(mmsg_len(len)),
+            flags,
+            opt_mut(timeout.as_mut())
+        ))
+    }
+}
+
+#[cfg(target_pointer_width = "32")]
+unsafe fn recvmmsg_old(
+    fd: BorrowedFd<'_>,
+    msgs: *mut c::mmsghdr,
+    len: usize,
+    flags: RecvFlags,
+    timeout: Option<&Timespec>,
+) -> io::Result<usize> {
+    let mut old_timeout = match timeout {
+        Some(timeout) => Some(__kernel_old_timespec {
+            tv_sec: timeout.tv_sec.try_into().map_err(|_| io::Errno::INVAL)?,
+            tv_nsec: timeout.tv_nsec.try_into().map_err(|_| io::Errno::INVAL)?,
+        }),
+        None => None,
+    };
+    ret_usize(syscall!(
+        __NR_recvmmsg,
+        fd,
+        msgs,
+        c_uint(mmsg_len(len)),
+        flags,
+        opt_mut(old_timeout.as_mut())
+    ))
+}
+
+#[inline]
+pub(crate) unsafe fn sendmmsg(
+    fd: BorrowedFd<'_>,
+    msgs: *mut c::mmsghdr,
+    len: usize,
+    flags: SendFlags,
+) -> io::Result<usize> {
+    ret_usize(syscall!(
+        __NR_sendmmsg,
+        fd,
+        msgs,
+        c_uint(mmsg_len(len)),
+        flags
+    ))
+}
+
 #[inline]
 pub(crate) fn getpeername(fd: BorrowedFd<'_>) -> io::Result<Option<SocketAddrAny>> {
     #[cfg(not(target_arch = "x86"))]
@@ -753,21 +1037,133 @@ pub(crate) fn listen(fd: BorrowedFd<'_>, backlog: c::c_int) -> io::Result<()> {
     }
 }
 
+/// `IFNAMSIZ`—The size of a network interface name, including the NUL.
+const IFNAMSIZ: usize = 16;
+
+/// `SIOCGIFNAME`
+const SIOCGIFNAME: c::c_uint = 0x8910;
+
+/// `SIOCGIFINDEX`
+const SIOCGIFINDEX: c::c_uint = 0x8933;
+
+/// `struct ifreq`, with only the `ifr_ifru` members we use.
+#[repr(C)]
+struct ifreq {
+    ifr_name: [u8; IFNAMSIZ],
+    ifr_ifru: ifreq_ifru,
+}
+
+/// The `ifr_ifru` union of `struct ifreq`. `ifru_map` is a stand-in for
+/// `struct ifmap`, the largest member, to give the union its full size.
+#[repr(C)]
+union ifreq_ifru {
+    ifru_ivalue: c::c_int,
+    ifru_addr: [u8; 16],
+    ifru_map: [usize; 3],
+}
+
+/// Perform an `ifreq` ioctl on a temporary socket.
+fn ifreq_ioctl(request: c::c_uint, ifr: &mut ifreq) -> io::Result<()> {
+    let fd = socket_with(
+        AddressFamily::UNIX,
+        SocketType::DGRAM,
+        SocketFlags::CLOEXEC,
+        Protocol::default(),
+    )?;
+    unsafe {
+        ret(syscall!(
+            __NR_ioctl,
+            fd.as_fd(),
+            c_uint(request),
+            by_mut(ifr)
+        ))
+    }
+}
+
+pub(crate) fn if_nametoindex(name: &ZStr) -> io::Result<u32> {
+    let name = name.to_bytes();
+    if name.len() >= IFNAMSIZ {
+        return Err(io::Errno::NODEV);
+    }
+    let mut ifr = ifreq {
+        ifr_name: [0; IFNAMSIZ],
+        ifr_ifru: ifreq_ifru { ifru_map: [0; 3] },
+    };
+    ifr.ifr_name[..name.len()].copy_from_slice(name);
+    ifreq_ioctl(SIOCGIFINDEX, &mut ifr)?;
+    unsafe { Ok(ifr.ifr_ifru.ifru_ivalue as u32) }
+}
+
+pub(crate) fn if_indextoname(index: u32) -> io::Result<ZString> {
+    let mut ifr = ifreq {
+        ifr_name: [0; IFNAMSIZ],
+        ifr_ifru: ifreq_ifru {
+            ifru_ivalue: index as c::c_int,
+        },
+    };
+    // Report an unknown index with `ENXIO`, as POSIX and libc do.
+    match ifreq_ioctl(SIOCGIFNAME, &mut ifr) {
+        Err(io::Errno::NODEV) => return Err(io::Errno::NXIO),
+        result => result?,
+    }
+    let len = ifr
+        .ifr_name
+        .iter()
+        .position(|b| *b == b'\0')
+        .unwrap_or(IFNAMSIZ);
+    Ok(ZString::new(&ifr.ifr_name[..len]).unwrap())
+}
+
 pub(crate) mod sockopt {
-    use super::{c, BorrowedFd};
+    use super::{c, BorrowedFd, IFNAMSIZ};
+    use crate::ffi::{ZStr, ZString};
     use crate::io;
-    use crate::net::sockopt::Timeout;
+    use crate::net::sockopt::{TcpInfo, TcpState, Timeout, UCred};
     use crate::net::{Ipv4Addr, Ipv6Addr, SocketType};
+    use crate::process::{Gid, Pid, Uid};
+    use crate::utils::as_ptr;
     use c::{SOL_SOCKET, SO_RCVTIMEO_NEW, SO_RCVTIMEO_OLD, SO_SNDTIMEO_NEW, SO_SNDTIMEO_OLD};
-    use core::convert::TryInto;
+    use core::convert::{TryFrom, TryInto};
     use core::time::Duration;
     use linux_raw_sys::general::{__kernel_timespec, timeval};
 
+    /// `TCP_CA_NAME_MAX`—The size of a TCP congestion control algorithm name,
+    /// including the NUL.
+    const TCP_CA_NAME_MAX: usize = 16;
+
     // TODO: With Rust 1.53 we can use `Duration::ZERO` instead.
     const DURATION_ZERO: Duration = Duration::from_secs(0);
 
     #[inline]
     fn getsockopt<T: Copy>(fd: BorrowedFd<'_>, level: u32, optname: u32) -> io::Result<T> {
+        let (value, optlen) = getsockopt_raw(fd, level, optname)?;
+        assert_eq!(
+            optlen,
+            core::mem::size_of::<T>(),
+            "unexpected getsockopt size"
+        );
+        Ok(value)
+    }
+
+    /// Like `getsockopt`, but for variable-size structs such as `tcp_info`,
+    /// which grow new fields over time. The kernel may write fewer bytes
+    /// than `size_of::<T>()`, in which case the remaining fields are zero.
+    #[inline]
+    fn getsockopt_partial<T: Copy>(fd: BorrowedFd<'_>, level: u32, optname: u32) -> io::Result<T> {
+        let (value, optlen) = getsockopt_raw(fd, level, optname)?;
+        assert!(
+            optlen <= core::mem::size_of::<T>(),
+            "unexpected getsockopt size"
+        );
+        Ok(value)
+    }
+
+    #[inline]
+    fn getsockopt_raw<T: Copy>(
+        fd: BorrowedFd<'_>,
+        level: u32,
+        optname: u32,
+    ) -> io::Result<(T, usize)> {
         use super::*;
 
         let mut optlen = core::mem::size_of::<T>();
@@ -778,7 +1174,7 @@ pub(crate) mod sockopt {
 
         #[cfg(not(target_arch = "x86"))]
         unsafe {
-            let mut value = MaybeUninit::<T>::uninit();
+            let mut value = MaybeUninit::<T>::zeroed();
             ret(syscall!(
                 __NR_getsockopt,
                 fd,
@@ -787,17 +1183,11 @@ pub(crate) mod sockopt {
                 &mut value,
                 by_mut(&mut optlen)
             ))?;
-
-            assert_eq!(
-                optlen as usize,
-                core::mem::size_of::<T>(),
-                "unexpected getsockopt size"
-            );
-            Ok(value.assume_init())
+            Ok((value.assume_init(), optlen))
         }
         #[cfg(target_arch = "x86")]
         unsafe {
-            let mut value = MaybeUninit::<T>::uninit();
+            let mut value = MaybeUninit::<T>::zeroed();
             ret(syscall!(
                 __NR_socketcall,
                 x86_sys(SYS_GETSOCKOPT),
@@ -809,12 +1199,7 @@ pub(crate) mod sockopt {
                     by_mut(&mut optlen),
                 ])
             ))?;
-            assert_eq!(
-                optlen as usize,
-                core::mem::size_of::<T>(),
-                "unexpected getsockopt size"
-            );
-            Ok(value.assume_init())
+            Ok((value.assume_init(), optlen))
         }
     }
 
@@ -833,19 +1218,33 @@ pub(crate) mod sockopt {
             "Socket APIs don't ever use `bool` directly"
         );
 
+        unsafe { setsockopt_raw(fd, level, optname, as_ptr(&value).cast(), optlen) }
+    }
+
+    /// Like `setsockopt`, but for variable-size values such as strings.
+    #[inline]
+    unsafe fn setsockopt_raw(
+        fd: BorrowedFd<'_>,
+        level: u32,
+        optname: u32,
+        value: *const c::c_void,
+        optlen: c::socklen_t,
+    ) -> io::Result<()> {
+        use super::*;
+
         #[cfg(not(target_arch = "x86"))]
-        unsafe {
+        {
             ret(syscall_readonly!(
                 __NR_setsockopt,
                 fd,
                 c_uint(level),
                 c_uint(optname),
-                by_ref(&value),
+                value,
                 socklen_t(optlen)
             ))
         }
         #[cfg(target_arch = "x86")]
-        unsafe {
+        {
             ret(syscall_readonly!(
                 __NR_socketcall,
                 x86_sys(SYS_SETSOCKOPT),
@@ -853,7 +1252,7 @@ pub(crate) mod sockopt {
                     fd.into(),
                     c_uint(level),
                     c_uint(optname),
-                    by_ref(&value),
+                    value.into(),
                     socklen_t(optlen),
                 ])
             ))
@@ -890,6 +1289,63 @@ pub(crate) mod sockopt {
         getsockopt(fd, c::SOL_SOCKET as _, c::SO_BROADCAST).map(to_bool)
     }
 
+    #[inline]
+    pub(crate) fn set_socket_keepalive(fd: BorrowedFd<'_>, keepalive: bool) -> io::Result<()> {
+        setsockopt(
+            fd,
+            c::SOL_SOCKET as _,
+            c::SO_KEEPALIVE,
+            from_bool(keepalive),
+        )
+    }
+
+    #[inline]
+    pub(crate) fn get_socket_keepalive(fd: BorrowedFd<'_>) -> io::Result<bool> {
+        getsockopt(fd, c::SOL_SOCKET as _, c::SO_KEEPALIVE).map(to_bool)
+    }
+
+    #[inline]
+    pub(crate) fn set_socket_recv_buffer_size(fd: BorrowedFd<'_>, size: usize) -> io::Result<()> {
+        let size: c::c_int = size.try_into().map_err(|_| io::Errno::INVAL)?;
+        setsockopt(fd, c::SOL_SOCKET as _, c::SO_RCVBUF, size)
+    }
+
+    #[inline]
+    pub(crate) fn set_socket_recv_buffer_size_force(
+        fd: BorrowedFd<'_>,
+        size: usize,
+    ) -> io::Result<()> {
+        let size: c::c_int = size.try_into().map_err(|_| io::Errno::INVAL)?;
+        setsockopt(fd, c::SOL_SOCKET as _, c::SO_RCVBUFFORCE, size)
+    }
+
+    #[inline]
+    pub(crate) fn get_socket_recv_buffer_size(fd: BorrowedFd<'_>) -> io::Result<usize> {
+        let size: c::c_int = getsockopt(fd, c::SOL_SOCKET as _, c::SO_RCVBUF)?;
+        Ok(size as usize)
+    }
+
+    #[inline]
+    pub(crate) fn set_socket_send_buffer_size(fd: BorrowedFd<'_>, size: usize) -> io::Result<()> {
+        let size: c::c_int = size.try_into().map_err(|_| io::Errno::INVAL)?;
+        setsockopt(fd, c::SOL_SOCKET as _, c::SO_SNDBUF, size)
+    }
+
+    #[inline]
+    pub(crate) fn set_socket_send_buffer_size_force(
+        fd: BorrowedFd<'_>,
+        size: usize,
+    ) -> io::Result<()> {
+        let size: c::c_int = size.try_into().map_err(|_| io::Errno::INVAL)?;
+        setsockopt(fd, c::SOL_SOCKET as _, c::SO_SNDBUFFORCE, size)
+    }
+
+    #[inline]
+    pub(crate) fn get_socket_send_buffer_size(fd: BorrowedFd<'_>) -> io::Result<usize> {
+        let size: c::c_int = getsockopt(fd, c::SOL_SOCKET as _, c::SO_SNDBUF)?;
+        Ok(size as usize)
+    }
+
     #[inline]
     pub(crate) fn set_socket_linger(
         fd: BorrowedFd<'_>,
@@ -933,6 +1389,90 @@ pub(crate) mod sockopt {
         getsockopt(fd, c::SOL_SOCKET as _, c::SO_PASSCRED).map(to_bool)
     }
 
+    #[inline]
+    pub(crate) fn set_socket_timestamp(fd: BorrowedFd<'_>, value: bool) -> io::Result<()> {
+        set_timestamp_option(fd, c::SO_TIMESTAMP_NEW, c::SO_TIMESTAMP_OLD, value)
+    }
+
+    #[inline]
+    pub(crate) fn get_socket_timestamp(fd: BorrowedFd<'_>) -> io::Result<bool> {
+        get_timestamp_option(fd, c::SO_TIMESTAMP_NEW, c::SO_TIMESTAMP_OLD)
+    }
+
+    #[inline]
+    pub(crate) fn set_socket_timestampns(fd: BorrowedFd<'_>, value: bool) -> io::Result<()> {
+        set_timestamp_option(fd, c::SO_TIMESTAMPNS_NEW, c::SO_TIMESTAMPNS_OLD, value)
+    }
+
+    #[inline]
+    pub(crate) fn get_socket_timestampns(fd: BorrowedFd<'_>) -> io::Result<bool> {
+        get_timestamp_option(fd, c::SO_TIMESTAMPNS_NEW, c::SO_TIMESTAMPNS_OLD)
+    }
+
+    /// Sets a receive timestamp option.
+    ///
+    /// On 64-bit platforms, the `_OLD` options already deliver 64-bit times,
+    /// so use them. On 32-bit platforms, use the `_NEW` options so that the
+    /// timestamps are 64-bit, falling back to `_OLD` on kernels before 5.1.
+    fn set_timestamp_option(fd: BorrowedFd<'_>, new: u32, old: u32, value: bool) -> io::Result<()> {
+        #[cfg(target_pointer_width = "32")]
+        match setsockopt(fd, c::SOL_SOCKET as _, new, from_bool(value)) {
+            Err(io::Errno::NOPROTOOPT) => (),
+            otherwise => return otherwise,
+        }
+        #[cfg(target_pointer_width = "64")]
+        let _ = new;
+
+        setsockopt(fd, c::SOL_SOCKET as _, old, from_bool(value))
+    }
+
+    /// Same as `set_timestamp_option` but gets the option.
+    fn get_timestamp_option(fd: BorrowedFd<'_>, new: u32, old: u32) -> io::Result<bool> {
+        #[cfg(target_pointer_width = "32")]
+        match getsockopt(fd, c::SOL_SOCKET as _, new).map(to_bool) {
+            Ok(false) | Err(io::Errno::NOPROTOOPT) => (),
+            otherwise => return otherwise,
+        }
+        #[cfg(target_pointer_width = "64")]
+        let _ = new;
+
+        getsockopt(fd, c::SOL_SOCKET as _, old).map(to_bool)
+    }
+
+    #[inline]
+    pub(crate) fn set_socket_bindtodevice(fd: BorrowedFd<'_>, name: &ZStr) -> io::Result<()> {
+        let name = name.to_bytes();
+        unsafe {
+            setsockopt_raw(
+                fd,
+                c::SOL_SOCKET as _,
+                c::SO_BINDTODEVICE,
+                name.as_ptr().cast(),
+                name.len().try_into().map_err(|_| io::Errno::INVAL)?,
+            )
+        }
+    }
+
+    #[inline]
+    pub(crate) fn get_socket_bindtodevice(fd: BorrowedFd<'_>) -> io::Result<ZString> {
+        let name: [u8; IFNAMSIZ] = getsockopt_partial(fd, c::SOL_SOCKET as _, c::SO_BINDTODEVICE)?;
+        let len = name.iter().position(|b| *b == b'\0').unwrap_or(IFNAMSIZ);
+        Ok(ZString::new(&name[..len]).unwrap())
+    }
+
+    #[inline]
+    pub(crate) fn get_socket_peercred(fd: BorrowedFd<'_>) -> io::Result<UCred> {
+        let cred: linux_raw_sys::general::ucred =
+            getsockopt(fd, c::SOL_SOCKET as _, c::SO_PEERCRED)?;
+        unsafe {
+            Ok(UCred {
+                pid: Pid::from_raw(cred.pid as _),
+                uid: Uid::from_raw(cred.uid),
+                gid: Gid::from_raw(cred.gid),
+            })
+        }
+    }
+
     #[inline]
     pub(crate) fn set_socket_timeout(
         fd: BorrowedFd<'_>,
@@ -1070,6 +1610,16 @@ pub(crate) mod sockopt {
         })
     }
 
+    #[inline]
+    pub(crate) fn get_socket_error(fd: BorrowedFd<'_>) -> io::Result<Result<(), io::Errno>> {
+        let err: c::c_int = getsockopt(fd, c::SOL_SOCKET as _, c::SO_ERROR)?;
+        Ok(if err == 0 {
+            Ok(())
+        } else {
+            Err(io::Errno::from_raw_os_error(err))
+        })
+    }
+
     #[inline]
     pub(crate) fn set_ip_ttl(fd: BorrowedFd<'_>, ttl: u32) -> io::Result<()> {
         setsockopt(fd, c::IPPROTO_IP as _, c::IP_TTL, ttl)
@@ -1080,6 +1630,28 @@ pub(crate) mod sockopt {
         getsockopt(fd, c::IPPROTO_IP as _, c::IP_TTL)
     }
 
+    #[inline]
+    pub(crate) fn set_ip_recverr(fd: BorrowedFd<'_>, value: bool) -> io::Result<()> {
+        setsockopt(fd, c::IPPROTO_IP as _, c::IP_RECVERR, from_bool(value))
+    }
+
+    #[inline]
+    pub(crate) fn get_ip_recverr(fd: BorrowedFd<'_>) -> io::Result<bool> {
+        getsockopt(fd, c::IPPROTO_IP as _, c::IP_RECVERR).map(to_bool)
+    }
+
+    #[inline]
+    pub(crate) fn set_ip_tos(fd: BorrowedFd<'_>, value: u8) -> io::Result<()> {
+        let value: c::c_int = value.into();
+        setsockopt(fd, c::IPPROTO_IP as _, c::IP_TOS, value)
+    }
+
+    #[inline]
+    pub(crate) fn get_ip_tos(fd: BorrowedFd<'_>) -> io::Result<u8> {
+        let value: c::c_int = getsockopt(fd, c::IPPROTO_IP as _, c::IP_TOS)?;
+        Ok(value as u8)
+    }
+
     #[inline]
     pub(crate) fn set_ipv6_v6only(fd: BorrowedFd<'_>, only_v6: bool) -> io::Result<()> {
         setsockopt(fd, c::IPPROTO_IPV6 as _, c::IPV6_V6ONLY, from_bool(only_v6))
@@ -1090,6 +1662,18 @@ pub(crate) mod sockopt {
         getsockopt(fd, c::IPPROTO_IPV6 as _, c::IPV6_V6ONLY).map(to_bool)
     }
 
+    #[inline]
+    pub(crate) fn set_ipv6_tclass(fd: BorrowedFd<'_>, value: u32) -> io::Result<()> {
+        let value: c::c_int = value.try_into().map_err(|_| io::Errno::INVAL)?;
+        setsockopt(fd, c::IPPROTO_IPV6 as _, c::IPV6_TCLASS, value)
+    }
+
+    #[inline]
+    pub(crate) fn get_ipv6_tclass(fd: BorrowedFd<'_>) -> io::Result<u32> {
+        let value: c::c_int = getsockopt(fd, c::IPPROTO_IPV6 as _, c::IPV6_TCLASS)?;
+        Ok(value as u32)
+    }
+
     #[inline]
     pub(crate) fn set_ip_multicast_loop(
         fd: BorrowedFd<'_>,
@@ -1186,6 +1770,129 @@ pub(crate) mod sockopt {
         getsockopt(fd, c::IPPROTO_TCP as _, c::TCP_NODELAY).map(to_bool)
     }
 
+    #[inline]
+    pub(crate) fn set_tcp_keepidle(fd: BorrowedFd<'_>, duration: Duration) -> io::Result<()> {
+        let secs: c::c_uint = duration_to_secs(duration)?;
+        setsockopt(fd, c::IPPROTO_TCP as _, c::TCP_KEEPIDLE, secs)
+    }
+
+    #[inline]
+    pub(crate) fn get_tcp_keepidle(fd: BorrowedFd<'_>) -> io::Result<Duration> {
+        let secs: c::c_uint = getsockopt(fd, c::IPPROTO_TCP as _, c::TCP_KEEPIDLE)?;
+        Ok(Duration::from_secs(secs.into()))
+    }
+
+    #[inline]
+    pub(crate) fn set_tcp_keepintvl(fd: BorrowedFd<'_>, duration: Duration) -> io::Result<()> {
+        let secs: c::c_uint = duration_to_secs(duration)?;
+        setsockopt(fd, c::IPPROTO_TCP as _, c::TCP_KEEPINTVL, secs)
+    }
+
+    #[inline]
+    pub(crate) fn get_tcp_keepintvl(fd: BorrowedFd<'_>) -> io::Result<Duration> {
+        let secs: c::c_uint = getsockopt(fd, c::IPPROTO_TCP as _, c::TCP_KEEPINTVL)?;
+        Ok(Duration::from_secs(secs.into()))
+    }
+
+    #[inline]
+    pub(crate) fn set_tcp_keepcnt(fd: BorrowedFd<'_>, count: u32) -> io::Result<()> {
+        setsockopt(fd, c::IPPROTO_TCP as _, c::TCP_KEEPCNT, count)
+    }
+
+    #[inline]
+    pub(crate) fn get_tcp_keepcnt(fd: BorrowedFd<'_>) -> io::Result<u32> {
+        getsockopt(fd, c::IPPROTO_TCP as _, c::TCP_KEEPCNT)
+    }
+
+    #[inline]
+    pub(crate) fn set_tcp_user_timeout(fd: BorrowedFd<'_>, timeout: Duration) -> io::Result<()> {
+        let millis: c::c_uint = duration_to_millis(timeout)?;
+        setsockopt(fd, c::IPPROTO_TCP as _, c::TCP_USER_TIMEOUT, millis)
+    }
+
+    #[inline]
+    pub(crate) fn get_tcp_user_timeout(fd: BorrowedFd<'_>) -> io::Result<Duration> {
+        let millis: c::c_uint = getsockopt(fd, c::IPPROTO_TCP as _, c::TCP_USER_TIMEOUT)?;
+        Ok(Duration::from_millis(millis.into()))
+    }
+
+    #[inline]
+    pub(crate) fn set_tcp_congestion(fd: BorrowedFd<'_>, name: &ZStr) -> io::Result<()> {
+        let name = name.to_bytes();
+        unsafe {
+            setsockopt_raw(
+                fd,
+                c::IPPROTO_TCP as _,
+                c::TCP_CONGESTION,
+                name.as_ptr().cast(),
+                name.len().try_into().map_err(|_| io::Errno::INVAL)?,
+            )
+        }
+    }
+
+    #[inline]
+    pub(crate) fn get_tcp_congestion(fd: BorrowedFd<'_>) -> io::Result<ZString> {
+        let name: [u8; TCP_CA_NAME_MAX] =
+            getsockopt_partial(fd, c::IPPROTO_TCP as _, c::TCP_CONGESTION)?;
+        let len = name
+            .iter()
+            .position(|b| *b == b'\0')
+            .unwrap_or(TCP_CA_NAME_MAX);
+        Ok(ZString::new(&name[..len]).unwrap())
+    }
+
+    /// The leading fields of Linux's `struct tcp_info`, which have been present
+    /// since Linux 2.6. Newer kernels append fields after these.
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    struct tcp_info {
+        tcpi_state: u8,
+        tcpi_ca_state: u8,
+        tcpi_retransmits: u8,
+        tcpi_probes: u8,
+        tcpi_backoff: u8,
+        tcpi_options: u8,
+        tcpi_wscale: u8,
+        tcpi_flags: u8,
+        tcpi_rto: u32,
+        tcpi_ato: u32,
+        tcpi_snd_mss: u32,
+        tcpi_rcv_mss: u32,
+        tcpi_unacked: u32,
+        tcpi_sacked: u32,
+        tcpi_lost: u32,
+        tcpi_retrans: u32,
+        tcpi_fackets: u32,
+        tcpi_last_data_sent: u32,
+        tcpi_last_ack_sent: u32,
+        tcpi_last_data_recv: u32,
+        tcpi_last_ack_recv: u32,
+        tcpi_pmtu: u32,
+        tcpi_rcv_ssthresh: u32,
+        tcpi_rtt: u32,
+        tcpi_rttvar: u32,
+        tcpi_snd_ssthresh: u32,
+        tcpi_snd_cwnd: u32,
+        tcpi_advmss: u32,
+        tcpi_reordering: u32,
+        tcpi_rcv_rtt: u32,
+        tcpi_rcv_space: u32,
+        tcpi_total_retrans: u32,
+    }
+
+    #[inline]
+    pub(crate) fn get_tcp_info(fd: BorrowedFd<'_>) -> io::Result<TcpInfo> {
+        let info: tcp_info = getsockopt_partial(fd, c::IPPROTO_TCP as _, c::TCP_INFO)?;
+        Ok(TcpInfo {
+            state: TcpState::from_raw(info.tcpi_state),
+            retransmits: info.tcpi_retransmits,
+            rtt: info.tcpi_rtt,
+            rttvar: info.tcpi_rttvar,
+            snd_cwnd: info.tcpi_snd_cwnd,
+            total_retrans: info.tcpi_total_retrans,
+        })
+    }
+
     #[inline]
     fn to_imr(multiaddr: &Ipv4Addr, interface: &Ipv4Addr) -> c::ip_mreq {
         c::ip_mreq {
@@ -1223,6 +1930,30 @@ pub(crate) mod sockopt {
         interface as c::c_int
     }
 
+    /// Convert a `Duration` to a whole number of seconds, rounding up.
+    #[inline]
+    fn duration_to_secs<T: TryFrom<u64>>(duration: Duration) -> io::Result<T> {
+        let mut secs = duration.as_secs();
+        if duration.subsec_nanos() != 0 {
+            secs = secs.checked_add(1).ok_or(io::Errno::INVAL)?;
+        }
+        secs.try_into().map_err(|_e| io::Errno::INVAL)
+    }
+
+    /// Convert a `Duration` to a whole number of milliseconds, rounding up.
+    #[inline]
+    fn duration_to_millis<T: TryFrom<u64>>(duration: Duration) -> io::Result<T> {
+        let mut millis = duration
+            .as_secs()
+            .checked_mul(1000)
+            .and_then(|millis| millis.checked_add(duration.subsec_millis().into()))
+            .ok_or(io::Errno::INVAL)?;
+        if duration.subsec_nanos() != duration.subsec_millis() * 1_000_000 {
+            millis = millis.checked_add(1).ok_or(io::Errno::INVAL)?;
+        }
+        millis.try_into().map_err(|_e| io::Errno::INVAL)
+    }
+
     #[inline]
     fn from_bool(value: bool) -> c::c_uint {
         c::c_uint::from(value)
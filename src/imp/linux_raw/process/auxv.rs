@@ -7,37 +7,47 @@
 
 use super::super::c;
 use super::super::elf::{Elf_Ehdr, Elf_Phdr};
-#[cfg(feature = "process")]
+#[cfg(any(feature = "param", feature = "process"))]
+use super::super::vdso::Vdso;
+#[cfg(any(feature = "param", feature = "process"))]
+use super::types::AuxvKind;
+#[cfg(any(feature = "param", feature = "process"))]
 use crate::ffi::ZStr;
 use core::mem::size_of;
 use core::ptr::null;
+#[cfg(any(feature = "param", feature = "process"))]
+use core::ptr::null_mut;
 #[cfg(feature = "runtime")]
 use core::slice;
+#[cfg(any(feature = "param", feature = "process"))]
+use core::sync::atomic::AtomicU8;
+#[cfg(any(feature = "param", feature = "process"))]
+use core::sync::atomic::Ordering::{Acquire, Relaxed, Release};
 use linux_raw_sys::general::{
     AT_CLKTCK, AT_EXECFN, AT_HWCAP, AT_HWCAP2, AT_NULL, AT_PAGESZ, AT_PHDR, AT_PHENT, AT_PHNUM,
     AT_SYSINFO_EHDR,
 };
 
-#[cfg(feature = "process")]
+#[cfg(any(feature = "mm", feature = "param", feature = "process"))]
 #[inline]
 pub(crate) fn page_size() -> usize {
     auxv().page_size
 }
 
-#[cfg(feature = "process")]
+#[cfg(any(feature = "param", feature = "process"))]
 #[inline]
 pub(crate) fn clock_ticks_per_second() -> u64 {
     auxv().clock_ticks_per_second as u64
 }
 
-#[cfg(feature = "process")]
+#[cfg(any(feature = "param", feature = "process"))]
 #[inline]
 pub(crate) fn linux_hwcap() -> (usize, usize) {
     let auxv = auxv();
     (auxv.hwcap, auxv.hwcap2)
 }
 
-#[cfg(feature = "process")]
+#[cfg(any(feature = "param", feature = "process"))]
 #[inline]
 pub(crate) fn linux_execfn() -> &'static ZStr {
     let execfn = auxv().execfn;
@@ -47,6 +57,74 @@ pub(crate) fn linux_execfn() -> &'static ZStr {
     unsafe { ZStr::from_ptr(execfn.cast()) }
 }
 
+#[cfg(any(feature = "param", feature = "process"))]
+#[inline]
+pub(crate) fn linux_auxv(kind: AuxvKind) -> usize {
+    let auxv = auxv();
+    match kind {
+        AuxvKind::Phdr => auxv.phdr as usize,
+        AuxvKind::Phnum => auxv.phnum,
+        AuxvKind::Pagesz => auxv.page_size,
+        AuxvKind::Hwcap => auxv.hwcap,
+        AuxvKind::Clktck => auxv.clock_ticks_per_second,
+        AuxvKind::Hwcap2 => auxv.hwcap2,
+        AuxvKind::Execfn => auxv.execfn as usize,
+        AuxvKind::SysinfoEhdr => auxv.sysinfo_ehdr as usize,
+    }
+}
+
+#[cfg(any(feature = "param", feature = "process"))]
+#[inline]
+pub(crate) fn vdso_sym(version: &ZStr, name: &ZStr) -> *mut c::c_void {
+    match vdso() {
+        Some(vdso) => vdso.sym(version, name),
+        None => null_mut(),
+    }
+}
+
+/// Return the parsed vDSO, parsing it on the first call.
+#[cfg(any(feature = "param", feature = "process"))]
+fn vdso() -> Option<Vdso> {
+    match VDSO_STATE.load(Acquire) {
+        // Safety: `VDSO` is only written before `VDSO_STATE` is set to
+        // `VDSO_READY`, and never mutated thereafter.
+        VDSO_READY => unsafe { VDSO },
+        VDSO_UNINIT
+            if VDSO_STATE
+                .compare_exchange(VDSO_UNINIT, VDSO_BUSY, Relaxed, Relaxed)
+                .is_ok() =>
+        {
+            let vdso = Vdso::new();
+            // Safety: We won the race to initialize `VDSO`, and no other code
+            // reads it until we set `VDSO_STATE` to `VDSO_READY`.
+            unsafe {
+                VDSO = vdso;
+            }
+            VDSO_STATE.store(VDSO_READY, Release);
+            vdso
+        }
+        // Another thread is parsing the vDSO; parse our own copy rather than
+        // waiting for it.
+        _ => Vdso::new(),
+    }
+}
+
+#[cfg(any(feature = "param", feature = "process"))]
+const VDSO_UNINIT: u8 = 0;
+#[cfg(any(feature = "param", feature = "process"))]
+const VDSO_BUSY: u8 = 1;
+#[cfg(any(feature = "param", feature = "process"))]
+const VDSO_READY: u8 = 2;
+
+/// The state of `VDSO`: one of `VDSO_UNINIT`, `VDSO_BUSY`, or `VDSO_READY`.
+#[cfg(any(feature = "param", feature = "process"))]
+static VDSO_STATE: AtomicU8 = AtomicU8::new(VDSO_UNINIT);
+
+/// The parsed vDSO, cached so that `vdso_sym` doesn't re-parse it on each
+/// call.
+#[cfg(any(feature = "param", feature = "process"))]
+static mut VDSO: Option<Vdso> = None;
+
 #[cfg(feature = "runtime")]
 #[inline]
 pub(crate) fn exe_phdrs() -> (*const c::c_void, usize) {
@@ -70,14 +148,15 @@ pub(in super::super) fn sysinfo_ehdr() -> *const Elf_Ehdr {
 }
 
 #[inline]
-fn auxv() -> &'static Auxv {
+fn auxv() -> Auxv {
     // Safety: `AUXV` is initialized from the `.init_array`, and we never
     // mutate it thereafter, so it's effectively initialized read-only in all
     // other code.
-    unsafe { &AUXV }
+    unsafe { AUXV }
 }
 
 /// A struct for holding fields obtained from the kernel-provided auxv array.
+#[derive(Copy, Clone)]
 struct Auxv {
     page_size: usize,
     clock_ticks_per_second: usize,
@@ -1,4 +1,6 @@
 #[cfg(any(
+    feature = "mm",
+    feature = "param",
     feature = "process",
     feature = "runtime",
     feature = "time",
@@ -6,6 +8,7 @@
 ))]
 pub(crate) mod auxv;
 pub(crate) mod cpu_set;
+pub(crate) mod sig_set;
 pub(crate) mod syscalls;
 pub(crate) mod types;
 pub(crate) mod wait;
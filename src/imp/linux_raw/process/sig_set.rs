@@ -0,0 +1,26 @@
+use super::types::{RawSigSet, Signal};
+
+#[inline]
+fn index(sig: Signal) -> (usize, u32) {
+    // Signal numbers start at 1.
+    let bit = sig as u32 - 1;
+    ((bit / 64) as usize, bit % 64)
+}
+
+#[inline]
+pub(crate) fn sigaddset(sigset: &mut RawSigSet, sig: Signal) {
+    let (idx, offset) = index(sig);
+    sigset.bits[idx] |= 1 << offset
+}
+
+#[inline]
+pub(crate) fn sigdelset(sigset: &mut RawSigSet, sig: Signal) {
+    let (idx, offset) = index(sig);
+    sigset.bits[idx] &= !(1 << offset)
+}
+
+#[inline]
+pub(crate) fn sigismember(sigset: &RawSigSet, sig: Signal) -> bool {
+    let (idx, offset) = index(sig);
+    (sigset.bits[idx] & (1 << offset)) != 0
+}
@@ -7,28 +7,51 @@
 #![allow(clippy::undocumented_unsafe_blocks)]
 
 use super::super::c;
+#[cfg(feature = "process")]
+use super::super::conv::slice;
 use super::super::conv::{
     by_mut, by_ref, c_int, c_uint, negative_pid, pass_usize, ret, ret_c_int, ret_c_uint,
     ret_infallible, ret_usize, ret_usize_infallible, size_of, slice_just_addr, slice_mut, zero,
 };
+#[cfg(feature = "process")]
+use super::types::{PtraceRequest, RawSysinfo};
 use super::types::{RawCpuSet, RawUname};
 use crate::fd::BorrowedFd;
 use crate::ffi::ZStr;
+#[cfg(feature = "fs")]
+use crate::fs::Mode;
+#[cfg(feature = "process")]
+use crate::imp::time::types::Timeval;
 use crate::io;
 use crate::process::{
     Cpuid, Gid, MembarrierCommand, MembarrierQuery, Pid, RawNonZeroPid, RawPid, Resource, Rlimit,
     Signal, Uid, WaitOptions, WaitStatus,
 };
+#[cfg(feature = "process")]
+use crate::process::{Personality, Rusage, RusageWho, Sysinfo};
 use core::convert::TryInto;
 use core::mem::MaybeUninit;
 use core::ptr::{null, null_mut};
 use linux_raw_sys::general::{__kernel_gid_t, __kernel_pid_t, __kernel_uid_t};
+#[cfg(feature = "process")]
+use {
+    super::super::conv::ret_owned_fd,
+    crate::fd::RawFd,
+    crate::io::{IoSlice, IoSliceMut, OwnedFd},
+    crate::process::RemoteIoVec,
+};
 
 #[inline]
 pub(crate) fn chdir(filename: &ZStr) -> io::Result<()> {
     unsafe { ret(syscall_readonly!(__NR_chdir, filename)) }
 }
 
+#[cfg(feature = "process")]
+#[inline]
+pub(crate) fn chroot(filename: &ZStr) -> io::Result<()> {
+    unsafe { ret(syscall_readonly!(__NR_chroot, filename)) }
+}
+
 #[inline]
 pub(crate) fn fchdir(fd: BorrowedFd<'_>) -> io::Result<()> {
     unsafe { ret(syscall_readonly!(__NR_fchdir, fd)) }
@@ -40,6 +63,13 @@ pub(crate) fn getcwd(buf: &mut [u8]) -> io::Result<usize> {
     unsafe { ret_usize(syscall!(__NR_getcwd, buf_addr_mut, buf_len)) }
 }
 
+#[cfg(feature = "fs")]
+#[inline]
+pub(crate) fn umask(mask: Mode) -> Mode {
+    let old = unsafe { ret_usize_infallible(syscall_readonly!(__NR_umask, mask)) };
+    Mode::from_bits_truncate(old as _)
+}
+
 #[inline]
 pub(crate) fn membarrier_query() -> MembarrierQuery {
     unsafe {
@@ -205,6 +235,144 @@ pub(crate) fn uname() -> RawUname {
     }
 }
 
+#[cfg(feature = "process")]
+#[inline]
+pub(crate) fn pidfd_open(pid: Pid, flags: u32) -> io::Result<OwnedFd> {
+    unsafe {
+        ret_owned_fd(syscall_readonly!(
+            __NR_pidfd_open,
+            c_uint(Pid::as_raw(Some(pid))),
+            c_uint(flags)
+        ))
+    }
+}
+
+#[cfg(feature = "process")]
+#[inline]
+pub(crate) fn pidfd_getfd(
+    pidfd: BorrowedFd<'_>,
+    targetfd: RawFd,
+    flags: u32,
+) -> io::Result<OwnedFd> {
+    unsafe {
+        ret_owned_fd(syscall_readonly!(
+            __NR_pidfd_getfd,
+            pidfd,
+            c_int(targetfd),
+            c_uint(flags)
+        ))
+    }
+}
+
+#[cfg(feature = "process")]
+#[inline]
+pub(crate) unsafe fn klogctl(action: u32, buf: *mut u8, len: u32) -> io::Result<usize> {
+    ret_usize(syscall!(__NR_syslog, c_uint(action), buf, c_uint(len)))
+}
+
+#[cfg(feature = "process")]
+#[inline]
+pub(crate) fn reboot(cmd: u32, arg: Option<&ZStr>) -> io::Result<()> {
+    /// `LINUX_REBOOT_MAGIC1`
+    const LINUX_REBOOT_MAGIC1: u32 = 0xfee1_dead;
+    /// `LINUX_REBOOT_MAGIC2`
+    const LINUX_REBOOT_MAGIC2: u32 = 0x2812_1969;
+
+    unsafe {
+        match arg {
+            Some(arg) => ret(syscall_readonly!(
+                __NR_reboot,
+                c_uint(LINUX_REBOOT_MAGIC1),
+                c_uint(LINUX_REBOOT_MAGIC2),
+                c_uint(cmd),
+                arg
+            )),
+            None => ret(syscall_readonly!(
+                __NR_reboot,
+                c_uint(LINUX_REBOOT_MAGIC1),
+                c_uint(LINUX_REBOOT_MAGIC2),
+                c_uint(cmd),
+                zero()
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "process")]
+#[inline]
+#[allow(clippy::useless_conversion)]
+pub(crate) fn sysinfo() -> io::Result<Sysinfo> {
+    let mut info = MaybeUninit::<RawSysinfo>::uninit();
+    unsafe {
+        ret(syscall!(__NR_sysinfo, &mut info))?;
+        let info = info.assume_init();
+        Ok(Sysinfo {
+            uptime: info.uptime.into(),
+            loads: [
+                info.loads[0].into(),
+                info.loads[1].into(),
+                info.loads[2].into(),
+            ],
+            totalram: info.totalram.into(),
+            freeram: info.freeram.into(),
+            sharedram: info.sharedram.into(),
+            bufferram: info.bufferram.into(),
+            totalswap: info.totalswap.into(),
+            freeswap: info.freeswap.into(),
+            procs: info.procs,
+            totalhigh: info.totalhigh.into(),
+            freehigh: info.freehigh.into(),
+            mem_unit: info.mem_unit,
+        })
+    }
+}
+
+#[cfg(feature = "process")]
+#[inline]
+pub(crate) fn getrusage(who: RusageWho) -> Rusage {
+    let mut usage = MaybeUninit::<linux_raw_sys::general::rusage>::uninit();
+    unsafe {
+        ret_infallible(syscall!(__NR_getrusage, c_int(who as c::c_int), &mut usage));
+        rusage_from_raw(usage.assume_init())
+    }
+}
+
+#[cfg(feature = "process")]
+#[allow(clippy::useless_conversion)]
+fn rusage_from_raw(usage: linux_raw_sys::general::rusage) -> Rusage {
+    Rusage {
+        ru_utime: Timeval {
+            tv_sec: usage.ru_utime.tv_sec.into(),
+            tv_usec: usage.ru_utime.tv_usec.into(),
+        },
+        ru_stime: Timeval {
+            tv_sec: usage.ru_stime.tv_sec.into(),
+            tv_usec: usage.ru_stime.tv_usec.into(),
+        },
+        ru_maxrss: usage.ru_maxrss as u64,
+        ru_ixrss: usage.ru_ixrss as u64,
+        ru_idrss: usage.ru_idrss as u64,
+        ru_isrss: usage.ru_isrss as u64,
+        ru_minflt: usage.ru_minflt as u64,
+        ru_majflt: usage.ru_majflt as u64,
+        ru_nswap: usage.ru_nswap as u64,
+        ru_inblock: usage.ru_inblock as u64,
+        ru_oublock: usage.ru_oublock as u64,
+        ru_msgsnd: usage.ru_msgsnd as u64,
+        ru_msgrcv: usage.ru_msgrcv as u64,
+        ru_nsignals: usage.ru_nsignals as u64,
+        ru_nvcsw: usage.ru_nvcsw as u64,
+        ru_nivcsw: usage.ru_nivcsw as u64,
+    }
+}
+
+#[cfg(feature = "process")]
+#[inline]
+pub(crate) fn sethostname(name: &[u8]) -> io::Result<()> {
+    let (name_addr, name_len) = slice(name);
+    unsafe { ret(syscall_readonly!(__NR_sethostname, name_addr, name_len)) }
+}
+
 #[inline]
 pub(crate) fn nice(inc: i32) -> io::Result<i32> {
     let priority = if inc > -40 && inc < 40 {
@@ -481,6 +649,31 @@ pub(crate) fn _waitpid(
     }
 }
 
+#[cfg(feature = "process")]
+#[inline]
+pub(crate) fn wait4(
+    pid: Option<Pid>,
+    waitopts: WaitOptions,
+) -> io::Result<Option<(WaitStatus, Rusage)>> {
+    unsafe {
+        let mut status = MaybeUninit::<u32>::uninit();
+        let mut usage = MaybeUninit::<linux_raw_sys::general::rusage>::uninit();
+        let pid = ret_c_uint(syscall!(
+            __NR_wait4,
+            c_int(Pid::as_raw(pid) as _),
+            &mut status,
+            c_int(waitopts.bits() as _),
+            &mut usage
+        ))?;
+        Ok(RawNonZeroPid::new(pid).map(|_| {
+            (
+                WaitStatus::new(status.assume_init()),
+                rusage_from_raw(usage.assume_init()),
+            )
+        }))
+    }
+}
+
 #[cfg(feature = "runtime")]
 #[inline]
 pub(crate) fn exit_group(code: c::c_int) -> ! {
@@ -498,6 +691,33 @@ pub(crate) fn setsid() -> io::Result<Pid> {
     }
 }
 
+#[inline]
+pub(crate) fn getsid(pid: Option<Pid>) -> io::Result<Option<Pid>> {
+    unsafe {
+        let sid = ret_c_uint(syscall_readonly!(__NR_getsid, c_uint(Pid::as_raw(pid))))?;
+        Ok(Pid::from_raw(sid))
+    }
+}
+
+#[inline]
+pub(crate) fn getpgid(pid: Option<Pid>) -> io::Result<Option<Pid>> {
+    unsafe {
+        let pgid = ret_c_uint(syscall_readonly!(__NR_getpgid, c_uint(Pid::as_raw(pid))))?;
+        Ok(Pid::from_raw(pgid))
+    }
+}
+
+#[inline]
+pub(crate) fn setpgid(pid: Option<Pid>, pgid: Option<Pid>) -> io::Result<()> {
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_setpgid,
+            c_uint(Pid::as_raw(pid)),
+            c_uint(Pid::as_raw(pgid))
+        ))
+    }
+}
+
 #[inline]
 pub(crate) fn kill_process(pid: Pid, sig: Signal) -> io::Result<()> {
     unsafe { ret(syscall_readonly!(__NR_kill, pid, sig)) }
@@ -512,3 +732,95 @@ pub(crate) fn kill_process_group(pid: Pid, sig: Signal) -> io::Result<()> {
 pub(crate) fn kill_current_process_group(sig: Signal) -> io::Result<()> {
     unsafe { ret(syscall_readonly!(__NR_kill, pass_usize(0), sig)) }
 }
+
+#[inline]
+pub(crate) fn test_kill_process(pid: Pid) -> io::Result<()> {
+    unsafe { ret(syscall_readonly!(__NR_kill, pid, pass_usize(0))) }
+}
+
+#[inline]
+pub(crate) fn test_kill_process_group(pid: Pid) -> io::Result<()> {
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_kill,
+            negative_pid(pid),
+            pass_usize(0)
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn test_kill_current_process_group() -> io::Result<()> {
+    unsafe { ret(syscall_readonly!(__NR_kill, pass_usize(0), pass_usize(0))) }
+}
+
+#[cfg(feature = "process")]
+#[inline]
+pub(crate) fn process_vm_readv(
+    pid: Pid,
+    local: &mut [IoSliceMut<'_>],
+    remote: &[RemoteIoVec],
+) -> io::Result<usize> {
+    let (local_addr, local_len) = slice(local);
+    let (remote_addr, remote_len) = slice(remote);
+    unsafe {
+        ret_usize(syscall!(
+            __NR_process_vm_readv,
+            pid,
+            local_addr,
+            local_len,
+            remote_addr,
+            remote_len,
+            c_uint(0)
+        ))
+    }
+}
+
+#[cfg(feature = "process")]
+#[inline]
+pub(crate) unsafe fn process_vm_writev(
+    pid: Pid,
+    local: &[IoSlice<'_>],
+    remote: &[RemoteIoVec],
+) -> io::Result<usize> {
+    let (local_addr, local_len) = slice(local);
+    let (remote_addr, remote_len) = slice(remote);
+    ret_usize(syscall!(
+        __NR_process_vm_writev,
+        pid,
+        local_addr,
+        local_len,
+        remote_addr,
+        remote_len,
+        c_uint(0)
+    ))
+}
+
+#[cfg(feature = "process")]
+#[inline]
+pub(crate) unsafe fn ptrace(
+    request: PtraceRequest,
+    pid: Option<Pid>,
+    addr: *mut c::c_void,
+    data: *mut c::c_void,
+) -> io::Result<()> {
+    ret(syscall!(
+        __NR_ptrace,
+        c_uint(request as u32),
+        c_uint(Pid::as_raw(pid)),
+        addr,
+        data
+    ))
+}
+
+#[cfg(feature = "process")]
+#[inline]
+pub(crate) fn personality(persona: u32) -> io::Result<Personality> {
+    unsafe {
+        let persona = ret_c_uint(syscall_readonly!(__NR_personality, c_uint(persona)))?;
+        // Safety: The persona value is coming from Linux, and unnamed bits
+        // are preserved so that the previous persona can be restored. See
+        // the discussion of `from_bits_unchecked` in `membarrier_query`.
+        Ok(Personality::from_bits_unchecked(persona))
+    }
+}
@@ -83,8 +83,82 @@ pub enum Resource {
     Rttime = linux_raw_sys::general::RLIMIT_RTTIME,
 }
 
+/// A `who` value for use with [`getrusage`].
+///
+/// [`getrusage`]: crate::process::getrusage
+#[cfg(feature = "process")]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(i32)]
+pub enum RusageWho {
+    /// `RUSAGE_SELF`
+    Self_ = linux_raw_sys::general::RUSAGE_SELF as i32,
+    /// `RUSAGE_CHILDREN`
+    Children = linux_raw_sys::general::RUSAGE_CHILDREN,
+    /// `RUSAGE_THREAD`
+    Thread = linux_raw_sys::general::RUSAGE_THREAD as i32,
+}
+
+/// A key for use with [`auxv`].
+///
+/// [`auxv`]: crate::param::auxv
+#[cfg(any(feature = "param", feature = "process"))]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum AuxvKind {
+    /// `AT_PHDR`—The address of the program headers of the executable.
+    Phdr = linux_raw_sys::general::AT_PHDR,
+    /// `AT_PHNUM`—The number of program headers of the executable.
+    Phnum = linux_raw_sys::general::AT_PHNUM,
+    /// `AT_PAGESZ`—The page size.
+    Pagesz = linux_raw_sys::general::AT_PAGESZ,
+    /// `AT_HWCAP`—Architecture-specific hardware capability bits.
+    Hwcap = linux_raw_sys::general::AT_HWCAP,
+    /// `AT_CLKTCK`—The number of clock ticks per second.
+    Clktck = linux_raw_sys::general::AT_CLKTCK,
+    /// `AT_HWCAP2`—More architecture-specific hardware capability bits.
+    Hwcap2 = linux_raw_sys::general::AT_HWCAP2,
+    /// `AT_EXECFN`—The address of the filesystem path of the executable.
+    Execfn = linux_raw_sys::general::AT_EXECFN,
+    /// `AT_SYSINFO_EHDR`—The address of the vDSO.
+    SysinfoEhdr = linux_raw_sys::general::AT_SYSINFO_EHDR,
+}
+
+/// A `PTRACE_*` request for use with [`ptrace`].
+///
+/// linux-raw-sys doesn't define these yet, so we define the constants
+/// ourselves.
+///
+/// [`ptrace`]: crate::process::ptrace
+#[cfg(feature = "process")]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub(crate) enum PtraceRequest {
+    TraceMe = 0,
+    PeekData = 2,
+    PokeData = 5,
+    Cont = 7,
+    SingleStep = 9,
+    Attach = 16,
+    Detach = 17,
+    Syscall = 24,
+    SetOptions = 0x4200,
+    GetRegSet = 0x4204,
+    SetRegSet = 0x4205,
+    Seize = 0x4206,
+}
+
+/// The `NT_PRSTATUS` register set, for use with `PTRACE_GETREGSET` and
+/// `PTRACE_SETREGSET`.
+#[cfg(feature = "process")]
+pub(crate) const NT_PRSTATUS: usize = 1;
+
 /// A signal number for use with [`kill_process`] and [`kill_process_group`].
 ///
+/// The real-time signals `SIGRTMIN` through `SIGRTMIN+32` are represented
+/// by [`Signal::Rtmin`] through [`Signal::Rt32`]; see also [`Signal::rt`].
+/// A C library in the same process may reserve the lowest few real-time
+/// signals for its own use.
+///
 /// [`kill_process`]: crate::process::kill_process
 /// [`kill_process_group`]: crate::process::kill_process_group
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
@@ -163,6 +237,70 @@ pub enum Signal {
     Sys = linux_raw_sys::general::SIGSYS,
     /// `SIGRTMIN`
     Rtmin = linux_raw_sys::general::SIGRTMIN,
+    /// `SIGRTMIN+1`
+    Rt1 = linux_raw_sys::general::SIGRTMIN + 1,
+    /// `SIGRTMIN+2`
+    Rt2 = linux_raw_sys::general::SIGRTMIN + 2,
+    /// `SIGRTMIN+3`
+    Rt3 = linux_raw_sys::general::SIGRTMIN + 3,
+    /// `SIGRTMIN+4`
+    Rt4 = linux_raw_sys::general::SIGRTMIN + 4,
+    /// `SIGRTMIN+5`
+    Rt5 = linux_raw_sys::general::SIGRTMIN + 5,
+    /// `SIGRTMIN+6`
+    Rt6 = linux_raw_sys::general::SIGRTMIN + 6,
+    /// `SIGRTMIN+7`
+    Rt7 = linux_raw_sys::general::SIGRTMIN + 7,
+    /// `SIGRTMIN+8`
+    Rt8 = linux_raw_sys::general::SIGRTMIN + 8,
+    /// `SIGRTMIN+9`
+    Rt9 = linux_raw_sys::general::SIGRTMIN + 9,
+    /// `SIGRTMIN+10`
+    Rt10 = linux_raw_sys::general::SIGRTMIN + 10,
+    /// `SIGRTMIN+11`
+    Rt11 = linux_raw_sys::general::SIGRTMIN + 11,
+    /// `SIGRTMIN+12`
+    Rt12 = linux_raw_sys::general::SIGRTMIN + 12,
+    /// `SIGRTMIN+13`
+    Rt13 = linux_raw_sys::general::SIGRTMIN + 13,
+    /// `SIGRTMIN+14`
+    Rt14 = linux_raw_sys::general::SIGRTMIN + 14,
+    /// `SIGRTMIN+15`
+    Rt15 = linux_raw_sys::general::SIGRTMIN + 15,
+    /// `SIGRTMIN+16`
+    Rt16 = linux_raw_sys::general::SIGRTMIN + 16,
+    /// `SIGRTMIN+17`
+    Rt17 = linux_raw_sys::general::SIGRTMIN + 17,
+    /// `SIGRTMIN+18`
+    Rt18 = linux_raw_sys::general::SIGRTMIN + 18,
+    /// `SIGRTMIN+19`
+    Rt19 = linux_raw_sys::general::SIGRTMIN + 19,
+    /// `SIGRTMIN+20`
+    Rt20 = linux_raw_sys::general::SIGRTMIN + 20,
+    /// `SIGRTMIN+21`
+    Rt21 = linux_raw_sys::general::SIGRTMIN + 21,
+    /// `SIGRTMIN+22`
+    Rt22 = linux_raw_sys::general::SIGRTMIN + 22,
+    /// `SIGRTMIN+23`
+    Rt23 = linux_raw_sys::general::SIGRTMIN + 23,
+    /// `SIGRTMIN+24`
+    Rt24 = linux_raw_sys::general::SIGRTMIN + 24,
+    /// `SIGRTMIN+25`
+    Rt25 = linux_raw_sys::general::SIGRTMIN + 25,
+    /// `SIGRTMIN+26`
+    Rt26 = linux_raw_sys::general::SIGRTMIN + 26,
+    /// `SIGRTMIN+27`
+    Rt27 = linux_raw_sys::general::SIGRTMIN + 27,
+    /// `SIGRTMIN+28`
+    Rt28 = linux_raw_sys::general::SIGRTMIN + 28,
+    /// `SIGRTMIN+29`
+    Rt29 = linux_raw_sys::general::SIGRTMIN + 29,
+    /// `SIGRTMIN+30`
+    Rt30 = linux_raw_sys::general::SIGRTMIN + 30,
+    /// `SIGRTMIN+31`
+    Rt31 = linux_raw_sys::general::SIGRTMIN + 31,
+    /// `SIGRTMIN+32`
+    Rt32 = linux_raw_sys::general::SIGRTMIN + 32,
 }
 
 impl Signal {
@@ -202,9 +340,49 @@ impl Signal {
             linux_raw_sys::general::SIGPWR => Some(Self::Power),
             linux_raw_sys::general::SIGSYS => Some(Self::Sys),
             linux_raw_sys::general::SIGRTMIN => Some(Self::Rtmin),
+            sig if sig == linux_raw_sys::general::SIGRTMIN + 1 => Some(Self::Rt1),
+            sig if sig == linux_raw_sys::general::SIGRTMIN + 2 => Some(Self::Rt2),
+            sig if sig == linux_raw_sys::general::SIGRTMIN + 3 => Some(Self::Rt3),
+            sig if sig == linux_raw_sys::general::SIGRTMIN + 4 => Some(Self::Rt4),
+            sig if sig == linux_raw_sys::general::SIGRTMIN + 5 => Some(Self::Rt5),
+            sig if sig == linux_raw_sys::general::SIGRTMIN + 6 => Some(Self::Rt6),
+            sig if sig == linux_raw_sys::general::SIGRTMIN + 7 => Some(Self::Rt7),
+            sig if sig == linux_raw_sys::general::SIGRTMIN + 8 => Some(Self::Rt8),
+            sig if sig == linux_raw_sys::general::SIGRTMIN + 9 => Some(Self::Rt9),
+            sig if sig == linux_raw_sys::general::SIGRTMIN + 10 => Some(Self::Rt10),
+            sig if sig == linux_raw_sys::general::SIGRTMIN + 11 => Some(Self::Rt11),
+            sig if sig == linux_raw_sys::general::SIGRTMIN + 12 => Some(Self::Rt12),
+            sig if sig == linux_raw_sys::general::SIGRTMIN + 13 => Some(Self::Rt13),
+            sig if sig == linux_raw_sys::general::SIGRTMIN + 14 => Some(Self::Rt14),
+            sig if sig == linux_raw_sys::general::SIGRTMIN + 15 => Some(Self::Rt15),
+            sig if sig == linux_raw_sys::general::SIGRTMIN + 16 => Some(Self::Rt16),
+            sig if sig == linux_raw_sys::general::SIGRTMIN + 17 => Some(Self::Rt17),
+            sig if sig == linux_raw_sys::general::SIGRTMIN + 18 => Some(Self::Rt18),
+            sig if sig == linux_raw_sys::general::SIGRTMIN + 19 => Some(Self::Rt19),
+            sig if sig == linux_raw_sys::general::SIGRTMIN + 20 => Some(Self::Rt20),
+            sig if sig == linux_raw_sys::general::SIGRTMIN + 21 => Some(Self::Rt21),
+            sig if sig == linux_raw_sys::general::SIGRTMIN + 22 => Some(Self::Rt22),
+            sig if sig == linux_raw_sys::general::SIGRTMIN + 23 => Some(Self::Rt23),
+            sig if sig == linux_raw_sys::general::SIGRTMIN + 24 => Some(Self::Rt24),
+            sig if sig == linux_raw_sys::general::SIGRTMIN + 25 => Some(Self::Rt25),
+            sig if sig == linux_raw_sys::general::SIGRTMIN + 26 => Some(Self::Rt26),
+            sig if sig == linux_raw_sys::general::SIGRTMIN + 27 => Some(Self::Rt27),
+            sig if sig == linux_raw_sys::general::SIGRTMIN + 28 => Some(Self::Rt28),
+            sig if sig == linux_raw_sys::general::SIGRTMIN + 29 => Some(Self::Rt29),
+            sig if sig == linux_raw_sys::general::SIGRTMIN + 30 => Some(Self::Rt30),
+            sig if sig == linux_raw_sys::general::SIGRTMIN + 31 => Some(Self::Rt31),
+            sig if sig == linux_raw_sys::general::SIGRTMIN + 32 => Some(Self::Rt32),
             _ => None,
         }
     }
+
+    /// Return the real-time signal `SIGRTMIN+n`, if `n` is at most 32.
+    pub fn rt(n: u32) -> Option<Self> {
+        if n > 32 {
+            return None;
+        }
+        Self::from_raw((linux_raw_sys::general::SIGRTMIN + n) as i32)
+    }
 }
 
 /// `EXIT_SUCCESS`
@@ -227,6 +405,25 @@ pub type RawCpuid = u32;
 
 pub(crate) type RawUname = linux_raw_sys::general::new_utsname;
 
+/// Linux's `struct sysinfo`, which linux-raw-sys doesn't define yet.
+#[repr(C)]
+pub(crate) struct RawSysinfo {
+    pub(crate) uptime: c::c_long,
+    pub(crate) loads: [c::c_ulong; 3],
+    pub(crate) totalram: c::c_ulong,
+    pub(crate) freeram: c::c_ulong,
+    pub(crate) sharedram: c::c_ulong,
+    pub(crate) bufferram: c::c_ulong,
+    pub(crate) totalswap: c::c_ulong,
+    pub(crate) freeswap: c::c_ulong,
+    pub(crate) procs: u16,
+    pub(crate) pad: u16,
+    pub(crate) totalhigh: c::c_ulong,
+    pub(crate) freehigh: c::c_ulong,
+    pub(crate) mem_unit: u32,
+    pub(crate) f: [u8; 20 - 2 * core::mem::size_of::<c::c_ulong>() - core::mem::size_of::<u32>()],
+}
+
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub(crate) struct RawCpuSet {
@@ -249,3 +446,28 @@ pub(crate) fn raw_cpu_set_new() -> RawCpuSet {
 }
 
 pub(crate) const CPU_SETSIZE: usize = 8 * core::mem::size_of::<RawCpuSet>();
+
+/// The kernel's `sigset_t`, as used by the `rt_sig*` family of syscalls.
+///
+/// This is larger than `linux_raw_sys::general::sigset_t`, which is the
+/// legacy single-word signal set, on some architectures.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub(crate) struct RawSigSet {
+    #[cfg(any(target_arch = "mips", target_arch = "mips64"))]
+    pub(crate) bits: [u64; 2],
+    #[cfg(not(any(target_arch = "mips", target_arch = "mips64")))]
+    pub(crate) bits: [u64; 1],
+}
+
+#[inline]
+pub(crate) fn raw_sig_set_new() -> RawSigSet {
+    #[cfg(any(target_arch = "mips", target_arch = "mips64"))]
+    {
+        RawSigSet { bits: [0; 2] }
+    }
+    #[cfg(not(any(target_arch = "mips", target_arch = "mips64")))]
+    {
+        RawSigSet { bits: [0; 1] }
+    }
+}
@@ -0,0 +1,34 @@
+//! linux_raw syscalls supporting `rustix::pty`.
+//!
+//! # Safety
+//!
+//! See the `rustix::imp` module documentation for details.
+#![allow(unsafe_code)]
+
+use super::super::c;
+use super::super::conv::{by_ref, c_uint, ret};
+use crate::fd::BorrowedFd;
+use crate::io;
+use core::mem::MaybeUninit;
+use linux_raw_sys::ioctl::{TIOCGPTN, TIOCSPTLCK};
+
+#[inline]
+pub(crate) fn ioctl_tiocgptn(fd: BorrowedFd<'_>) -> io::Result<u32> {
+    unsafe {
+        let mut result = MaybeUninit::<u32>::uninit();
+        ret(syscall!(__NR_ioctl, fd, c_uint(TIOCGPTN), &mut result)).map(|()| result.assume_init())
+    }
+}
+
+#[inline]
+pub(crate) fn unlockpt(fd: BorrowedFd<'_>) -> io::Result<()> {
+    let unlock: c::c_int = 0;
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_ioctl,
+            fd,
+            c_uint(TIOCSPTLCK),
+            by_ref(&unlock)
+        ))
+    }
+}
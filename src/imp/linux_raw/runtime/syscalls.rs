@@ -8,16 +8,117 @@
 use super::super::c;
 #[cfg(target_arch = "x86")]
 use super::super::conv::by_mut;
-use super::super::conv::{c_int, c_uint, ret, ret_c_uint, ret_error, ret_usize_infallible, zero};
+use super::super::conv::{
+    by_ref, c_int, c_uint, opt_ref, ret, ret_c_uint, ret_error, ret_usize_infallible, size_of, zero,
+};
+use super::super::process::types::RawSigSet;
+use super::types::{KernelSigaction, SigactionFlags, Siginfo, StackFlags};
 use crate::fd::BorrowedFd;
+use crate::fd::RawFd;
 use crate::ffi::ZStr;
 use crate::fs::AtFlags;
 use crate::io;
-use crate::process::{Pid, RawNonZeroPid};
-use linux_raw_sys::general::{__kernel_pid_t, PR_SET_NAME, SIGCHLD};
+use crate::process::{Pid, RawNonZeroPid, SigSet, Signal};
+use crate::runtime::{CloneArgs, SigHandler, Sigaction, Stack};
+use core::mem::{transmute, MaybeUninit};
+use linux_raw_sys::general::{__kernel_pid_t, clone_args, stack_t, PR_SET_NAME, SIGCHLD};
 #[cfg(target_arch = "x86_64")]
 use {super::super::conv::ret_infallible, linux_raw_sys::general::ARCH_SET_FS};
 
+pub(crate) unsafe fn sigaction(sig: Signal, new: Option<&Sigaction>) -> io::Result<Sigaction> {
+    let new = new.map(|new| KernelSigaction {
+        sa_handler: match new.handler {
+            SigHandler::Default => SIG_DFL,
+            SigHandler::Ignore => SIG_IGN,
+            SigHandler::Handler(handler) => handler as usize,
+            SigHandler::SigInfo(handler) => handler as usize,
+        },
+        sa_flags: new.flags.bits() as _,
+        #[cfg(not(any(target_arch = "mips", target_arch = "mips64", target_arch = "riscv64")))]
+        sa_restorer: new.restorer,
+        sa_mask: new.mask.sigset,
+    });
+    let mut old = MaybeUninit::<KernelSigaction>::uninit();
+    ret(syscall!(
+        __NR_rt_sigaction,
+        sig,
+        opt_ref(new.as_ref()),
+        &mut old,
+        size_of::<RawSigSet, _>()
+    ))?;
+    let old = old.assume_init();
+
+    let flags = SigactionFlags::from_bits_truncate(old.sa_flags as _);
+    Ok(Sigaction {
+        handler: match old.sa_handler {
+            SIG_DFL => SigHandler::Default,
+            SIG_IGN => SigHandler::Ignore,
+            handler if flags.contains(SigactionFlags::SIGINFO) => {
+                SigHandler::SigInfo(transmute::<
+                    usize,
+                    unsafe extern "C" fn(i32, *mut Siginfo, *mut c::c_void),
+                >(handler))
+            }
+            handler => SigHandler::Handler(transmute::<usize, unsafe extern "C" fn(i32)>(handler)),
+        },
+        mask: SigSet {
+            sigset: old.sa_mask,
+        },
+        flags,
+        #[cfg(not(any(target_arch = "mips", target_arch = "mips64", target_arch = "riscv64")))]
+        restorer: old.sa_restorer,
+        #[cfg(any(target_arch = "mips", target_arch = "mips64", target_arch = "riscv64"))]
+        restorer: None,
+    })
+}
+
+pub(crate) unsafe fn sigaltstack(new: Option<&Stack>) -> io::Result<Stack> {
+    let new = new.map(|new| stack_t {
+        ss_sp: new.ss_sp,
+        ss_flags: new.ss_flags.bits() as _,
+        ss_size: new.ss_size as _,
+    });
+    let mut old = MaybeUninit::<stack_t>::uninit();
+    ret(syscall!(__NR_sigaltstack, opt_ref(new.as_ref()), &mut old))?;
+    let old = old.assume_init();
+    Ok(Stack {
+        ss_sp: old.ss_sp,
+        ss_flags: StackFlags::from_bits_truncate(old.ss_flags as _),
+        ss_size: old.ss_size as _,
+    })
+}
+
+pub(crate) unsafe fn clone3(args: &mut CloneArgs<'_>) -> io::Result<Option<Pid>> {
+    let raw = clone_args {
+        flags: args.flags.bits(),
+        pidfd: match &mut args.pidfd {
+            Some(pidfd) => (*pidfd as *mut RawFd) as usize as u64,
+            None => 0,
+        },
+        child_tid: args.child_tid as usize as u64,
+        parent_tid: args.parent_tid as usize as u64,
+        exit_signal: args.exit_signal.map_or(0, |sig| sig as u64),
+        stack: args.stack as usize as u64,
+        stack_size: args.stack_size as u64,
+        tls: args.tls as usize as u64,
+        set_tid: 0,
+        set_tid_size: 0,
+        cgroup: 0,
+    };
+    let pid = ret_c_uint(syscall!(
+        __NR_clone3,
+        by_ref(&raw),
+        size_of::<clone_args, _>()
+    ))?;
+    Ok(Pid::from_raw(pid))
+}
+
+/// `SIG_DFL`, as a `sa_handler` value.
+const SIG_DFL: usize = 0;
+
+/// `SIG_IGN`, as a `sa_handler` value.
+const SIG_IGN: usize = 1;
+
 #[inline]
 pub(crate) unsafe fn fork() -> io::Result<Option<Pid>> {
     let pid = ret_c_uint(syscall_readonly!(
@@ -0,0 +1,131 @@
+use super::super::c;
+use super::super::process::types::RawSigSet;
+use bitflags::bitflags;
+
+bitflags! {
+    /// `SA_*` constants for use with [`Sigaction`].
+    ///
+    /// [`Sigaction`]: crate::runtime::Sigaction
+    pub struct SigactionFlags: u32 {
+        /// `SA_NOCLDSTOP`
+        const NOCLDSTOP = linux_raw_sys::general::SA_NOCLDSTOP;
+        /// `SA_NOCLDWAIT`
+        const NOCLDWAIT = linux_raw_sys::general::SA_NOCLDWAIT;
+        /// `SA_SIGINFO`
+        const SIGINFO = linux_raw_sys::general::SA_SIGINFO;
+        /// `SA_ONSTACK`—Run the handler on the alternate signal stack
+        /// established with [`sigaltstack`].
+        ///
+        /// [`sigaltstack`]: crate::runtime::sigaltstack
+        const ONSTACK = linux_raw_sys::general::SA_ONSTACK;
+        /// `SA_RESTART`
+        const RESTART = linux_raw_sys::general::SA_RESTART;
+        /// `SA_NODEFER`
+        const NODEFER = linux_raw_sys::general::SA_NODEFER;
+        /// `SA_RESETHAND`
+        const RESETHAND = linux_raw_sys::general::SA_RESETHAND;
+        /// `SA_RESTORER`
+        #[cfg(not(any(
+            target_arch = "mips",
+            target_arch = "mips64",
+            target_arch = "riscv64"
+        )))]
+        const RESTORER = linux_raw_sys::general::SA_RESTORER;
+    }
+}
+
+/// `siginfo_t`, as passed to [`SigHandler::SigInfo`] handlers.
+///
+/// [`SigHandler::SigInfo`]: crate::runtime::SigHandler::SigInfo
+pub type Siginfo = linux_raw_sys::general::siginfo_t;
+
+/// The kernel's `struct sigaction`, as used by `rt_sigaction`.
+///
+/// This differs from `linux_raw_sys::general::sigaction`, which is the legacy
+/// `sigaction` layout, on some architectures.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub(crate) struct KernelSigaction {
+    #[cfg(any(target_arch = "mips", target_arch = "mips64"))]
+    pub(crate) sa_flags: c::c_uint,
+    pub(crate) sa_handler: usize,
+    #[cfg(not(any(target_arch = "mips", target_arch = "mips64")))]
+    pub(crate) sa_flags: c::c_ulong,
+    #[cfg(not(any(target_arch = "mips", target_arch = "mips64", target_arch = "riscv64")))]
+    pub(crate) sa_restorer: Option<unsafe extern "C" fn()>,
+    pub(crate) sa_mask: RawSigSet,
+}
+
+bitflags! {
+    /// `SS_*` constants for use with [`Stack`].
+    ///
+    /// [`Stack`]: crate::runtime::Stack
+    pub struct StackFlags: u32 {
+        /// `SS_ONSTACK`
+        const ONSTACK = linux_raw_sys::general::SS_ONSTACK;
+        /// `SS_DISABLE`
+        const DISABLE = linux_raw_sys::general::SS_DISABLE;
+        /// `SS_AUTODISARM`
+        const AUTODISARM = linux_raw_sys::general::SS_AUTODISARM;
+    }
+}
+
+bitflags! {
+    /// `CLONE_*` constants for use with [`CloneArgs`].
+    ///
+    /// [`CloneArgs`]: crate::runtime::CloneArgs
+    pub struct CloneFlags: u64 {
+        /// `CLONE_NEWTIME`
+        const NEWTIME = linux_raw_sys::general::CLONE_NEWTIME as u64;
+        /// `CLONE_VM`
+        const VM = linux_raw_sys::general::CLONE_VM as u64;
+        /// `CLONE_FS`
+        const FS = linux_raw_sys::general::CLONE_FS as u64;
+        /// `CLONE_FILES`
+        const FILES = linux_raw_sys::general::CLONE_FILES as u64;
+        /// `CLONE_SIGHAND`
+        const SIGHAND = linux_raw_sys::general::CLONE_SIGHAND as u64;
+        /// `CLONE_PIDFD`
+        const PIDFD = linux_raw_sys::general::CLONE_PIDFD as u64;
+        /// `CLONE_PTRACE`
+        const PTRACE = linux_raw_sys::general::CLONE_PTRACE as u64;
+        /// `CLONE_VFORK`
+        const VFORK = linux_raw_sys::general::CLONE_VFORK as u64;
+        /// `CLONE_PARENT`
+        const PARENT = linux_raw_sys::general::CLONE_PARENT as u64;
+        /// `CLONE_THREAD`
+        const THREAD = linux_raw_sys::general::CLONE_THREAD as u64;
+        /// `CLONE_NEWNS`
+        const NEWNS = linux_raw_sys::general::CLONE_NEWNS as u64;
+        /// `CLONE_SYSVSEM`
+        const SYSVSEM = linux_raw_sys::general::CLONE_SYSVSEM as u64;
+        /// `CLONE_SETTLS`
+        const SETTLS = linux_raw_sys::general::CLONE_SETTLS as u64;
+        /// `CLONE_PARENT_SETTID`
+        const PARENT_SETTID = linux_raw_sys::general::CLONE_PARENT_SETTID as u64;
+        /// `CLONE_CHILD_CLEARTID`
+        const CHILD_CLEARTID = linux_raw_sys::general::CLONE_CHILD_CLEARTID as u64;
+        /// `CLONE_UNTRACED`
+        const UNTRACED = linux_raw_sys::general::CLONE_UNTRACED as u64;
+        /// `CLONE_CHILD_SETTID`
+        const CHILD_SETTID = linux_raw_sys::general::CLONE_CHILD_SETTID as u64;
+        /// `CLONE_NEWCGROUP`
+        const NEWCGROUP = linux_raw_sys::general::CLONE_NEWCGROUP as u64;
+        /// `CLONE_NEWUTS`
+        const NEWUTS = linux_raw_sys::general::CLONE_NEWUTS as u64;
+        /// `CLONE_NEWIPC`
+        const NEWIPC = linux_raw_sys::general::CLONE_NEWIPC as u64;
+        /// `CLONE_NEWUSER`
+        const NEWUSER = linux_raw_sys::general::CLONE_NEWUSER as u64;
+        /// `CLONE_NEWPID`
+        const NEWPID = linux_raw_sys::general::CLONE_NEWPID as u64;
+        /// `CLONE_NEWNET`
+        const NEWNET = linux_raw_sys::general::CLONE_NEWNET as u64;
+        /// `CLONE_IO`
+        const IO = linux_raw_sys::general::CLONE_IO as u64;
+        /// `CLONE_CLEAR_SIGHAND`
+        const CLEAR_SIGHAND = linux_raw_sys::general::CLONE_CLEAR_SIGHAND;
+        /// `CLONE_INTO_CGROUP`
+        const INTO_CGROUP = linux_raw_sys::general::CLONE_INTO_CGROUP;
+    }
+}
@@ -6,10 +6,17 @@
 #![allow(unsafe_code)]
 #![allow(clippy::undocumented_unsafe_blocks)]
 
-use super::super::conv::{by_ref, c_int, c_uint, ret, ret_usize, ret_usize_infallible, zero};
-use crate::io;
-use crate::process::{Pid, RawNonZeroPid};
-use crate::thread::{ClockId, FutexFlags, FutexOperation, NanosleepRelativeResult, Timespec};
+use super::super::conv::{
+    by_mut, by_ref, c_int, c_uint, ret, ret_c_uint, ret_owned_fd, ret_usize, ret_usize_infallible,
+    size_of, zero,
+};
+use crate::fd::{AsRawFd, BorrowedFd};
+use crate::io::{self, OwnedFd};
+use crate::process::{Pid, RawNonZeroPid, Signal};
+use crate::thread::{
+    ClockId, FutexFlags, FutexOperation, NanosleepRelativeResult, SeccompNotif, SockFilter,
+    Timespec,
+};
 use core::mem::MaybeUninit;
 use linux_raw_sys::general::{__kernel_pid_t, __kernel_timespec, TIMER_ABSTIME};
 #[cfg(target_pointer_width = "32")]
@@ -210,6 +217,11 @@ pub(crate) fn gettid() -> Pid {
     }
 }
 
+#[inline]
+pub(crate) fn tgkill(tgid: Pid, tid: Pid, sig: Signal) -> io::Result<()> {
+    unsafe { ret(syscall_readonly!(__NR_tgkill, tgid, tid, sig)) }
+}
+
 // TODO: This could be de-multiplexed.
 #[inline]
 pub(crate) unsafe fn futex(
@@ -264,6 +276,29 @@ unsafe fn futex_old(
     uaddr2: *mut u32,
     val3: u32,
 ) -> io::Result<usize> {
+    // `utime` is optional for most operations, and for some, such as
+    // `FUTEX_REQUEUE`, it holds an integer rather than a pointer, so only
+    // convert it for the operations that take a timeout.
+    if utime.is_null()
+        || !matches!(
+            op,
+            FutexOperation::Wait
+                | FutexOperation::LockPi
+                | FutexOperation::WaitBitset
+                | FutexOperation::WaitRequeuePi
+        )
+    {
+        return ret_usize(syscall!(
+            __NR_futex,
+            uaddr,
+            (op, flags),
+            c_uint(val),
+            utime,
+            uaddr2,
+            c_uint(val3)
+        ));
+    }
+
     let old_utime = __kernel_old_timespec {
         tv_sec: (*utime).tv_sec.try_into().map_err(|_| io::Errno::INVAL)?,
         tv_nsec: (*utime).tv_nsec.try_into().map_err(|_| io::Errno::INVAL)?,
@@ -278,3 +313,242 @@ unsafe fn futex_old(
         c_uint(val3)
     ))
 }
+
+/// `PR_SET_SECCOMP`
+const PR_SET_SECCOMP: u32 = 22;
+
+/// `PR_SET_NO_NEW_PRIVS`
+const PR_SET_NO_NEW_PRIVS: u32 = 38;
+
+/// `PR_GET_NO_NEW_PRIVS`
+const PR_GET_NO_NEW_PRIVS: u32 = 39;
+
+/// `SECCOMP_MODE_FILTER`
+const SECCOMP_MODE_FILTER: u32 = 2;
+
+/// `SECCOMP_SET_MODE_FILTER`
+const SECCOMP_SET_MODE_FILTER: u32 = 1;
+
+/// `SECCOMP_IOCTL_NOTIF_RECV`
+const SECCOMP_IOCTL_NOTIF_RECV: u32 = 0xc050_2100;
+
+/// `SECCOMP_IOCTL_NOTIF_SEND`
+const SECCOMP_IOCTL_NOTIF_SEND: u32 = 0xc018_2101;
+
+/// `SECCOMP_IOCTL_NOTIF_ID_VALID`
+#[cfg(not(any(
+    target_arch = "mips",
+    target_arch = "mips64",
+    target_arch = "powerpc64"
+)))]
+const SECCOMP_IOCTL_NOTIF_ID_VALID: u32 = 0x4008_2102;
+
+/// `SECCOMP_IOCTL_NOTIF_ID_VALID`
+#[cfg(any(
+    target_arch = "mips",
+    target_arch = "mips64",
+    target_arch = "powerpc64"
+))]
+const SECCOMP_IOCTL_NOTIF_ID_VALID: u32 = 0x8008_2102;
+
+/// `struct seccomp_notif_resp`
+#[repr(C)]
+struct seccomp_notif_resp {
+    id: u64,
+    val: i64,
+    error: i32,
+    flags: u32,
+}
+
+/// `struct sock_fprog`
+#[repr(C)]
+struct sock_fprog {
+    len: u16,
+    filter: *const SockFilter,
+}
+
+#[inline]
+pub(crate) fn seccomp_set_mode_filter(
+    flags: u32,
+    len: u16,
+    filter: *const SockFilter,
+) -> io::Result<u32> {
+    let prog = sock_fprog { len, filter };
+    unsafe {
+        match ret_c_uint(syscall!(
+            __NR_seccomp,
+            c_uint(SECCOMP_SET_MODE_FILTER),
+            c_uint(flags),
+            by_ref(&prog)
+        )) {
+            // `seccomp` was added in Linux 3.17; before that, filters without
+            // flags can be installed with `prctl`.
+            Err(io::Errno::NOSYS) if flags == 0 => {
+                ret(syscall!(
+                    __NR_prctl,
+                    c_uint(PR_SET_SECCOMP),
+                    c_uint(SECCOMP_MODE_FILTER),
+                    by_ref(&prog)
+                ))?;
+                Ok(0)
+            }
+            result => result,
+        }
+    }
+}
+
+#[inline]
+pub(crate) fn set_no_new_privs() -> io::Result<()> {
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_prctl,
+            c_uint(PR_SET_NO_NEW_PRIVS),
+            c_uint(1),
+            zero(),
+            zero(),
+            zero()
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn no_new_privs() -> io::Result<bool> {
+    unsafe {
+        ret_c_uint(syscall_readonly!(
+            __NR_prctl,
+            c_uint(PR_GET_NO_NEW_PRIVS),
+            zero(),
+            zero(),
+            zero(),
+            zero()
+        ))
+        .map(|value| value != 0)
+    }
+}
+
+#[inline]
+pub(crate) fn seccomp_notif_recv(fd: BorrowedFd<'_>) -> io::Result<SeccompNotif> {
+    // The kernel requires the struct to be zeroed.
+    let mut notif = SeccompNotif::default();
+    unsafe {
+        ret(syscall!(
+            __NR_ioctl,
+            fd,
+            c_uint(SECCOMP_IOCTL_NOTIF_RECV),
+            by_mut(&mut notif)
+        ))?;
+    }
+    Ok(notif)
+}
+
+#[inline]
+pub(crate) fn seccomp_notif_send(
+    fd: BorrowedFd<'_>,
+    id: u64,
+    val: i64,
+    error: i32,
+    flags: u32,
+) -> io::Result<()> {
+    let resp = seccomp_notif_resp {
+        id,
+        val,
+        error,
+        flags,
+    };
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_ioctl,
+            fd,
+            c_uint(SECCOMP_IOCTL_NOTIF_SEND),
+            by_ref(&resp)
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn seccomp_notif_id_valid(fd: BorrowedFd<'_>, id: u64) -> io::Result<()> {
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_ioctl,
+            fd,
+            c_uint(SECCOMP_IOCTL_NOTIF_ID_VALID),
+            by_ref(&id)
+        ))
+    }
+}
+
+/// `LANDLOCK_CREATE_RULESET_VERSION`
+const LANDLOCK_CREATE_RULESET_VERSION: u32 = 1 << 0;
+
+/// `LANDLOCK_RULE_PATH_BENEATH`
+const LANDLOCK_RULE_PATH_BENEATH: u32 = 1;
+
+/// `struct landlock_ruleset_attr`
+#[repr(C)]
+struct landlock_ruleset_attr {
+    handled_access_fs: u64,
+}
+
+/// `struct landlock_path_beneath_attr`
+#[repr(C, packed)]
+struct landlock_path_beneath_attr {
+    allowed_access: u64,
+    parent_fd: i32,
+}
+
+#[inline]
+pub(crate) fn landlock_create_ruleset(handled_access_fs: u64, flags: u32) -> io::Result<OwnedFd> {
+    let attr = landlock_ruleset_attr { handled_access_fs };
+    unsafe {
+        ret_owned_fd(syscall_readonly!(
+            __NR_landlock_create_ruleset,
+            by_ref(&attr),
+            size_of::<landlock_ruleset_attr, _>(),
+            c_uint(flags)
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn landlock_abi_version() -> io::Result<u32> {
+    unsafe {
+        ret_c_uint(syscall_readonly!(
+            __NR_landlock_create_ruleset,
+            zero(),
+            zero(),
+            c_uint(LANDLOCK_CREATE_RULESET_VERSION)
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn landlock_add_rule_path_beneath(
+    ruleset_fd: BorrowedFd<'_>,
+    allowed_access: u64,
+    parent_fd: BorrowedFd<'_>,
+) -> io::Result<()> {
+    let attr = landlock_path_beneath_attr {
+        allowed_access,
+        parent_fd: parent_fd.as_raw_fd(),
+    };
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_landlock_add_rule,
+            ruleset_fd,
+            c_uint(LANDLOCK_RULE_PATH_BENEATH),
+            by_ref(&attr),
+            zero()
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn landlock_restrict_self(ruleset_fd: BorrowedFd<'_>, flags: u32) -> io::Result<()> {
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_landlock_restrict_self,
+            ruleset_fd,
+            c_uint(flags)
+        ))
+    }
+}
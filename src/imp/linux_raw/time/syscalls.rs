@@ -7,18 +7,23 @@
 #![allow(clippy::undocumented_unsafe_blocks)]
 
 #[cfg(feature = "time")]
-use super::super::conv::{by_ref, ret_owned_fd};
-use super::super::conv::{ret, ret_infallible};
-use super::types::ClockId;
+use super::super::conv::{by_mut, by_ref, ret_c_int, ret_owned_fd};
+use super::super::conv::{c_int, ret, ret_infallible};
+use super::types::{ClockId, DynamicClockId};
 #[cfg(feature = "time")]
 use crate::fd::BorrowedFd;
 use crate::io;
 #[cfg(feature = "time")]
 use crate::io::OwnedFd;
 #[cfg(feature = "time")]
-use crate::time::{Itimerspec, TimerfdClockId, TimerfdFlags, TimerfdTimerFlags};
+use crate::time::{
+    Itimerspec, TimerfdClockId, TimerfdFlags, TimerfdTimerFlags, Timeval, Timex, TimexModes,
+    TimexState,
+};
 use core::mem::MaybeUninit;
-use linux_raw_sys::general::__kernel_timespec;
+use linux_raw_sys::general::{__kernel_clockid_t, __kernel_timespec};
+#[cfg(feature = "time")]
+use {super::super::c, core::convert::TryFrom};
 #[cfg(feature = "time")]
 #[cfg(target_pointer_width = "32")]
 use {core::convert::TryInto, linux_raw_sys::general::itimerspec as __kernel_old_itimerspec};
@@ -27,7 +32,7 @@ use {core::ptr, linux_raw_sys::general::timespec as __kernel_old_timespec};
 
 // `clock_gettime` has special optimizations via the vDSO.
 #[cfg(feature = "time")]
-pub(crate) use super::super::vdso_wrappers::{clock_gettime, clock_gettime_dynamic};
+pub(crate) use super::super::vdso_wrappers::{clock_gettime, clock_gettime_dynamic, gettimeofday};
 
 #[inline]
 pub(crate) fn clock_getres(which_clock: ClockId) -> __kernel_timespec {
@@ -65,6 +70,122 @@ unsafe fn clock_getres_old(which_clock: ClockId, result: &mut MaybeUninit<__kern
     );
 }
 
+#[cfg(feature = "time")]
+#[inline]
+pub(crate) fn clock_getres_dynamic(
+    which_clock: DynamicClockId<'_>,
+) -> io::Result<__kernel_timespec> {
+    let id = dynamic_clockid(which_clock);
+
+    #[cfg(target_pointer_width = "32")]
+    unsafe {
+        let mut result = MaybeUninit::<__kernel_timespec>::uninit();
+        match ret(syscall!(__NR_clock_getres_time64, c_int(id), &mut result)) {
+            Err(io::Errno::NOSYS) => clock_getres_dynamic_old(id, &mut result)?,
+            otherwise => otherwise?,
+        }
+        Ok(result.assume_init())
+    }
+    #[cfg(target_pointer_width = "64")]
+    unsafe {
+        let mut result = MaybeUninit::<__kernel_timespec>::uninit();
+        ret(syscall!(__NR_clock_getres, c_int(id), &mut result))?;
+        Ok(result.assume_init())
+    }
+}
+
+#[cfg(feature = "time")]
+#[cfg(target_pointer_width = "32")]
+unsafe fn clock_getres_dynamic_old(
+    id: __kernel_clockid_t,
+    result: &mut MaybeUninit<__kernel_timespec>,
+) -> io::Result<()> {
+    let mut old_result = MaybeUninit::<__kernel_old_timespec>::uninit();
+    ret(syscall!(__NR_clock_getres, c_int(id), &mut old_result))?;
+    let old_result = old_result.assume_init();
+    // TODO: With Rust 1.55, we can use MaybeUninit::write here.
+    ptr::write(
+        result.as_mut_ptr(),
+        __kernel_timespec {
+            tv_sec: old_result.tv_sec.into(),
+            tv_nsec: old_result.tv_nsec.into(),
+        },
+    );
+    Ok(())
+}
+
+/// Convert a `DynamicClockId` into the raw clock id the kernel expects.
+#[inline]
+pub(in super::super) fn dynamic_clockid(which_clock: DynamicClockId<'_>) -> __kernel_clockid_t {
+    match which_clock {
+        DynamicClockId::Known(id) => id as __kernel_clockid_t,
+
+        DynamicClockId::Dynamic(fd) => {
+            // See `FD_TO_CLOCKID` in Linux's `clock_gettime` documentation.
+            use crate::imp::fd::AsRawFd;
+            const CLOCKFD: i32 = 3;
+            ((!fd.as_raw_fd() << 3) | CLOCKFD) as __kernel_clockid_t
+        }
+
+        DynamicClockId::RealtimeAlarm => {
+            linux_raw_sys::general::CLOCK_REALTIME_ALARM as __kernel_clockid_t
+        }
+        DynamicClockId::Tai => linux_raw_sys::general::CLOCK_TAI as __kernel_clockid_t,
+        DynamicClockId::Boottime => linux_raw_sys::general::CLOCK_BOOTTIME as __kernel_clockid_t,
+        DynamicClockId::BoottimeAlarm => {
+            linux_raw_sys::general::CLOCK_BOOTTIME_ALARM as __kernel_clockid_t
+        }
+    }
+}
+
+#[cfg(feature = "time")]
+#[inline]
+pub(crate) fn settimeofday(tv: &Timeval) -> io::Result<()> {
+    // Like libc implementations, set the time with `clock_settime`, which
+    // has a y2038-safe variant on 32-bit platforms.
+    if !(0..1_000_000).contains(&tv.tv_usec) {
+        return Err(io::Errno::INVAL);
+    }
+    let timespec = __kernel_timespec {
+        tv_sec: tv.tv_sec,
+        tv_nsec: tv.tv_usec * 1000,
+    };
+
+    #[cfg(target_pointer_width = "32")]
+    unsafe {
+        match ret(syscall_readonly!(
+            __NR_clock_settime64,
+            ClockId::Realtime,
+            by_ref(&timespec)
+        )) {
+            Err(io::Errno::NOSYS) => {
+                let old_timespec = __kernel_old_timespec {
+                    tv_sec: timespec
+                        .tv_sec
+                        .try_into()
+                        .map_err(|_| io::Errno::OVERFLOW)?,
+                    tv_nsec: timespec.tv_nsec as _,
+                };
+                ret(syscall_readonly!(
+                    __NR_clock_settime,
+                    ClockId::Realtime,
+                    by_ref(&old_timespec)
+                ))
+            }
+            otherwise => otherwise,
+        }
+    }
+
+    #[cfg(target_pointer_width = "64")]
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_clock_settime,
+            ClockId::Realtime,
+            by_ref(&timespec)
+        ))
+    }
+}
+
 #[cfg(feature = "time")]
 #[inline]
 pub(crate) fn timerfd_create(clockid: TimerfdClockId, flags: TimerfdFlags) -> io::Result<OwnedFd> {
@@ -225,3 +346,142 @@ unsafe fn timerfd_gettime_old(
     );
     Ok(())
 }
+
+/// Linux's `struct timex`, generic over the type of its `long` fields and its
+/// explicit padding fields.
+///
+/// `RawTimex<i64, c_int>` is `struct __kernel_timex`, as used by
+/// `clock_adjtime64`, and by `clock_adjtime` on 64-bit platforms.
+/// `RawTimex<c_long, ()>` is the old 32-bit `struct timex`.
+#[cfg(feature = "time")]
+#[repr(C)]
+#[derive(Default)]
+struct RawTimex<Long, Pad> {
+    modes: c::c_uint,
+    pad0: Pad,
+    offset: Long,
+    freq: Long,
+    maxerror: Long,
+    esterror: Long,
+    status: c::c_int,
+    pad1: Pad,
+    constant: Long,
+    precision: Long,
+    tolerance: Long,
+    time_sec: Long,
+    time_usec: Long,
+    tick: Long,
+    ppsfreq: Long,
+    jitter: Long,
+    shift: c::c_int,
+    pad2: Pad,
+    stabil: Long,
+    jitcnt: Long,
+    calcnt: Long,
+    errcnt: Long,
+    stbcnt: Long,
+    tai: c::c_int,
+    reserved: [c::c_int; 11],
+}
+
+#[cfg(feature = "time")]
+impl<Long: TryFrom<i64> + Into<i64> + Copy, Pad: Default> RawTimex<Long, Pad> {
+    fn from_timex(timex: &Timex) -> io::Result<Self> {
+        let long = |x: i64| Long::try_from(x).map_err(|_| io::Errno::OVERFLOW);
+        Ok(Self {
+            modes: timex.modes.bits(),
+            pad0: Pad::default(),
+            offset: long(timex.offset)?,
+            freq: long(timex.freq)?,
+            maxerror: long(timex.maxerror)?,
+            esterror: long(timex.esterror)?,
+            status: timex.status,
+            pad1: Pad::default(),
+            constant: long(timex.constant)?,
+            precision: long(timex.precision)?,
+            tolerance: long(timex.tolerance)?,
+            time_sec: long(timex.time.tv_sec)?,
+            time_usec: long(timex.time.tv_usec)?,
+            tick: long(timex.tick)?,
+            ppsfreq: long(timex.ppsfreq)?,
+            jitter: long(timex.jitter)?,
+            shift: timex.shift,
+            pad2: Pad::default(),
+            stabil: long(timex.stabil)?,
+            jitcnt: long(timex.jitcnt)?,
+            calcnt: long(timex.calcnt)?,
+            errcnt: long(timex.errcnt)?,
+            stbcnt: long(timex.stbcnt)?,
+            tai: timex.tai,
+            reserved: [0; 11],
+        })
+    }
+
+    fn to_timex(&self) -> Timex {
+        Timex {
+            modes: TimexModes::from_bits_truncate(self.modes),
+            offset: self.offset.into(),
+            freq: self.freq.into(),
+            maxerror: self.maxerror.into(),
+            esterror: self.esterror.into(),
+            status: self.status,
+            constant: self.constant.into(),
+            precision: self.precision.into(),
+            tolerance: self.tolerance.into(),
+            time: Timeval {
+                tv_sec: self.time_sec.into(),
+                tv_usec: self.time_usec.into(),
+            },
+            tick: self.tick.into(),
+            ppsfreq: self.ppsfreq.into(),
+            jitter: self.jitter.into(),
+            shift: self.shift,
+            stabil: self.stabil.into(),
+            jitcnt: self.jitcnt.into(),
+            calcnt: self.calcnt.into(),
+            errcnt: self.errcnt.into(),
+            stbcnt: self.stbcnt.into(),
+            tai: self.tai,
+        }
+    }
+}
+
+#[cfg(feature = "time")]
+#[inline]
+pub(crate) fn clock_adjtime(id: ClockId, timex: &mut Timex) -> io::Result<TimexState> {
+    #[cfg(target_pointer_width = "32")]
+    unsafe {
+        let mut raw = RawTimex::<i64, c::c_int>::from_timex(timex)?;
+        let state = match ret_c_int(syscall!(__NR_clock_adjtime64, id, by_mut(&mut raw))) {
+            Err(io::Errno::NOSYS) => {
+                let mut old_raw = RawTimex::<c::c_long, ()>::from_timex(timex)?;
+                let state = ret_c_int(syscall!(__NR_clock_adjtime, id, by_mut(&mut old_raw)))?;
+                *timex = old_raw.to_timex();
+                return Ok(timex_state(state));
+            }
+            otherwise => otherwise?,
+        };
+        *timex = raw.to_timex();
+        Ok(timex_state(state))
+    }
+
+    #[cfg(target_pointer_width = "64")]
+    unsafe {
+        let mut raw = RawTimex::<i64, c::c_int>::from_timex(timex)?;
+        let state = ret_c_int(syscall!(__NR_clock_adjtime, id, by_mut(&mut raw)))?;
+        *timex = raw.to_timex();
+        Ok(timex_state(state))
+    }
+}
+
+#[cfg(feature = "time")]
+fn timex_state(state: c::c_int) -> TimexState {
+    match state {
+        0 => TimexState::Ok,
+        1 => TimexState::Ins,
+        2 => TimexState::Del,
+        3 => TimexState::Oop,
+        4 => TimexState::Wait,
+        _ => TimexState::Error,
+    }
+}
@@ -11,6 +11,16 @@ pub type Secs = linux_raw_sys::general::__kernel_time64_t;
 /// A type for the `tv_nsec` field of [`Timespec`].
 pub type Nsecs = i64;
 
+/// `struct timeval`—A time value with microsecond precision.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+pub struct Timeval {
+    /// Seconds.
+    pub tv_sec: Secs,
+
+    /// Microseconds. Must be less than 1_000_000.
+    pub tv_usec: i64,
+}
+
 /// `struct itimerspec` for use with [`timerfd_gettime`] and
 /// [`timerfd_settime`].
 ///
@@ -153,3 +163,77 @@ pub enum TimerfdClockId {
     /// Use of this clock requires the `CAP_WAKE_ALARM` Linux capability.
     BoottimeAlarm = linux_raw_sys::general::CLOCK_BOOTTIME_ALARM,
 }
+
+// linux-raw-sys doesn't define the `timex` constants, so define them here.
+bitflags! {
+    /// `ADJ_*` flags for use with [`clock_adjtime`].
+    ///
+    /// [`clock_adjtime`]: crate::time::clock_adjtime
+    #[derive(Default)]
+    pub struct TimexModes: c::c_uint {
+        /// `ADJ_OFFSET`—Set the time offset from `offset`.
+        const OFFSET = 0x0001;
+
+        /// `ADJ_FREQUENCY`—Set the frequency offset from `freq`.
+        const FREQUENCY = 0x0002;
+
+        /// `ADJ_MAXERROR`—Set the maximum error from `maxerror`.
+        const MAXERROR = 0x0004;
+
+        /// `ADJ_ESTERROR`—Set the estimated error from `esterror`.
+        const ESTERROR = 0x0008;
+
+        /// `ADJ_STATUS`—Set the clock status bits from `status`.
+        const STATUS = 0x0010;
+
+        /// `ADJ_TIMECONST`—Set the PLL time constant from `constant`.
+        const TIMECONST = 0x0020;
+
+        /// `ADJ_TAI`—Set the TAI offset from `constant`.
+        const TAI = 0x0080;
+
+        /// `ADJ_SETOFFSET`—Add `time` to the current time.
+        const SETOFFSET = 0x0100;
+
+        /// `ADJ_MICRO`—Select microsecond resolution.
+        const MICRO = 0x1000;
+
+        /// `ADJ_NANO`—Select nanosecond resolution.
+        const NANO = 0x2000;
+
+        /// `ADJ_TICK`—Set the tick value from `tick`.
+        const TICK = 0x4000;
+
+        /// `ADJ_OFFSET_SINGLESHOT`—Old-fashioned `adjtime` offset adjustment.
+        const OFFSET_SINGLESHOT = 0x8001;
+
+        /// `ADJ_OFFSET_SS_READ`—Read the remaining old-fashioned `adjtime` offset.
+        const OFFSET_SS_READ = 0xa001;
+    }
+}
+
+/// `TIME_*` clock states returned by [`clock_adjtime`].
+///
+/// [`clock_adjtime`]: crate::time::clock_adjtime
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[repr(i32)]
+#[non_exhaustive]
+pub enum TimexState {
+    /// `TIME_OK`—Clock synchronized, no leap second adjustment pending.
+    Ok = 0,
+
+    /// `TIME_INS`—A leap second will be inserted at the end of the UTC day.
+    Ins = 1,
+
+    /// `TIME_DEL`—A leap second will be deleted at the end of the UTC day.
+    Del = 2,
+
+    /// `TIME_OOP`—A leap second insertion is in progress.
+    Oop = 3,
+
+    /// `TIME_WAIT`—A leap second insertion or deletion has completed.
+    Wait = 4,
+
+    /// `TIME_ERROR`—The clock is not synchronized.
+    Error = 5,
+}
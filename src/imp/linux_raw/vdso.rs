@@ -22,6 +22,7 @@ use core::ffi::c_void;
 use core::mem::{align_of, size_of};
 use core::ptr::{null, null_mut};
 
+#[derive(Copy, Clone)]
 pub(super) struct Vdso {
     // Load information
     load_addr: *const Elf_Ehdr,
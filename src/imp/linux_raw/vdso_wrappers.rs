@@ -12,6 +12,9 @@
 use super::conv::{c_int, ret};
 #[cfg(target_arch = "x86")]
 use super::reg::{ArgReg, RetReg, SyscallNumber, A0, A1, A2, A3, A4, A5, R0};
+use super::time::syscalls::dynamic_clockid;
+#[cfg(feature = "time")]
+use super::time::types::Timeval;
 use super::time::types::{ClockId, DynamicClockId, Timespec};
 use super::{c, vdso};
 use crate::io;
@@ -21,9 +24,11 @@ use core::mem::{transmute, MaybeUninit};
 use core::ptr::null_mut;
 use core::sync::atomic::AtomicPtr;
 use core::sync::atomic::Ordering::Relaxed;
+use linux_raw_sys::general::__kernel_timespec;
 #[cfg(target_pointer_width = "32")]
 use linux_raw_sys::general::timespec as __kernel_old_timespec;
-use linux_raw_sys::general::{__kernel_clockid_t, __kernel_timespec};
+#[cfg(target_pointer_width = "64")]
+use linux_raw_sys::general::timeval;
 
 #[inline]
 pub(crate) fn clock_gettime(which_clock: ClockId) -> __kernel_timespec {
@@ -44,25 +49,7 @@ pub(crate) fn clock_gettime(which_clock: ClockId) -> __kernel_timespec {
 
 #[inline]
 pub(crate) fn clock_gettime_dynamic(which_clock: DynamicClockId<'_>) -> io::Result<Timespec> {
-    let id = match which_clock {
-        DynamicClockId::Known(id) => id as __kernel_clockid_t,
-
-        DynamicClockId::Dynamic(fd) => {
-            // See `FD_TO_CLOCKID` in Linux's `clock_gettime` documentation.
-            use crate::imp::fd::AsRawFd;
-            const CLOCKFD: i32 = 3;
-            ((!fd.as_raw_fd() << 3) | CLOCKFD) as __kernel_clockid_t
-        }
-
-        DynamicClockId::RealtimeAlarm => {
-            linux_raw_sys::general::CLOCK_REALTIME_ALARM as __kernel_clockid_t
-        }
-        DynamicClockId::Tai => linux_raw_sys::general::CLOCK_TAI as __kernel_clockid_t,
-        DynamicClockId::Boottime => linux_raw_sys::general::CLOCK_BOOTTIME as __kernel_clockid_t,
-        DynamicClockId::BoottimeAlarm => {
-            linux_raw_sys::general::CLOCK_BOOTTIME_ALARM as __kernel_clockid_t
-        }
-    };
+    let id = dynamic_clockid(which_clock);
 
     // Safety: `CLOCK_GETTIME` contains either null or the address of a
     // function with an ABI like libc `clock_gettime`, and calling it has
@@ -83,6 +70,43 @@ pub(crate) fn clock_gettime_dynamic(which_clock: DynamicClockId<'_>) -> io::Resu
     }
 }
 
+#[cfg(feature = "time")]
+#[inline]
+pub(crate) fn gettimeofday() -> Timeval {
+    // The vDSO `gettimeofday` uses a `long` for the seconds, so only use it
+    // on 64-bit platforms, where that's y2038-safe.
+    //
+    // Safety: `GETTIMEOFDAY` contains either null or the address of a
+    // function with an ABI like libc `gettimeofday`, and calling it has
+    // the side effect of writing to the result buffer, and no others.
+    #[cfg(target_pointer_width = "64")]
+    unsafe {
+        let mut result = MaybeUninit::<timeval>::uninit();
+        let callee = match transmute::<*mut Function, Option<GettimeofdayType>>(
+            GETTIMEOFDAY.load(Relaxed),
+        ) {
+            Some(callee) => callee,
+            None => init_gettimeofday(),
+        };
+        let r0 = callee(result.as_mut_ptr(), null_mut());
+        assert_eq!(r0, 0);
+        let result = result.assume_init();
+        Timeval {
+            tv_sec: result.tv_sec,
+            tv_usec: result.tv_usec,
+        }
+    }
+
+    #[cfg(target_pointer_width = "32")]
+    {
+        let timespec = clock_gettime(ClockId::Realtime);
+        Timeval {
+            tv_sec: timespec.tv_sec,
+            tv_usec: timespec.tv_nsec / 1000,
+        }
+    }
+}
+
 #[cfg(target_arch = "x86")]
 pub(super) mod x86_via_vdso {
     use super::{transmute, ArgReg, Relaxed, RetReg, SyscallNumber, A0, A1, A2, A3, A4, A5, R0};
@@ -207,6 +231,8 @@ pub(super) mod x86_via_vdso {
 }
 
 type ClockGettimeType = unsafe extern "C" fn(c::c_int, *mut Timespec) -> c::c_int;
+#[cfg(target_pointer_width = "64")]
+type GettimeofdayType = unsafe extern "C" fn(*mut timeval, *mut c::c_void) -> c::c_int;
 
 /// The underlying syscall functions are only called from asm, using the
 /// special syscall calling convention to pass arguments and return values,
@@ -221,6 +247,14 @@ fn init_clock_gettime() -> ClockGettimeType {
     unsafe { transmute(CLOCK_GETTIME.load(Relaxed)) }
 }
 
+#[cfg(target_pointer_width = "64")]
+fn init_gettimeofday() -> GettimeofdayType {
+    init();
+    // Safety: Load the function address from static storage that we
+    // just initialized.
+    unsafe { transmute::<*mut Function, GettimeofdayType>(GETTIMEOFDAY.load(Relaxed)) }
+}
+
 #[cfg(target_arch = "x86")]
 fn init_syscall() -> SyscallType {
     init();
@@ -233,6 +267,8 @@ fn init_syscall() -> SyscallType {
 /// placeholder type, and cast it as needed.
 struct Function;
 static mut CLOCK_GETTIME: AtomicPtr<Function> = AtomicPtr::new(null_mut());
+#[cfg(target_pointer_width = "64")]
+static mut GETTIMEOFDAY: AtomicPtr<Function> = AtomicPtr::new(null_mut());
 #[cfg(target_arch = "x86")]
 static mut SYSCALL: AtomicPtr<Function> = AtomicPtr::new(null_mut());
 
@@ -246,6 +282,21 @@ unsafe extern "C" fn rustix_clock_gettime_via_syscall(
     }
 }
 
+/// If the vDSO doesn't have `gettimeofday`, emulate it with
+/// `clock_gettime(CLOCK_REALTIME)`, which may still use the vDSO.
+#[cfg(target_pointer_width = "64")]
+unsafe extern "C" fn rustix_gettimeofday_via_clock_gettime(
+    tv: *mut timeval,
+    _tz: *mut c::c_void,
+) -> c::c_int {
+    let timespec = clock_gettime(ClockId::Realtime);
+    *tv = timeval {
+        tv_sec: timespec.tv_sec,
+        tv_usec: timespec.tv_nsec / 1000,
+    };
+    0
+}
+
 #[cfg(target_pointer_width = "32")]
 unsafe fn _rustix_clock_gettime_via_syscall(
     clockid: c::c_int,
@@ -320,6 +371,17 @@ fn minimal_init() {
                 Relaxed,
             )
             .ok();
+        #[cfg(target_pointer_width = "64")]
+        {
+            GETTIMEOFDAY
+                .compare_exchange(
+                    null_mut(),
+                    rustix_gettimeofday_via_clock_gettime as *mut Function,
+                    Relaxed,
+                    Relaxed,
+                )
+                .ok();
+        }
         #[cfg(target_arch = "x86")]
         {
             SYSCALL
@@ -381,6 +443,28 @@ fn init() {
             }
         }
 
+        // On 64-bit platforms, also look up `gettimeofday`. If it's not
+        // present, keep using the `clock_gettime` emulation.
+        #[cfg(target_arch = "x86_64")]
+        let ptr = vdso.sym(zstr!("LINUX_2.6"), zstr!("__vdso_gettimeofday"));
+        #[cfg(target_arch = "aarch64")]
+        let ptr = vdso.sym(zstr!("LINUX_2.6.39"), zstr!("__kernel_gettimeofday"));
+        #[cfg(target_arch = "riscv64")]
+        let ptr = vdso.sym(zstr!("LINUX_4.15"), zstr!("__vdso_gettimeofday"));
+        #[cfg(target_arch = "powerpc64")]
+        let ptr = vdso.sym(zstr!("LINUX_2.6.15"), zstr!("__kernel_gettimeofday"));
+        #[cfg(target_arch = "mips64")]
+        let ptr = vdso.sym(zstr!("LINUX_2.6"), zstr!("__vdso_gettimeofday"));
+
+        #[cfg(target_pointer_width = "64")]
+        if !ptr.is_null() {
+            // Safety: As above, store the computed function addresses in
+            // static storage.
+            unsafe {
+                GETTIMEOFDAY.store(ptr.cast(), Relaxed);
+            }
+        }
+
         // On x86, also look up the vsyscall entry point.
         #[cfg(target_arch = "x86")]
         {
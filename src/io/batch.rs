@@ -0,0 +1,109 @@
+//! A batch of independent I/O operations.
+
+use crate::fd::{AsFd, BorrowedFd};
+use crate::{imp, io};
+use alloc::vec::Vec;
+use core::fmt;
+
+/// A batch of independent `read`, `write`, and `fsync` operations, which
+/// are submitted together with [`Batch::submit_and_wait`].
+///
+/// Currently, this performs each operation with its own system call, in
+/// order. Operations don't depend on each other: a failed operation doesn't
+/// stop the operations after it, and each result is reported separately.
+/// Operations on the same file descriptor are performed in the order they
+/// were added, but code shouldn't otherwise depend on operations being
+/// sequential, so that the batch can be submitted in fewer system calls
+/// where possible.
+pub struct Batch<'a> {
+    ops: Vec<Op<'a>>,
+}
+
+enum Op<'a> {
+    Read(BorrowedFd<'a>, &'a mut [u8]),
+    Write(BorrowedFd<'a>, &'a [u8]),
+    Fsync(BorrowedFd<'a>),
+}
+
+impl<'a> Batch<'a> {
+    /// Constructs a new empty `Batch`.
+    #[inline]
+    pub const fn new() -> Self {
+        Self { ops: Vec::new() }
+    }
+
+    /// Constructs a new empty `Batch` with space for `capacity` operations.
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            ops: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Adds a `read(fd, buf)` operation to the batch.
+    ///
+    /// Its result is the number of bytes read into `buf`.
+    #[inline]
+    pub fn read<Fd: AsFd>(&mut self, fd: &'a Fd, buf: &'a mut [u8]) -> &mut Self {
+        self.ops.push(Op::Read(fd.as_fd(), buf));
+        self
+    }
+
+    /// Adds a `write(fd, buf)` operation to the batch.
+    ///
+    /// Its result is the number of bytes written from `buf`.
+    #[inline]
+    pub fn write<Fd: AsFd>(&mut self, fd: &'a Fd, buf: &'a [u8]) -> &mut Self {
+        self.ops.push(Op::Write(fd.as_fd(), buf));
+        self
+    }
+
+    /// Adds an `fsync(fd)` operation to the batch.
+    ///
+    /// Its result is 0.
+    #[inline]
+    pub fn fsync<Fd: AsFd>(&mut self, fd: &'a Fd) -> &mut Self {
+        self.ops.push(Op::Fsync(fd.as_fd()));
+        self
+    }
+
+    /// Returns the number of operations in the batch.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.ops.len()
+    }
+
+    /// Returns `true` if the batch has no operations.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.ops.is_empty()
+    }
+
+    /// Performs all the operations in the batch, and waits for them to
+    /// complete.
+    ///
+    /// Returns the result of each operation, in the order they were added.
+    pub fn submit_and_wait(self) -> Vec<io::Result<usize>> {
+        self.ops
+            .into_iter()
+            .map(|op| match op {
+                Op::Read(fd, buf) => imp::io::syscalls::read(fd, buf),
+                Op::Write(fd, buf) => imp::io::syscalls::write(fd, buf),
+                Op::Fsync(fd) => imp::fs::syscalls::fsync(fd).map(|()| 0),
+            })
+            .collect()
+    }
+}
+
+impl<'a> Default for Batch<'a> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> fmt::Debug for Batch<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Batch").field("len", &self.len()).finish()
+    }
+}
@@ -32,6 +32,13 @@ impl Errno {
     pub fn kind(self) -> std::io::ErrorKind {
         std::io::Error::from(self).kind()
     }
+
+    /// Shorthand for `std::io::Error::from(self)`.
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn io_error(self) -> std::io::Error {
+        std::io::Error::from(self)
+    }
 }
 
 impl fmt::Display for Errno {
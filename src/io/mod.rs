@@ -1,5 +1,7 @@
 //! I/O operations.
 
+#[cfg(not(windows))]
+mod batch;
 mod close;
 #[cfg(not(windows))]
 mod dup;
@@ -22,11 +24,24 @@ mod procfs;
 mod read_write;
 #[cfg(not(feature = "std"))]
 mod seek_from;
+#[cfg(not(any(windows, target_os = "redox", target_os = "wasi")))]
+mod select;
 #[cfg(not(windows))]
 mod stdio;
 
+#[cfg(not(any(windows, target_os = "wasi")))]
+pub use crate::fs::fcntl::fcntl_dupfd_cloexec;
+#[cfg(not(windows))]
+pub use crate::fs::fcntl::set_nonblocking;
+#[cfg(not(windows))]
+#[cfg(feature = "fs")]
+pub use crate::fs::fcntl::{fcntl_getfl, fcntl_setfl};
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub use crate::fs::{fcntl_getown, fcntl_setown, fcntl_setsig, Owner};
 #[cfg(any(target_os = "android", target_os = "linux"))]
 pub use crate::imp::io::epoll;
+#[cfg(not(windows))]
+pub use batch::Batch;
 pub use close::close;
 #[cfg(not(any(windows, target_os = "wasi")))]
 pub use dup::{dup, dup2, dup3, DupFlags};
@@ -61,11 +76,16 @@ pub use poll::{poll, PollFd, PollFlags};
 #[cfg(all(feature = "procfs", any(target_os = "android", target_os = "linux")))]
 pub use procfs::{proc_self_fd, proc_self_fdinfo_fd, proc_self_maps, proc_self_pagemap};
 #[cfg(not(windows))]
-pub use read_write::{pread, pwrite, read, readv, write, writev, IoSlice, IoSliceMut};
+pub use read_write::{pread, pwrite, read, read_uninit, readv, write, writev, IoSlice, IoSliceMut};
 #[cfg(not(any(windows, target_os = "redox")))]
 pub use read_write::{preadv, pwritev};
 #[cfg(any(target_os = "android", target_os = "linux"))]
 pub use read_write::{preadv2, pwritev2, ReadWriteFlags};
+#[cfg(not(any(windows, target_os = "redox", target_os = "wasi")))]
+#[cfg(feature = "process")]
+pub use select::pselect;
+#[cfg(not(any(windows, target_os = "redox", target_os = "wasi")))]
+pub use select::{select, FdSet, Timespec, Timeval};
 #[cfg(not(windows))]
 pub use stdio::{stderr, stdin, stdout, take_stderr, take_stdin, take_stdout};
 
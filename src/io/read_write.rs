@@ -1,6 +1,14 @@
 //! `read` and `write`, optionally positioned, optionally vectored
+//!
+//! # Safety
+//!
+//! `read_uninit` converts the part of its buffer which the OS has
+//! initialized into a `&mut [u8]`.
+#![allow(unsafe_code)]
 
 use crate::{imp, io};
+use core::mem::MaybeUninit;
+use core::slice;
 use imp::fd::AsFd;
 
 // Declare `IoSlice` and `IoSliceMut`.
@@ -28,6 +36,36 @@ pub fn read<Fd: AsFd>(fd: Fd, buf: &mut [u8]) -> io::Result<usize> {
     imp::io::syscalls::read(fd.as_fd(), buf)
 }
 
+/// `read(fd, buf)`—Reads from a stream into a buffer which may be
+/// uninitialized.
+///
+/// This avoids the need to zero-fill a buffer before reading into it. On
+/// success, returns `buf` split into two parts: the first is the bytes that
+/// were read, which are initialized, and the second is the rest of `buf`.
+/// Only the first part has defined contents; the second part may or may not
+/// have been written to, and must still be treated as uninitialized, even if
+/// it was initialized before this call.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/read.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/read.2.html
+#[inline]
+#[doc(alias = "read")]
+pub fn read_uninit<Fd: AsFd>(
+    fd: Fd,
+    buf: &mut [MaybeUninit<u8>],
+) -> io::Result<(&mut [u8], &mut [MaybeUninit<u8>])> {
+    let nread = imp::io::syscalls::read_uninit(fd.as_fd(), buf)?;
+    let (init, uninit) = buf.split_at_mut(nread);
+
+    // The OS initialized the first `nread` bytes of `buf`.
+    let init = unsafe { slice::from_raw_parts_mut(init.as_mut_ptr().cast::<u8>(), init.len()) };
+    Ok((init, uninit))
+}
+
 /// `write(fd, buf)`—Writes to a stream.
 ///
 /// # References
@@ -69,6 +107,9 @@ pub fn pwrite<Fd: AsFd>(fd: Fd, buf: &[u8], offset: u64) -> io::Result<usize> {
 
 /// `readv(fd, bufs)`—Reads from a stream into multiple buffers.
 ///
+/// If `bufs` contains more than `IOV_MAX` buffers, this fails with
+/// [`io::Errno::INVAL`].
+///
 /// # References
 ///  - [POSIX]
 ///  - [Linux]
@@ -82,6 +123,9 @@ pub fn readv<Fd: AsFd>(fd: Fd, bufs: &mut [IoSliceMut<'_>]) -> io::Result<usize>
 
 /// `writev(fd, bufs)`—Writes to a stream from multiple buffers.
 ///
+/// If `bufs` contains more than `IOV_MAX` buffers, this fails with
+/// [`io::Errno::INVAL`].
+///
 /// # References
 ///  - [POSIX]
 ///  - [Linux]
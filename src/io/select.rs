@@ -0,0 +1,180 @@
+use crate::fd::{AsFd, AsRawFd, RawFd};
+#[cfg(feature = "process")]
+use crate::process::SigSet;
+use crate::{imp, io};
+use core::convert::TryInto;
+
+pub use imp::time::types::{Timespec, Timeval};
+
+/// `fd_set`—A set of file descriptors, for use with [`select`] and
+/// [`pselect`].
+///
+/// An `FdSet` can only hold file descriptors less than
+/// [`FdSet::SETSIZE`]. In C, passing a larger file descriptor to `FD_SET`
+/// and friends is undefined behavior, and since processes can easily have
+/// more open files than that, it's a common source of memory corruption in
+/// programs using `select`. Here, it panics instead. Programs which may
+/// have that many files open should use [`poll`] instead.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/select.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/select.2.html
+/// [`poll`]: crate::io::poll
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct FdSet {
+    fd_set: imp::io::types::RawFdSet,
+}
+
+impl FdSet {
+    /// `FD_SETSIZE`—The maximum number of file descriptors in an `FdSet`.
+    pub const SETSIZE: usize = imp::io::types::FD_SETSIZE;
+
+    /// Create a new and empty `FdSet`.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            fd_set: imp::io::types::raw_fd_set_new(),
+        }
+    }
+
+    /// Test to see if a file descriptor is in the `FdSet`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `fd` is not less than [`FdSet::SETSIZE`].
+    #[doc(alias = "FD_ISSET")]
+    #[inline]
+    pub fn contains<Fd: AsFd>(&self, fd: Fd) -> bool {
+        imp::io::fd_set::FD_ISSET(index(fd), &self.fd_set)
+    }
+
+    /// Add a file descriptor to the `FdSet`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `fd` is not less than [`FdSet::SETSIZE`].
+    #[doc(alias = "FD_SET")]
+    #[inline]
+    pub fn insert<Fd: AsFd>(&mut self, fd: Fd) {
+        imp::io::fd_set::FD_SET(index(fd), &mut self.fd_set)
+    }
+
+    /// Remove a file descriptor from the `FdSet`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `fd` is not less than [`FdSet::SETSIZE`].
+    #[doc(alias = "FD_CLR")]
+    #[inline]
+    pub fn remove<Fd: AsFd>(&mut self, fd: Fd) {
+        imp::io::fd_set::FD_CLR(index(fd), &mut self.fd_set)
+    }
+
+    /// Zeroes the `FdSet`.
+    #[doc(alias = "FD_ZERO")]
+    #[inline]
+    pub fn clear(&mut self) {
+        imp::io::fd_set::FD_ZERO(&mut self.fd_set)
+    }
+}
+
+impl Default for FdSet {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Return the raw value of `fd`, checking that it fits in an `FdSet`.
+#[inline]
+fn index<Fd: AsFd>(fd: Fd) -> RawFd {
+    let fd = fd.as_fd().as_raw_fd();
+    assert!(
+        (fd as usize) < FdSet::SETSIZE,
+        "fd out of bounds: the fd max is {} but the fd is {}",
+        FdSet::SETSIZE,
+        fd
+    );
+    fd
+}
+
+/// Convert `nfds` to a `c_int`, checking that it's within the bounds of an
+/// `FdSet`.
+#[inline]
+fn nfds(nfds: usize) -> io::Result<imp::c::c_int> {
+    if nfds > FdSet::SETSIZE {
+        return Err(io::Errno::INVAL);
+    }
+    nfds.try_into().map_err(|_| io::Errno::INVAL)
+}
+
+/// `select(nfds, readfds, writefds, exceptfds, timeout)`—Wait for any of
+/// the file descriptors in the given sets to become ready.
+///
+/// `nfds` is one more than the highest file descriptor in any of the sets;
+/// if it's greater than [`FdSet::SETSIZE`], this fails with
+/// [`io::Errno::INVAL`]. On success, the sets are modified to hold only
+/// the file descriptors which are ready, and this returns the total number
+/// of them.
+///
+/// If `timeout` is `None`, this waits indefinitely.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/select.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/select.2.html
+#[inline]
+pub fn select(
+    nfds: usize,
+    readfds: Option<&mut FdSet>,
+    writefds: Option<&mut FdSet>,
+    exceptfds: Option<&mut FdSet>,
+    timeout: Option<&Timeval>,
+) -> io::Result<usize> {
+    imp::io::syscalls::select(
+        self::nfds(nfds)?,
+        readfds.map(|set| &mut set.fd_set),
+        writefds.map(|set| &mut set.fd_set),
+        exceptfds.map(|set| &mut set.fd_set),
+        timeout,
+    )
+}
+
+/// `pselect(nfds, readfds, writefds, exceptfds, timeout, sigmask)`—Wait for
+/// any of the file descriptors in the given sets to become ready, with a
+/// nanosecond-precision timeout.
+///
+/// This is like [`select`], except that if `sigmask` is `Some`, the calling
+/// thread's signal mask is replaced by it for the duration of the wait.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/pselect.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/pselect.2.html
+#[cfg(feature = "process")]
+#[inline]
+pub fn pselect(
+    nfds: usize,
+    readfds: Option<&mut FdSet>,
+    writefds: Option<&mut FdSet>,
+    exceptfds: Option<&mut FdSet>,
+    timeout: Option<&Timespec>,
+    sigmask: Option<&SigSet>,
+) -> io::Result<usize> {
+    imp::io::syscalls::pselect(
+        self::nfds(nfds)?,
+        readfds.map(|set| &mut set.fd_set),
+        writefds.map(|set| &mut set.fd_set),
+        exceptfds.map(|set| &mut set.fd_set),
+        timeout,
+        sigmask.map(|sigmask| &sigmask.sigset),
+    )
+}
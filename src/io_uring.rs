@@ -41,6 +41,10 @@ pub fn io_uring_setup(entries: u32, params: &mut io_uring_params) -> io::Result<
 /// `io_uring_register(fd, opcode, arg, nr_args)`—Register files or user
 /// buffers for asynchronous I/O.
 ///
+/// On success, this returns the non-negative value returned by the
+/// operation, such as the ID assigned by `IORING_REGISTER_PERSONALITY`, or
+/// zero for operations which don't return a value.
+///
 /// # Safety
 ///
 /// io_uring operates on raw pointers and raw file descriptors. Users are
@@ -57,10 +61,42 @@ pub unsafe fn io_uring_register<Fd: AsFd>(
     opcode: IoringRegisterOp,
     arg: *const c_void,
     nr_args: u32,
-) -> io::Result<()> {
+) -> io::Result<u32> {
     imp::io_uring::syscalls::io_uring_register(fd.as_fd(), opcode, arg, nr_args)
 }
 
+/// `io_uring_register(fd, IORING_REGISTER_PROBE, probe, 256)`—Query which
+/// opcodes the kernel supports.
+///
+/// Use [`supports_opcode`] or [`IoringProbe::supports`] to test the result.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man.archlinux.org/man/io_uring_register.2.en
+#[inline]
+#[doc(alias = "IORING_REGISTER_PROBE")]
+pub fn io_uring_register_probe<Fd: AsFd>(fd: Fd, probe: &mut IoringProbe) -> io::Result<()> {
+    // Linux requires the probe to be zeroed.
+    *probe = IoringProbe::new();
+    unsafe {
+        imp::io_uring::syscalls::io_uring_register(
+            fd.as_fd(),
+            IoringRegisterOp::RegisterProbe,
+            (probe as *mut IoringProbe).cast(),
+            IoringProbe::MAX_OPS as u32,
+        )
+        .map(|_| ())
+    }
+}
+
+/// Test whether `probe`, as filled in by [`io_uring_register_probe`],
+/// reports that `op` is supported.
+#[inline]
+pub fn supports_opcode(probe: &IoringProbe, op: IoringOp) -> bool {
+    probe.supports(op)
+}
+
 /// `io_uring_enter(fd, to_submit, min_complete, flags, arg, size)`—Initiate
 /// and/or complete asynchronous I/O.
 ///
@@ -844,6 +880,80 @@ pub struct io_uring_probe_op {
     pub resv2: u32,
 }
 
+/// A `struct io_uring_probe` with room for every opcode, for use with
+/// [`io_uring_register_probe`].
+///
+/// Unlike [`io_uring_probe`], this represents opcodes as raw integers, so
+/// it can hold opcodes from kernels newer than this version of rustix.
+#[repr(C)]
+#[derive(Clone)]
+pub struct IoringProbe {
+    last_op: u8,
+    ops_len: u8,
+    resv: u16,
+    resv2: [u32; 3],
+    ops: [RawProbeOp; IoringProbe::MAX_OPS],
+}
+
+/// A `struct io_uring_probe_op` with a raw opcode.
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Default)]
+struct RawProbeOp {
+    op: u8,
+    resv: u8,
+    flags: IoringOpFlags,
+    resv2: u32,
+}
+
+impl IoringProbe {
+    /// The maximum number of opcodes Linux reports in a probe.
+    pub const MAX_OPS: usize = 256;
+
+    /// Create a new and empty `IoringProbe`.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            last_op: 0,
+            ops_len: 0,
+            resv: 0,
+            resv2: [0; 3],
+            ops: [RawProbeOp::default(); Self::MAX_OPS],
+        }
+    }
+
+    /// Return the raw value of the last opcode the kernel knows about.
+    #[inline]
+    pub fn last_op(&self) -> u8 {
+        self.last_op
+    }
+
+    /// Test whether the kernel supports `op`.
+    #[inline]
+    pub fn supports(&self, op: IoringOp) -> bool {
+        let op = op as u8;
+        op < self.ops_len
+            && self.ops[op as usize]
+                .flags
+                .contains(IoringOpFlags::SUPPORTED)
+    }
+}
+
+impl Default for IoringProbe {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl core::fmt::Debug for IoringProbe {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        fmt.debug_struct("IoringProbe")
+            .field("last_op", &self.last_op)
+            .field("ops", &&self.ops[..self.ops_len as usize])
+            .finish()
+    }
+}
+
 #[allow(missing_docs)]
 #[repr(C, align(8))]
 #[derive(Debug, Copy, Clone, Default)]
@@ -1132,6 +1242,18 @@ fn io_uring_layouts() {
     );
     check_struct!(io_uring_probe, last_op, ops_len, resv, resv2, ops);
     check_struct!(io_uring_probe_op, op, resv, flags, resv2);
+    assert_eq!(
+        (size_of::<IoringProbe>(), align_of::<IoringProbe>()),
+        (
+            size_of::<sys::io_uring_probe>()
+                + IoringProbe::MAX_OPS * size_of::<sys::io_uring_probe_op>(),
+            align_of::<sys::io_uring_probe>()
+        )
+    );
+    assert_eq!(
+        offset_of!(IoringProbe, ops),
+        offset_of!(sys::io_uring_probe, ops)
+    );
     check_struct!(io_uring_files_update, offset, resv, fds);
     check_struct!(io_uring_rsrc_register, nr, resv, resv2, data, tags);
     check_struct!(io_uring_rsrc_update, offset, resv, data);
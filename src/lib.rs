@@ -179,6 +179,10 @@ pub mod mm;
 #[cfg_attr(doc_cfg, doc(cfg(feature = "net")))]
 pub mod net;
 #[cfg(not(windows))]
+#[cfg(feature = "param")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "param")))]
+pub mod param;
+#[cfg(not(windows))]
 #[cfg(any(feature = "fs", feature = "net"))]
 #[cfg_attr(doc_cfg, doc(cfg(any(feature = "fs", feature = "net"))))]
 pub mod path;
@@ -186,6 +190,10 @@ pub mod path;
 #[cfg(feature = "process")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "process")))]
 pub mod process;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg(feature = "pty")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "pty")))]
+pub mod pty;
 #[cfg(not(windows))]
 #[cfg(feature = "rand")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "rand")))]
@@ -218,6 +226,10 @@ pub mod runtime;
 #[cfg(not(feature = "fs"))]
 pub(crate) mod fs;
 #[cfg(not(windows))]
+#[cfg(not(feature = "param"))]
+#[cfg(feature = "process")]
+pub(crate) mod param;
+#[cfg(not(windows))]
 #[cfg(not(any(feature = "fs", feature = "net")))]
 pub(crate) mod path;
 #[cfg(not(windows))]
@@ -0,0 +1,295 @@
+//! The Linux NUMA memory policy API.
+//!
+//! On kernels built without NUMA support, these functions fail with
+//! [`io::Errno::NOSYS`].
+//!
+//! # Safety
+//!
+//! `mbind` operates on a raw pointer, and may migrate the memory it refers
+//! to.
+#![allow(unsafe_code)]
+
+use crate::{imp, io};
+use core::ffi::c_void;
+use core::mem::size_of;
+use core::ptr::null_mut;
+
+/// `MPOL_*` constants for use with [`set_mempolicy`], [`get_mempolicy`],
+/// and [`mbind`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[repr(u32)]
+pub enum MempolicyMode {
+    /// `MPOL_DEFAULT`—Use the process policy, or allocate on the node of the
+    /// CPU which triggered the allocation.
+    Default = 0,
+
+    /// `MPOL_PREFERRED`—Prefer allocating on a single node.
+    Preferred = 1,
+
+    /// `MPOL_BIND`—Only allocate on the given nodes.
+    Bind = 2,
+
+    /// `MPOL_INTERLEAVE`—Interleave allocations across the given nodes.
+    Interleave = 3,
+
+    /// `MPOL_LOCAL`—Allocate on the node of the CPU which triggered the
+    /// allocation.
+    Local = 4,
+
+    /// `MPOL_PREFERRED_MANY`—Prefer allocating on any of the given nodes
+    /// (since Linux 5.15).
+    ///
+    /// Older kernels fail with [`io::Errno::INVAL`] when setting this mode.
+    PreferredMany = 5,
+}
+
+impl MempolicyMode {
+    /// Convert a raw `MPOL_*` value into a `MempolicyMode`, ignoring any
+    /// `MPOL_F_*` mode flags.
+    fn from_raw(raw: u32) -> io::Result<Self> {
+        match raw & !MPOL_MODE_FLAGS {
+            0 => Ok(Self::Default),
+            1 => Ok(Self::Preferred),
+            2 => Ok(Self::Bind),
+            3 => Ok(Self::Interleave),
+            4 => Ok(Self::Local),
+            5 => Ok(Self::PreferredMany),
+            _ => Err(io::Errno::INVAL),
+        }
+    }
+}
+
+bitflags::bitflags! {
+    /// `MPOL_F_*` flags for use with [`get_mempolicy`].
+    pub struct GetMempolicyFlags: u32 {
+        /// `MPOL_F_ADDR`—Return the policy of the memory at `addr`.
+        const ADDR = 1 << 1;
+
+        /// `MPOL_F_MEMS_ALLOWED`—Return the set of nodes the thread is
+        /// allowed to use.
+        const MEMS_ALLOWED = 1 << 2;
+    }
+}
+
+bitflags::bitflags! {
+    /// `MPOL_MF_*` flags for use with [`mbind`].
+    pub struct MbindFlags: u32 {
+        /// `MPOL_MF_STRICT`—Fail with [`io::Errno::IO`] if existing pages
+        /// don't follow the policy.
+        const STRICT = 1 << 0;
+
+        /// `MPOL_MF_MOVE`—Move existing pages used only by this process to
+        /// follow the policy.
+        const MOVE = 1 << 1;
+
+        /// `MPOL_MF_MOVE_ALL`—Move all existing pages to follow the policy.
+        ///
+        /// This requires the `CAP_SYS_NICE` capability.
+        const MOVE_ALL = 1 << 2;
+    }
+}
+
+/// `MPOL_F_NODE`
+const MPOL_F_NODE: u32 = 1 << 0;
+
+/// `MPOL_MODE_FLAGS`
+const MPOL_MODE_FLAGS: u32 = 0x7 << 13;
+
+// `usize::BITS` requires Rust 1.53.
+#[allow(clippy::manual_bits)]
+const BITS_PER_WORD: usize = size_of::<usize>() * 8;
+
+/// `NodeMask` represents a bit-mask of NUMA nodes.
+///
+/// `NodeMask`s are used by [`set_mempolicy`], [`get_mempolicy`], and
+/// [`mbind`].
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct NodeMask {
+    bits: [usize; NodeMask::MAX_NODE / BITS_PER_WORD],
+}
+
+impl NodeMask {
+    /// The maximum number of nodes in a `NodeMask`.
+    ///
+    /// This is the largest `MAX_NUMNODES` Linux supports, as `get_mempolicy`
+    /// requires the mask to be at least that large.
+    pub const MAX_NODE: usize = 1024;
+
+    /// Create a new and empty `NodeMask`.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            bits: [0; Self::MAX_NODE / BITS_PER_WORD],
+        }
+    }
+
+    /// Test to see if a node is in the `NodeMask`.
+    ///
+    /// `field` is the node id to test.
+    #[inline]
+    pub fn is_set(&self, field: usize) -> bool {
+        self.bits[field / BITS_PER_WORD] & (1 << (field % BITS_PER_WORD)) != 0
+    }
+
+    /// Add a node to the `NodeMask`.
+    ///
+    /// `field` is the node id to add.
+    #[inline]
+    pub fn set(&mut self, field: usize) {
+        self.bits[field / BITS_PER_WORD] |= 1 << (field % BITS_PER_WORD)
+    }
+
+    /// Remove a node from the `NodeMask`.
+    ///
+    /// `field` is the node id to remove.
+    #[inline]
+    pub fn unset(&mut self, field: usize) {
+        self.bits[field / BITS_PER_WORD] &= !(1 << (field % BITS_PER_WORD))
+    }
+
+    /// Count the number of nodes set in the `NodeMask`.
+    #[inline]
+    pub fn count(&self) -> u32 {
+        self.bits.iter().map(|word| word.count_ones()).sum()
+    }
+
+    /// Zeroes the `NodeMask`.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.bits = [0; Self::MAX_NODE / BITS_PER_WORD];
+    }
+
+    /// The `maxnode` argument to pass along with this mask. Linux ignores the
+    /// last bit of `maxnode`.
+    #[inline]
+    fn maxnode() -> usize {
+        Self::MAX_NODE + 1
+    }
+}
+
+impl Default for NodeMask {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// `set_mempolicy(mode, nodemask, maxnode)`—Set the calling thread's
+/// NUMA memory policy.
+///
+/// For [`MempolicyMode::Default`] and [`MempolicyMode::Local`], `nodemask`
+/// must be empty.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/set_mempolicy.2.html
+#[inline]
+pub fn set_mempolicy(mode: MempolicyMode, nodemask: &NodeMask) -> io::Result<()> {
+    unsafe {
+        imp::mm::syscalls::set_mempolicy(mode as u32, nodemask.bits.as_ptr(), NodeMask::maxnode())
+    }
+}
+
+/// `get_mempolicy(&mode, nodemask, maxnode, addr, flags)`—Get the NUMA
+/// memory policy of the calling thread, or of the memory at `addr`.
+///
+/// `addr` must be `Some` if and only if `flags` contains
+/// [`GetMempolicyFlags::ADDR`]. With [`GetMempolicyFlags::MEMS_ALLOWED`],
+/// the returned mask is the set of nodes the thread is allowed to use.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/get_mempolicy.2.html
+#[inline]
+pub fn get_mempolicy(
+    addr: Option<*mut c_void>,
+    flags: GetMempolicyFlags,
+) -> io::Result<(MempolicyMode, NodeMask)> {
+    let mut nodemask = NodeMask::new();
+    let mode = unsafe {
+        imp::mm::syscalls::get_mempolicy(
+            nodemask.bits.as_mut_ptr(),
+            NodeMask::maxnode(),
+            addr.unwrap_or_else(null_mut),
+            flags.bits(),
+        )?
+    };
+    if flags.contains(GetMempolicyFlags::MEMS_ALLOWED) {
+        return Ok((MempolicyMode::Default, nodemask));
+    }
+    Ok((MempolicyMode::from_raw(mode)?, nodemask))
+}
+
+/// `get_mempolicy(&node, NULL, 0, addr, MPOL_F_NODE | MPOL_F_ADDR)`—Return
+/// the NUMA node of the page at `addr`.
+///
+/// If the page isn't present yet, this allocates it.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/get_mempolicy.2.html
+// The kernel only uses `addr` to look up a mapping, and fails with
+// `EFAULT` if there isn't one.
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+#[inline]
+pub fn get_mempolicy_node(addr: *mut c_void) -> io::Result<u32> {
+    unsafe {
+        imp::mm::syscalls::get_mempolicy(
+            null_mut(),
+            0,
+            addr,
+            MPOL_F_NODE | GetMempolicyFlags::ADDR.bits(),
+        )
+    }
+}
+
+/// `getcpu(NULL, &node, NULL)`—Return the NUMA node the calling thread is
+/// running on.
+///
+/// By the time this returns, the thread may have been migrated to another
+/// node.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/getcpu.2.html
+#[inline]
+#[doc(alias = "getcpu")]
+pub fn numa_node() -> io::Result<u32> {
+    imp::mm::syscalls::getcpu_node()
+}
+
+/// `mbind(addr, len, mode, nodemask, maxnode, flags)`—Set the
+/// NUMA memory policy for a range of memory.
+///
+/// # Safety
+///
+/// `addr` must be a valid pointer to memory that is appropriate to call
+/// `mbind` on. With [`MbindFlags::MOVE`] or [`MbindFlags::MOVE_ALL`], this
+/// may migrate the memory to other nodes.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/mbind.2.html
+#[inline]
+pub unsafe fn mbind(
+    addr: *mut c_void,
+    len: usize,
+    mode: MempolicyMode,
+    nodemask: &NodeMask,
+    flags: MbindFlags,
+) -> io::Result<()> {
+    imp::mm::syscalls::mbind(
+        addr,
+        len,
+        mode as u32,
+        nodemask.bits.as_ptr(),
+        NodeMask::maxnode(),
+        flags.bits(),
+    )
+}
@@ -0,0 +1,41 @@
+//! The `mincore` function.
+//!
+//! # Safety
+//!
+//! `mincore` operates on a raw pointer.
+#![allow(unsafe_code)]
+
+use crate::{imp, io};
+use core::ffi::c_void;
+
+/// `mincore(addr, len, vec)`—Reports which pages of a mapping are resident
+/// in memory.
+///
+/// On success, bit 0 of each byte of `vec` is set if the corresponding page
+/// is resident; the other bits are reserved. `vec` must have at least one
+/// byte for each page in `addr..addr + len`, or this fails with
+/// [`io::Errno::INVAL`] without making the call.
+///
+/// # Safety
+///
+/// `addr` must be page-aligned, and `addr..addr + len` must be a range of
+/// mapped memory that is appropriate to call `mincore` on.
+///
+/// # References
+///  - [Linux]
+///  - [FreeBSD]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/mincore.2.html
+/// [FreeBSD]: https://www.freebsd.org/cgi/man.cgi?query=mincore&sektion=2
+#[inline]
+pub unsafe fn mincore(addr: *mut c_void, len: usize, vec: &mut [u8]) -> io::Result<()> {
+    let page_size = imp::process::auxv::page_size();
+    let pages = match len {
+        0 => 0,
+        len => (len - 1) / page_size + 1,
+    };
+    if vec.len() < pages {
+        return Err(io::Errno::INVAL);
+    }
+    imp::mm::syscalls::mincore(addr, len, vec.as_mut_ptr())
+}
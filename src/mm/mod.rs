@@ -2,15 +2,61 @@
 
 #[cfg(not(any(target_os = "redox", target_os = "wasi")))]
 mod madvise;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod mempolicy;
+#[cfg(any(
+    target_os = "android",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "ios",
+    target_os = "linux",
+    target_os = "macos",
+    target_os = "netbsd",
+    target_os = "openbsd",
+))]
+mod mincore;
 #[cfg(not(target_os = "wasi"))]
 mod mmap;
 #[cfg(not(target_os = "wasi"))]
 mod msync;
+#[cfg(any(
+    linux_raw,
+    all(
+        libc,
+        any(target_os = "android", target_os = "linux"),
+        any(
+            target_arch = "aarch64",
+            target_arch = "arm",
+            target_arch = "mips",
+            target_arch = "mips64",
+            target_arch = "riscv64",
+            target_arch = "x86",
+            target_arch = "x86_64"
+        )
+    )
+))]
+mod pkey;
 #[cfg(any(target_os = "android", target_os = "linux"))]
 mod userfaultfd;
 
 #[cfg(not(any(target_os = "redox", target_os = "wasi")))]
 pub use madvise::{madvise, Advice};
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub use mempolicy::{
+    get_mempolicy, get_mempolicy_node, mbind, numa_node, set_mempolicy, GetMempolicyFlags,
+    MbindFlags, MempolicyMode, NodeMask,
+};
+#[cfg(any(
+    target_os = "android",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "ios",
+    target_os = "linux",
+    target_os = "macos",
+    target_os = "netbsd",
+    target_os = "openbsd",
+))]
+pub use mincore::mincore;
 #[cfg(not(target_os = "wasi"))]
 pub use mmap::{
     mlock, mmap, mmap_anonymous, mprotect, munlock, munmap, MapFlags, MprotectFlags, ProtFlags,
@@ -21,5 +67,22 @@ pub use mmap::{mlock_with, MlockFlags};
 pub use mmap::{mremap, mremap_fixed, MremapFlags};
 #[cfg(not(target_os = "wasi"))]
 pub use msync::{msync, MsyncFlags};
+#[cfg(any(
+    linux_raw,
+    all(
+        libc,
+        any(target_os = "android", target_os = "linux"),
+        any(
+            target_arch = "aarch64",
+            target_arch = "arm",
+            target_arch = "mips",
+            target_arch = "mips64",
+            target_arch = "riscv64",
+            target_arch = "x86",
+            target_arch = "x86_64"
+        )
+    )
+))]
+pub use pkey::{pkey_alloc, pkey_free, pkey_mprotect, Pkey, PkeyAccessRights};
 #[cfg(any(target_os = "android", target_os = "linux"))]
 pub use userfaultfd::{userfaultfd, UserfaultfdFlags};
@@ -0,0 +1,98 @@
+//! Linux memory protection keys.
+//!
+//! Protection keys are only supported on some architectures, such as x86
+//! with PKU. Elsewhere, [`pkey_alloc`] fails with [`io::Errno::NOSYS`] or
+//! [`io::Errno::INVAL`].
+//!
+//! # Safety
+//!
+//! `pkey_mprotect` operates on a raw pointer.
+#![allow(unsafe_code)]
+
+use crate::mm::ProtFlags;
+use crate::{imp, io};
+use core::ffi::c_void;
+
+bitflags::bitflags! {
+    /// `PKEY_DISABLE_*` flags for use with [`pkey_alloc`].
+    pub struct PkeyAccessRights: u32 {
+        /// `PKEY_DISABLE_ACCESS`—Disable all data access to memory
+        /// protected by the key.
+        const DISABLE_ACCESS = 0x1;
+
+        /// `PKEY_DISABLE_WRITE`—Disable writes to memory protected by the
+        /// key.
+        const DISABLE_WRITE = 0x2;
+    }
+}
+
+/// A memory protection key, allocated with [`pkey_alloc`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[repr(transparent)]
+pub struct Pkey(u32);
+
+impl Pkey {
+    /// Converts a raw key number into a `Pkey`.
+    #[inline]
+    pub const fn from_raw(raw: u32) -> Self {
+        Self(raw)
+    }
+
+    /// Converts a `Pkey` into its raw key number.
+    #[inline]
+    pub const fn as_raw(self) -> u32 {
+        self.0
+    }
+}
+
+/// `pkey_alloc(flags, access_rights)`—Allocate a memory protection key.
+///
+/// `flags` is reserved, and must be 0. The access rights apply to the
+/// calling thread, and are the initial rights of other threads created
+/// afterward. There are only a few keys, typically 16 on x86, so once
+/// they're all allocated, this fails with [`io::Errno::NOSPC`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/pkey_alloc.2.html
+#[inline]
+pub fn pkey_alloc(flags: u32, access_rights: PkeyAccessRights) -> io::Result<Pkey> {
+    imp::mm::syscalls::pkey_alloc(flags, access_rights.bits()).map(Pkey)
+}
+
+/// `pkey_free(pkey)`—Free a memory protection key.
+///
+/// Memory which is still protected by `pkey` keeps it; if the key is
+/// allocated again, the new owner's access rights apply to that memory.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/pkey_alloc.2.html
+#[inline]
+pub fn pkey_free(pkey: Pkey) -> io::Result<()> {
+    imp::mm::syscalls::pkey_free(pkey.0)
+}
+
+/// `pkey_mprotect(ptr, len, prot, pkey)`—Change the protection flags of a
+/// range of memory, and associate it with a memory protection key.
+///
+/// # Safety
+///
+/// `ptr` must be a valid pointer to memory that is appropriate to call
+/// `pkey_mprotect` on, as with `mprotect`.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/pkey_mprotect.2.html
+#[inline]
+pub unsafe fn pkey_mprotect(
+    ptr: *mut c_void,
+    len: usize,
+    prot: ProtFlags,
+    pkey: Pkey,
+) -> io::Result<()> {
+    imp::mm::syscalls::pkey_mprotect(ptr, len, prot, pkey.0)
+}
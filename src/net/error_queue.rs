@@ -0,0 +1,33 @@
+use crate::{imp, io};
+use alloc::vec::Vec;
+use imp::fd::AsFd;
+
+pub use imp::net::types::SockExtendedErr;
+
+/// Receives all the errors in a socket's error queue.
+///
+/// This repeatedly calls `recvmsg` with `MSG_ERRQUEUE`, without blocking,
+/// until the queue is empty, and returns the `IP_RECVERR` and `IPV6_RECVERR`
+/// errors it received, in order. Errors are only queued on sockets that have
+/// enabled them, such as with [`set_ip_recverr`].
+///
+/// # References
+///  - [Linux `recvmsg`]
+///  - [Linux `ip`]
+///
+/// [Linux `recvmsg`]: https://man7.org/linux/man-pages/man2/recvmsg.2.html
+/// [Linux `ip`]: https://man7.org/linux/man-pages/man7/ip.7.html
+/// [`set_ip_recverr`]: crate::net::sockopt::set_ip_recverr
+#[doc(alias = "MSG_ERRQUEUE")]
+pub fn drain_error_queue<Fd: AsFd>(fd: Fd) -> io::Result<Vec<SockExtendedErr>> {
+    let fd = fd.as_fd();
+    let mut errors = Vec::new();
+    loop {
+        match imp::net::syscalls::recv_error_queue(fd) {
+            Ok(Some(err)) => errors.push(err),
+            Ok(None) => (),
+            Err(io::Errno::AGAIN) => return Ok(errors),
+            Err(err) => return Err(err),
+        }
+    }
+}
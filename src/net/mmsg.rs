@@ -0,0 +1,211 @@
+//! `recvmmsg` and `sendmmsg`, for receiving and sending batches of
+//! messages.
+//!
+//! # Safety
+//!
+//! [`MMsgHdr`] holds raw pointers to the buffers and address it borrows,
+//! which are passed to the kernel.
+#![allow(unsafe_code)]
+
+use crate::imp::c;
+use crate::imp::time::types::Timespec;
+use crate::io::{self, IoSlice, IoSliceMut};
+use crate::net::{RecvFlags, SendFlags, SocketAddrAny, SocketAddrStorage};
+use crate::{imp, utils};
+use core::fmt;
+use core::marker::PhantomData;
+use core::mem::{size_of, zeroed};
+use core::ptr::null_mut;
+use imp::fd::AsFd;
+
+/// `struct mmsghdr`—A message for use with [`recvmmsg`] and [`sendmmsg`].
+///
+/// Each message has its own list of buffers, and optionally an address
+/// buffer. After a call, [`MMsgHdr::msg_len`] returns the number of bytes
+/// received or sent for this message.
+#[repr(transparent)]
+pub struct MMsgHdr<'a> {
+    raw: c::mmsghdr,
+    _p: PhantomData<&'a mut [u8]>,
+}
+
+impl<'a> MMsgHdr<'a> {
+    /// Construct a message to receive into `bufs`, for use with
+    /// [`recvmmsg`].
+    ///
+    /// If `addr` is `Some`, the sender's address is written to it, and can
+    /// be retrieved with [`MMsgHdr::addr`].
+    ///
+    /// The message can be reused for multiple calls to [`recvmmsg`]; each
+    /// call resets its address length and flags before receiving.
+    #[inline]
+    pub fn new_recv(
+        bufs: &'a mut [IoSliceMut<'_>],
+        addr: Option<&'a mut SocketAddrStorage>,
+    ) -> Self {
+        let name = match addr {
+            Some(addr) => utils::as_mut_ptr(addr).cast(),
+            None => null_mut(),
+        };
+        // `recvmmsg` sets the address length before receiving; until then,
+        // leave it zero so that `addr` doesn't decode uninitialized storage.
+        unsafe { Self::new(name, 0, bufs.as_mut_ptr().cast(), bufs.len()) }
+    }
+
+    /// Construct a message to send from `bufs`, for use with [`sendmmsg`]
+    /// on a connected socket.
+    #[inline]
+    pub fn new_send(bufs: &'a [IoSlice<'_>]) -> Self {
+        unsafe { Self::new(null_mut(), 0, bufs.as_ptr() as *mut _, bufs.len()) }
+    }
+
+    /// Construct a message to send from `bufs` to `addr`, for use with
+    /// [`sendmmsg`].
+    ///
+    /// `addr` is encoded into `storage`, which must live as long as the
+    /// message.
+    #[inline]
+    pub fn new_sendto(
+        bufs: &'a [IoSlice<'_>],
+        addr: &SocketAddrAny,
+        storage: &'a mut SocketAddrStorage,
+    ) -> Self {
+        let storage = utils::as_mut_ptr(storage);
+        unsafe {
+            let namelen = addr.write(storage);
+            Self::new(storage.cast(), namelen, bufs.as_ptr() as *mut _, bufs.len())
+        }
+    }
+
+    unsafe fn new(name: *mut c::c_void, namelen: usize, iov: *mut c::iovec, iovlen: usize) -> Self {
+        // Some libc implementations have padding fields in `msghdr`, so
+        // start with all zeros.
+        let mut raw: c::mmsghdr = zeroed();
+        raw.msg_hdr.msg_name = name;
+        raw.msg_hdr.msg_namelen = namelen as _;
+        raw.msg_hdr.msg_iov = iov;
+        raw.msg_hdr.msg_iovlen = iovlen as _;
+        Self {
+            raw,
+            _p: PhantomData,
+        }
+    }
+
+    /// Prepare the message to be received into by [`recvmmsg`].
+    #[inline]
+    fn reset_recv(&mut self) {
+        let hdr = &mut self.raw.msg_hdr;
+        // Every address buffer is a `SocketAddrStorage`, and the kernel
+        // shrinks `msg_namelen` to the length of the address it receives.
+        hdr.msg_namelen = if hdr.msg_name.is_null() {
+            0
+        } else {
+            size_of::<SocketAddrStorage>() as _
+        };
+        hdr.msg_flags = 0;
+        self.raw.msg_len = 0;
+    }
+
+    /// Returns the number of bytes received or sent for this message by the
+    /// last call to [`recvmmsg`] or [`sendmmsg`].
+    #[inline]
+    pub fn msg_len(&self) -> usize {
+        self.raw.msg_len as usize
+    }
+
+    /// Returns the sender's address of a message received by [`recvmmsg`],
+    /// if it was constructed with an address buffer and the socket reported
+    /// one.
+    ///
+    /// Returns `None` if the last call to [`recvmmsg`] didn't receive into
+    /// this message.
+    #[inline]
+    pub fn addr(&self) -> Option<SocketAddrAny> {
+        let hdr = &self.raw.msg_hdr;
+        if hdr.msg_name.is_null() || hdr.msg_namelen == 0 {
+            return None;
+        }
+        unsafe { SocketAddrAny::read(hdr.msg_name.cast(), hdr.msg_namelen as usize).ok() }
+    }
+
+    /// Returns the flags of a message received by [`recvmmsg`], such as
+    /// [`RecvFlags::TRUNC`] if it was truncated to fit the buffers.
+    #[inline]
+    pub fn flags(&self) -> RecvFlags {
+        RecvFlags::from_bits_truncate(self.raw.msg_hdr.msg_flags as _)
+    }
+}
+
+impl<'a> fmt::Debug for MMsgHdr<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MMsgHdr")
+            .field("msg_len", &self.msg_len())
+            .finish()
+    }
+}
+
+/// `recvmmsg(fd, msgs, msgs.len(), flags, timeout)`—Receives multiple
+/// messages from a socket.
+///
+/// Returns the number of messages received, which are at the front of
+/// `msgs`; the number of bytes in each is given by [`MMsgHdr::msg_len`].
+/// This blocks until at least one message is received, unless `flags`
+/// contains [`RecvFlags::DONTWAIT`] or the socket is non-blocking. With
+/// [`RecvFlags::WAITFORONE`], it returns as soon as no more messages are
+/// immediately available.
+///
+/// The kernel only checks `timeout` after each message is received, so it
+/// bounds how long this keeps waiting to fill `msgs`, but not how long it
+/// waits for the first message. Use the socket's receive timeout or
+/// non-blocking mode for that.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/recvmmsg.2.html
+#[inline]
+pub fn recvmmsg<Fd: AsFd>(
+    fd: Fd,
+    msgs: &mut [MMsgHdr<'_>],
+    flags: RecvFlags,
+    timeout: Option<&Timespec>,
+) -> io::Result<usize> {
+    for msg in msgs.iter_mut() {
+        msg.reset_recv();
+    }
+
+    let result = unsafe {
+        imp::net::syscalls::recvmmsg(
+            fd.as_fd(),
+            msgs.as_mut_ptr().cast(),
+            msgs.len(),
+            flags,
+            timeout,
+        )
+    };
+
+    // Messages the kernel didn't receive into don't have an address.
+    let received = *result.as_ref().unwrap_or(&0);
+    for msg in &mut msgs[received..] {
+        msg.raw.msg_hdr.msg_namelen = 0;
+    }
+
+    result
+}
+
+/// `sendmmsg(fd, msgs, msgs.len(), flags)`—Sends multiple messages on a
+/// socket.
+///
+/// Returns the number of messages sent, which are at the front of `msgs`;
+/// the number of bytes sent for each is given by [`MMsgHdr::msg_len`]. If
+/// an error occurs after at least one message is sent, this returns the
+/// number sent, and the error is reported by the next call.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/sendmmsg.2.html
+#[inline]
+pub fn sendmmsg<Fd: AsFd>(fd: Fd, msgs: &mut [MMsgHdr<'_>], flags: SendFlags) -> io::Result<usize> {
+    unsafe { imp::net::syscalls::sendmmsg(fd.as_fd(), msgs.as_mut_ptr().cast(), msgs.len(), flags) }
+}
@@ -9,18 +9,36 @@
 
 #[cfg(not(feature = "std"))]
 mod addr;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod error_queue;
 #[cfg(not(feature = "std"))]
 mod ip;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod mmsg;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod netdevice;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod recvmsg;
 mod send_recv;
 mod socket;
 mod socket_addr_any;
 #[cfg(not(any(windows, target_os = "wasi")))]
 mod socketpair;
+#[cfg(not(any(windows, target_os = "ios", target_os = "macos", target_os = "wasi")))]
+mod tcp_pair;
 #[cfg(windows)]
 mod wsa;
 
 pub mod sockopt;
 
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub use error_queue::{drain_error_queue, SockExtendedErr};
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub use mmsg::{recvmmsg, sendmmsg, MMsgHdr};
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub use netdevice::{if_indextoname, if_nametoindex};
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub use recvmsg::{recvmsg, ControlMessage, RecvMsg};
 #[cfg(unix)]
 pub use send_recv::sendto_unix;
 pub use send_recv::{
@@ -28,14 +46,17 @@ pub use send_recv::{
 };
 pub use socket::{
     accept, accept_with, acceptfrom, acceptfrom_with, bind, bind_any, bind_v4, bind_v6, connect,
-    connect_any, connect_v4, connect_v6, getpeername, getsockname, listen, shutdown, socket,
-    socket_with, AcceptFlags, AddressFamily, Protocol, Shutdown, SocketFlags, SocketType,
+    connect_any, connect_result, connect_v4, connect_v6, getpeername, getsockname, listen,
+    shutdown, socket, socket_with, AcceptFlags, AddressFamily, Protocol, Shutdown, SocketFlags,
+    SocketType,
 };
 #[cfg(unix)]
 pub use socket::{bind_unix, connect_unix, SocketAddrUnix};
 pub use socket_addr_any::{SocketAddrAny, SocketAddrStorage};
 #[cfg(not(any(windows, target_os = "wasi")))]
 pub use socketpair::socketpair;
+#[cfg(not(any(windows, target_os = "ios", target_os = "macos", target_os = "wasi")))]
+pub use tcp_pair::tcp_pair;
 #[cfg(windows)]
 pub use wsa::{wsa_cleanup, wsa_startup};
 
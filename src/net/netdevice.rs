@@ -0,0 +1,39 @@
+//! Network interface name and index conversions.
+
+use crate::ffi::{ZStr, ZString};
+use crate::{imp, io};
+
+/// `if_nametoindex(name)`—Returns the index of the network interface named
+/// `name`.
+///
+/// If there's no interface with that name, this fails with
+/// [`io::Errno::NODEV`].
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/if_nametoindex.html
+/// [Linux]: https://man7.org/linux/man-pages/man3/if_nametoindex.3.html
+#[inline]
+pub fn if_nametoindex(name: &ZStr) -> io::Result<u32> {
+    imp::net::syscalls::if_nametoindex(name)
+}
+
+/// `if_indextoname(index, buf)`—Returns the name of the network interface
+/// with index `index`.
+///
+/// If there's no interface with that index, this fails with
+/// [`io::Errno::NXIO`]. Interface indices start at 1, so this always fails
+/// for 0.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/if_indextoname.html
+/// [Linux]: https://man7.org/linux/man-pages/man3/if_indextoname.3.html
+#[inline]
+pub fn if_indextoname(index: u32) -> io::Result<ZString> {
+    imp::net::syscalls::if_indextoname(index)
+}
@@ -0,0 +1,53 @@
+use crate::imp::time::types::{Timespec, Timeval};
+use crate::io::IoSliceMut;
+use crate::net::RecvFlags;
+use crate::{imp, io};
+use alloc::vec::Vec;
+use imp::fd::AsFd;
+
+/// A control message received with [`recvmsg`].
+#[derive(Debug, Clone, Copy)]
+#[non_exhaustive]
+pub enum ControlMessage {
+    /// `SCM_TIMESTAMP`—The time the message was received, with microsecond
+    /// resolution, enabled with [`set_socket_timestamp`].
+    ///
+    /// [`set_socket_timestamp`]: crate::net::sockopt::set_socket_timestamp
+    ScmTimestamp(Timeval),
+
+    /// `SCM_TIMESTAMPNS`—The time the message was received, with nanosecond
+    /// resolution, enabled with [`set_socket_timestampns`].
+    ///
+    /// [`set_socket_timestampns`]: crate::net::sockopt::set_socket_timestampns
+    ScmTimestampNs(Timespec),
+}
+
+/// The result of [`recvmsg`].
+#[derive(Debug, Clone)]
+pub struct RecvMsg {
+    /// The number of bytes received.
+    pub bytes: usize,
+
+    /// The control messages received with the data. Control messages of
+    /// kinds rustix doesn't decode are omitted.
+    pub control_messages: Vec<ControlMessage>,
+}
+
+/// `recvmsg(fd, msg, flags)`—Receives a message from a socket, along with
+/// its control messages.
+///
+/// The data is scattered into `bufs`, and the control messages are decoded
+/// into [`ControlMessage`]s.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/recvmsg.2.html
+#[inline]
+pub fn recvmsg<Fd: AsFd>(
+    fd: Fd,
+    bufs: &mut [IoSliceMut<'_>],
+    flags: RecvFlags,
+) -> io::Result<RecvMsg> {
+    imp::net::syscalls::recvmsg(fd.as_fd(), bufs, flags)
+}
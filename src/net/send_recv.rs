@@ -10,6 +10,11 @@ pub use imp::net::send_recv::{RecvFlags, SendFlags};
 
 /// `recv(fd, buf, flags)`—Reads data from a socket.
 ///
+/// With [`RecvFlags::PEEK`], the data is returned without being removed
+/// from the socket's receive queue, so a following `recv` returns it again.
+/// On Linux, with [`RecvFlags::TRUNC`] on a datagram socket, this returns
+/// the full length of the datagram even if only part of it fit in `buf`.
+///
 /// # References
 ///  - [POSIX]
 ///  - [Linux]
@@ -43,7 +43,9 @@ pub fn socket(domain: AddressFamily, type_: SocketType, protocol: Protocol) -> i
 /// may be unexpectedly allocated on other threads or in libraries.
 ///
 /// `socket_with` is the same as [`socket`] but adds an additional flags
-/// operand.
+/// operand. The flags are applied atomically as the socket is created, so
+/// unlike setting them afterwards with `fcntl`, there's no window in which
+/// another thread can `fork` and `exec` and leak the file descriptor.
 ///
 /// # References
 ///  - [POSIX]
@@ -158,6 +160,11 @@ pub fn bind_unix<Fd: AsFd>(sockfd: Fd, addr: &SocketAddrUnix) -> io::Result<()>
 
 /// `connect(sockfd, addr)`—Initiates a connection to an IP address.
 ///
+/// On a nonblocking socket, if the connection can't be established
+/// immediately, this fails with [`io::Errno::INPROGRESS`] (or
+/// [`io::Errno::WOULDBLOCK`] on Windows). Wait for the socket to become
+/// writable, and then use [`connect_result`] to get the outcome.
+///
 /// # References
 ///  - [POSIX]
 ///  - [Linux]
@@ -177,6 +184,11 @@ fn _connect(sockfd: BorrowedFd<'_>, addr: &SocketAddr) -> io::Result<()> {
 
 /// `connect(sockfd, addr)`—Initiates a connection.
 ///
+/// On a nonblocking socket, if the connection can't be established
+/// immediately, this fails with [`io::Errno::INPROGRESS`] (or
+/// [`io::Errno::WOULDBLOCK`] on Windows). Wait for the socket to become
+/// writable, and then use [`connect_result`] to get the outcome.
+///
 /// # References
 ///  - [POSIX]
 ///  - [Linux]
@@ -247,6 +259,26 @@ pub fn connect_unix<Fd: AsFd>(sockfd: Fd, addr: &SocketAddrUnix) -> io::Result<(
     imp::net::syscalls::connect_unix(sockfd.as_fd(), addr)
 }
 
+/// `getsockopt(sockfd, SOL_SOCKET, SO_ERROR)`—Returns the result of a
+/// nonblocking `connect`.
+///
+/// After a nonblocking `connect` fails with [`io::Errno::INPROGRESS`] and the
+/// socket has been polled for writability, this returns `Ok(())` if the
+/// connection was established, or the error that caused it to fail, such as
+/// [`io::Errno::CONNREFUSED`].
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/connect.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/connect.2.html
+#[inline]
+#[doc(alias = "SO_ERROR")]
+pub fn connect_result<Fd: AsFd>(sockfd: Fd) -> io::Result<()> {
+    imp::net::syscalls::sockopt::get_socket_error(sockfd.as_fd())?
+}
+
 /// `listen(fd, backlog)`—Enables listening for incoming connections.
 ///
 /// # References
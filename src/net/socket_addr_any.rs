@@ -15,6 +15,7 @@ use crate::net::{AddressFamily, SocketAddrV4, SocketAddrV6};
 use crate::{imp, io};
 #[cfg(feature = "std")]
 use core::fmt;
+use core::mem::size_of;
 
 pub use imp::net::addr::SocketAddrStorage;
 
@@ -66,6 +67,31 @@ impl SocketAddrAny {
     pub unsafe fn read(storage: *const SocketAddrStorage, len: usize) -> io::Result<Self> {
         imp::net::read_sockaddr::read_sockaddr(storage, len)
     }
+
+    /// Writes a platform-specific encoding of this socket address to
+    /// `storage`, and returns the number of bytes used.
+    ///
+    /// This is a safe form of [`SocketAddrAny::write`], for passing
+    /// addresses to code that takes a `sockaddr` pointer and length.
+    #[inline]
+    pub fn write_to(&self, storage: &mut SocketAddrStorage) -> usize {
+        unsafe { self.write(storage) }
+    }
+
+    /// Reads a platform-specific encoding of a socket address from the
+    /// first `len` bytes of `storage`.
+    ///
+    /// This is a safe form of [`SocketAddrAny::read`], for addresses
+    /// received from code that produces a `sockaddr` and length. It fails
+    /// with [`io::Errno::INVAL`] if the address family is not supported, or
+    /// if `len` is too short for the family or longer than `storage`.
+    #[inline]
+    pub fn from_raw(storage: &SocketAddrStorage, len: usize) -> io::Result<Self> {
+        if len > size_of::<SocketAddrStorage>() {
+            return Err(io::Errno::INVAL);
+        }
+        unsafe { Self::read(storage, len) }
+    }
 }
 
 #[cfg(feature = "std")]
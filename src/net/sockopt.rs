@@ -6,12 +6,34 @@
 #![doc(alias = "getsockopt")]
 #![doc(alias = "setsockopt")]
 
+#[cfg(any(target_os = "android", target_os = "linux"))]
+use crate::ffi::{ZStr, ZString};
 use crate::net::{Ipv4Addr, Ipv6Addr, SocketType};
+#[cfg(any(target_os = "android", target_os = "linux"))]
+use crate::process::{Gid, Pid, Uid};
 use crate::{imp, io};
 use core::time::Duration;
 use imp::fd::AsFd;
 
 pub use imp::net::types::Timeout;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub use imp::net::types::{TcpInfo, TcpState};
+
+/// `struct ucred`—The credentials of a process, as returned by
+/// [`get_socket_peercred`].
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct UCred {
+    /// `pid`—The process ID, or `None` if the process isn't visible in the
+    /// calling process' PID namespace.
+    pub pid: Option<Pid>,
+
+    /// `uid`—The effective user ID.
+    pub uid: Uid,
+
+    /// `gid`—The effective group ID.
+    pub gid: Gid,
+}
 
 /// `getsockopt(fd, SOL_SOCKET, SO_TYPE)`—Returns the type of a socket.
 ///
@@ -101,6 +123,189 @@ pub fn get_socket_broadcast<Fd: AsFd>(fd: Fd) -> io::Result<bool> {
     imp::net::syscalls::sockopt::get_socket_broadcast(fd.as_fd())
 }
 
+/// `setsockopt(fd, SOL_SOCKET, SO_KEEPALIVE, keepalive)`—Enable or disable
+/// sending keepalive probes on a connection-oriented socket.
+///
+/// # References
+///  - [POSIX `setsockopt`]
+///  - [POSIX `sys/socket.h`]
+///  - [Linux `setsockopt`]
+///  - [Linux `socket`]
+///  - [Winsock2 `setsockopt`]
+///  - [Winsock2 `SOL_SOCKET` options]
+///
+/// [POSIX `setsockopt`]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/setsockopt.html
+/// [POSIX `sys/socket.h`]: https://pubs.opengroup.org/onlinepubs/9699919799/basedefs/sys_socket.h.html
+/// [Linux `setsockopt`]: https://man7.org/linux/man-pages/man2/setsockopt.2.html
+/// [Linux `socket`]: https://man7.org/linux/man-pages/man7/socket.7.html
+/// [Winsock2 `setsockopt`]: https://docs.microsoft.com/en-us/windows/win32/api/winsock2/nf-winsock2-setsockopt
+/// [Winsock2 `SOL_SOCKET` options]: https://docs.microsoft.com/en-us/windows/win32/winsock/sol-socket-socket-options
+#[inline]
+#[doc(alias = "SO_KEEPALIVE")]
+pub fn set_socket_keepalive<Fd: AsFd>(fd: Fd, keepalive: bool) -> io::Result<()> {
+    imp::net::syscalls::sockopt::set_socket_keepalive(fd.as_fd(), keepalive)
+}
+
+/// `getsockopt(fd, SOL_SOCKET, SO_KEEPALIVE)`
+///
+/// # References
+///  - [POSIX `getsockopt`]
+///  - [POSIX `sys/socket.h`]
+///  - [Linux `getsockopt`]
+///  - [Linux `socket`]
+///  - [Winsock2 `getsockopt`]
+///  - [Winsock2 `SOL_SOCKET` options]
+///
+/// [POSIX `getsockopt`]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/getsockopt.html
+/// [POSIX `sys/socket.h`]: https://pubs.opengroup.org/onlinepubs/9699919799/basedefs/sys_socket.h.html
+/// [Linux `getsockopt`]: https://man7.org/linux/man-pages/man2/getsockopt.2.html
+/// [Linux `socket`]: https://man7.org/linux/man-pages/man7/socket.7.html
+/// [Winsock2 `getsockopt`]: https://docs.microsoft.com/en-us/windows/win32/api/winsock2/nf-winsock2-getsockopt
+/// [Winsock2 `SOL_SOCKET` options]: https://docs.microsoft.com/en-us/windows/win32/winsock/sol-socket-socket-options
+#[inline]
+#[doc(alias = "SO_KEEPALIVE")]
+pub fn get_socket_keepalive<Fd: AsFd>(fd: Fd) -> io::Result<bool> {
+    imp::net::syscalls::sockopt::get_socket_keepalive(fd.as_fd())
+}
+
+/// `setsockopt(fd, SOL_SOCKET, SO_RCVBUF, size)`—Set the size of a
+/// socket's receive buffer.
+///
+/// On Linux, the kernel doubles `size` to leave room for bookkeeping, so
+/// [`get_socket_recv_buffer_size`] returns about twice the value set
+/// here, and `size` is capped by the `net.core.rmem_max` sysctl. To
+/// exceed that limit, use [`set_socket_recv_buffer_size_force`].
+///
+/// # References
+///  - [POSIX `setsockopt`]
+///  - [POSIX `sys/socket.h`]
+///  - [Linux `setsockopt`]
+///  - [Linux `socket`]
+///  - [Winsock2 `setsockopt`]
+///  - [Winsock2 `SOL_SOCKET` options]
+///
+/// [POSIX `setsockopt`]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/setsockopt.html
+/// [POSIX `sys/socket.h`]: https://pubs.opengroup.org/onlinepubs/9699919799/basedefs/sys_socket.h.html
+/// [Linux `setsockopt`]: https://man7.org/linux/man-pages/man2/setsockopt.2.html
+/// [Linux `socket`]: https://man7.org/linux/man-pages/man7/socket.7.html
+/// [Winsock2 `setsockopt`]: https://docs.microsoft.com/en-us/windows/win32/api/winsock2/nf-winsock2-setsockopt
+/// [Winsock2 `SOL_SOCKET` options]: https://docs.microsoft.com/en-us/windows/win32/winsock/sol-socket-socket-options
+#[inline]
+#[doc(alias = "SO_RCVBUF")]
+pub fn set_socket_recv_buffer_size<Fd: AsFd>(fd: Fd, size: usize) -> io::Result<()> {
+    imp::net::syscalls::sockopt::set_socket_recv_buffer_size(fd.as_fd(), size)
+}
+
+/// `setsockopt(fd, SOL_SOCKET, SO_RCVBUFFORCE, size)`—Set the size of a
+/// socket's receive buffer, ignoring the `net.core.rmem_max` limit.
+///
+/// This requires the `CAP_NET_ADMIN` capability. Like
+/// [`set_socket_recv_buffer_size`], the kernel doubles `size`.
+///
+/// # References
+///  - [Linux `setsockopt`]
+///  - [Linux `socket`]
+///
+/// [Linux `setsockopt`]: https://man7.org/linux/man-pages/man2/setsockopt.2.html
+/// [Linux `socket`]: https://man7.org/linux/man-pages/man7/socket.7.html
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "SO_RCVBUFFORCE")]
+pub fn set_socket_recv_buffer_size_force<Fd: AsFd>(fd: Fd, size: usize) -> io::Result<()> {
+    imp::net::syscalls::sockopt::set_socket_recv_buffer_size_force(fd.as_fd(), size)
+}
+
+/// `getsockopt(fd, SOL_SOCKET, SO_RCVBUF)`
+///
+/// # References
+///  - [POSIX `getsockopt`]
+///  - [POSIX `sys/socket.h`]
+///  - [Linux `getsockopt`]
+///  - [Linux `socket`]
+///  - [Winsock2 `getsockopt`]
+///  - [Winsock2 `SOL_SOCKET` options]
+///
+/// [POSIX `getsockopt`]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/getsockopt.html
+/// [POSIX `sys/socket.h`]: https://pubs.opengroup.org/onlinepubs/9699919799/basedefs/sys_socket.h.html
+/// [Linux `getsockopt`]: https://man7.org/linux/man-pages/man2/getsockopt.2.html
+/// [Linux `socket`]: https://man7.org/linux/man-pages/man7/socket.7.html
+/// [Winsock2 `getsockopt`]: https://docs.microsoft.com/en-us/windows/win32/api/winsock2/nf-winsock2-getsockopt
+/// [Winsock2 `SOL_SOCKET` options]: https://docs.microsoft.com/en-us/windows/win32/winsock/sol-socket-socket-options
+#[inline]
+#[doc(alias = "SO_RCVBUF")]
+pub fn get_socket_recv_buffer_size<Fd: AsFd>(fd: Fd) -> io::Result<usize> {
+    imp::net::syscalls::sockopt::get_socket_recv_buffer_size(fd.as_fd())
+}
+
+/// `setsockopt(fd, SOL_SOCKET, SO_SNDBUF, size)`—Set the size of a
+/// socket's send buffer.
+///
+/// On Linux, the kernel doubles `size` to leave room for bookkeeping, so
+/// [`get_socket_send_buffer_size`] returns about twice the value set
+/// here, and `size` is capped by the `net.core.wmem_max` sysctl. To
+/// exceed that limit, use [`set_socket_send_buffer_size_force`].
+///
+/// # References
+///  - [POSIX `setsockopt`]
+///  - [POSIX `sys/socket.h`]
+///  - [Linux `setsockopt`]
+///  - [Linux `socket`]
+///  - [Winsock2 `setsockopt`]
+///  - [Winsock2 `SOL_SOCKET` options]
+///
+/// [POSIX `setsockopt`]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/setsockopt.html
+/// [POSIX `sys/socket.h`]: https://pubs.opengroup.org/onlinepubs/9699919799/basedefs/sys_socket.h.html
+/// [Linux `setsockopt`]: https://man7.org/linux/man-pages/man2/setsockopt.2.html
+/// [Linux `socket`]: https://man7.org/linux/man-pages/man7/socket.7.html
+/// [Winsock2 `setsockopt`]: https://docs.microsoft.com/en-us/windows/win32/api/winsock2/nf-winsock2-setsockopt
+/// [Winsock2 `SOL_SOCKET` options]: https://docs.microsoft.com/en-us/windows/win32/winsock/sol-socket-socket-options
+#[inline]
+#[doc(alias = "SO_SNDBUF")]
+pub fn set_socket_send_buffer_size<Fd: AsFd>(fd: Fd, size: usize) -> io::Result<()> {
+    imp::net::syscalls::sockopt::set_socket_send_buffer_size(fd.as_fd(), size)
+}
+
+/// `setsockopt(fd, SOL_SOCKET, SO_SNDBUFFORCE, size)`—Set the size of a
+/// socket's send buffer, ignoring the `net.core.wmem_max` limit.
+///
+/// This requires the `CAP_NET_ADMIN` capability. Like
+/// [`set_socket_send_buffer_size`], the kernel doubles `size`.
+///
+/// # References
+///  - [Linux `setsockopt`]
+///  - [Linux `socket`]
+///
+/// [Linux `setsockopt`]: https://man7.org/linux/man-pages/man2/setsockopt.2.html
+/// [Linux `socket`]: https://man7.org/linux/man-pages/man7/socket.7.html
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "SO_SNDBUFFORCE")]
+pub fn set_socket_send_buffer_size_force<Fd: AsFd>(fd: Fd, size: usize) -> io::Result<()> {
+    imp::net::syscalls::sockopt::set_socket_send_buffer_size_force(fd.as_fd(), size)
+}
+
+/// `getsockopt(fd, SOL_SOCKET, SO_SNDBUF)`
+///
+/// # References
+///  - [POSIX `getsockopt`]
+///  - [POSIX `sys/socket.h`]
+///  - [Linux `getsockopt`]
+///  - [Linux `socket`]
+///  - [Winsock2 `getsockopt`]
+///  - [Winsock2 `SOL_SOCKET` options]
+///
+/// [POSIX `getsockopt`]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/getsockopt.html
+/// [POSIX `sys/socket.h`]: https://pubs.opengroup.org/onlinepubs/9699919799/basedefs/sys_socket.h.html
+/// [Linux `getsockopt`]: https://man7.org/linux/man-pages/man2/getsockopt.2.html
+/// [Linux `socket`]: https://man7.org/linux/man-pages/man7/socket.7.html
+/// [Winsock2 `getsockopt`]: https://docs.microsoft.com/en-us/windows/win32/api/winsock2/nf-winsock2-getsockopt
+/// [Winsock2 `SOL_SOCKET` options]: https://docs.microsoft.com/en-us/windows/win32/winsock/sol-socket-socket-options
+#[inline]
+#[doc(alias = "SO_SNDBUF")]
+pub fn get_socket_send_buffer_size<Fd: AsFd>(fd: Fd) -> io::Result<usize> {
+    imp::net::syscalls::sockopt::get_socket_send_buffer_size(fd.as_fd())
+}
+
 /// `setsockopt(fd, SOL_SOCKET, SO_LINGER, linger)`
 ///
 /// # References
@@ -175,6 +380,139 @@ pub fn get_socket_passcred<Fd: AsFd>(fd: Fd) -> io::Result<bool> {
     imp::net::syscalls::sockopt::get_socket_passcred(fd.as_fd())
 }
 
+/// `setsockopt(fd, SOL_SOCKET, SO_TIMESTAMP, value)`—Enables or disables
+/// microsecond-resolution receive timestamps.
+///
+/// When enabled, the kernel attaches the time each packet was received to
+/// it, which [`recvmsg`] returns as a [`ControlMessage::ScmTimestamp`].
+///
+/// # References
+///  - [Linux `setsockopt`]
+///  - [Linux `socket`]
+///
+/// [Linux `setsockopt`]: https://man7.org/linux/man-pages/man2/setsockopt.2.html
+/// [Linux `socket`]: https://man7.org/linux/man-pages/man7/socket.7.html
+/// [`recvmsg`]: crate::net::recvmsg
+/// [`ControlMessage::ScmTimestamp`]: crate::net::ControlMessage::ScmTimestamp
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "SO_TIMESTAMP")]
+pub fn set_socket_timestamp<Fd: AsFd>(fd: Fd, value: bool) -> io::Result<()> {
+    imp::net::syscalls::sockopt::set_socket_timestamp(fd.as_fd(), value)
+}
+
+/// `getsockopt(fd, SOL_SOCKET, SO_TIMESTAMP)`
+///
+/// # References
+///  - [Linux `getsockopt`]
+///  - [Linux `socket`]
+///
+/// [Linux `getsockopt`]: https://man7.org/linux/man-pages/man2/getsockopt.2.html
+/// [Linux `socket`]: https://man7.org/linux/man-pages/man7/socket.7.html
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "SO_TIMESTAMP")]
+pub fn get_socket_timestamp<Fd: AsFd>(fd: Fd) -> io::Result<bool> {
+    imp::net::syscalls::sockopt::get_socket_timestamp(fd.as_fd())
+}
+
+/// `setsockopt(fd, SOL_SOCKET, SO_TIMESTAMPNS, value)`—Enables or disables
+/// nanosecond-resolution receive timestamps.
+///
+/// When enabled, the kernel attaches the time each packet was received to
+/// it, which [`recvmsg`] returns as a [`ControlMessage::ScmTimestampNs`].
+///
+/// # References
+///  - [Linux `setsockopt`]
+///  - [Linux `socket`]
+///
+/// [Linux `setsockopt`]: https://man7.org/linux/man-pages/man2/setsockopt.2.html
+/// [Linux `socket`]: https://man7.org/linux/man-pages/man7/socket.7.html
+/// [`recvmsg`]: crate::net::recvmsg
+/// [`ControlMessage::ScmTimestampNs`]: crate::net::ControlMessage::ScmTimestampNs
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "SO_TIMESTAMPNS")]
+pub fn set_socket_timestampns<Fd: AsFd>(fd: Fd, value: bool) -> io::Result<()> {
+    imp::net::syscalls::sockopt::set_socket_timestampns(fd.as_fd(), value)
+}
+
+/// `getsockopt(fd, SOL_SOCKET, SO_TIMESTAMPNS)`
+///
+/// # References
+///  - [Linux `getsockopt`]
+///  - [Linux `socket`]
+///
+/// [Linux `getsockopt`]: https://man7.org/linux/man-pages/man2/getsockopt.2.html
+/// [Linux `socket`]: https://man7.org/linux/man-pages/man7/socket.7.html
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "SO_TIMESTAMPNS")]
+pub fn get_socket_timestampns<Fd: AsFd>(fd: Fd) -> io::Result<bool> {
+    imp::net::syscalls::sockopt::get_socket_timestampns(fd.as_fd())
+}
+
+/// `setsockopt(fd, SOL_SOCKET, SO_BINDTODEVICE, name)`—Binds a socket to a
+/// network interface.
+///
+/// Only packets received on the interface named `name` are processed by the
+/// socket, and packets it sends are routed through that interface. An empty
+/// `name` removes the binding.
+///
+/// This requires the `CAP_NET_RAW` capability, and fails with
+/// [`io::Errno::PERM`] without it.
+///
+/// # References
+///  - [Linux `setsockopt`]
+///  - [Linux `socket`]
+///
+/// [Linux `setsockopt`]: https://man7.org/linux/man-pages/man2/setsockopt.2.html
+/// [Linux `socket`]: https://man7.org/linux/man-pages/man7/socket.7.html
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "SO_BINDTODEVICE")]
+pub fn set_socket_bindtodevice<Fd: AsFd>(fd: Fd, name: &ZStr) -> io::Result<()> {
+    imp::net::syscalls::sockopt::set_socket_bindtodevice(fd.as_fd(), name)
+}
+
+/// `getsockopt(fd, SOL_SOCKET, SO_BINDTODEVICE)`—Returns the name of the
+/// network interface a socket is bound to, or an empty string if it isn't
+/// bound to one.
+///
+/// # References
+///  - [Linux `getsockopt`]
+///  - [Linux `socket`]
+///
+/// [Linux `getsockopt`]: https://man7.org/linux/man-pages/man2/getsockopt.2.html
+/// [Linux `socket`]: https://man7.org/linux/man-pages/man7/socket.7.html
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "SO_BINDTODEVICE")]
+pub fn get_socket_bindtodevice<Fd: AsFd>(fd: Fd) -> io::Result<ZString> {
+    imp::net::syscalls::sockopt::get_socket_bindtodevice(fd.as_fd())
+}
+
+/// `getsockopt(fd, SOL_SOCKET, SO_PEERCRED)`—Returns the credentials of
+/// the peer of a Unix-domain socket.
+///
+/// The credentials are verified by the kernel. For a connected stream
+/// socket, they're those of the peer process at the time it called
+/// `connect` or `listen`; for a socket created with `socketpair`, they're
+/// those at the time of the `socketpair` call.
+///
+/// # References
+///  - [Linux `getsockopt`]
+///  - [Linux `unix`]
+///
+/// [Linux `getsockopt`]: https://man7.org/linux/man-pages/man2/getsockopt.2.html
+/// [Linux `unix`]: https://man7.org/linux/man-pages/man7/unix.7.html
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "SO_PEERCRED")]
+pub fn get_socket_peercred<Fd: AsFd>(fd: Fd) -> io::Result<UCred> {
+    imp::net::syscalls::sockopt::get_socket_peercred(fd.as_fd())
+}
+
 /// `setsockopt(fd, SOL_SOCKET, id, timeout)`—Set the sending
 /// or receiving timeout.
 ///
@@ -226,6 +564,32 @@ pub fn get_socket_timeout<Fd: AsFd>(fd: Fd, id: Timeout) -> io::Result<Option<Du
     imp::net::syscalls::sockopt::get_socket_timeout(fd.as_fd(), id)
 }
 
+/// `getsockopt(fd, SOL_SOCKET, SO_ERROR)`—Returns and clears the pending
+/// error on a socket.
+///
+/// The outer `Result` reports whether `getsockopt` itself failed; the inner
+/// `Result` holds the socket's pending error, if any.
+///
+/// # References
+///  - [POSIX `getsockopt`]
+///  - [POSIX `sys/socket.h`]
+///  - [Linux `getsockopt`]
+///  - [Linux `socket`]
+///  - [Winsock2 `getsockopt`]
+///  - [Winsock2 `SOL_SOCKET` options]
+///
+/// [POSIX `getsockopt`]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/getsockopt.html
+/// [POSIX `sys/socket.h`]: https://pubs.opengroup.org/onlinepubs/9699919799/basedefs/sys_socket.h.html
+/// [Linux `getsockopt`]: https://man7.org/linux/man-pages/man2/getsockopt.2.html
+/// [Linux `socket`]: https://man7.org/linux/man-pages/man7/socket.7.html
+/// [Winsock2 `getsockopt`]: https://docs.microsoft.com/en-us/windows/win32/api/winsock2/nf-winsock2-getsockopt
+/// [Winsock2 `SOL_SOCKET` options]: https://docs.microsoft.com/en-us/windows/win32/winsock/sol-socket-socket-options
+#[inline]
+#[doc(alias = "SO_ERROR")]
+pub fn get_socket_error<Fd: AsFd>(fd: Fd) -> io::Result<Result<(), io::Errno>> {
+    imp::net::syscalls::sockopt::get_socket_error(fd.as_fd())
+}
+
 /// `setsockopt(fd, IPPROTO_IP, IP_TTL, ttl)`
 ///
 /// # References
@@ -269,7 +633,83 @@ pub fn get_ip_ttl<Fd: AsFd>(fd: Fd) -> io::Result<u32> {
     imp::net::syscalls::sockopt::get_ip_ttl(fd.as_fd())
 }
 
-/// `setsockopt(fd, IPPROTO_IPV6, IPV6_V6ONLY, only_v6)`
+/// `setsockopt(fd, IPPROTO_IP, IP_RECVERR, value)`—Enables queueing of
+/// extended errors, for use with [`drain_error_queue`].
+///
+/// # References
+///  - [Linux `setsockopt`]
+///  - [Linux `ip`]
+///
+/// [Linux `setsockopt`]: https://man7.org/linux/man-pages/man2/setsockopt.2.html
+/// [Linux `ip`]: https://man7.org/linux/man-pages/man7/ip.7.html
+/// [`drain_error_queue`]: crate::net::drain_error_queue
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "IP_RECVERR")]
+pub fn set_ip_recverr<Fd: AsFd>(fd: Fd, value: bool) -> io::Result<()> {
+    imp::net::syscalls::sockopt::set_ip_recverr(fd.as_fd(), value)
+}
+
+/// `getsockopt(fd, IPPROTO_IP, IP_RECVERR)`
+///
+/// # References
+///  - [Linux `getsockopt`]
+///  - [Linux `ip`]
+///
+/// [Linux `getsockopt`]: https://man7.org/linux/man-pages/man2/getsockopt.2.html
+/// [Linux `ip`]: https://man7.org/linux/man-pages/man7/ip.7.html
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "IP_RECVERR")]
+pub fn get_ip_recverr<Fd: AsFd>(fd: Fd) -> io::Result<bool> {
+    imp::net::syscalls::sockopt::get_ip_recverr(fd.as_fd())
+}
+
+/// `setsockopt(fd, IPPROTO_IP, IP_TOS, value)`—Set the type-of-service
+/// byte of outgoing IPv4 packets.
+///
+/// `value` is the whole byte, so to set a DSCP codepoint, shift it into the
+/// upper six bits with `dscp << 2`. The lower two bits are the ECN field,
+/// which the kernel manages itself for TCP sockets.
+///
+/// # References
+///  - [Linux `setsockopt`]
+///  - [Linux `ip`]
+///
+/// [Linux `setsockopt`]: https://man7.org/linux/man-pages/man2/setsockopt.2.html
+/// [Linux `ip`]: https://man7.org/linux/man-pages/man7/ip.7.html
+#[cfg(not(windows))]
+#[inline]
+#[doc(alias = "IP_TOS")]
+pub fn set_ip_tos<Fd: AsFd>(fd: Fd, value: u8) -> io::Result<()> {
+    imp::net::syscalls::sockopt::set_ip_tos(fd.as_fd(), value)
+}
+
+/// `getsockopt(fd, IPPROTO_IP, IP_TOS)`
+///
+/// The DSCP codepoint is the upper six bits of the result, `value >> 2`.
+///
+/// # References
+///  - [Linux `getsockopt`]
+///  - [Linux `ip`]
+///
+/// [Linux `getsockopt`]: https://man7.org/linux/man-pages/man2/getsockopt.2.html
+/// [Linux `ip`]: https://man7.org/linux/man-pages/man7/ip.7.html
+#[cfg(not(windows))]
+#[inline]
+#[doc(alias = "IP_TOS")]
+pub fn get_ip_tos<Fd: AsFd>(fd: Fd) -> io::Result<u8> {
+    imp::net::syscalls::sockopt::get_ip_tos(fd.as_fd())
+}
+
+/// `setsockopt(fd, IPPROTO_IPV6, IPV6_V6ONLY, only_v6)`—Set whether an
+/// IPv6 socket is restricted to IPv6, or also accepts IPv4 traffic using
+/// IPv4-mapped addresses.
+///
+/// This must be set before the socket is bound; afterwards, Linux fails
+/// with [`io::Errno::INVAL`]. The default is `false` on Linux, unless the
+/// `net.ipv6.bindv6only` sysctl is set, and `true` on Windows and most
+/// BSDs, so portable code should set it explicitly.
 ///
 /// # References
 ///  - [POSIX `setsockopt`]
@@ -313,6 +753,65 @@ pub fn get_ipv6_v6only<Fd: AsFd>(fd: Fd) -> io::Result<bool> {
     imp::net::syscalls::sockopt::get_ipv6_v6only(fd.as_fd())
 }
 
+/// `setsockopt(fd, IPPROTO_IPV6, IPV6_TCLASS, value)`—Set the traffic
+/// class byte of outgoing IPv6 packets.
+///
+/// `value` is the whole byte, so to set a DSCP codepoint, shift it into the
+/// upper six bits with `dscp << 2`. Values greater than 255 fail with
+/// [`io::Errno::INVAL`].
+///
+/// # References
+///  - [Linux `setsockopt`]
+///  - [Linux `ipv6`]
+///
+/// [Linux `setsockopt`]: https://man7.org/linux/man-pages/man2/setsockopt.2.html
+/// [Linux `ipv6`]: https://man7.org/linux/man-pages/man7/ipv6.7.html
+#[cfg(any(
+    target_os = "android",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "illumos",
+    target_os = "ios",
+    target_os = "linux",
+    target_os = "macos",
+    target_os = "netbsd",
+    target_os = "openbsd",
+    target_os = "solaris",
+))]
+#[inline]
+#[doc(alias = "IPV6_TCLASS")]
+pub fn set_ipv6_tclass<Fd: AsFd>(fd: Fd, value: u32) -> io::Result<()> {
+    imp::net::syscalls::sockopt::set_ipv6_tclass(fd.as_fd(), value)
+}
+
+/// `getsockopt(fd, IPPROTO_IPV6, IPV6_TCLASS)`
+///
+/// The DSCP codepoint is the upper six bits of the result, `value >> 2`.
+///
+/// # References
+///  - [Linux `getsockopt`]
+///  - [Linux `ipv6`]
+///
+/// [Linux `getsockopt`]: https://man7.org/linux/man-pages/man2/getsockopt.2.html
+/// [Linux `ipv6`]: https://man7.org/linux/man-pages/man7/ipv6.7.html
+#[cfg(any(
+    target_os = "android",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "illumos",
+    target_os = "ios",
+    target_os = "linux",
+    target_os = "macos",
+    target_os = "netbsd",
+    target_os = "openbsd",
+    target_os = "solaris",
+))]
+#[inline]
+#[doc(alias = "IPV6_TCLASS")]
+pub fn get_ipv6_tclass<Fd: AsFd>(fd: Fd) -> io::Result<u32> {
+    imp::net::syscalls::sockopt::get_ipv6_tclass(fd.as_fd())
+}
+
 /// `setsockopt(fd, IPPROTO_IP, IP_MULTICAST_LOOP, multicast_loop)`
 ///
 /// # References
@@ -598,3 +1097,199 @@ pub fn set_tcp_nodelay<Fd: AsFd>(fd: Fd, nodelay: bool) -> io::Result<()> {
 pub fn get_tcp_nodelay<Fd: AsFd>(fd: Fd) -> io::Result<bool> {
     imp::net::syscalls::sockopt::get_tcp_nodelay(fd.as_fd())
 }
+
+/// `setsockopt(fd, IPPROTO_TCP, TCP_KEEPIDLE, duration)`—Set how long a
+/// connection must be idle before keepalive probes are sent.
+///
+/// The kernel only supports whole seconds, so `duration` is rounded up to the
+/// next second. A zero or out-of-range value fails with
+/// [`io::Errno::INVAL`].
+///
+/// # References
+///  - [Linux `setsockopt`]
+///  - [Linux `tcp`]
+///
+/// [Linux `setsockopt`]: https://man7.org/linux/man-pages/man2/setsockopt.2.html
+/// [Linux `tcp`]: https://man7.org/linux/man-pages/man7/tcp.7.html
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "TCP_KEEPIDLE")]
+pub fn set_tcp_keepidle<Fd: AsFd>(fd: Fd, duration: Duration) -> io::Result<()> {
+    imp::net::syscalls::sockopt::set_tcp_keepidle(fd.as_fd(), duration)
+}
+
+/// `getsockopt(fd, IPPROTO_TCP, TCP_KEEPIDLE)`
+///
+/// # References
+///  - [Linux `getsockopt`]
+///  - [Linux `tcp`]
+///
+/// [Linux `getsockopt`]: https://man7.org/linux/man-pages/man2/getsockopt.2.html
+/// [Linux `tcp`]: https://man7.org/linux/man-pages/man7/tcp.7.html
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "TCP_KEEPIDLE")]
+pub fn get_tcp_keepidle<Fd: AsFd>(fd: Fd) -> io::Result<Duration> {
+    imp::net::syscalls::sockopt::get_tcp_keepidle(fd.as_fd())
+}
+
+/// `setsockopt(fd, IPPROTO_TCP, TCP_KEEPINTVL, duration)`—Set the time
+/// between keepalive probes.
+///
+/// The kernel only supports whole seconds, so `duration` is rounded up to the
+/// next second. A zero or out-of-range value fails with
+/// [`io::Errno::INVAL`].
+///
+/// # References
+///  - [Linux `setsockopt`]
+///  - [Linux `tcp`]
+///
+/// [Linux `setsockopt`]: https://man7.org/linux/man-pages/man2/setsockopt.2.html
+/// [Linux `tcp`]: https://man7.org/linux/man-pages/man7/tcp.7.html
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "TCP_KEEPINTVL")]
+pub fn set_tcp_keepintvl<Fd: AsFd>(fd: Fd, duration: Duration) -> io::Result<()> {
+    imp::net::syscalls::sockopt::set_tcp_keepintvl(fd.as_fd(), duration)
+}
+
+/// `getsockopt(fd, IPPROTO_TCP, TCP_KEEPINTVL)`
+///
+/// # References
+///  - [Linux `getsockopt`]
+///  - [Linux `tcp`]
+///
+/// [Linux `getsockopt`]: https://man7.org/linux/man-pages/man2/getsockopt.2.html
+/// [Linux `tcp`]: https://man7.org/linux/man-pages/man7/tcp.7.html
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "TCP_KEEPINTVL")]
+pub fn get_tcp_keepintvl<Fd: AsFd>(fd: Fd) -> io::Result<Duration> {
+    imp::net::syscalls::sockopt::get_tcp_keepintvl(fd.as_fd())
+}
+
+/// `setsockopt(fd, IPPROTO_TCP, TCP_KEEPCNT, count)`—Set the number of
+/// unanswered keepalive probes after which the connection is dropped.
+///
+/// A zero or out-of-range count fails with [`io::Errno::INVAL`].
+///
+/// # References
+///  - [Linux `setsockopt`]
+///  - [Linux `tcp`]
+///
+/// [Linux `setsockopt`]: https://man7.org/linux/man-pages/man2/setsockopt.2.html
+/// [Linux `tcp`]: https://man7.org/linux/man-pages/man7/tcp.7.html
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "TCP_KEEPCNT")]
+pub fn set_tcp_keepcnt<Fd: AsFd>(fd: Fd, count: u32) -> io::Result<()> {
+    imp::net::syscalls::sockopt::set_tcp_keepcnt(fd.as_fd(), count)
+}
+
+/// `getsockopt(fd, IPPROTO_TCP, TCP_KEEPCNT)`
+///
+/// # References
+///  - [Linux `getsockopt`]
+///  - [Linux `tcp`]
+///
+/// [Linux `getsockopt`]: https://man7.org/linux/man-pages/man2/getsockopt.2.html
+/// [Linux `tcp`]: https://man7.org/linux/man-pages/man7/tcp.7.html
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "TCP_KEEPCNT")]
+pub fn get_tcp_keepcnt<Fd: AsFd>(fd: Fd) -> io::Result<u32> {
+    imp::net::syscalls::sockopt::get_tcp_keepcnt(fd.as_fd())
+}
+
+/// `setsockopt(fd, IPPROTO_TCP, TCP_USER_TIMEOUT, timeout)`—Set how long
+/// transmitted data may remain unacknowledged before the connection is
+/// dropped.
+///
+/// The kernel takes milliseconds, so `timeout` is rounded up to the next
+/// millisecond. A zero `timeout` selects the system default. An
+/// out-of-range value fails with [`io::Errno::INVAL`].
+///
+/// # References
+///  - [Linux `setsockopt`]
+///  - [Linux `tcp`]
+///
+/// [Linux `setsockopt`]: https://man7.org/linux/man-pages/man2/setsockopt.2.html
+/// [Linux `tcp`]: https://man7.org/linux/man-pages/man7/tcp.7.html
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "TCP_USER_TIMEOUT")]
+pub fn set_tcp_user_timeout<Fd: AsFd>(fd: Fd, timeout: Duration) -> io::Result<()> {
+    imp::net::syscalls::sockopt::set_tcp_user_timeout(fd.as_fd(), timeout)
+}
+
+/// `getsockopt(fd, IPPROTO_TCP, TCP_USER_TIMEOUT)`
+///
+/// # References
+///  - [Linux `getsockopt`]
+///  - [Linux `tcp`]
+///
+/// [Linux `getsockopt`]: https://man7.org/linux/man-pages/man2/getsockopt.2.html
+/// [Linux `tcp`]: https://man7.org/linux/man-pages/man7/tcp.7.html
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "TCP_USER_TIMEOUT")]
+pub fn get_tcp_user_timeout<Fd: AsFd>(fd: Fd) -> io::Result<Duration> {
+    imp::net::syscalls::sockopt::get_tcp_user_timeout(fd.as_fd())
+}
+
+/// `setsockopt(fd, IPPROTO_TCP, TCP_CONGESTION, name)`—Set the congestion
+/// control algorithm for a socket.
+///
+/// If the algorithm isn't available, this fails with
+/// [`io::Errno::NOENT`]. Algorithms not listed in
+/// `net.ipv4.tcp_allowed_congestion_control` require the `CAP_NET_ADMIN`
+/// capability.
+///
+/// # References
+///  - [Linux `setsockopt`]
+///  - [Linux `tcp`]
+///
+/// [Linux `setsockopt`]: https://man7.org/linux/man-pages/man2/setsockopt.2.html
+/// [Linux `tcp`]: https://man7.org/linux/man-pages/man7/tcp.7.html
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "TCP_CONGESTION")]
+pub fn set_tcp_congestion<Fd: AsFd>(fd: Fd, name: &ZStr) -> io::Result<()> {
+    imp::net::syscalls::sockopt::set_tcp_congestion(fd.as_fd(), name)
+}
+
+/// `getsockopt(fd, IPPROTO_TCP, TCP_CONGESTION)`—Returns the name of the
+/// congestion control algorithm for a socket.
+///
+/// # References
+///  - [Linux `getsockopt`]
+///  - [Linux `tcp`]
+///
+/// [Linux `getsockopt`]: https://man7.org/linux/man-pages/man2/getsockopt.2.html
+/// [Linux `tcp`]: https://man7.org/linux/man-pages/man7/tcp.7.html
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "TCP_CONGESTION")]
+pub fn get_tcp_congestion<Fd: AsFd>(fd: Fd) -> io::Result<ZString> {
+    imp::net::syscalls::sockopt::get_tcp_congestion(fd.as_fd())
+}
+
+/// `getsockopt(fd, IPPROTO_TCP, TCP_INFO)`—Returns statistics about a TCP
+/// connection.
+///
+/// The size of `struct tcp_info` has grown over time, so this passes the size
+/// of the buffer to the kernel and only uses the leading fields, which all
+/// Linux versions fill in.
+///
+/// # References
+///  - [Linux `getsockopt`]
+///  - [Linux `tcp`]
+///
+/// [Linux `getsockopt`]: https://man7.org/linux/man-pages/man2/getsockopt.2.html
+/// [Linux `tcp`]: https://man7.org/linux/man-pages/man7/tcp.7.html
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "TCP_INFO")]
+pub fn get_tcp_info<Fd: AsFd>(fd: Fd) -> io::Result<TcpInfo> {
+    imp::net::syscalls::sockopt::get_tcp_info(fd.as_fd())
+}
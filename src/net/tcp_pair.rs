@@ -0,0 +1,42 @@
+use crate::io::{self, OwnedFd};
+use crate::net::{
+    accept_with, bind_v4, connect_v4, getsockname, listen, socket_with, AcceptFlags, AddressFamily,
+    Ipv4Addr, Protocol, SocketAddrAny, SocketAddrV4, SocketFlags, SocketType,
+};
+
+/// Creates a pair of connected TCP sockets over the IPv4 loopback interface.
+///
+/// This binds a listening socket to `127.0.0.1` on an ephemeral port, connects
+/// a new socket to it, accepts the connection, and then closes the listening
+/// socket. It returns the client and server ends of the connection, in that
+/// order, and both have the close-on-exec flag set.
+///
+/// This is similar to [`socketpair`], but for cases where a real TCP
+/// connection is needed.
+///
+/// [`socketpair`]: crate::net::socketpair
+pub fn tcp_pair() -> io::Result<(OwnedFd, OwnedFd)> {
+    let listener = socket_with(
+        AddressFamily::INET,
+        SocketType::STREAM,
+        SocketFlags::CLOEXEC,
+        Protocol::TCP,
+    )?;
+    bind_v4(&listener, &SocketAddrV4::new(Ipv4Addr::LOCALHOST, 0))?;
+    listen(&listener, 1)?;
+    let addr = match getsockname(&listener)? {
+        SocketAddrAny::V4(addr) => addr,
+        _ => return Err(io::Errno::AFNOSUPPORT),
+    };
+
+    let client = socket_with(
+        AddressFamily::INET,
+        SocketType::STREAM,
+        SocketFlags::CLOEXEC,
+        Protocol::TCP,
+    )?;
+    connect_v4(&client, &addr)?;
+    let server = accept_with(&listener, AcceptFlags::CLOEXEC)?;
+
+    Ok((client, server))
+}
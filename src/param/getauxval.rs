@@ -0,0 +1,51 @@
+//! Linux APIs for reading values from the auxv array.
+
+use crate::ffi::ZStr;
+use crate::imp;
+
+pub use imp::process::types::AuxvKind;
+
+/// `getauxval(kind)`—Returns a value from the Linux auxv array.
+///
+/// Return the value of the auxv entry `kind` passed to the current process,
+/// or 0 if it is not available. For entries which are pointers, such as
+/// [`AuxvKind::SysinfoEhdr`], this is the address.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man3/getauxval.3.html
+#[inline]
+#[doc(alias = "getauxval")]
+pub fn auxv(kind: AuxvKind) -> usize {
+    imp::process::auxv::linux_auxv(kind)
+}
+
+/// `(getauxval(AT_HWCAP), getauxval(AT_HWCAP2)`—Returns the Linux "hwcap"
+/// data.
+///
+/// Return the Linux `AT_HWCAP` and `AT_HWCAP2` values passed to the
+/// current process. Returns 0 for each value if it is not available.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man3/getauxval.3.html
+#[inline]
+pub fn linux_hwcap() -> (usize, usize) {
+    imp::process::auxv::linux_hwcap()
+}
+
+/// `getauxval(AT_EXECFN)`—Returns the Linux "execfn" string.
+///
+/// Return the string that Linux has recorded as the filesystem path to the
+/// executable. Returns an empty string if the string is not available.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man3/getauxval.3.html
+#[inline]
+pub fn linux_execfn() -> &'static ZStr {
+    imp::process::auxv::linux_execfn()
+}
@@ -0,0 +1,40 @@
+//! Process parameters.
+//!
+//! These values correspond to `sysconf` in POSIX, and the auxv array in Linux.
+//! Despite the POSIX name "sysconf", these aren't *system* configuration
+//! parameters; they're *process* configuration parameters, as they may differ
+//! between different processes on the same system.
+
+mod auxv;
+#[cfg(any(
+    linux_raw,
+    all(
+        libc,
+        any(
+            all(target_os = "android", target_pointer_width = "64"),
+            target_os = "linux"
+        )
+    )
+))]
+mod getauxval;
+#[cfg(linux_raw)]
+mod vdso;
+
+#[cfg(not(target_os = "wasi"))]
+pub use auxv::clock_ticks_per_second;
+#[cfg(target_vendor = "mustang")]
+pub use auxv::init;
+pub use auxv::page_size;
+#[cfg(any(
+    linux_raw,
+    all(
+        libc,
+        any(
+            all(target_os = "android", target_pointer_width = "64"),
+            target_os = "linux"
+        )
+    )
+))]
+pub use getauxval::{auxv, linux_execfn, linux_hwcap, AuxvKind};
+#[cfg(linux_raw)]
+pub use vdso::vdso_sym;
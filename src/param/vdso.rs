@@ -0,0 +1,25 @@
+//! Linux vDSO symbol lookup.
+
+use crate::ffi::ZStr;
+use crate::imp;
+use core::ffi::c_void;
+
+/// Look up a symbol in the Linux vDSO.
+///
+/// Returns the address of the function named `name` with symbol version
+/// `version` in the vDSO, or null if the vDSO isn't available or doesn't
+/// contain a matching symbol. Symbol names and versions are
+/// architecture-specific; see the vDSO documentation for the values on each
+/// platform.
+///
+/// The vDSO is parsed on the first call, and the result is reused by later
+/// calls.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man7/vdso.7.html
+#[inline]
+pub fn vdso_sym(version: &ZStr, name: &ZStr) -> *mut c_void {
+    imp::process::auxv::vdso_sym(version, name)
+}
@@ -1,4 +1,4 @@
-use crate::ffi::ZString;
+use crate::ffi::{ZStr, ZString};
 use crate::path::SMALL_PATH_BUFFER_SIZE;
 use crate::{imp, io, path};
 use alloc::vec::Vec;
@@ -7,6 +7,12 @@ use imp::fd::AsFd;
 
 /// `chdir(path)`—Change the current working directory.
 ///
+/// The current working directory is shared by all threads in the process.
+/// On Linux, the underlying system call changes the working directory of
+/// the calling thread's filesystem context, which is shared with the other
+/// threads unless the thread has called `unshare(CLONE_FS)` or was created
+/// without `CLONE_FS`.
+///
 /// # References
 ///  - [POSIX]
 ///  - [Linux]
@@ -20,6 +26,13 @@ pub fn chdir<P: path::Arg>(path: P) -> io::Result<()> {
 
 /// `fchdir(fd)`—Change the current working directory.
 ///
+/// Unlike [`chdir`], this doesn't look up a path, so when `fd` was opened
+/// with `OFlags::DIRECTORY`, it changes to exactly that directory even if
+/// the path it was opened with has since been renamed or replaced. If `fd`
+/// doesn't refer to a directory, this fails with `NOTDIR`.
+///
+/// See [`chdir`] for how this affects other threads.
+///
 /// # References
 ///  - [POSIX]
 ///  - [Linux]
@@ -35,6 +48,14 @@ pub fn fchdir<Fd: AsFd>(fd: Fd) -> io::Result<()> {
 /// `getcwd()`—Return the current working directory.
 ///
 /// If `reuse` is non-empty, reuse its buffer to store the result if possible.
+/// The buffer is grown until the path fits.
+///
+/// If the current working directory has been removed, this fails with
+/// `NOENT`, or on some platforms, returns the old path with a ` (deleted)`
+/// suffix. On Linux, if it isn't reachable from the process' root
+/// directory, such as after a `chroot`, the path may begin with
+/// `(unreachable)`. Callers which care about these cases need to handle
+/// them.
 ///
 /// # References
 ///  - [POSIX]
@@ -48,6 +69,27 @@ pub fn getcwd<B: Into<Vec<u8>>>(reuse: B) -> io::Result<ZString> {
     _getcwd(reuse.into())
 }
 
+/// `getcwd(buf, buf.len())`—Return the current working directory, stored
+/// in `buf`.
+///
+/// This doesn't allocate; if `buf` is too small to hold the path and its NUL
+/// terminator, this fails with `RANGE`. See [`getcwd`] for details on what
+/// happens when the current working directory has been removed.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/getcwd.html
+/// [Linux]: https://man7.org/linux/man-pages/man3/getcwd.3.html
+#[cfg(not(target_os = "wasi"))]
+#[inline]
+pub fn getcwd_raw(buf: &mut [u8]) -> io::Result<&ZStr> {
+    imp::process::syscalls::getcwd(buf)?;
+    let len = buf.iter().position(|x| *x == b'\0').unwrap();
+    Ok(ZStr::from_bytes_with_nul(&buf[..=len]).unwrap())
+}
+
 fn _getcwd(mut buffer: Vec<u8>) -> io::Result<ZString> {
     // This code would benefit from having a better way to read into
     // uninitialized memory, but that requires `unsafe`.
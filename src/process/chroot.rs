@@ -0,0 +1,16 @@
+use crate::{imp, io, path};
+
+/// `chroot(path)`—Change the process root directory.
+///
+/// This requires the `CAP_SYS_CHROOT` capability, and fails with `PERM`
+/// otherwise. It doesn't change the current working directory, which may
+/// remain outside the new root.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/chroot.2.html
+#[inline]
+pub fn chroot<P: path::Arg>(path: P) -> io::Result<()> {
+    path.into_with_z_str(imp::process::syscalls::chroot)
+}
@@ -0,0 +1,40 @@
+use crate::{imp, io};
+
+/// `gethostname(buf)`—Returns the system's hostname.
+///
+/// Linux has no `gethostname` system call, so this reads the `nodename`
+/// field of [`uname`]. The name is written to the start of `buf`, without a
+/// NUL terminator, and its length is returned. If `buf` is too small to hold
+/// the name, this fails with `NAMETOOLONG`.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/gethostname.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/gethostname.2.html
+/// [`uname`]: crate::process::uname
+#[inline]
+pub fn gethostname(buf: &mut [u8]) -> io::Result<usize> {
+    let uname = crate::process::uname();
+    let name = uname.nodename().to_bytes();
+    let dest = buf.get_mut(..name.len()).ok_or(io::Errno::NAMETOOLONG)?;
+    dest.copy_from_slice(name);
+    Ok(name.len())
+}
+
+/// `sethostname(name, len)`—Sets the system's hostname.
+///
+/// This requires the `CAP_SYS_ADMIN` capability in the UTS namespace, and
+/// fails with `PERM` otherwise, which Linux checks before anything else.
+/// With the capability, names longer than `HOST_NAME_MAX` fail with `INVAL`.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/sethostname.2.html
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+pub fn sethostname(name: &[u8]) -> io::Result<()> {
+    imp::process::syscalls::sethostname(name)
+}
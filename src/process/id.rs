@@ -241,6 +241,13 @@ pub fn getegid() -> Gid {
 
 /// `getpid()`—Returns the process' ID.
 ///
+/// rustix doesn't cache this; each call queries the kernel, directly or via
+/// libc's `getpid`, which glibc 2.25 and later and musl don't cache either.
+/// So it's accurate in the child after a `fork`. For the calling thread's
+/// ID, use [`rustix::thread::gettid`].
+///
+/// [`rustix::thread::gettid`]: crate::thread::gettid
+///
 /// # References
 ///  - [POSIX]
 ///  - [Linux]
@@ -269,6 +276,11 @@ pub fn getppid() -> Option<Pid> {
 
 /// `setsid()`—Create a new session.
 ///
+/// On success, the calling process is the leader of a new session and a new
+/// process group, with no controlling terminal, and this returns the new
+/// session ID, which equals its pid. If the calling process is already a
+/// process group leader, this fails with `PERM`.
+///
 /// # References
 ///  - [POSIX]
 ///  - [Linux]
@@ -279,3 +291,73 @@ pub fn getppid() -> Option<Pid> {
 pub fn setsid() -> io::Result<Pid> {
     imp::process::syscalls::setsid()
 }
+
+/// `getsid(pid)`—Returns the session ID of the given process.
+///
+/// If `pid` is `None`, this returns the session ID of the calling process.
+///
+/// This returns `None` if the session leader is in a different PID
+/// namespace, such as when running in a container.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/getsid.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/getsid.2.html
+#[inline]
+pub fn getsid(pid: Option<Pid>) -> io::Result<Option<Pid>> {
+    imp::process::syscalls::getsid(pid)
+}
+
+/// `getpgid(pid)`—Returns the process group ID of the given process.
+///
+/// If `pid` is `None`, this returns the process group ID of the calling
+/// process.
+///
+/// This returns `None` if the process group leader is in a different PID
+/// namespace, such as when running in a container.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/getpgid.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/getpgid.2.html
+#[inline]
+pub fn getpgid(pid: Option<Pid>) -> io::Result<Option<Pid>> {
+    imp::process::syscalls::getpgid(pid)
+}
+
+/// `setpgid(pid, pgid)`—Sets the process group ID of the given process.
+///
+/// If `pid` is `None`, this sets the process group ID of the calling
+/// process. If `pgid` is `None`, the process group ID is set to the pid of
+/// the target process, making it the leader of a new process group.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/setpgid.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/setpgid.2.html
+#[inline]
+pub fn setpgid(pid: Option<Pid>, pgid: Option<Pid>) -> io::Result<()> {
+    imp::process::syscalls::setpgid(pid, pgid)
+}
+
+/// Translate the optional owner and group arguments of the `*chown`
+/// functions into raw ids, using `-1` to mean "leave unchanged".
+pub(crate) fn translate_fchown_args(owner: Option<Uid>, group: Option<Gid>) -> (RawUid, RawGid) {
+    let ow = match owner {
+        Some(o) => o.as_raw(),
+        None => !0,
+    };
+
+    let gr = match group {
+        Some(g) => g.as_raw(),
+        None => !0,
+    };
+
+    (ow, gr)
+}
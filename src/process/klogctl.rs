@@ -0,0 +1,85 @@
+//! The Linux `syslog` system call, for the kernel log buffer.
+//!
+//! # Safety
+//!
+//! `klogctl` writes into a buffer which, for some actions, is passed as a
+//! raw pointer.
+#![allow(unsafe_code)]
+
+use crate::{imp, io};
+use core::cmp::min;
+use core::ptr::null_mut;
+
+/// `SYSLOG_ACTION_*` constants for use with [`klogctl`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum KlogAction {
+    /// `SYSLOG_ACTION_READ`—Wait until the log buffer is non-empty, then
+    /// read and consume messages from it.
+    Read,
+
+    /// `SYSLOG_ACTION_READ_ALL`—Read the most recent messages in the log
+    /// buffer, without consuming them.
+    ReadAll,
+
+    /// `SYSLOG_ACTION_READ_CLEAR`—Read the most recent messages in the log
+    /// buffer, and then clear it.
+    ReadClear,
+
+    /// `SYSLOG_ACTION_CLEAR`—Clear the log buffer.
+    Clear,
+
+    /// `SYSLOG_ACTION_CONSOLE_OFF`—Stop printing messages to the console,
+    /// except for panics.
+    ConsoleOff,
+
+    /// `SYSLOG_ACTION_CONSOLE_ON`—Restore the console log level saved by
+    /// `ConsoleOff`.
+    ConsoleOn,
+
+    /// `SYSLOG_ACTION_CONSOLE_LEVEL`—Set the console log level, from 1 to
+    /// 8; messages with a lower level are printed to the console.
+    ConsoleLevel(u8),
+
+    /// `SYSLOG_ACTION_SIZE_UNREAD`—Return the number of bytes which
+    /// `Read` would consume.
+    SizeUnread,
+
+    /// `SYSLOG_ACTION_SIZE_BUFFER`—Return the size of the log buffer.
+    SizeBuffer,
+}
+
+/// `syslog(action, buf, len)`—Read or control the kernel log buffer.
+///
+/// For [`KlogAction::Read`], [`KlogAction::ReadAll`], and
+/// [`KlogAction::ReadClear`], messages are written to `buf`, and the number
+/// of bytes written is returned. For [`KlogAction::SizeUnread`] and
+/// [`KlogAction::SizeBuffer`], the size is returned, and `buf` is unused.
+/// Otherwise, `buf` is unused and 0 is returned.
+///
+/// This requires the `CAP_SYSLOG` capability, and fails with `PERM`
+/// otherwise, except that [`KlogAction::ReadAll`] and
+/// [`KlogAction::SizeBuffer`] are permitted when the `kernel.dmesg_restrict`
+/// sysctl is 0.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/syslog.2.html
+#[inline]
+#[doc(alias = "syslog")]
+pub fn klogctl(action: KlogAction, buf: &mut [u8]) -> io::Result<usize> {
+    // The kernel takes the length as an `int`.
+    let len = min(buf.len(), i32::MAX as usize) as u32;
+    let (raw, buf, len) = match action {
+        KlogAction::Read => (2, buf.as_mut_ptr(), len),
+        KlogAction::ReadAll => (3, buf.as_mut_ptr(), len),
+        KlogAction::ReadClear => (4, buf.as_mut_ptr(), len),
+        KlogAction::Clear => (5, null_mut(), 0),
+        KlogAction::ConsoleOff => (6, null_mut(), 0),
+        KlogAction::ConsoleOn => (7, null_mut(), 0),
+        KlogAction::ConsoleLevel(level) => (8, null_mut(), level.into()),
+        KlogAction::SizeUnread => (9, null_mut(), 0),
+        KlogAction::SizeBuffer => (10, null_mut(), 0),
+    };
+    unsafe { imp::process::syscalls::klogctl(raw, buf, len) }
+}
@@ -1,20 +1,45 @@
 //! Process-associated operations.
 
-#[cfg(feature = "process")]
-mod auxv;
 #[cfg(not(target_os = "wasi"))]
 mod chdir;
+#[cfg(not(any(target_os = "fuchsia", target_os = "wasi")))]
+#[cfg(feature = "process")]
+mod chroot;
 mod exit;
+#[cfg(not(target_os = "wasi"))] // WASI doesn't have uname.
+#[cfg(feature = "process")]
+mod hostname;
 #[cfg(not(target_os = "wasi"))] // WASI doesn't have get[gpu]id.
 mod id;
 #[cfg(not(target_os = "wasi"))]
 mod kill;
 #[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg(feature = "process")]
+mod klogctl;
+#[cfg(any(target_os = "android", target_os = "linux"))]
 mod membarrier;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg(feature = "process")]
+mod personality;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg(feature = "process")]
+mod pidfd;
 #[cfg(not(any(target_os = "fuchsia", target_os = "wasi")))] // WASI doesn't have [gs]etpriority.
 mod priority;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg(feature = "process")]
+mod process_vm;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg(feature = "process")]
+pub mod ptrace;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg(feature = "process")]
+mod reboot;
 #[cfg(not(any(target_os = "fuchsia", target_os = "redox", target_os = "wasi")))]
 mod rlimit;
+#[cfg(not(any(target_os = "fuchsia", target_os = "redox", target_os = "wasi")))]
+#[cfg(feature = "process")]
+mod rusage;
 #[cfg(any(
     target_os = "linux",
     target_os = "android",
@@ -23,19 +48,29 @@ mod rlimit;
 ))]
 mod sched;
 mod sched_yield;
+#[cfg(not(target_os = "wasi"))]
+mod signal;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg(feature = "process")]
+mod sysinfo;
+#[cfg(not(target_os = "wasi"))]
+#[cfg(feature = "fs")]
+mod umask;
 #[cfg(not(target_os = "wasi"))] // WASI doesn't have uname.
 mod uname;
 #[cfg(not(target_os = "wasi"))]
 mod wait;
 
+// The auxv-derived parameters live in `rustix::param`; re-export them here so
+// that existing users of `rustix::process` continue to work.
 #[cfg(not(target_os = "wasi"))]
 #[cfg(feature = "process")]
-pub use auxv::clock_ticks_per_second;
+pub use crate::param::clock_ticks_per_second;
 #[cfg(target_vendor = "mustang")]
 #[cfg(feature = "process")]
-pub use auxv::init;
+pub use crate::param::init;
 #[cfg(feature = "process")]
-pub use auxv::page_size;
+pub use crate::param::page_size;
 #[cfg(any(
     linux_raw,
     all(
@@ -47,29 +82,52 @@ pub use auxv::page_size;
     )
 ))]
 #[cfg(feature = "process")]
-pub use auxv::{linux_execfn, linux_hwcap};
+pub use crate::param::{linux_execfn, linux_hwcap};
 #[cfg(not(target_os = "wasi"))]
 pub use chdir::chdir;
 #[cfg(not(any(target_os = "wasi", target_os = "fuchsia")))]
 pub use chdir::fchdir;
 #[cfg(not(target_os = "wasi"))]
-pub use chdir::getcwd;
+pub use chdir::{getcwd, getcwd_raw};
+#[cfg(not(any(target_os = "fuchsia", target_os = "wasi")))]
+#[cfg(feature = "process")]
+pub use chroot::chroot;
 #[cfg(not(target_os = "wasi"))]
 pub use exit::EXIT_SIGNALED_SIGABRT;
 pub use exit::{EXIT_FAILURE, EXIT_SUCCESS};
+#[cfg(not(target_os = "wasi"))]
+#[cfg(feature = "process")]
+pub use hostname::gethostname;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg(feature = "process")]
+pub use hostname::sethostname;
+#[cfg(not(target_os = "wasi"))]
+pub(crate) use id::translate_fchown_args;
 #[cfg(any(target_os = "android", target_os = "linux"))]
 pub use id::Cpuid;
 #[cfg(not(target_os = "wasi"))]
 pub use id::{
-    getegid, geteuid, getgid, getpid, getppid, getuid, setsid, Gid, Pid, RawGid, RawNonZeroPid,
-    RawPid, RawUid, Uid,
+    getegid, geteuid, getgid, getpgid, getpid, getppid, getsid, getuid, setpgid, setsid, Gid, Pid,
+    RawGid, RawNonZeroPid, RawPid, RawUid, Uid,
 };
 #[cfg(not(target_os = "wasi"))]
-pub use kill::{kill_current_process_group, kill_process, kill_process_group, Signal};
+pub use kill::{
+    kill_current_process_group, kill_process, kill_process_group, test_kill_current_process_group,
+    test_kill_process, test_kill_process_group, Signal,
+};
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg(feature = "process")]
+pub use klogctl::{klogctl, KlogAction};
 #[cfg(any(target_os = "android", target_os = "linux"))]
 pub use membarrier::{
     membarrier, membarrier_cpu, membarrier_query, MembarrierCommand, MembarrierQuery,
 };
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg(feature = "process")]
+pub use personality::{personality, personality_query, Personality};
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg(feature = "process")]
+pub use pidfd::{pidfd_getfd, pidfd_open, PidfdFlags, PidfdGetfdFlags};
 #[cfg(not(any(target_os = "fuchsia", target_os = "wasi")))]
 pub use priority::nice;
 #[cfg(not(any(target_os = "fuchsia", target_os = "redox", target_os = "wasi")))]
@@ -78,9 +136,18 @@ pub use priority::{
     setpriority_user,
 };
 #[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg(feature = "process")]
+pub use process_vm::{process_vm_readv, process_vm_writev, RemoteIoVec};
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg(feature = "process")]
+pub use reboot::{reboot, RebootCommand};
+#[cfg(any(target_os = "android", target_os = "linux"))]
 pub use rlimit::prlimit;
 #[cfg(not(any(target_os = "fuchsia", target_os = "redox", target_os = "wasi")))]
 pub use rlimit::{getrlimit, setrlimit, Resource, Rlimit};
+#[cfg(not(any(target_os = "fuchsia", target_os = "redox", target_os = "wasi")))]
+#[cfg(feature = "process")]
+pub use rusage::{getrusage, Rusage, RusageWho, Timeval};
 #[cfg(any(
     target_os = "linux",
     target_os = "android",
@@ -90,6 +157,22 @@ pub use rlimit::{getrlimit, setrlimit, Resource, Rlimit};
 pub use sched::{sched_getaffinity, sched_setaffinity, CpuSet};
 pub use sched_yield::sched_yield;
 #[cfg(not(target_os = "wasi"))]
+pub use signal::SigSet;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg(feature = "process")]
+pub use sysinfo::{sysinfo, Sysinfo};
+#[cfg(not(target_os = "wasi"))]
+#[cfg(feature = "fs")]
+pub use umask::umask;
+#[cfg(not(target_os = "wasi"))]
 pub use uname::{uname, Uname};
+#[cfg(not(any(
+    target_os = "fuchsia",
+    target_os = "illumos",
+    target_os = "redox",
+    target_os = "wasi"
+)))]
+#[cfg(feature = "process")]
+pub use wait::wait4;
 #[cfg(not(target_os = "wasi"))]
 pub use wait::{wait, waitpid, WaitOptions, WaitStatus};
@@ -0,0 +1,81 @@
+//! The Linux `personality` syscall.
+
+use crate::{imp, io};
+
+bitflags::bitflags! {
+    /// A process execution domain and its flags, for use with
+    /// [`personality`].
+    ///
+    /// The low byte holds the base persona, such as [`Personality::LINUX`]
+    /// or [`Personality::LINUX32`], and the remaining bits are flags.
+    pub struct Personality: u32 {
+        /// `PER_LINUX`—The standard Linux execution domain.
+        const LINUX = 0x0000_0000;
+
+        /// `PER_LINUX32`—Linux with a 32-bit `uname` machine name.
+        const LINUX32 = 0x0000_0008;
+
+        /// `UNAME26`—Report a 2.6.40+ kernel version from `uname`.
+        const UNAME26 = 0x0002_0000;
+
+        /// `ADDR_NO_RANDOMIZE`—Disable address-space-layout randomization.
+        const ADDR_NO_RANDOMIZE = 0x0004_0000;
+
+        /// `FDPIC_FUNCPTRS`—Function pointers point to descriptors.
+        const FDPIC_FUNCPTRS = 0x0008_0000;
+
+        /// `MMAP_PAGE_ZERO`—Map page 0 as read-only.
+        const MMAP_PAGE_ZERO = 0x0010_0000;
+
+        /// `ADDR_COMPAT_LAYOUT`—Use the legacy virtual address space layout.
+        const ADDR_COMPAT_LAYOUT = 0x0020_0000;
+
+        /// `READ_IMPLIES_EXEC`—Make readable mappings also executable.
+        const READ_IMPLIES_EXEC = 0x0040_0000;
+
+        /// `ADDR_LIMIT_32BIT`—Limit the address space to 32 bits.
+        const ADDR_LIMIT_32BIT = 0x0080_0000;
+
+        /// `SHORT_INODE`—No effect.
+        const SHORT_INODE = 0x0100_0000;
+
+        /// `WHOLE_SECONDS`—No effect.
+        const WHOLE_SECONDS = 0x0200_0000;
+
+        /// `STICKY_TIMEOUTS`—Don't modify the timeouts passed to `select`,
+        /// `pselect`, and `ppoll`.
+        const STICKY_TIMEOUTS = 0x0400_0000;
+
+        /// `ADDR_LIMIT_3GB`—Limit the address space to 3 GiB.
+        const ADDR_LIMIT_3GB = 0x0800_0000;
+    }
+}
+
+/// `personality(persona)`—Set the process execution domain, returning the
+/// previous one.
+///
+/// The new persona takes effect for the calling process, and is inherited
+/// by children and preserved across `execve`, so it can be used to, for
+/// example, disable ASLR for a program before executing it.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/personality.2.html
+#[inline]
+pub fn personality(persona: Personality) -> io::Result<Personality> {
+    imp::process::syscalls::personality(persona.bits())
+}
+
+/// `personality(0xffffffff)`—Return the process execution domain without
+/// changing it.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/personality.2.html
+#[inline]
+#[doc(alias = "personality")]
+pub fn personality_query() -> io::Result<Personality> {
+    imp::process::syscalls::personality(0xffff_ffff)
+}
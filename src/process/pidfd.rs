@@ -0,0 +1,61 @@
+use crate::fd::{AsFd, RawFd};
+use crate::fs::OFlags;
+use crate::io::OwnedFd;
+use crate::process::Pid;
+use crate::{imp, io};
+
+bitflags::bitflags! {
+    /// `PIDFD_*` flags for use with [`pidfd_open`].
+    pub struct PidfdFlags: u32 {
+        /// `PIDFD_NONBLOCK` (since Linux 5.10)
+        const NONBLOCK = OFlags::NONBLOCK.bits() as _;
+    }
+}
+
+bitflags::bitflags! {
+    /// Flags for use with [`pidfd_getfd`].
+    ///
+    /// No flags are currently defined.
+    pub struct PidfdGetfdFlags: u32 {}
+}
+
+/// `pidfd_open(pid, flags)`—Open a file descriptor which refers to a
+/// process.
+///
+/// Unlike a [`Pid`], the file descriptor keeps referring to the same
+/// process even after it exits and its pid is reused. `pid` must be the
+/// pid of a thread-group leader. Before Linux 5.3, this fails with
+/// [`io::Errno::NOSYS`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/pidfd_open.2.html
+#[inline]
+pub fn pidfd_open(pid: Pid, flags: PidfdFlags) -> io::Result<OwnedFd> {
+    imp::process::syscalls::pidfd_open(pid, flags.bits())
+}
+
+/// `pidfd_getfd(pidfd, targetfd, flags)`—Duplicate a file descriptor from
+/// another process.
+///
+/// Returns a new file descriptor in the calling process which refers to the
+/// same open file description as `targetfd` in the process `pidfd` refers
+/// to. The new file descriptor has `FD_CLOEXEC` set.
+///
+/// This requires permission to `ptrace` the process, and fails with
+/// [`io::Errno::PERM`] otherwise. Before Linux 5.6, this fails with
+/// [`io::Errno::NOSYS`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/pidfd_getfd.2.html
+#[inline]
+pub fn pidfd_getfd<Fd: AsFd>(
+    pidfd: Fd,
+    targetfd: RawFd,
+    flags: PidfdGetfdFlags,
+) -> io::Result<OwnedFd> {
+    imp::process::syscalls::pidfd_getfd(pidfd.as_fd(), targetfd, flags.bits())
+}
@@ -0,0 +1,75 @@
+//! The Linux `process_vm_readv` and `process_vm_writev` syscalls.
+//!
+//! # Safety
+//!
+//! `process_vm_writev` can write to arbitrary memory in the calling process.
+#![allow(unsafe_code)]
+
+use crate::imp;
+use crate::io::{self, IoSlice, IoSliceMut};
+use crate::process::Pid;
+
+/// A region of memory in another process, for use with [`process_vm_readv`]
+/// and [`process_vm_writev`].
+///
+/// This has the same layout as a `struct iovec`.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct RemoteIoVec {
+    /// The address of the region in the remote process.
+    pub base: usize,
+
+    /// The length of the region, in bytes.
+    pub len: usize,
+}
+
+/// `process_vm_readv(pid, local, remote, 0)`—Read memory from another
+/// process.
+///
+/// This returns the number of bytes read, which may be less than requested
+/// if part of a remote region is not mapped.
+///
+/// The caller needs `PTRACE_MODE_ATTACH_REALCREDS` access to the target
+/// process; otherwise this fails with [`io::Errno::PERM`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/process_vm_readv.2.html
+#[inline]
+pub fn process_vm_readv(
+    pid: Pid,
+    local: &mut [IoSliceMut<'_>],
+    remote: &[RemoteIoVec],
+) -> io::Result<usize> {
+    imp::process::syscalls::process_vm_readv(pid, local, remote)
+}
+
+/// `process_vm_writev(pid, local, remote, 0)`—Write memory in another
+/// process.
+///
+/// This returns the number of bytes written, which may be less than requested
+/// if part of a remote region is not mapped.
+///
+/// The caller needs `PTRACE_MODE_ATTACH_REALCREDS` access to the target
+/// process; otherwise this fails with [`io::Errno::PERM`].
+///
+/// # Safety
+///
+/// If `pid` is the calling process, or a process sharing its address space,
+/// this writes to arbitrary memory in the caller. In that case, the `remote`
+/// regions must be valid for writes and not otherwise accessed while this
+/// runs.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/process_vm_writev.2.html
+#[inline]
+pub unsafe fn process_vm_writev(
+    pid: Pid,
+    local: &[IoSlice<'_>],
+    remote: &[RemoteIoVec],
+) -> io::Result<usize> {
+    imp::process::syscalls::process_vm_writev(pid, local, remote)
+}
@@ -0,0 +1,420 @@
+//! The Linux `ptrace` API, for tracing and controlling other processes.
+//!
+//! A tracer must have `PTRACE_MODE_ATTACH_REALCREDS` access to the tracee:
+//! typically it must have the same real user ID as the tracee, or have the
+//! `CAP_SYS_PTRACE` capability, and be permitted by the Yama
+//! `ptrace_scope` setting. Otherwise, attaching fails with
+//! [`io::Errno::PERM`]. Most requests also require the tracee to be
+//! stopped, and fail with [`io::Errno::SRCH`] if it isn't.
+//!
+//! # Safety
+//!
+//! A tracee may share its address space with the tracer, so requests which
+//! modify the tracee's memory or registers are `unsafe`.
+//!
+//! # References
+//!  - [Linux]
+//!
+//! [Linux]: https://man7.org/linux/man-pages/man2/ptrace.2.html
+#![allow(unsafe_code)]
+
+use crate::process::{Pid, Signal};
+use crate::{imp, io};
+use core::mem::{size_of, MaybeUninit};
+use core::ptr::null_mut;
+use imp::process::types::{PtraceRequest, NT_PRSTATUS};
+
+bitflags::bitflags! {
+    /// `PTRACE_O_*` constants for use with [`seize`] and [`setoptions`].
+    pub struct PtraceOptions: u32 {
+        /// `PTRACE_O_TRACESYSGOOD`—Set bit 7 of the signal number of
+        /// syscall stops.
+        const TRACESYSGOOD = 0x0000_0001;
+
+        /// `PTRACE_O_TRACEFORK`—Stop at the next `fork` and trace the child.
+        const TRACEFORK = 0x0000_0002;
+
+        /// `PTRACE_O_TRACEVFORK`—Stop at the next `vfork` and trace the
+        /// child.
+        const TRACEVFORK = 0x0000_0004;
+
+        /// `PTRACE_O_TRACECLONE`—Stop at the next `clone` and trace the
+        /// child.
+        const TRACECLONE = 0x0000_0008;
+
+        /// `PTRACE_O_TRACEEXEC`—Stop at the next `execve`.
+        const TRACEEXEC = 0x0000_0010;
+
+        /// `PTRACE_O_TRACEVFORKDONE`—Stop when a `vfork` child releases the
+        /// parent.
+        const TRACEVFORKDONE = 0x0000_0020;
+
+        /// `PTRACE_O_TRACEEXIT`—Stop when the tracee exits.
+        const TRACEEXIT = 0x0000_0040;
+
+        /// `PTRACE_O_TRACESECCOMP`—Stop when a seccomp `SECCOMP_RET_TRACE`
+        /// rule is triggered.
+        const TRACESECCOMP = 0x0000_0080;
+
+        /// `PTRACE_O_EXITKILL`—Kill the tracee if the tracer exits.
+        const EXITKILL = 0x0010_0000;
+
+        /// `PTRACE_O_SUSPEND_SECCOMP`—Suspend the tracee's seccomp
+        /// protections.
+        const SUSPEND_SECCOMP = 0x0020_0000;
+    }
+}
+
+/// The general-purpose registers of a tracee, as a `struct
+/// user_regs_struct`.
+#[cfg(target_arch = "x86_64")]
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+#[allow(missing_docs)]
+pub struct UserRegs {
+    pub r15: u64,
+    pub r14: u64,
+    pub r13: u64,
+    pub r12: u64,
+    pub rbp: u64,
+    pub rbx: u64,
+    pub r11: u64,
+    pub r10: u64,
+    pub r9: u64,
+    pub r8: u64,
+    pub rax: u64,
+    pub rcx: u64,
+    pub rdx: u64,
+    pub rsi: u64,
+    pub rdi: u64,
+    pub orig_rax: u64,
+    pub rip: u64,
+    pub cs: u64,
+    pub eflags: u64,
+    pub rsp: u64,
+    pub ss: u64,
+    pub fs_base: u64,
+    pub gs_base: u64,
+    pub ds: u64,
+    pub es: u64,
+    pub fs: u64,
+    pub gs: u64,
+}
+
+/// The general-purpose registers of a tracee, as a `struct
+/// user_regs_struct`.
+#[cfg(target_arch = "x86")]
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+#[allow(missing_docs)]
+pub struct UserRegs {
+    pub ebx: u32,
+    pub ecx: u32,
+    pub edx: u32,
+    pub esi: u32,
+    pub edi: u32,
+    pub ebp: u32,
+    pub eax: u32,
+    pub xds: u32,
+    pub xes: u32,
+    pub xfs: u32,
+    pub xgs: u32,
+    pub orig_eax: u32,
+    pub eip: u32,
+    pub xcs: u32,
+    pub eflags: u32,
+    pub esp: u32,
+    pub xss: u32,
+}
+
+/// The general-purpose registers of a tracee, as a `struct
+/// user_pt_regs`.
+#[cfg(target_arch = "aarch64")]
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+#[allow(missing_docs)]
+pub struct UserRegs {
+    pub regs: [u64; 31],
+    pub sp: u64,
+    pub pc: u64,
+    pub pstate: u64,
+}
+
+/// The general-purpose registers of a tracee, as a `struct pt_regs`.
+///
+/// `uregs[15]` is the program counter, `uregs[16]` is the CPSR, and
+/// `uregs[17]` is the original `r0`.
+#[cfg(target_arch = "arm")]
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+#[allow(missing_docs)]
+pub struct UserRegs {
+    pub uregs: [u32; 18],
+}
+
+/// The general-purpose registers of a tracee, as a `struct
+/// user_regs_struct`.
+#[cfg(target_arch = "riscv64")]
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+#[allow(missing_docs)]
+pub struct UserRegs {
+    pub pc: u64,
+    pub ra: u64,
+    pub sp: u64,
+    pub gp: u64,
+    pub tp: u64,
+    pub t0: u64,
+    pub t1: u64,
+    pub t2: u64,
+    pub s0: u64,
+    pub s1: u64,
+    pub a0: u64,
+    pub a1: u64,
+    pub a2: u64,
+    pub a3: u64,
+    pub a4: u64,
+    pub a5: u64,
+    pub a6: u64,
+    pub a7: u64,
+    pub s2: u64,
+    pub s3: u64,
+    pub s4: u64,
+    pub s5: u64,
+    pub s6: u64,
+    pub s7: u64,
+    pub s8: u64,
+    pub s9: u64,
+    pub s10: u64,
+    pub s11: u64,
+    pub t3: u64,
+    pub t4: u64,
+    pub t5: u64,
+    pub t6: u64,
+}
+
+/// A `struct iovec`, for use with `PTRACE_GETREGSET` and
+/// `PTRACE_SETREGSET`.
+#[cfg(any(
+    target_arch = "aarch64",
+    target_arch = "arm",
+    target_arch = "riscv64",
+    target_arch = "x86",
+    target_arch = "x86_64",
+))]
+#[repr(C)]
+struct Iovec {
+    base: *mut UserRegs,
+    len: usize,
+}
+
+#[inline]
+fn signal_data(sig: Option<Signal>) -> *mut core::ffi::c_void {
+    sig.map_or(0, |sig| sig as usize) as *mut _
+}
+
+/// `ptrace(PTRACE_TRACEME, None, 0, 0)`—Ask to be traced by the parent
+/// process.
+///
+/// This is typically called in a child process before it calls `execve`.
+#[inline]
+#[doc(alias = "PTRACE_TRACEME")]
+pub fn traceme() -> io::Result<()> {
+    unsafe { imp::process::syscalls::ptrace(PtraceRequest::TraceMe, None, null_mut(), null_mut()) }
+}
+
+/// `ptrace(PTRACE_ATTACH, pid, 0, 0)`—Attach to a process and stop it
+/// with `SIGSTOP`.
+#[inline]
+#[doc(alias = "PTRACE_ATTACH")]
+pub fn attach(pid: Pid) -> io::Result<()> {
+    unsafe {
+        imp::process::syscalls::ptrace(PtraceRequest::Attach, Some(pid), null_mut(), null_mut())
+    }
+}
+
+/// `ptrace(PTRACE_SEIZE, pid, 0, options)`—Attach to a process without
+/// stopping it.
+#[inline]
+#[doc(alias = "PTRACE_SEIZE")]
+pub fn seize(pid: Pid, options: PtraceOptions) -> io::Result<()> {
+    unsafe {
+        imp::process::syscalls::ptrace(
+            PtraceRequest::Seize,
+            Some(pid),
+            null_mut(),
+            options.bits() as usize as *mut _,
+        )
+    }
+}
+
+/// `ptrace(PTRACE_SETOPTIONS, pid, 0, options)`—Set the tracing options
+/// for a stopped tracee.
+#[inline]
+#[doc(alias = "PTRACE_SETOPTIONS")]
+pub fn setoptions(pid: Pid, options: PtraceOptions) -> io::Result<()> {
+    unsafe {
+        imp::process::syscalls::ptrace(
+            PtraceRequest::SetOptions,
+            Some(pid),
+            null_mut(),
+            options.bits() as usize as *mut _,
+        )
+    }
+}
+
+/// `ptrace(PTRACE_CONT, pid, 0, sig)`—Resume a stopped tracee, optionally
+/// delivering a signal.
+#[inline]
+#[doc(alias = "PTRACE_CONT")]
+pub fn cont(pid: Pid, sig: Option<Signal>) -> io::Result<()> {
+    unsafe {
+        imp::process::syscalls::ptrace(PtraceRequest::Cont, Some(pid), null_mut(), signal_data(sig))
+    }
+}
+
+/// `ptrace(PTRACE_SYSCALL, pid, 0, sig)`—Resume a stopped tracee until the
+/// next syscall entry or exit.
+#[inline]
+#[doc(alias = "PTRACE_SYSCALL")]
+pub fn syscall(pid: Pid, sig: Option<Signal>) -> io::Result<()> {
+    unsafe {
+        imp::process::syscalls::ptrace(
+            PtraceRequest::Syscall,
+            Some(pid),
+            null_mut(),
+            signal_data(sig),
+        )
+    }
+}
+
+/// `ptrace(PTRACE_SINGLESTEP, pid, 0, sig)`—Resume a stopped tracee for a
+/// single instruction.
+///
+/// Some architectures, such as RISC-V, don't support this, and fail with
+/// [`io::Errno::IO`].
+#[inline]
+#[doc(alias = "PTRACE_SINGLESTEP")]
+pub fn singlestep(pid: Pid, sig: Option<Signal>) -> io::Result<()> {
+    unsafe {
+        imp::process::syscalls::ptrace(
+            PtraceRequest::SingleStep,
+            Some(pid),
+            null_mut(),
+            signal_data(sig),
+        )
+    }
+}
+
+/// `ptrace(PTRACE_DETACH, pid, 0, sig)`—Detach from a stopped tracee and
+/// resume it, optionally delivering a signal.
+#[inline]
+#[doc(alias = "PTRACE_DETACH")]
+pub fn detach(pid: Pid, sig: Option<Signal>) -> io::Result<()> {
+    unsafe {
+        imp::process::syscalls::ptrace(
+            PtraceRequest::Detach,
+            Some(pid),
+            null_mut(),
+            signal_data(sig),
+        )
+    }
+}
+
+/// `ptrace(PTRACE_PEEKDATA, pid, addr, &mut word)`—Read a word from a
+/// stopped tracee's memory.
+#[inline]
+#[doc(alias = "PTRACE_PEEKDATA")]
+pub fn peek(pid: Pid, addr: usize) -> io::Result<usize> {
+    let mut word = MaybeUninit::<usize>::uninit();
+    unsafe {
+        imp::process::syscalls::ptrace(
+            PtraceRequest::PeekData,
+            Some(pid),
+            addr as *mut _,
+            word.as_mut_ptr().cast(),
+        )?;
+        Ok(word.assume_init())
+    }
+}
+
+/// `ptrace(PTRACE_POKEDATA, pid, addr, data)`—Write a word to a stopped
+/// tracee's memory.
+///
+/// # Safety
+///
+/// If the tracee shares its address space with the caller, this writes to
+/// the caller's memory, and `addr` must be valid for writes.
+#[inline]
+#[doc(alias = "PTRACE_POKEDATA")]
+pub unsafe fn poke(pid: Pid, addr: usize, data: usize) -> io::Result<()> {
+    imp::process::syscalls::ptrace(
+        PtraceRequest::PokeData,
+        Some(pid),
+        addr as *mut _,
+        data as *mut _,
+    )
+}
+
+/// `ptrace(PTRACE_GETREGSET, pid, NT_PRSTATUS, &iov)`—Read a stopped
+/// tracee's general-purpose registers.
+///
+/// If the tracee's register layout differs from the caller's, such as a
+/// 32-bit tracee of a 64-bit tracer, this fails with [`io::Errno::INVAL`].
+#[cfg(any(
+    target_arch = "aarch64",
+    target_arch = "arm",
+    target_arch = "riscv64",
+    target_arch = "x86",
+    target_arch = "x86_64",
+))]
+#[doc(alias = "PTRACE_GETREGS")]
+#[doc(alias = "PTRACE_GETREGSET")]
+pub fn getregs(pid: Pid) -> io::Result<UserRegs> {
+    let mut regs = MaybeUninit::<UserRegs>::uninit();
+    let mut iov = Iovec {
+        base: regs.as_mut_ptr(),
+        len: size_of::<UserRegs>(),
+    };
+    unsafe {
+        imp::process::syscalls::ptrace(
+            PtraceRequest::GetRegSet,
+            Some(pid),
+            NT_PRSTATUS as *mut _,
+            (&mut iov as *mut Iovec).cast(),
+        )?;
+        if iov.len != size_of::<UserRegs>() {
+            return Err(io::Errno::INVAL);
+        }
+        Ok(regs.assume_init())
+    }
+}
+
+/// `ptrace(PTRACE_SETREGSET, pid, NT_PRSTATUS, &iov)`—Set a stopped
+/// tracee's general-purpose registers.
+///
+/// # Safety
+///
+/// If the tracee shares its address space with the caller, changing its
+/// registers can cause it to modify the caller's memory.
+#[cfg(any(
+    target_arch = "aarch64",
+    target_arch = "arm",
+    target_arch = "riscv64",
+    target_arch = "x86",
+    target_arch = "x86_64",
+))]
+#[doc(alias = "PTRACE_SETREGS")]
+#[doc(alias = "PTRACE_SETREGSET")]
+pub unsafe fn setregs(pid: Pid, regs: &UserRegs) -> io::Result<()> {
+    let mut iov = Iovec {
+        base: regs as *const UserRegs as *mut UserRegs,
+        len: size_of::<UserRegs>(),
+    };
+    imp::process::syscalls::ptrace(
+        PtraceRequest::SetRegSet,
+        Some(pid),
+        NT_PRSTATUS as *mut _,
+        (&mut iov as *mut Iovec).cast(),
+    )
+}
@@ -0,0 +1,76 @@
+use crate::ffi::ZString;
+use crate::{imp, io};
+
+/// `LINUX_REBOOT_CMD_*` constants for use with [`reboot`].
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum RebootCommand {
+    /// `LINUX_REBOOT_CMD_RESTART`—Restart the system.
+    Restart,
+
+    /// `LINUX_REBOOT_CMD_HALT`—Halt the system, without powering it off.
+    Halt,
+
+    /// `LINUX_REBOOT_CMD_POWER_OFF`—Power off the system, if possible.
+    PowerOff,
+
+    /// `LINUX_REBOOT_CMD_RESTART2`—Restart the system, passing the given
+    /// command to the bootloader or firmware.
+    RestartCommand(ZString),
+
+    /// `LINUX_REBOOT_CMD_CAD_ON`—Make Ctrl-Alt-Delete restart the system
+    /// immediately.
+    CadOn,
+
+    /// `LINUX_REBOOT_CMD_CAD_OFF`—Make Ctrl-Alt-Delete send `SIGINT` to
+    /// the init process.
+    CadOff,
+
+    /// `LINUX_REBOOT_CMD_SW_SUSPEND`—Hibernate the system, if the kernel
+    /// supports it.
+    SwSuspend,
+
+    /// `LINUX_REBOOT_CMD_KEXEC`—Execute the kernel previously loaded with
+    /// `kexec_load` or `kexec_file_load`.
+    Kexec,
+}
+
+impl RebootCommand {
+    /// Returns the raw `LINUX_REBOOT_CMD_*` value for this command.
+    const fn as_raw(&self) -> u32 {
+        match self {
+            Self::Restart => 0x0123_4567,
+            Self::Halt => 0xcdef_0123,
+            Self::PowerOff => 0x4321_fedc,
+            Self::RestartCommand(_) => 0xa1b2_c3d4,
+            Self::CadOn => 0x89ab_cdef,
+            Self::CadOff => 0x0000_0000,
+            Self::SwSuspend => 0xd000_fce2,
+            Self::Kexec => 0x4558_4543,
+        }
+    }
+}
+
+/// `reboot(LINUX_REBOOT_MAGIC1, LINUX_REBOOT_MAGIC2, cmd, arg)`—Reboot the
+/// system, or change how Ctrl-Alt-Delete is handled.
+///
+/// This doesn't sync filesystems first; call `sync` beforehand to avoid
+/// losing data. It requires the `CAP_SYS_BOOT` capability, and fails with
+/// `PERM` otherwise.
+///
+/// In a PID namespace other than the initial one, the restart, halt, and
+/// power-off commands don't affect the system; instead, they kill the
+/// namespace's init process, which ends the namespace. Other commands fail
+/// with `INVAL` there.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/reboot.2.html
+#[inline]
+pub fn reboot(cmd: RebootCommand) -> io::Result<()> {
+    let arg = match &cmd {
+        RebootCommand::RestartCommand(arg) => Some(arg.as_c_str()),
+        _ => None,
+    };
+    imp::process::syscalls::reboot(cmd.as_raw(), arg)
+}
@@ -0,0 +1,75 @@
+use crate::imp;
+
+pub use imp::process::types::RusageWho;
+pub use imp::time::types::Timeval;
+
+/// `getrusage(who)`—Returns resource usage statistics.
+///
+/// Use [`RusageWho::Self_`] for the calling process, [`RusageWho::Children`]
+/// for its terminated and waited-for children, and, on Linux,
+/// [`RusageWho::Thread`] for the calling thread alone.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/getrusage.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/getrusage.2.html
+#[inline]
+pub fn getrusage(who: RusageWho) -> Rusage {
+    imp::process::syscalls::getrusage(who)
+}
+
+/// `struct rusage`—Return type for [`getrusage`].
+///
+/// Fields which the platform doesn't maintain are zero.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+pub struct Rusage {
+    /// User CPU time used.
+    pub ru_utime: Timeval,
+
+    /// System CPU time used.
+    pub ru_stime: Timeval,
+
+    /// Maximum resident set size, in kilobytes.
+    pub ru_maxrss: u64,
+
+    /// Integral shared memory size.
+    pub ru_ixrss: u64,
+
+    /// Integral unshared data size.
+    pub ru_idrss: u64,
+
+    /// Integral unshared stack size.
+    pub ru_isrss: u64,
+
+    /// Page reclaims (soft page faults).
+    pub ru_minflt: u64,
+
+    /// Page faults (hard page faults).
+    pub ru_majflt: u64,
+
+    /// Swaps.
+    pub ru_nswap: u64,
+
+    /// Block input operations.
+    pub ru_inblock: u64,
+
+    /// Block output operations.
+    pub ru_oublock: u64,
+
+    /// IPC messages sent.
+    pub ru_msgsnd: u64,
+
+    /// IPC messages received.
+    pub ru_msgrcv: u64,
+
+    /// Signals received.
+    pub ru_nsignals: u64,
+
+    /// Voluntary context switches.
+    pub ru_nvcsw: u64,
+
+    /// Involuntary context switches.
+    pub ru_nivcsw: u64,
+}
@@ -0,0 +1,59 @@
+use crate::imp;
+use crate::process::Signal;
+
+/// `sigset_t`—A set of signals.
+///
+/// `SigSet`s are used to specify signal masks, such as with
+/// [`Epoll::wait_timeout`], for example.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/sigemptyset.html
+/// [Linux]: https://man7.org/linux/man-pages/man3/sigemptyset.3.html
+/// [`Epoll::wait_timeout`]: crate::io::epoll::Epoll::wait_timeout
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct SigSet {
+    pub(crate) sigset: imp::process::types::RawSigSet,
+}
+
+impl SigSet {
+    /// Create a new and empty `SigSet`.
+    #[doc(alias = "sigemptyset")]
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            sigset: imp::process::types::raw_sig_set_new(),
+        }
+    }
+
+    /// Test to see if a signal is in the `SigSet`.
+    #[doc(alias = "sigismember")]
+    #[inline]
+    pub fn contains(&self, sig: Signal) -> bool {
+        imp::process::sig_set::sigismember(&self.sigset, sig)
+    }
+
+    /// Add a signal to the `SigSet`.
+    #[doc(alias = "sigaddset")]
+    #[inline]
+    pub fn add(&mut self, sig: Signal) {
+        imp::process::sig_set::sigaddset(&mut self.sigset, sig)
+    }
+
+    /// Remove a signal from the `SigSet`.
+    #[doc(alias = "sigdelset")]
+    #[inline]
+    pub fn remove(&mut self, sig: Signal) {
+        imp::process::sig_set::sigdelset(&mut self.sigset, sig)
+    }
+}
+
+impl Default for SigSet {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,58 @@
+use crate::{imp, io};
+
+/// `sysinfo()`—Returns overall system statistics.
+///
+/// The memory sizes in the returned [`Sysinfo`] are in units of
+/// [`Sysinfo::mem_unit`] bytes; multiply by `mem_unit` to get byte counts.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/sysinfo.2.html
+#[inline]
+pub fn sysinfo() -> io::Result<Sysinfo> {
+    imp::process::syscalls::sysinfo()
+}
+
+/// `struct sysinfo`—Return type for [`sysinfo`].
+///
+/// The memory sizes are in units of `mem_unit` bytes.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+pub struct Sysinfo {
+    /// Seconds since boot.
+    pub uptime: i64,
+
+    /// 1, 5, and 15 minute load averages, scaled by `1 << SI_LOAD_SHIFT`
+    /// (65536).
+    pub loads: [u64; 3],
+
+    /// Total usable main memory size.
+    pub totalram: u64,
+
+    /// Available memory size.
+    pub freeram: u64,
+
+    /// Amount of shared memory.
+    pub sharedram: u64,
+
+    /// Memory used by buffers.
+    pub bufferram: u64,
+
+    /// Total swap space size.
+    pub totalswap: u64,
+
+    /// Swap space still available.
+    pub freeswap: u64,
+
+    /// Number of current processes.
+    pub procs: u16,
+
+    /// Total high memory size.
+    pub totalhigh: u64,
+
+    /// Available high memory size.
+    pub freehigh: u64,
+
+    /// Memory unit size in bytes.
+    pub mem_unit: u32,
+}
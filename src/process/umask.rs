@@ -0,0 +1,32 @@
+use crate::fs::Mode;
+use crate::imp;
+
+/// `umask(mask)`—Set the process file mode creation mask, and return the
+/// previous mask.
+///
+/// Permission bits which are set in the mask are cleared from the mode of
+/// files and directories created by the process.
+///
+/// There's no way to read the mask without setting it, so to read it,
+/// set it to some value and then immediately set it back:
+///
+/// ```rust,no_run
+/// # use rustix::fs::Mode;
+/// # use rustix::process::umask;
+/// let mask = umask(Mode::empty());
+/// umask(mask);
+/// ```
+///
+/// The mask is shared by all threads in the process, so another thread
+/// creating files in between may see the temporary mask.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/umask.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/umask.2.html
+#[inline]
+pub fn umask(mask: Mode) -> Mode {
+    imp::process::syscalls::umask(mask)
+}
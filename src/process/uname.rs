@@ -12,6 +12,13 @@ use core::fmt;
 
 /// `uname()`—Returns high-level information about the runtime OS and
 /// hardware.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/uname.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/uname.2.html
 #[inline]
 pub fn uname() -> Uname {
     Uname(imp::process::syscalls::uname())
@@ -1,4 +1,12 @@
 use crate::process::Pid;
+#[cfg(not(any(
+    target_os = "fuchsia",
+    target_os = "illumos",
+    target_os = "redox",
+    target_os = "wasi"
+)))]
+#[cfg(feature = "process")]
+use crate::process::Rusage;
 use crate::{imp, io};
 use bitflags::bitflags;
 
@@ -127,3 +135,32 @@ pub fn waitpid(pid: Option<Pid>, waitopts: WaitOptions) -> io::Result<Option<Wai
 pub fn wait(waitopts: WaitOptions) -> io::Result<Option<(Pid, WaitStatus)>> {
     imp::process::syscalls::wait(waitopts)
 }
+
+/// `wait4(pid, waitopts, &mut rusage)`—Wait for a process to change state,
+/// and return its resource usage.
+///
+/// The `pid` argument is interpreted as in [`waitpid`]. This collects the
+/// resource usage of the reaped child alone, at the time it's reaped, unlike
+/// [`getrusage`] with [`RusageWho::Children`], which aggregates across all
+/// waited-for children.
+///
+/// If `NOHANG` was specified in the options, and the selected child process
+/// didn't change state, returns `None`.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/wait4.2.html
+/// [`getrusage`]: crate::process::getrusage
+/// [`RusageWho::Children`]: crate::process::RusageWho::Children
+#[cfg(not(any(
+    target_os = "fuchsia",
+    target_os = "illumos",
+    target_os = "redox",
+    target_os = "wasi"
+)))]
+#[cfg(feature = "process")]
+#[inline]
+pub fn wait4(pid: Option<Pid>, waitopts: WaitOptions) -> io::Result<Option<(WaitStatus, Rusage)>> {
+    imp::process::syscalls::wait4(pid, waitopts)
+}
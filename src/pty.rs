@@ -0,0 +1,116 @@
+//! Pseudoterminal operations.
+//!
+//! To set up a pseudoterminal, open a master with [`openpt`], then call
+//! [`grantpt`] and [`unlockpt`] on it, and use [`ptsname`] to find the path
+//! of the corresponding slave. [`openpt_unlocked`] performs all of these
+//! steps at once.
+//!
+//! # References
+//!  - [Linux]
+//!
+//! [Linux]: https://man7.org/linux/man-pages/man7/pty.7.html
+
+use crate::fd::{AsFd, BorrowedFd};
+use crate::ffi::ZString;
+use crate::fs::{cwd, openat, Mode, OFlags};
+use crate::imp;
+use crate::io::{self, OwnedFd};
+use crate::path::DecInt;
+use alloc::vec::Vec;
+pub use imp::pty::types::OpenptFlags;
+
+/// `posix_openpt(flags)`—Open a pseudoterminal master.
+///
+/// On Linux, this opens `/dev/ptmx`. The resulting master is locked; use
+/// [`unlockpt`] before opening the slave.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/posix_openpt.html
+/// [Linux]: https://man7.org/linux/man-pages/man3/posix_openpt.3.html
+#[inline]
+#[doc(alias = "posix_openpt")]
+pub fn openpt(flags: OpenptFlags) -> io::Result<OwnedFd> {
+    openat(
+        cwd(),
+        zstr!("/dev/ptmx"),
+        OFlags::from_bits_truncate(flags.bits()),
+        Mode::empty(),
+    )
+}
+
+/// `grantpt(fd)`—Grant access to the slave of a pseudoterminal master.
+///
+/// On Linux, devpts assigns the slave's ownership and permissions itself, so
+/// this only checks that `fd` is a pseudoterminal master.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/grantpt.html
+/// [Linux]: https://man7.org/linux/man-pages/man3/grantpt.3.html
+#[inline]
+pub fn grantpt<Fd: AsFd>(fd: Fd) -> io::Result<()> {
+    imp::pty::syscalls::ioctl_tiocgptn(fd.as_fd()).map(|_| ())
+}
+
+/// `unlockpt(fd)`—Unlock the slave of a pseudoterminal master.
+///
+/// Also known as the `TIOCSPTLCK` operation with `ioctl`.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/unlockpt.html
+/// [Linux]: https://man7.org/linux/man-pages/man3/unlockpt.3.html
+#[inline]
+#[doc(alias = "TIOCSPTLCK")]
+pub fn unlockpt<Fd: AsFd>(fd: Fd) -> io::Result<()> {
+    imp::pty::syscalls::unlockpt(fd.as_fd())
+}
+
+/// `ptsname(fd)`—Return the path of the slave of a pseudoterminal master.
+///
+/// This uses the `TIOCGPTN` operation with `ioctl` to find the slave's
+/// number, and returns the corresponding `/dev/pts/N` path.
+///
+/// If `reuse` is non-empty, reuse its buffer to store the result if possible.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/ptsname.html
+/// [Linux]: https://man7.org/linux/man-pages/man3/ptsname.3.html
+#[inline]
+#[doc(alias = "ptsname_r")]
+#[doc(alias = "TIOCGPTN")]
+pub fn ptsname<Fd: AsFd, B: Into<Vec<u8>>>(fd: Fd, reuse: B) -> io::Result<ZString> {
+    _ptsname(fd.as_fd(), reuse.into())
+}
+
+fn _ptsname(fd: BorrowedFd<'_>, mut buffer: Vec<u8>) -> io::Result<ZString> {
+    let n = imp::pty::syscalls::ioctl_tiocgptn(fd)?;
+
+    buffer.clear();
+    buffer.extend_from_slice(b"/dev/pts/");
+    buffer.extend_from_slice(DecInt::new(n).as_bytes());
+    Ok(ZString::new(buffer).unwrap())
+}
+
+/// Open a pseudoterminal master, grant access to its slave, and unlock it.
+///
+/// This returns the master along with the path of its slave, which is ready
+/// to be opened.
+#[inline]
+pub fn openpt_unlocked(flags: OpenptFlags) -> io::Result<(OwnedFd, ZString)> {
+    let master = openpt(flags)?;
+    grantpt(&master)?;
+    unlockpt(&master)?;
+    let name = ptsname(&master, Vec::new())?;
+    Ok((master, name))
+}
@@ -19,6 +19,8 @@
 //! serious problems.
 #![allow(unsafe_code)]
 
+#[cfg(linux_raw)]
+use crate::fd::RawFd;
 #[cfg(linux_raw)]
 use crate::ffi::ZStr;
 #[cfg(linux_raw)]
@@ -27,9 +29,9 @@ use crate::imp;
 #[cfg(linux_raw)]
 use crate::io;
 #[cfg(linux_raw)]
-use crate::process::Pid;
+use crate::process::{Pid, RawPid, SigSet, Signal};
 #[cfg(linux_raw)]
-use core::ffi::c_void;
+use core::{convert::Infallible, ffi::c_void, ptr::null};
 #[cfg(linux_raw)]
 use imp::fd::AsFd;
 
@@ -54,6 +56,24 @@ pub unsafe fn set_fs(data: *mut c_void) {
     imp::runtime::syscalls::tls::set_fs(data)
 }
 
+/// `set_tid_address(data)`—Set the calling thread's `clear_child_tid`
+/// pointer, and return its thread id.
+///
+/// When the thread exits, the kernel stores 0 to the `u32` at `data` and
+/// does a `FUTEX_WAKE` on it, which a threading library can use to implement
+/// joining threads.
+///
+/// # Safety
+///
+/// `data` must point to memory that remains valid for a `u32` store until
+/// the thread exits, or be null. This is a very low-level feature for
+/// implementing threading libraries, and overrides any pointer set up by
+/// the existing threading library, if any.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/set_tid_address.2.html
 #[cfg(linux_raw)]
 #[inline]
 pub unsafe fn set_tid_address(data: *mut c_void) -> Pid {
@@ -62,6 +82,9 @@ pub unsafe fn set_tid_address(data: *mut c_void) -> Pid {
 
 /// `prctl(PR_SET_NAME, name)`
 ///
+/// The kernel silently truncates names longer than 15 bytes, which leaves
+/// room for the terminating NUL in its 16-byte buffer.
+///
 /// # References
 ///  - [Linux]: https://man7.org/linux/man-pages/man2/prctl.2.html
 ///
@@ -83,9 +106,21 @@ pub use imp::runtime::tls::UserDesc;
 
 /// `syscall(SYS_exit, status)`—Exit the current thread.
 ///
+/// Unlike [`exit_group`], this exits only the calling thread. If it was the
+/// last thread in the process, the process exits with `status`. If a
+/// `clear_child_tid` pointer was set with [`set_tid_address`], the kernel
+/// clears it and wakes any futex waiters on it.
+///
 /// # Safety
 ///
 /// This is a very low-level feature for implementing threading libraries.
+/// No destructors run, and any memory the thread owns, including its stack,
+/// is leaked unless something else frees it.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/exit.2.html
 #[cfg(linux_raw)]
 #[inline]
 pub unsafe fn exit_thread(status: i32) -> ! {
@@ -140,10 +175,121 @@ pub fn exe_phdrs() -> (*const c_void, usize) {
 #[cfg(linux_raw)]
 pub use imp::runtime::tls::StartupTlsInfo;
 
+#[cfg(linux_raw)]
+pub use imp::runtime::types::{CloneFlags, SigactionFlags, Siginfo, StackFlags};
+
+/// A signal disposition, for use with [`Sigaction`].
+#[cfg(linux_raw)]
+#[derive(Clone, Copy, Debug)]
+pub enum SigHandler {
+    /// `SIG_DFL`—Perform the default action for the signal.
+    Default,
+
+    /// `SIG_IGN`—Ignore the signal.
+    Ignore,
+
+    /// A handler, called with the signal number.
+    Handler(unsafe extern "C" fn(i32)),
+
+    /// A handler for use with [`SigactionFlags::SIGINFO`], called with the
+    /// signal number, a pointer to a [`Siginfo`], and a pointer to the
+    /// interrupted `ucontext_t`.
+    SigInfo(unsafe extern "C" fn(i32, *mut Siginfo, *mut c_void)),
+}
+
+/// `struct sigaction`—A signal action, for use with [`sigaction`].
+#[cfg(linux_raw)]
+#[derive(Clone, Copy, Debug)]
+pub struct Sigaction {
+    /// The disposition of the signal.
+    pub handler: SigHandler,
+
+    /// Signals to block while the handler runs.
+    pub mask: SigSet,
+
+    /// Flags modifying the behavior of the signal.
+    pub flags: SigactionFlags,
+
+    /// The function the handler returns to, which must call `rt_sigreturn`.
+    ///
+    /// This is only used when [`SigactionFlags::RESTORER`] is set. The kernel
+    /// requires one on x86-64 in order for handlers to return. It's always
+    /// `None` on architectures without `SA_RESTORER`.
+    pub restorer: Option<unsafe extern "C" fn()>,
+}
+
+/// `rt_sigaction(sig, new, old, sizeof(sigset_t))`—Examine and optionally
+/// change the action for a signal.
+///
+/// Returns the previous action. If `new` is `None`, the action is left
+/// unchanged.
+///
+/// # Safety
+///
+/// Signal handlers run asynchronously and may only call
+/// [async-signal-safe] functions. Unlike libc's `sigaction`, this doesn't
+/// supply a restorer, so installing a handler on an architecture that needs
+/// one requires setting [`Sigaction::restorer`]. And it doesn't coordinate
+/// with any other code in the process which may be managing signal handlers.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/rt_sigaction.2.html
+/// [async-signal-safe]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/V2_chap02.html#tag_15_04_03
+#[cfg(linux_raw)]
+#[inline]
+pub unsafe fn sigaction(sig: Signal, new: Option<&Sigaction>) -> io::Result<Sigaction> {
+    imp::runtime::syscalls::sigaction(sig, new)
+}
+
+/// `stack_t`—An alternate signal stack, for use with [`sigaltstack`].
+#[cfg(linux_raw)]
+#[derive(Clone, Copy, Debug)]
+pub struct Stack {
+    /// The base address of the stack.
+    pub ss_sp: *mut c_void,
+
+    /// Flags. When setting a stack, this is either empty, or
+    /// [`StackFlags::DISABLE`] to disable the alternate stack, optionally
+    /// with [`StackFlags::AUTODISARM`]. When querying, [`StackFlags::ONSTACK`]
+    /// indicates that the calling thread is currently running on it.
+    pub ss_flags: StackFlags,
+
+    /// The size of the stack, in bytes.
+    pub ss_size: usize,
+}
+
+/// `sigaltstack(new, old)`—Examine and optionally change the calling
+/// thread's alternate signal stack.
+///
+/// Returns the previous stack. If `new` is `None`, the stack is left
+/// unchanged.
+///
+/// The alternate stack is only used for signals whose handlers were
+/// installed by [`sigaction`] with [`SigactionFlags::ONSTACK`]; other
+/// handlers continue to run on the interrupted stack. This makes it possible
+/// to handle `SIGSEGV` caused by a stack overflow.
+///
+/// # Safety
+///
+/// `ss_sp` and `ss_size` must describe memory that remains valid and unused
+/// by anything else for as long as it's installed as the alternate stack.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/sigaltstack.2.html
+#[cfg(linux_raw)]
+#[inline]
+pub unsafe fn sigaltstack(new: Option<&Stack>) -> io::Result<Stack> {
+    imp::runtime::syscalls::sigaltstack(new)
+}
+
 /// `fork()`—Creates a new process by duplicating the calling process.
 ///
-/// On success, the pid of the child process is returned in the parent, and
-/// `None` is returned in the child.
+/// On success, [`Fork::Parent`] with the pid of the child process is returned
+/// in the parent, and [`Fork::Child`] is returned in the child.
 ///
 /// Unlike its POSIX and libc counterparts, this `fork` does not invoke any
 /// handlers (such as those registered with `pthread_atfork`).
@@ -218,13 +364,166 @@ pub use imp::runtime::tls::StartupTlsInfo;
 /// [Linux]: https://man7.org/linux/man-pages/man2/fork.2.html
 /// [async-signal-safe]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/V2_chap02.html#tag_15_04_03
 #[cfg(linux_raw)]
-pub unsafe fn fork() -> io::Result<Option<Pid>> {
-    imp::runtime::syscalls::fork()
+pub unsafe fn fork() -> io::Result<Fork> {
+    Ok(match imp::runtime::syscalls::fork()? {
+        Some(pid) => Fork::Parent(pid),
+        None => Fork::Child,
+    })
+}
+
+/// `struct clone_args`—Arguments for [`clone3`].
+#[cfg(linux_raw)]
+#[derive(Debug)]
+pub struct CloneArgs<'a> {
+    /// Flags controlling what the child shares with the parent.
+    pub flags: CloneFlags,
+
+    /// With [`CloneFlags::PIDFD`], where to store a pidfd referring to the
+    /// child.
+    pub pidfd: Option<&'a mut RawFd>,
+
+    /// With [`CloneFlags::CHILD_SETTID`] or [`CloneFlags::CHILD_CLEARTID`],
+    /// where to store or clear the child's thread id, in the child's memory.
+    pub child_tid: *mut RawPid,
+
+    /// With [`CloneFlags::PARENT_SETTID`], where to store the child's thread
+    /// id, in the parent's memory.
+    pub parent_tid: *mut RawPid,
+
+    /// The signal to send to the parent when the child terminates, if any.
+    pub exit_signal: Option<Signal>,
+
+    /// The lowest address of the child's stack, or null to use a copy of the
+    /// parent's stack.
+    pub stack: *mut c_void,
+
+    /// The size of the child's stack, or 0 if `stack` is null.
+    pub stack_size: usize,
+
+    /// With [`CloneFlags::SETTLS`], the child's thread-local storage
+    /// pointer.
+    pub tls: *mut c_void,
+}
+
+/// `clone3(args, sizeof(struct clone_args))`—Creates a new process or
+/// thread.
+///
+/// Like [`fork`], this returns [`Fork::Parent`] with the pid of the child in
+/// the parent, and [`Fork::Child`] in the child. Unlike `fork`, it can share
+/// resources with the child, place it in new namespaces, and with
+/// [`CloneFlags::PIDFD`], return a pidfd referring to it, avoiding races with
+/// pid reuse.
+///
+/// On kernels before Linux 5.3, this fails with `NOSYS`.
+///
+/// # Safety
+///
+/// All of the safety requirements of [`fork`] apply to the child. In
+/// addition, the pointers in `args` must be valid for the uses implied by
+/// `flags`. The child returns from this function just as the parent does, so
+/// if `stack` is non-null, it returns on a stack that doesn't contain the
+/// frames it's returning into; this is only usable from code which never
+/// returns in the child, such as an assembly trampoline. Sharing memory with
+/// [`CloneFlags::VM`] without [`CloneFlags::VFORK`] has the same problem.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/clone3.2.html
+#[cfg(linux_raw)]
+#[inline]
+pub unsafe fn clone3(args: &mut CloneArgs<'_>) -> io::Result<Fork> {
+    Ok(match imp::runtime::syscalls::clone3(args)? {
+        Some(pid) => Fork::Parent(pid),
+        None => Fork::Child,
+    })
+}
+
+/// The result of [`fork`], indicating which side of the fork we're on.
+#[cfg(linux_raw)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Fork {
+    /// This is the parent process, and the child has the given pid.
+    Parent(Pid),
+
+    /// This is the child process.
+    Child,
 }
 
 /// `execveat(dirfd, path.as_z_str(), argv, envp, flags)`—Execute a new
 /// command using the current process.
 ///
+/// This only returns if it fails, such as with `NOENT` if `path` doesn't
+/// exist or `ACCESS` if it isn't executable. `argv` and `envp` must each end
+/// with a null pointer; if either doesn't, this fails with `INVAL`.
+///
+/// To execute a file that has already been opened and validated, pass its fd
+/// as `dirfd`, an empty `path`, and [`AtFlags::EMPTY_PATH`].
+///
+/// # Safety
+///
+/// The elements of `argv` and `envp` before the terminating null pointers
+/// must be pointers to NUL-terminated byte arrays.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/execveat.2.html
+#[inline]
+#[cfg(linux_raw)]
+pub unsafe fn execveat<Fd: AsFd>(
+    dirfd: Fd,
+    path: &ZStr,
+    argv: &[*const u8],
+    envp: &[*const u8],
+    flags: AtFlags,
+) -> io::Result<Infallible> {
+    if argv.last() != Some(&null()) || envp.last() != Some(&null()) {
+        return Err(io::Errno::INVAL);
+    }
+    Err(execveat_raw(
+        dirfd,
+        path,
+        argv.as_ptr(),
+        envp.as_ptr(),
+        flags,
+    ))
+}
+
+/// `execve(path.as_z_str(), argv, envp)`—Execute a new command using the
+/// current process.
+///
+/// This only returns if it fails, such as with `NOENT` if `path` doesn't
+/// exist or `ACCESS` if it isn't executable. `argv` and `envp` must each end
+/// with a null pointer; if either doesn't, this fails with `INVAL`.
+///
+/// # Safety
+///
+/// The elements of `argv` and `envp` before the terminating null pointers
+/// must be pointers to NUL-terminated byte arrays.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/execve.2.html
+#[inline]
+#[cfg(linux_raw)]
+pub unsafe fn execve(
+    path: &ZStr,
+    argv: &[*const u8],
+    envp: &[*const u8],
+) -> io::Result<Infallible> {
+    if argv.last() != Some(&null()) || envp.last() != Some(&null()) {
+        return Err(io::Errno::INVAL);
+    }
+    Err(execve_raw(path, argv.as_ptr(), envp.as_ptr()))
+}
+
+/// `execveat(dirfd, path.as_z_str(), argv, envp, flags)`—Like [`execveat`],
+/// but with raw `argv` and `envp` arrays.
+///
+/// This only returns if it fails, so it returns the error directly.
+///
 /// # Safety
 ///
 /// The `argv` and `envp` pointers must point to NUL-terminated arrays, and
@@ -236,7 +535,7 @@ pub unsafe fn fork() -> io::Result<Option<Pid>> {
 /// [Linux]: https://man7.org/linux/man-pages/man2/execveat.2.html
 #[inline]
 #[cfg(linux_raw)]
-pub unsafe fn execveat<Fd: AsFd>(
+pub unsafe fn execveat_raw<Fd: AsFd>(
     dirfd: Fd,
     path: &ZStr,
     argv: *const *const u8,
@@ -246,8 +545,10 @@ pub unsafe fn execveat<Fd: AsFd>(
     imp::runtime::syscalls::execveat(dirfd.as_fd(), path, argv, envp, flags)
 }
 
-/// `execve(path.as_z_str(), argv, envp)`—Execute a new command using the
-/// current process.
+/// `execve(path.as_z_str(), argv, envp)`—Like [`execve`], but with raw
+/// `argv` and `envp` arrays.
+///
+/// This only returns if it fails, so it returns the error directly.
 ///
 /// # Safety
 ///
@@ -260,6 +561,6 @@ pub unsafe fn execveat<Fd: AsFd>(
 /// [Linux]: https://man7.org/linux/man-pages/man2/execve.2.html
 #[inline]
 #[cfg(linux_raw)]
-pub unsafe fn execve(path: &ZStr, argv: *const *const u8, envp: *const *const u8) -> io::Errno {
+pub unsafe fn execve_raw(path: &ZStr, argv: *const *const u8, envp: *const *const u8) -> io::Errno {
     imp::runtime::syscalls::execve(path, argv, envp)
 }
@@ -1,39 +1,46 @@
 use crate::termios::{Speed, Termios};
 use crate::{imp, io};
 
-/// `cfgetospeed(termios)`
+/// `cfgetospeed(termios)`—Return the output baud rate in `termios`.
 #[inline]
 #[must_use]
 pub fn cfgetospeed(termios: &Termios) -> Speed {
     imp::termios::syscalls::cfgetospeed(termios)
 }
 
-/// `cfgetispeed(termios)`
+/// `cfgetispeed(termios)`—Return the input baud rate in `termios`.
 #[inline]
 #[must_use]
 pub fn cfgetispeed(termios: &Termios) -> Speed {
     imp::termios::syscalls::cfgetispeed(termios)
 }
 
-/// `cfmakeraw(termios)`
+/// `cfmakeraw(termios)`—Configure `termios` for raw mode.
+///
+/// This disables input processing, output processing, echoing, and
+/// canonical mode, and sets the character size to 8 bits. The change only
+/// takes effect once `termios` is passed to [`tcsetattr`].
+///
+/// [`tcsetattr`]: crate::termios::tcsetattr
 #[inline]
 pub fn cfmakeraw(termios: &mut Termios) {
     imp::termios::syscalls::cfmakeraw(termios)
 }
 
-/// `cfsetospeed(termios, speed)`
+/// `cfsetospeed(termios, speed)`—Set the output baud rate in `termios`.
 #[inline]
 pub fn cfsetospeed(termios: &mut Termios, speed: Speed) -> io::Result<()> {
     imp::termios::syscalls::cfsetospeed(termios, speed)
 }
 
-/// `cfsetispeed(termios, speed)`
+/// `cfsetispeed(termios, speed)`—Set the input baud rate in `termios`.
 #[inline]
 pub fn cfsetispeed(termios: &mut Termios, speed: Speed) -> io::Result<()> {
     imp::termios::syscalls::cfsetispeed(termios, speed)
 }
 
-/// `cfsetspeed(termios, speed)`
+/// `cfsetspeed(termios, speed)`—Set the input and output baud rates in
+/// `termios`.
 #[inline]
 pub fn cfsetspeed(termios: &mut Termios, speed: Speed) -> io::Result<()> {
     imp::termios::syscalls::cfsetspeed(termios, speed)
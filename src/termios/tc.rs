@@ -74,6 +74,25 @@ pub fn tcsetpgrp<Fd: AsFd>(fd: Fd, pid: Pid) -> io::Result<()> {
     imp::termios::syscalls::tcsetpgrp(fd.as_fd(), pid)
 }
 
+/// `ioctl(fd, TIOCSCTTY, 0)`—Make the terminal the controlling terminal of
+/// the calling process.
+///
+/// The calling process must be a session leader without a controlling
+/// terminal. If the terminal is already the controlling terminal of another
+/// session, this fails with [`io::Errno::PERM`]; this function never
+/// attempts to steal it.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man4/tty_ioctl.4.html
+#[cfg(not(any(windows, target_os = "wasi")))]
+#[inline]
+#[doc(alias = "TIOCSCTTY")]
+pub fn ioctl_tiocsctty<Fd: AsFd>(fd: Fd) -> io::Result<()> {
+    imp::termios::syscalls::ioctl_tiocsctty(fd.as_fd())
+}
+
 /// `tcsetattr(fd)`—Set terminal attributes.
 ///
 /// Also known as the `TCSETS` operation with `ioctl`.
@@ -118,14 +137,17 @@ pub fn tcsendbreak<Fd: AsFd>(fd: Fd) -> io::Result<()> {
     imp::termios::syscalls::tcsendbreak(fd.as_fd())
 }
 
-/// `tcdrain(fd, duration)`—Wait until all pending output has been written.
+/// `tcdrain(fd)`—Wait until all pending output has been written.
+///
+/// Also known as the `TCSBRK` operation with `ioctl`, with a non-zero
+/// argument.
 ///
 /// # References
 ///  - [POSIX `tcdrain`]
 ///  - [Linux `ioctl_tty`]
 ///  - [Linux `termios`]
 ///
-/// [POSIX `tcsetattr`]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/tcdrain.html
+/// [POSIX `tcdrain`]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/tcdrain.html
 /// [Linux `ioctl_tty`]: https://man7.org/linux/man-pages/man4/tty_ioctl.4.html
 /// [Linux `termios`]: https://man7.org/linux/man-pages/man3/termios.3.html
 #[inline]
@@ -133,8 +155,7 @@ pub fn tcdrain<Fd: AsFd>(fd: Fd) -> io::Result<()> {
     imp::termios::syscalls::tcdrain(fd.as_fd())
 }
 
-/// `tcflush(fd, queue_selector)`—Wait until all pending output has been
-/// written.
+/// `tcflush(fd, queue_selector)`—Discard pending input, output, or both.
 ///
 /// # References
 ///  - [POSIX `tcflush`]
@@ -181,7 +202,7 @@ pub fn tcgetsid<Fd: AsFd>(fd: Fd) -> io::Result<Pid> {
     imp::termios::syscalls::tcgetsid(fd.as_fd())
 }
 
-/// `tcsetwinsize(fd)`—Set the current terminal window size.
+/// `tcsetwinsize(fd, winsize)`—Set the current terminal window size.
 ///
 /// Also known as the `TIOCSWINSZ` operation with `ioctl`.
 ///
@@ -20,6 +20,10 @@ pub use imp::time::types::ClockId;
 /// This is `clock_nanosleep` specialized for the case of a relative sleep
 /// interval. See [`clock_nanosleep_absolute`] for absolute intervals.
 ///
+/// If the sleep is interrupted by a signal, the remaining time is returned
+/// in [`NanosleepRelativeResult::Interrupted`], so that the caller can resume
+/// it.
+///
 /// # References
 ///  - [POSIX]
 ///  - [Linux]
@@ -47,6 +51,11 @@ pub fn clock_nanosleep_relative(id: ClockId, request: &Timespec) -> NanosleepRel
 /// This is `clock_nanosleep` specialized for the case of an absolute sleep
 /// interval. See [`clock_nanosleep_relative`] for relative intervals.
 ///
+/// Sleeping until a deadline on [`ClockId::Monotonic`] is unaffected by
+/// changes to the system's wall-clock time, and doesn't accumulate drift when
+/// resumed after being interrupted by a signal, as a loop of relative sleeps
+/// would.
+///
 /// # References
 ///  - [POSIX]
 ///  - [Linux]
@@ -6,6 +6,8 @@ use crate::process::Pid;
 /// This returns the OS thread ID, which is not necessarily the same as the
 /// `rust::thread::Thread::id` or the pthread ID.
 ///
+/// rustix doesn't cache this; each call queries the kernel.
+///
 /// # References
 ///  - [Linux]
 ///
@@ -0,0 +1,18 @@
+use crate::process::{Pid, Signal};
+use crate::{imp, io};
+
+/// `tgkill(tgid, tid, sig)`—Sends a signal to a specific thread.
+///
+/// The signal is delivered to the thread `tid` only if it belongs to the
+/// thread group `tgid`; otherwise this fails with `SRCH`. This guards
+/// against sending a signal to an unrelated thread if `tid` has been
+/// recycled.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/tgkill.2.html
+#[inline]
+pub fn tgkill(tgid: Pid, tid: Pid, sig: Signal) -> io::Result<()> {
+    imp::thread::syscalls::tgkill(tgid, tid, sig)
+}
@@ -0,0 +1,195 @@
+//! Landlock support for unprivileged filesystem sandboxing.
+//!
+//! A sandbox is built by creating a ruleset with [`create_ruleset`], which
+//! lists the kinds of access it handles, adding rules which allow some of
+//! those kinds of access beneath particular directories with [`add_rule`],
+//! and then enforcing it on the calling thread with [`restrict_self`].
+//! Access which the ruleset handles and no rule allows fails with
+//! [`io::Errno::ACCESS`].
+//!
+//! Before Linux 5.13, or if Landlock is disabled, these functions fail with
+//! [`io::Errno::NOSYS`] or [`io::Errno::OPNOTSUPP`].
+//!
+//! # References
+//!  - [Linux]
+//!
+//! [Linux]: https://man7.org/linux/man-pages/man7/landlock.7.html
+
+use crate::fd::{AsFd, BorrowedFd};
+use crate::imp;
+use crate::io::{self, OwnedFd};
+
+bitflags::bitflags! {
+    /// `LANDLOCK_ACCESS_FS_*` flags for use with [`RulesetAttr`] and
+    /// [`PathBeneathAttr`].
+    pub struct AccessFs: u64 {
+        /// `LANDLOCK_ACCESS_FS_EXECUTE`
+        const EXECUTE = 1 << 0;
+
+        /// `LANDLOCK_ACCESS_FS_WRITE_FILE`
+        const WRITE_FILE = 1 << 1;
+
+        /// `LANDLOCK_ACCESS_FS_READ_FILE`
+        const READ_FILE = 1 << 2;
+
+        /// `LANDLOCK_ACCESS_FS_READ_DIR`
+        const READ_DIR = 1 << 3;
+
+        /// `LANDLOCK_ACCESS_FS_REMOVE_DIR`
+        const REMOVE_DIR = 1 << 4;
+
+        /// `LANDLOCK_ACCESS_FS_REMOVE_FILE`
+        const REMOVE_FILE = 1 << 5;
+
+        /// `LANDLOCK_ACCESS_FS_MAKE_CHAR`
+        const MAKE_CHAR = 1 << 6;
+
+        /// `LANDLOCK_ACCESS_FS_MAKE_DIR`
+        const MAKE_DIR = 1 << 7;
+
+        /// `LANDLOCK_ACCESS_FS_MAKE_REG`
+        const MAKE_REG = 1 << 8;
+
+        /// `LANDLOCK_ACCESS_FS_MAKE_SOCK`
+        const MAKE_SOCK = 1 << 9;
+
+        /// `LANDLOCK_ACCESS_FS_MAKE_FIFO`
+        const MAKE_FIFO = 1 << 10;
+
+        /// `LANDLOCK_ACCESS_FS_MAKE_BLOCK`
+        const MAKE_BLOCK = 1 << 11;
+
+        /// `LANDLOCK_ACCESS_FS_MAKE_SYM`
+        const MAKE_SYM = 1 << 12;
+
+        /// `LANDLOCK_ACCESS_FS_REFER` (since ABI version 2)
+        const REFER = 1 << 13;
+
+        /// `LANDLOCK_ACCESS_FS_TRUNCATE` (since ABI version 3)
+        const TRUNCATE = 1 << 14;
+    }
+}
+
+bitflags::bitflags! {
+    /// `LANDLOCK_CREATE_RULESET_*` flags for use with [`create_ruleset`].
+    ///
+    /// No flags are currently defined for creating a ruleset; to query the
+    /// ABI version, use [`abi_version`].
+    pub struct CreateRulesetFlags: u32 {}
+}
+
+bitflags::bitflags! {
+    /// Flags for use with [`restrict_self`].
+    ///
+    /// No flags are currently defined.
+    pub struct RestrictSelfFlags: u32 {}
+}
+
+/// `struct landlock_ruleset_attr`—The kinds of access a ruleset handles,
+/// for use with [`create_ruleset`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct RulesetAttr {
+    /// The kinds of filesystem access which are denied unless a rule allows
+    /// them.
+    pub handled_access_fs: AccessFs,
+}
+
+/// `LANDLOCK_RULE_*` constants for use with [`add_rule`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[repr(u32)]
+#[non_exhaustive]
+pub enum RuleType {
+    /// `LANDLOCK_RULE_PATH_BENEATH`—Allow access to a file, or to a
+    /// directory and everything beneath it.
+    PathBeneath = 1,
+}
+
+/// `struct landlock_path_beneath_attr`—A rule for use with [`add_rule`] and
+/// [`RuleType::PathBeneath`].
+#[derive(Debug, Clone, Copy)]
+pub struct PathBeneathAttr<'a> {
+    /// The kinds of access to allow. These must all be handled by the
+    /// ruleset.
+    pub allowed_access: AccessFs,
+
+    /// The file or directory to allow access beneath. This is typically
+    /// opened with `OFlags::PATH`.
+    pub parent_fd: BorrowedFd<'a>,
+}
+
+/// `landlock_create_ruleset(attr, sizeof(*attr), flags)`—Create a new
+/// Landlock ruleset.
+///
+/// Handling access which the running kernel's ABI version doesn't support
+/// fails with [`io::Errno::INVAL`]; use [`abi_version`] to find which it
+/// supports.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/landlock_create_ruleset.2.html
+#[inline]
+#[doc(alias = "landlock_create_ruleset")]
+pub fn create_ruleset(attr: &RulesetAttr, flags: CreateRulesetFlags) -> io::Result<OwnedFd> {
+    imp::thread::syscalls::landlock_create_ruleset(attr.handled_access_fs.bits(), flags.bits())
+}
+
+/// `landlock_create_ruleset(NULL, 0, LANDLOCK_CREATE_RULESET_VERSION)`—Return
+/// the highest Landlock ABI version the running kernel supports.
+///
+/// Version 1 is supported since Linux 5.13, version 2, which adds
+/// [`AccessFs::REFER`], since Linux 5.19, and version 3, which adds
+/// [`AccessFs::TRUNCATE`], since Linux 6.2.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/landlock_create_ruleset.2.html
+#[inline]
+pub fn abi_version() -> io::Result<u32> {
+    imp::thread::syscalls::landlock_abi_version()
+}
+
+/// `landlock_add_rule(ruleset_fd, rule_type, rule_attr, 0)`—Add a rule to a
+/// Landlock ruleset.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/landlock_add_rule.2.html
+#[inline]
+#[doc(alias = "landlock_add_rule")]
+pub fn add_rule<Fd: AsFd>(
+    ruleset_fd: Fd,
+    rule_type: RuleType,
+    rule_attr: &PathBeneathAttr<'_>,
+) -> io::Result<()> {
+    match rule_type {
+        RuleType::PathBeneath => imp::thread::syscalls::landlock_add_rule_path_beneath(
+            ruleset_fd.as_fd(),
+            rule_attr.allowed_access.bits(),
+            rule_attr.parent_fd,
+        ),
+    }
+}
+
+/// `landlock_restrict_self(ruleset_fd, flags)`—Enforce a Landlock ruleset
+/// on the calling thread.
+///
+/// The ruleset also applies to threads and processes the thread creates
+/// afterward, and can't be removed. Unless the thread has the
+/// `CAP_SYS_ADMIN` capability in its user namespace, it must have called
+/// [`set_no_new_privs`] first, or this fails with [`io::Errno::PERM`].
+///
+/// Changes made to the ruleset after this call don't affect the thread.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/landlock_restrict_self.2.html
+/// [`set_no_new_privs`]: crate::thread::set_no_new_privs
+#[inline]
+#[doc(alias = "landlock_restrict_self")]
+pub fn restrict_self<Fd: AsFd>(ruleset_fd: Fd, flags: RestrictSelfFlags) -> io::Result<()> {
+    imp::thread::syscalls::landlock_restrict_self(ruleset_fd.as_fd(), flags.bits())
+}
@@ -6,12 +6,26 @@ mod clock;
 mod futex;
 #[cfg(any(target_os = "android", target_os = "linux"))]
 mod id;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod kill;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub mod landlock;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod seccomp;
 
 #[cfg(linux_raw)]
 pub use futex::{futex, FutexFlags, FutexOperation};
 
 #[cfg(any(target_os = "android", target_os = "linux"))]
 pub use id::gettid;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub use kill::tgkill;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub use seccomp::{
+    no_new_privs, seccomp_notif_id_valid, seccomp_notif_recv, seccomp_notif_send,
+    seccomp_set_mode_filter, set_no_new_privs, SeccompData, SeccompFilterFlags, SeccompNotif,
+    SeccompNotifResp, SeccompNotifRespFlags, SockFilter, SockFprog,
+};
 
 #[cfg(not(target_os = "redox"))]
 pub use clock::{nanosleep, NanosleepRelativeResult, Timespec};
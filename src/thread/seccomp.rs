@@ -0,0 +1,273 @@
+//! Linux seccomp filters.
+//!
+//! # Safety
+//!
+//! With [`SeccompFilterFlags::NEW_LISTENER`], the kernel returns a new file
+//! descriptor, which we take ownership of.
+#![allow(unsafe_code)]
+
+use crate::fd::{AsFd, FromRawFd};
+use crate::io::OwnedFd;
+use crate::{imp, io};
+use core::convert::TryFrom;
+
+bitflags::bitflags! {
+    /// `SECCOMP_FILTER_FLAG_*` flags for use with
+    /// [`seccomp_set_mode_filter`].
+    pub struct SeccompFilterFlags: u32 {
+        /// `SECCOMP_FILTER_FLAG_TSYNC`—Install the filter on all threads of
+        /// the process, not just the calling thread.
+        const TSYNC = 1 << 0;
+
+        /// `SECCOMP_FILTER_FLAG_LOG`—Log all actions the filter takes,
+        /// except `SECCOMP_RET_ALLOW`.
+        const LOG = 1 << 1;
+
+        /// `SECCOMP_FILTER_FLAG_SPEC_ALLOW`—Don't enable speculative store
+        /// bypass mitigation.
+        const SPEC_ALLOW = 1 << 2;
+
+        /// `SECCOMP_FILTER_FLAG_NEW_LISTENER` (since Linux 5.0)—Return a
+        /// file descriptor for receiving notifications of system calls for
+        /// which the filter returns `SECCOMP_RET_USER_NOTIF`.
+        const NEW_LISTENER = 1 << 3;
+
+        /// `SECCOMP_FILTER_FLAG_TSYNC_ESRCH` (since Linux 5.7)—With `TSYNC`,
+        /// fail with `SRCH` rather than returning a thread id when a thread
+        /// can't be synchronized. This is required to use `TSYNC` with
+        /// `NEW_LISTENER`.
+        const TSYNC_ESRCH = 1 << 4;
+    }
+}
+
+/// `struct sock_filter`—A classic BPF instruction.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash)]
+pub struct SockFilter {
+    /// The opcode.
+    pub code: u16,
+
+    /// The offset to jump to if a conditional is true.
+    pub jt: u8,
+
+    /// The offset to jump to if a conditional is false.
+    pub jf: u8,
+
+    /// A generic value, such as an immediate operand or a return value.
+    pub k: u32,
+}
+
+bitflags::bitflags! {
+    /// `SECCOMP_USER_NOTIF_FLAG_*` flags for use with [`SeccompNotifResp`].
+    pub struct SeccompNotifRespFlags: u32 {
+        /// `SECCOMP_USER_NOTIF_FLAG_CONTINUE` (since Linux 5.5)—Let the
+        /// system call run as if the filter had allowed it. `val` and
+        /// `error` must be zero.
+        const CONTINUE = 1 << 0;
+    }
+}
+
+/// `struct seccomp_data`—The system call which triggered a notification.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash)]
+pub struct SeccompData {
+    /// The system call number.
+    pub nr: i32,
+
+    /// The `AUDIT_ARCH_*` value of the system call's calling convention.
+    pub arch: u32,
+
+    /// The instruction pointer at the time of the system call.
+    pub instruction_pointer: u64,
+
+    /// The system call's arguments.
+    pub args: [u64; 6],
+}
+
+/// `struct seccomp_notif`—A notification received by [`seccomp_notif_recv`].
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash)]
+pub struct SeccompNotif {
+    /// The id of this notification, for use in [`SeccompNotifResp::id`]
+    /// and [`seccomp_notif_id_valid`].
+    pub id: u64,
+
+    /// The id of the thread which made the system call, in the listener's
+    /// pid namespace, or 0 if it isn't visible there.
+    pub pid: u32,
+
+    /// Reserved; currently always 0.
+    pub flags: u32,
+
+    /// The system call.
+    pub data: SeccompData,
+}
+
+/// `struct seccomp_notif_resp`—A response to a notification, for use with
+/// [`seccomp_notif_send`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct SeccompNotifResp {
+    /// The [`SeccompNotif::id`] of the notification being responded to.
+    pub id: u64,
+
+    /// The value for the system call to return, if `error` is 0.
+    pub val: i64,
+
+    /// The negated errno value for the system call to fail with, or 0.
+    pub error: i32,
+
+    /// Flags for the response.
+    pub flags: SeccompNotifRespFlags,
+}
+
+/// `struct sock_fprog`—A classic BPF program, for use with
+/// [`seccomp_set_mode_filter`].
+#[derive(Debug, Clone, Copy)]
+pub struct SockFprog<'a> {
+    filter: &'a [SockFilter],
+}
+
+impl<'a> SockFprog<'a> {
+    /// Construct a program consisting of the instructions in `filter`.
+    #[inline]
+    pub const fn new(filter: &'a [SockFilter]) -> Self {
+        Self { filter }
+    }
+
+    /// Returns the instructions of this program.
+    #[inline]
+    pub const fn filter(&self) -> &'a [SockFilter] {
+        self.filter
+    }
+}
+
+/// `seccomp(SECCOMP_SET_MODE_FILTER, flags, prog)`—Install a seccomp filter
+/// on the calling thread.
+///
+/// The filter is run on each system call the thread, and threads it
+/// creates afterward, make, and it can't be removed. Unless the thread has
+/// the `CAP_SYS_ADMIN` capability in its user namespace, it must have
+/// called [`set_no_new_privs`] first, or this fails with `ACCESS`.
+///
+/// With [`SeccompFilterFlags::NEW_LISTENER`], this returns a file
+/// descriptor for receiving notifications from the filter; otherwise, it
+/// returns `None`. With [`SeccompFilterFlags::TSYNC`], if another thread
+/// can't be synchronized, this fails with `SRCH`. Programs with no
+/// instructions, or too many, fail with `INVAL`.
+///
+/// Before Linux 3.17, which doesn't have `seccomp`, this falls back to
+/// `prctl(PR_SET_SECCOMP, SECCOMP_MODE_FILTER, prog)` when `flags` is empty.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/seccomp.2.html
+#[inline]
+pub fn seccomp_set_mode_filter(
+    flags: SeccompFilterFlags,
+    prog: &SockFprog<'_>,
+) -> io::Result<Option<OwnedFd>> {
+    let len = match prog.filter.len() {
+        0 => return Err(io::Errno::INVAL),
+        len => u16::try_from(len).map_err(|_| io::Errno::INVAL)?,
+    };
+    let raw =
+        imp::thread::syscalls::seccomp_set_mode_filter(flags.bits(), len, prog.filter.as_ptr())?;
+    if flags.contains(SeccompFilterFlags::NEW_LISTENER) {
+        // Safety: With `NEW_LISTENER`, the kernel returns a new file
+        // descriptor.
+        Ok(Some(unsafe { OwnedFd::from_raw_fd(raw as _) }))
+    } else if raw != 0 {
+        // With `TSYNC`, the kernel returns the id of a thread which couldn't
+        // be synchronized.
+        Err(io::Errno::SRCH)
+    } else {
+        Ok(None)
+    }
+}
+
+/// `prctl(PR_SET_NO_NEW_PRIVS, 1, 0, 0, 0)`—Prevent the calling thread, and
+/// threads and processes it creates, from gaining privileges through
+/// `execve`.
+///
+/// Once set, this can't be unset. It allows unprivileged threads to use
+/// [`seccomp_set_mode_filter`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/prctl.2.html
+#[inline]
+pub fn set_no_new_privs() -> io::Result<()> {
+    imp::thread::syscalls::set_no_new_privs()
+}
+
+/// `prctl(PR_GET_NO_NEW_PRIVS, 0, 0, 0, 0)`—Returns whether
+/// [`set_no_new_privs`] is in effect for the calling thread.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/prctl.2.html
+#[inline]
+pub fn no_new_privs() -> io::Result<bool> {
+    imp::thread::syscalls::no_new_privs()
+}
+
+/// `ioctl(fd, SECCOMP_IOCTL_NOTIF_RECV, &notif)`—Receive a notification
+/// from a seccomp listener file descriptor.
+///
+/// `fd` is a file descriptor returned by [`seccomp_set_mode_filter`] with
+/// [`SeccompFilterFlags::NEW_LISTENER`]. This blocks until a thread makes a
+/// system call for which the filter returns `SECCOMP_RET_USER_NOTIF`. The
+/// thread remains blocked until [`seccomp_notif_send`] responds to the
+/// notification. If the thread is interrupted first, this fails with
+/// `NOENT`.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/seccomp_unotify.2.html
+#[inline]
+pub fn seccomp_notif_recv<Fd: AsFd>(fd: Fd) -> io::Result<SeccompNotif> {
+    imp::thread::syscalls::seccomp_notif_recv(fd.as_fd())
+}
+
+/// `ioctl(fd, SECCOMP_IOCTL_NOTIF_SEND, resp)`—Respond to a notification
+/// received by [`seccomp_notif_recv`], unblocking the thread which made the
+/// system call.
+///
+/// If the thread is no longer waiting for the response, such as because it
+/// was interrupted, this fails with `NOENT`.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/seccomp_unotify.2.html
+#[inline]
+pub fn seccomp_notif_send<Fd: AsFd>(fd: Fd, resp: &SeccompNotifResp) -> io::Result<()> {
+    imp::thread::syscalls::seccomp_notif_send(
+        fd.as_fd(),
+        resp.id,
+        resp.val,
+        resp.error,
+        resp.flags.bits(),
+    )
+}
+
+/// `ioctl(fd, SECCOMP_IOCTL_NOTIF_ID_VALID, &id)`—Test whether a
+/// notification received by [`seccomp_notif_recv`] is still pending.
+///
+/// Fails with `NOENT` if it isn't. Use this after reading from the
+/// target's memory, such as with `/proc/<pid>/mem`, to check that the
+/// target hasn't exited and been replaced by another process with the
+/// same pid.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/seccomp_unotify.2.html
+#[inline]
+pub fn seccomp_notif_id_valid<Fd: AsFd>(fd: Fd, id: u64) -> io::Result<()> {
+    imp::thread::syscalls::seccomp_notif_id_valid(fd.as_fd(), id)
+}
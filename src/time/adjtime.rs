@@ -0,0 +1,91 @@
+use crate::time::{ClockId, Timeval};
+use crate::{imp, io};
+
+pub use imp::time::types::{TimexModes, TimexState};
+
+/// `struct timex` for use with [`clock_adjtime`].
+///
+/// Fields are only read by the kernel when the corresponding flag is set in
+/// `modes`. On success, all fields are overwritten with the current state of
+/// the clock.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+pub struct Timex {
+    /// Which fields to set; see [`TimexModes`].
+    pub modes: TimexModes,
+
+    /// Time offset, in microseconds, or nanoseconds if `STA_NANO` is set in
+    /// `status`.
+    pub offset: i64,
+
+    /// Frequency offset, in units of 2<sup>-16</sup> ppm.
+    pub freq: i64,
+
+    /// Maximum error, in microseconds.
+    pub maxerror: i64,
+
+    /// Estimated error, in microseconds.
+    pub esterror: i64,
+
+    /// Clock command and status, as `STA_*` bits.
+    pub status: i32,
+
+    /// PLL time constant.
+    pub constant: i64,
+
+    /// Clock precision, in microseconds. Read-only.
+    pub precision: i64,
+
+    /// Clock frequency tolerance, in units of 2<sup>-16</sup> ppm. Read-only.
+    pub tolerance: i64,
+
+    /// Current time. Read-only, except with [`TimexModes::SETOFFSET`]. The
+    /// `tv_usec` field holds nanoseconds if `STA_NANO` is set in `status`.
+    pub time: Timeval,
+
+    /// Microseconds between clock ticks.
+    pub tick: i64,
+
+    /// PPS frequency, in units of 2<sup>-16</sup> ppm. Read-only.
+    pub ppsfreq: i64,
+
+    /// PPS jitter. Read-only.
+    pub jitter: i64,
+
+    /// PPS interval duration, in seconds (as a power of two). Read-only.
+    pub shift: i32,
+
+    /// PPS stability. Read-only.
+    pub stabil: i64,
+
+    /// PPS count of jitter limit exceeded events. Read-only.
+    pub jitcnt: i64,
+
+    /// PPS count of calibration intervals. Read-only.
+    pub calcnt: i64,
+
+    /// PPS count of calibration errors. Read-only.
+    pub errcnt: i64,
+
+    /// PPS count of stability limit exceeded events. Read-only.
+    pub stbcnt: i64,
+
+    /// TAI offset, in seconds.
+    pub tai: i32,
+}
+
+/// `clock_adjtime(id, timex)`—Read or adjust a clock's parameters.
+///
+/// This is the interface NTP-style daemons use to steer a clock. With
+/// `timex.modes` empty it only reads the clock's state; setting any mode
+/// requires `CAP_SYS_TIME`, and fails with `PERM` otherwise.
+///
+/// On success, returns the clock state, such as a pending leap second.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/clock_adjtime.2.html
+#[inline]
+pub fn clock_adjtime(id: ClockId, timex: &mut Timex) -> io::Result<TimexState> {
+    imp::time::syscalls::clock_adjtime(id, timex)
+}
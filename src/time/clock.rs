@@ -1,11 +1,33 @@
+#[cfg(not(target_os = "wasi"))]
+use crate::fd::BorrowedFd;
 use crate::{imp, io};
 
-pub use imp::time::types::{Nsecs, Secs, Timespec};
+pub use imp::time::types::{Nsecs, Secs, Timespec, Timeval};
 
 /// `clockid_t`
 #[cfg(any(not(target_os = "wasi")))]
 pub use imp::time::types::{ClockId, DynamicClockId};
 
+#[cfg(not(target_os = "wasi"))]
+impl ClockId {
+    /// Construct a [`DynamicClockId`] for a dynamic clock, such as a PTP
+    /// hardware clock opened from `/dev/ptpN`.
+    ///
+    /// The fd is encoded into a clock id with Linux's `FD_TO_CLOCKID`
+    /// encoding. Use the result with [`clock_gettime_dynamic`] or
+    /// [`clock_getres_dynamic`]; if the fd does not refer to a clock that
+    /// supports the operation, they fail with `INVAL`.
+    ///
+    /// # References
+    ///  - [Linux]
+    ///
+    /// [Linux]: https://www.kernel.org/doc/html/latest/driver-api/ptp.html
+    #[inline]
+    pub const fn dynamic(fd: BorrowedFd<'_>) -> DynamicClockId<'_> {
+        DynamicClockId::Dynamic(fd)
+    }
+}
+
 /// `clock_getres(id)`—Returns the resolution of a clock.
 ///
 /// # References
@@ -21,6 +43,24 @@ pub fn clock_getres(id: ClockId) -> Timespec {
     imp::time::syscalls::clock_getres(id)
 }
 
+/// Like [`clock_getres`] but with support for dynamic clocks.
+///
+/// Unlike `clock_getres`, this can fail, for example if the clock is not
+/// supported by the running kernel, or if a `DynamicClockId::Dynamic` file
+/// descriptor does not refer to a clock device such as `/dev/ptp0`.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/clock_getres.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/clock_getres.2.html
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+#[inline]
+pub fn clock_getres_dynamic(id: DynamicClockId<'_>) -> io::Result<Timespec> {
+    imp::time::syscalls::clock_getres_dynamic(id)
+}
+
 /// `clock_gettime(id)`—Returns the current value of a clock.
 ///
 /// This function uses `ClockId` which only contains clocks which are known to
@@ -54,3 +94,38 @@ pub fn clock_gettime(id: ClockId) -> Timespec {
 pub fn clock_gettime_dynamic(id: DynamicClockId<'_>) -> io::Result<Timespec> {
     imp::time::syscalls::clock_gettime_dynamic(id)
 }
+
+/// `gettimeofday()`—Returns the current wall-clock time, with microsecond
+/// precision.
+///
+/// This is a legacy interface; [`clock_gettime`] with [`ClockId::Realtime`]
+/// provides the same time with nanosecond precision. On Linux, this uses the
+/// vDSO when available.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/gettimeofday.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/gettimeofday.2.html
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+#[inline]
+#[must_use]
+pub fn gettimeofday() -> Timeval {
+    imp::time::syscalls::gettimeofday()
+}
+
+/// `settimeofday(tv, NULL)`—Sets the current wall-clock time.
+///
+/// This requires the `CAP_SYS_TIME` capability, and fails with `PERM`
+/// otherwise.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/settimeofday.2.html
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+pub fn settimeofday(tv: &Timeval) -> io::Result<()> {
+    imp::time::syscalls::settimeofday(tv)
+}
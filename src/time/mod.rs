@@ -1,5 +1,7 @@
 //! Time-related operations.
 
+#[cfg(target_os = "linux")]
+mod adjtime;
 mod clock;
 #[cfg(any(target_os = "android", target_os = "fuchsia", target_os = "linux"))]
 #[cfg(feature = "time")]
@@ -7,11 +9,15 @@ mod timerfd;
 
 // TODO: Convert WASI'S clock APIs to use handles rather than ambient clock
 // identifiers, update `wasi-libc`, and then add support in `rustix`.
+#[cfg(target_os = "linux")]
+pub use adjtime::{clock_adjtime, Timex, TimexModes, TimexState};
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub use clock::settimeofday;
 #[cfg(not(any(target_os = "redox", target_os = "wasi")))]
-pub use clock::clock_getres;
+pub use clock::{clock_getres, clock_getres_dynamic, gettimeofday};
 #[cfg(not(target_os = "wasi"))]
 pub use clock::{clock_gettime, clock_gettime_dynamic, ClockId, DynamicClockId};
-pub use clock::{Nsecs, Secs, Timespec};
+pub use clock::{Nsecs, Secs, Timespec, Timeval};
 #[cfg(any(target_os = "android", target_os = "fuchsia", target_os = "linux"))]
 #[cfg(feature = "time")]
 pub use timerfd::{
@@ -0,0 +1,45 @@
+#[test]
+fn test_accessat() {
+    use rustix::fs::{accessat, cwd, openat, symlinkat, Access, AtFlags, Mode, OFlags};
+
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(cwd(), tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+
+    let _ = openat(
+        &dir,
+        "file",
+        OFlags::CREATE | OFlags::WRONLY,
+        Mode::RUSR | Mode::WUSR,
+    )
+    .unwrap();
+    accessat(&dir, "file", Access::EXISTS, AtFlags::empty()).unwrap();
+    accessat(
+        &dir,
+        "file",
+        Access::READ_OK | Access::WRITE_OK,
+        AtFlags::empty(),
+    )
+    .unwrap();
+    assert_eq!(
+        accessat(&dir, "missing", Access::EXISTS, AtFlags::empty()),
+        Err(rustix::io::Errno::NOENT)
+    );
+
+    // `EACCESS` uses `faccessat2` on Linux when the process is setuid or
+    // setgid, which may not be available on older kernels.
+    match accessat(&dir, "file", Access::READ_OK, AtFlags::EACCESS) {
+        Ok(()) | Err(rustix::io::Errno::NOSYS) => (),
+        Err(err) => panic!("{:?}", err),
+    }
+
+    // With `SYMLINK_NOFOLLOW`, a dangling symlink exists.
+    symlinkat("missing", &dir, "link").unwrap();
+    assert_eq!(
+        accessat(&dir, "link", Access::EXISTS, AtFlags::empty()),
+        Err(rustix::io::Errno::NOENT)
+    );
+    match accessat(&dir, "link", Access::EXISTS, AtFlags::SYMLINK_NOFOLLOW) {
+        Ok(()) | Err(rustix::io::Errno::NOSYS) => (),
+        Err(err) => panic!("{:?}", err),
+    }
+}
@@ -0,0 +1,41 @@
+#[test]
+fn test_cachestat() {
+    use rustix::fs::{cachestat, openat, CachestatRange, Mode, OFlags};
+    use rustix::io::{self, write};
+
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(
+        rustix::fs::cwd(),
+        tmp.path(),
+        OFlags::RDONLY | OFlags::CLOEXEC,
+        Mode::empty(),
+    )
+    .unwrap();
+    let file = openat(
+        &dir,
+        "file",
+        OFlags::RDWR | OFlags::CREATE | OFlags::CLOEXEC,
+        Mode::RUSR | Mode::WUSR,
+    )
+    .unwrap();
+    write(&file, &[0_u8; 8192]).unwrap();
+
+    let stat = match cachestat(&file, &CachestatRange { off: 0, len: 0 }) {
+        Ok(stat) => stat,
+        Err(io::Errno::NOSYS) => return,
+        Err(err) => panic!("cachestat failed: {:?}", err),
+    };
+    assert!(stat.nr_cache >= 1);
+    assert!(stat.nr_dirty <= stat.nr_cache);
+
+    // A range past the end of the file has nothing cached.
+    let stat = cachestat(
+        &file,
+        &CachestatRange {
+            off: 1 << 30,
+            len: 4096,
+        },
+    )
+    .unwrap();
+    assert_eq!(stat.nr_cache, 0);
+}
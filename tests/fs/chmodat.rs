@@ -0,0 +1,29 @@
+#[test]
+fn test_chmodat() {
+    use rustix::fs::{chmodat, cwd, openat, statat, symlinkat, AtFlags, Mode, OFlags};
+
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(cwd(), tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+    let _ = openat(&dir, "file", OFlags::CREATE | OFlags::WRONLY, Mode::RUSR).unwrap();
+    symlinkat("file", &dir, "link").unwrap();
+
+    chmodat(&dir, "file", Mode::RWXU, AtFlags::empty()).unwrap();
+    let stat = statat(&dir, "file", AtFlags::empty()).unwrap();
+    assert_eq!(Mode::from_raw_mode(stat.st_mode), Mode::RWXU);
+
+    // Following a symlink changes the file it refers to.
+    chmodat(&dir, "link", Mode::RUSR | Mode::WUSR, AtFlags::empty()).unwrap();
+    let stat = statat(&dir, "file", AtFlags::empty()).unwrap();
+    assert_eq!(Mode::from_raw_mode(stat.st_mode), Mode::RUSR | Mode::WUSR);
+
+    chmodat(&dir, "file", Mode::RUSR, AtFlags::SYMLINK_NOFOLLOW).unwrap();
+    let stat = statat(&dir, "file", AtFlags::empty()).unwrap();
+    assert_eq!(Mode::from_raw_mode(stat.st_mode), Mode::RUSR);
+
+    // Linux doesn't support changing the permissions of a symlink itself.
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    assert_eq!(
+        chmodat(&dir, "link", Mode::RWXU, AtFlags::SYMLINK_NOFOLLOW),
+        Err(rustix::io::Errno::OPNOTSUPP)
+    );
+}
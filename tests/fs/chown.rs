@@ -0,0 +1,53 @@
+#[test]
+fn test_chownat() {
+    use rustix::fs::{chownat, cwd, openat, statat, symlinkat, AtFlags, Mode, OFlags};
+    use rustix::process::{getgid, getuid};
+
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(cwd(), tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+    let _ = openat(&dir, "file", OFlags::CREATE | OFlags::WRONLY, Mode::RUSR).unwrap();
+    symlinkat("file", &dir, "link").unwrap();
+
+    // Passing `None` for both leaves the ownership unchanged.
+    let before = statat(&dir, "file", AtFlags::empty()).unwrap();
+    chownat(&dir, "file", None, None, AtFlags::empty()).unwrap();
+    let after = statat(&dir, "file", AtFlags::empty()).unwrap();
+    assert_eq!(before.st_uid, after.st_uid);
+    assert_eq!(before.st_gid, after.st_gid);
+
+    // We can always change the group to our own group.
+    chownat(&dir, "file", None, Some(getgid()), AtFlags::empty()).unwrap();
+    let stat = statat(&dir, "file", AtFlags::empty()).unwrap();
+    assert_eq!(stat.st_gid, getgid().as_raw());
+
+    chownat(
+        &dir,
+        "link",
+        Some(getuid()),
+        Some(getgid()),
+        AtFlags::SYMLINK_NOFOLLOW,
+    )
+    .unwrap();
+    let stat = statat(&dir, "link", AtFlags::SYMLINK_NOFOLLOW).unwrap();
+    assert_eq!(stat.st_uid, getuid().as_raw());
+    assert_eq!(stat.st_gid, getgid().as_raw());
+}
+
+#[test]
+fn test_fchown() {
+    use rustix::fs::{fchown, fstat};
+    use rustix::process::{getgid, getuid};
+
+    let file = tempfile::tempfile().unwrap();
+
+    let before = fstat(&file).unwrap();
+    fchown(&file, None, None).unwrap();
+    let after = fstat(&file).unwrap();
+    assert_eq!(before.st_uid, after.st_uid);
+    assert_eq!(before.st_gid, after.st_gid);
+
+    fchown(&file, Some(getuid()), Some(getgid())).unwrap();
+    let stat = fstat(&file).unwrap();
+    assert_eq!(stat.st_uid, getuid().as_raw());
+    assert_eq!(stat.st_gid, getgid().as_raw());
+}
@@ -0,0 +1,81 @@
+use rustix::fs::{
+    copy_tree, cwd, fchmod, futimens, mkdirat, mkfifoat, openat, readlinkat, statat, symlinkat,
+    AtFlags, FileType, Mode, OFlags, Timespec, Timestamps,
+};
+use rustix::io::{self, read, write};
+
+#[test]
+fn test_copy_tree() {
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(cwd(), tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+
+    mkdirat(&dir, "src", Mode::RWXU).unwrap();
+    mkdirat(&dir, "src/sub", Mode::RWXU).unwrap();
+    let file = openat(
+        &dir,
+        "src/sub/file",
+        OFlags::WRONLY | OFlags::CREATE | OFlags::CLOEXEC,
+        Mode::RUSR | Mode::WUSR,
+    )
+    .unwrap();
+    write(&file, b"hello, world").unwrap();
+    fchmod(&file, Mode::RUSR | Mode::RGRP).unwrap();
+    let times = Timestamps {
+        last_access: Timespec {
+            tv_sec: 1_000_000,
+            tv_nsec: 1,
+        },
+        last_modification: Timespec {
+            tv_sec: 2_000_000,
+            tv_nsec: 2,
+        },
+    };
+    futimens(&file, &times).unwrap();
+    symlinkat("sub/file", &dir, "src/link").unwrap();
+    symlinkat("/nonexistent", &dir, "src/dangling").unwrap();
+    mkfifoat(&dir, "src/fifo", Mode::RUSR | Mode::WUSR).unwrap();
+
+    copy_tree(&dir, "src", &dir, "dst").unwrap();
+
+    let stat = statat(&dir, "dst/sub/file", AtFlags::empty()).unwrap();
+    assert_eq!(stat.st_mode & 0o777, 0o440);
+    assert_eq!(stat.st_mtime, 2_000_000);
+    assert_eq!(stat.st_mtime_nsec, 2);
+    let copy = openat(&dir, "dst/sub/file", OFlags::RDONLY, Mode::empty()).unwrap();
+    let mut buf = [0_u8; 32];
+    let nread = read(&copy, &mut buf).unwrap();
+    assert_eq!(&buf[..nread], b"hello, world");
+
+    assert_eq!(
+        readlinkat(&dir, "dst/link", Vec::new()).unwrap().to_bytes(),
+        b"sub/file"
+    );
+    assert_eq!(
+        readlinkat(&dir, "dst/dangling", Vec::new())
+            .unwrap()
+            .to_bytes(),
+        b"/nonexistent"
+    );
+    let stat = statat(&dir, "dst/fifo", AtFlags::SYMLINK_NOFOLLOW).unwrap();
+    assert_eq!(FileType::from_raw_mode(stat.st_mode), FileType::Fifo);
+
+    // The destination must not already exist.
+    assert_eq!(copy_tree(&dir, "src", &dir, "dst"), Err(io::Errno::EXIST));
+}
+
+#[test]
+fn test_copy_tree_into_itself() {
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(cwd(), tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+
+    mkdirat(&dir, "src", Mode::RWXU).unwrap();
+    mkdirat(&dir, "src/sub", Mode::RWXU).unwrap();
+
+    copy_tree(&dir, "src", &dir, "src/sub/copy").unwrap();
+
+    statat(&dir, "src/sub/copy/sub", AtFlags::empty()).unwrap();
+    assert_eq!(
+        statat(&dir, "src/sub/copy/sub/copy", AtFlags::empty()).unwrap_err(),
+        io::Errno::NOENT
+    );
+}
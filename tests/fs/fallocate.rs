@@ -0,0 +1,35 @@
+use rustix::fs::{cwd, fallocate_reserve, fstat, ftruncate, openat, Mode, OFlags};
+use rustix::io;
+
+#[test]
+fn test_fallocate_reserve() {
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(cwd(), tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+    let file = openat(
+        &dir,
+        "file",
+        OFlags::CREATE | OFlags::RDWR | OFlags::CLOEXEC,
+        Mode::RUSR | Mode::WUSR,
+    )
+    .unwrap();
+
+    match fallocate_reserve(&file, 0, 8192) {
+        Ok(()) => {}
+        // The filesystem may not support allocation.
+        Err(io::Errno::OPNOTSUPP) => return,
+        Err(err) => panic!("fallocate_reserve failed: {:?}", err),
+    }
+
+    // The file is extended to cover the range.
+    let stat = fstat(&file).unwrap();
+    assert_eq!(stat.st_size, 8192);
+    assert!(stat.st_blocks * 512 >= 8192);
+
+    // It's never shrunk.
+    fallocate_reserve(&file, 0, 100).unwrap();
+    assert_eq!(fstat(&file).unwrap().st_size, 8192);
+
+    ftruncate(&file, 0).unwrap();
+    fallocate_reserve(&file, 4096, 4096).unwrap();
+    assert_eq!(fstat(&file).unwrap().st_size, 8192);
+}
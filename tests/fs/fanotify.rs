@@ -0,0 +1,167 @@
+use rustix::fd::{AsRawFd, BorrowedFd};
+use rustix::fs::fanotify::{self, EventFlags, FanotifyResponse, InitFlags, MarkFlags, Reader};
+use rustix::fs::{cwd, fstat, openat, Mode, OFlags};
+use rustix::io;
+use std::convert::TryInto;
+use std::os::unix::fs::MetadataExt;
+
+#[test]
+fn test_fanotify() {
+    let fanotify = match fanotify::init(
+        InitFlags::CLOEXEC | InitFlags::NONBLOCK | InitFlags::CLASS_NOTIF,
+        OFlags::RDONLY | OFlags::CLOEXEC,
+    ) {
+        Ok(fanotify) => fanotify,
+        // fanotify requires `CAP_SYS_ADMIN`.
+        Err(io::Errno::PERM) | Err(io::Errno::NOSYS) => return,
+        Err(err) => panic!("fanotify_init failed: {:?}", err),
+    };
+
+    let tmp = tempfile::tempdir().unwrap();
+    fanotify::mark(
+        &fanotify,
+        MarkFlags::ADD,
+        EventFlags::CLOSE_WRITE | EventFlags::EVENT_ON_CHILD,
+        cwd(),
+        tmp.path(),
+    )
+    .unwrap();
+
+    let mut buf = [0_u8; 4096];
+    let mut reader = Reader::new(&fanotify, &mut buf);
+    assert_eq!(reader.next().unwrap_err(), io::Errno::AGAIN);
+
+    let file = openat(
+        cwd(),
+        tmp.path().join("file"),
+        OFlags::CREATE | OFlags::WRONLY | OFlags::CLOEXEC,
+        Mode::RUSR,
+    )
+    .unwrap();
+    drop(file);
+
+    let event = reader.next().unwrap();
+    assert_eq!(event.mask, EventFlags::CLOSE_WRITE);
+    assert_eq!(
+        event.pid as u32,
+        rustix::process::getpid().as_raw_nonzero().get() as u32
+    );
+    assert!(event.fd.is_some());
+    assert!(reader.is_buffer_empty());
+}
+
+#[test]
+fn test_fanotify_respond() {
+    let fanotify = match fanotify::init(
+        InitFlags::CLOEXEC | InitFlags::CLASS_CONTENT,
+        OFlags::RDONLY | OFlags::CLOEXEC,
+    ) {
+        Ok(fanotify) => fanotify,
+        Err(io::Errno::PERM) | Err(io::Errno::NOSYS) | Err(io::Errno::INVAL) => return,
+        Err(err) => panic!("fanotify_init failed: {:?}", err),
+    };
+
+    let tmp = tempfile::tempdir().unwrap();
+    let path = tmp.path().join("file");
+    std::fs::write(&path, b"data").unwrap();
+    fanotify::mark(
+        &fanotify,
+        MarkFlags::ADD,
+        EventFlags::OPEN_PERM,
+        cwd(),
+        &path,
+    )
+    .unwrap();
+
+    let mut buf = [0_u8; 4096];
+    let mut reader = Reader::new(&fanotify, &mut buf);
+    for &response in &[FanotifyResponse::Allow, FanotifyResponse::Deny] {
+        let opener = {
+            let path = path.clone();
+            std::thread::spawn(move || {
+                openat(
+                    cwd(),
+                    &path,
+                    OFlags::RDONLY | OFlags::CLOEXEC,
+                    Mode::empty(),
+                )
+                .map(drop)
+            })
+        };
+
+        let event = reader.next().unwrap();
+        assert_eq!(event.mask, EventFlags::OPEN_PERM);
+        let event_fd = event.fd.unwrap();
+        fanotify::respond(&fanotify, &event_fd, response).unwrap();
+        drop(event_fd);
+
+        match response {
+            FanotifyResponse::Allow => opener.join().unwrap().unwrap(),
+            FanotifyResponse::Deny => {
+                assert_eq!(opener.join().unwrap(), Err(io::Errno::PERM))
+            }
+        }
+    }
+}
+
+#[test]
+fn test_fanotify_reader_drop() {
+    let fanotify = match fanotify::init(
+        InitFlags::CLOEXEC | InitFlags::NONBLOCK | InitFlags::CLASS_NOTIF,
+        OFlags::RDONLY | OFlags::CLOEXEC,
+    ) {
+        Ok(fanotify) => fanotify,
+        Err(io::Errno::PERM) | Err(io::Errno::NOSYS) => return,
+        Err(err) => panic!("fanotify_init failed: {:?}", err),
+    };
+
+    let tmp = tempfile::tempdir().unwrap();
+    fanotify::mark(
+        &fanotify,
+        MarkFlags::ADD,
+        EventFlags::CLOSE_WRITE | EventFlags::EVENT_ON_CHILD,
+        cwd(),
+        tmp.path(),
+    )
+    .unwrap();
+
+    for name in &["a", "b"] {
+        let file = openat(
+            cwd(),
+            tmp.path().join(name),
+            OFlags::CREATE | OFlags::WRONLY | OFlags::CLOEXEC,
+            Mode::RUSR,
+        )
+        .unwrap();
+        drop(file);
+    }
+
+    let mut buf = [0_u8; 4096];
+    let mut reader = Reader::new(&fanotify, &mut buf);
+    let first = reader.next().unwrap();
+    assert!(!reader.is_buffer_empty());
+    drop(reader);
+
+    // Find the file descriptor of the unconsumed second event in the buffer,
+    // and check that dropping the `Reader` closed it. Another test may have
+    // reused the number since then, but not for the file from the event.
+    let first_len = u32::from_ne_bytes(buf[0..4].try_into().unwrap()) as usize;
+    let second_fd = i32::from_ne_bytes(buf[first_len + 16..first_len + 20].try_into().unwrap());
+    assert_ne!(second_fd, first.fd.unwrap().as_raw_fd());
+    let second_ino = std::fs::metadata(tmp.path().join("b")).unwrap().ino();
+    match fstat(unsafe { BorrowedFd::borrow_raw(second_fd) }) {
+        Err(io::Errno::BADF) => (),
+        Ok(stat) => assert_ne!(stat.st_ino, second_ino),
+        Err(err) => panic!("unexpected fstat error: {:?}", err),
+    }
+}
+
+#[test]
+fn test_fanotify_not_a_fanotify_fd() {
+    let file = tempfile::tempfile().unwrap();
+    // Older kernels fail with `EBADF`, newer ones with `EINVAL`.
+    match fanotify::mark_fd(&file, MarkFlags::ADD, EventFlags::OPEN, &file) {
+        Err(io::Errno::INVAL) | Err(io::Errno::BADF) => (),
+        otherwise => panic!("unexpected result: {:?}", otherwise),
+    }
+}
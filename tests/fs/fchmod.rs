@@ -0,0 +1,52 @@
+use rustix::fs::{fchmod, fstat, memfd_create, openat, AtFlags, MemfdFlags, Mode, OFlags};
+use rustix::io;
+
+#[test]
+fn test_fchmod_memfd() {
+    let fd = memfd_create("test", MemfdFlags::CLOEXEC).unwrap();
+    fchmod(&fd, Mode::RUSR | Mode::WUSR | Mode::RGRP).unwrap();
+    let stat = fstat(&fd).unwrap();
+    assert_eq!(stat.st_mode as u32 & 0o777, 0o640);
+}
+
+#[test]
+fn test_fchmod_tmpfile() {
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(
+        rustix::fs::cwd(),
+        tmp.path(),
+        OFlags::RDONLY | OFlags::CLOEXEC,
+        Mode::empty(),
+    )
+    .unwrap();
+    let fd = match openat(
+        &dir,
+        ".",
+        OFlags::WRONLY | OFlags::TMPFILE | OFlags::CLOEXEC,
+        Mode::RUSR | Mode::WUSR,
+    ) {
+        Ok(fd) => fd,
+        // Not all filesystems support `O_TMPFILE`.
+        Err(io::Errno::OPNOTSUPP) | Err(io::Errno::ISDIR) => return,
+        Err(err) => panic!("openat failed: {:?}", err),
+    };
+
+    fchmod(&fd, Mode::RUSR | Mode::RGRP | Mode::ROTH).unwrap();
+    rustix::fs::linkat(&fd, "", &dir, "file", AtFlags::EMPTY_PATH)
+        .or_else(|_| {
+            // `AT_EMPTY_PATH` requires `CAP_DAC_READ_SEARCH`; fall back to
+            // the `/proc` path.
+            let path = format!("/proc/self/fd/{}", rustix::fd::AsRawFd::as_raw_fd(&fd));
+            rustix::fs::linkat(
+                rustix::fs::cwd(),
+                path,
+                &dir,
+                "file",
+                AtFlags::SYMLINK_FOLLOW,
+            )
+        })
+        .unwrap();
+
+    let stat = rustix::fs::statat(&dir, "file", AtFlags::empty()).unwrap();
+    assert_eq!(stat.st_mode as u32 & 0o777, 0o444);
+}
@@ -0,0 +1,64 @@
+use rustix::fs::{cwd, fsync, fsync_parent_dir, openat, renameat, Mode, OFlags};
+use rustix::io::{self, write};
+
+#[test]
+fn test_fsync() {
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(
+        cwd(),
+        tmp.path(),
+        OFlags::RDONLY | OFlags::CLOEXEC,
+        Mode::empty(),
+    )
+    .unwrap();
+    let file = openat(
+        &dir,
+        "tmp",
+        OFlags::CREATE | OFlags::WRONLY | OFlags::CLOEXEC,
+        Mode::RUSR | Mode::WUSR,
+    )
+    .unwrap();
+
+    // The usual sequence for atomically and durably replacing a file.
+    write(&file, b"hello").unwrap();
+    fsync(&file).unwrap();
+    renameat(&dir, "tmp", &dir, "file").unwrap();
+    fsync_parent_dir(&dir).unwrap();
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+fn test_fsync_parent_dir_o_path() {
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(
+        cwd(),
+        tmp.path(),
+        OFlags::PATH | OFlags::CLOEXEC,
+        Mode::empty(),
+    )
+    .unwrap();
+    assert_eq!(fsync_parent_dir(&dir), Err(io::Errno::BADF));
+}
+
+#[cfg(not(any(
+    target_os = "dragonfly",
+    target_os = "ios",
+    target_os = "macos",
+    target_os = "redox"
+)))]
+#[test]
+fn test_fdatasync() {
+    use rustix::fs::fdatasync;
+
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(cwd(), tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+    let file = openat(
+        &dir,
+        "file",
+        OFlags::CREATE | OFlags::WRONLY | OFlags::APPEND | OFlags::CLOEXEC,
+        Mode::RUSR | Mode::WUSR,
+    )
+    .unwrap();
+    write(&file, b"hello").unwrap();
+    fdatasync(&file).unwrap();
+}
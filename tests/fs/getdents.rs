@@ -0,0 +1,102 @@
+use rustix::fd::AsFd;
+use rustix::fs::{cwd, getdents, openat, Dir, FileType, Mode, OFlags, RawDirEntries};
+use rustix::io;
+use std::collections::HashMap;
+
+#[test]
+fn test_getdents() {
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(cwd(), tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+    let _ = openat(
+        &dir,
+        "file",
+        OFlags::CREATE | OFlags::WRONLY | OFlags::CLOEXEC,
+        Mode::RUSR,
+    )
+    .unwrap();
+    rustix::fs::mkdirat(&dir, "subdir", Mode::RWXU).unwrap();
+
+    let mut entries = HashMap::new();
+    let mut buf = [0_u8; 4096];
+    loop {
+        let nread = getdents(&dir, &mut buf).unwrap();
+        if nread == 0 {
+            break;
+        }
+        let mut total = 0;
+        for entry in RawDirEntries::new(&buf[..nread]) {
+            total += entry.reclen() as usize;
+            entries.insert(
+                entry.file_name().to_str().unwrap().to_owned(),
+                (entry.file_type(), entry.ino()),
+            );
+        }
+        assert_eq!(total, nread);
+    }
+
+    assert_eq!(entries.len(), 4);
+    assert_eq!(entries["."].0, FileType::Directory);
+    assert_eq!(entries[".."].0, FileType::Directory);
+    assert_eq!(entries["file"].0, FileType::RegularFile);
+    assert_eq!(entries["subdir"].0, FileType::Directory);
+    assert_eq!(
+        entries["file"].1,
+        rustix::fs::statat(&dir, "file", rustix::fs::AtFlags::empty())
+            .unwrap()
+            .st_ino as u64
+    );
+}
+
+#[test]
+fn test_getdents_small_buffer() {
+    let dir = openat(cwd(), ".", OFlags::RDONLY, Mode::empty()).unwrap();
+    let mut buf = [0_u8; 8];
+    assert_eq!(getdents(&dir, &mut buf), Err(io::Errno::INVAL));
+}
+
+#[test]
+fn test_raw_dir_entries_truncated() {
+    let dir = openat(cwd(), ".", OFlags::RDONLY, Mode::empty()).unwrap();
+    let mut buf = [0_u8; 4096];
+    let nread = getdents(&dir, &mut buf).unwrap();
+    let first = RawDirEntries::new(&buf[..nread]).next().unwrap();
+
+    // A buffer which ends partway through a record yields only the complete
+    // records before it.
+    let truncated = first.reclen() as usize - 1;
+    assert_eq!(RawDirEntries::new(&buf[..truncated]).count(), 0);
+}
+
+#[test]
+fn test_dir_new_in() {
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(cwd(), tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+    for i in 0..64 {
+        let _ = openat(
+            &dir,
+            format!("file{}", i),
+            OFlags::CREATE | OFlags::WRONLY | OFlags::CLOEXEC,
+            Mode::RUSR,
+        )
+        .unwrap();
+    }
+
+    // Use a buffer which is too small to hold all the entries at once, so
+    // that it has to be refilled.
+    let mut buf = [0_u8; 256];
+    let mut reader = Dir::new_in(dir.as_fd(), &mut buf);
+    let mut count = 0;
+    while let Some(entry) = reader.read() {
+        let entry = entry.unwrap();
+        let name = entry.file_name().to_str().unwrap();
+        if name != "." && name != ".." {
+            assert!(name.starts_with("file"));
+            assert_eq!(entry.file_type(), FileType::RegularFile);
+        }
+        count += 1;
+    }
+    assert_eq!(count, 66);
+
+    reader.rewind().unwrap();
+    assert!(reader.read().is_some());
+}
@@ -0,0 +1,59 @@
+use rustix::fs::inotify::{self, CreateFlags, ReadFlags, Reader, WatchFlags};
+use rustix::fs::{cwd, openat, renameat, unlinkat, AtFlags, Mode, OFlags};
+use rustix::io;
+
+#[test]
+fn test_inotify() {
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(
+        cwd(),
+        tmp.path(),
+        OFlags::RDONLY | OFlags::CLOEXEC,
+        Mode::empty(),
+    )
+    .unwrap();
+
+    let inotify = inotify::init(CreateFlags::CLOEXEC | CreateFlags::NONBLOCK).unwrap();
+    let wd = inotify::add_watch(
+        &inotify,
+        tmp.path(),
+        WatchFlags::CREATE | WatchFlags::DELETE | WatchFlags::MOVE,
+    )
+    .unwrap();
+
+    let mut buf = [0_u8; 4096];
+    let mut reader = Reader::new(&inotify, &mut buf);
+    assert_eq!(reader.next().unwrap_err(), io::Errno::AGAIN);
+
+    let _file = openat(&dir, "file", OFlags::CREATE | OFlags::WRONLY, Mode::RUSR).unwrap();
+    renameat(&dir, "file", &dir, "renamed").unwrap();
+    unlinkat(&dir, "renamed", AtFlags::empty()).unwrap();
+
+    let event = reader.next().unwrap();
+    assert_eq!(event.wd, wd);
+    assert_eq!(event.mask, ReadFlags::CREATE);
+    assert_eq!(event.name.unwrap().to_bytes(), b"file");
+
+    let event = reader.next().unwrap();
+    assert_eq!(event.mask, ReadFlags::MOVED_FROM);
+    assert_eq!(event.name.unwrap().to_bytes(), b"file");
+    let cookie = event.cookie;
+
+    let event = reader.next().unwrap();
+    assert_eq!(event.mask, ReadFlags::MOVED_TO);
+    assert_eq!(event.name.unwrap().to_bytes(), b"renamed");
+    assert_eq!(event.cookie, cookie);
+
+    let event = reader.next().unwrap();
+    assert_eq!(event.mask, ReadFlags::DELETE);
+    assert_eq!(event.name.unwrap().to_bytes(), b"renamed");
+    assert!(reader.is_buffer_empty());
+
+    inotify::remove_watch(&inotify, wd).unwrap();
+    let event = reader.next().unwrap();
+    assert_eq!(event.wd, wd);
+    assert_eq!(event.mask, ReadFlags::IGNORED);
+    assert!(event.name.is_none());
+
+    assert_eq!(inotify::remove_watch(&inotify, wd), Err(io::Errno::INVAL));
+}
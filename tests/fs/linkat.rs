@@ -0,0 +1,67 @@
+use rustix::fs::{
+    cwd, linkat, mkdirat, openat, statat, symlinkat, unlinkat, AtFlags, FileType, Mode, OFlags,
+};
+use rustix::io;
+
+#[test]
+fn test_linkat() {
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(cwd(), tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+
+    let _ = openat(
+        &dir,
+        "file",
+        OFlags::CREATE | OFlags::WRONLY | OFlags::CLOEXEC,
+        Mode::RUSR,
+    )
+    .unwrap();
+    symlinkat("file", &dir, "symlink").unwrap();
+    let file_stat = statat(&dir, "file", AtFlags::empty()).unwrap();
+
+    linkat(&dir, "file", &dir, "link", AtFlags::empty()).unwrap();
+    let stat = statat(&dir, "link", AtFlags::empty()).unwrap();
+    assert_eq!(stat.st_ino, file_stat.st_ino);
+    assert_eq!(stat.st_nlink, 2);
+
+    // Without `SYMLINK_FOLLOW`, the symlink itself is linked.
+    linkat(&dir, "symlink", &dir, "symlink_link", AtFlags::empty()).unwrap();
+    let stat = statat(&dir, "symlink_link", AtFlags::SYMLINK_NOFOLLOW).unwrap();
+    assert_eq!(FileType::from_raw_mode(stat.st_mode), FileType::Symlink);
+
+    // With `SYMLINK_FOLLOW`, the file it refers to is linked.
+    linkat(&dir, "symlink", &dir, "followed", AtFlags::SYMLINK_FOLLOW).unwrap();
+    let stat = statat(&dir, "followed", AtFlags::SYMLINK_NOFOLLOW).unwrap();
+    assert_eq!(FileType::from_raw_mode(stat.st_mode), FileType::RegularFile);
+    assert_eq!(stat.st_ino, file_stat.st_ino);
+}
+
+#[test]
+fn test_unlinkat() {
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(cwd(), tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+
+    mkdirat(&dir, "dir", Mode::RWXU).unwrap();
+    let _ = openat(
+        &dir,
+        "dir/file",
+        OFlags::CREATE | OFlags::WRONLY | OFlags::CLOEXEC,
+        Mode::RUSR,
+    )
+    .unwrap();
+
+    assert_eq!(
+        unlinkat(&dir, "dir", AtFlags::REMOVEDIR),
+        Err(io::Errno::NOTEMPTY)
+    );
+    assert_eq!(
+        unlinkat(&dir, "dir/file", AtFlags::REMOVEDIR),
+        Err(io::Errno::NOTDIR)
+    );
+
+    unlinkat(&dir, "dir/file", AtFlags::empty()).unwrap();
+    unlinkat(&dir, "dir", AtFlags::REMOVEDIR).unwrap();
+    assert_eq!(
+        statat(&dir, "dir", AtFlags::empty()).unwrap_err(),
+        io::Errno::NOENT
+    );
+}
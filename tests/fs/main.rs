@@ -5,13 +5,50 @@
 #![cfg_attr(target_os = "wasi", feature(wasi_ext))]
 #![cfg_attr(io_lifetimes_use_std, feature(io_safety))]
 
+#[cfg(not(any(
+    target_os = "android",
+    target_os = "emscripten",
+    target_os = "illumos",
+    target_os = "redox",
+    target_os = "wasi",
+)))]
+mod accessat;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod cachestat;
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+mod chmodat;
+#[cfg(feature = "process")]
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+mod chown;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod copy_tree;
 mod dir;
+#[cfg(not(any(
+    target_os = "dragonfly",
+    target_os = "illumos",
+    target_os = "netbsd",
+    target_os = "openbsd",
+    target_os = "redox",
+    target_os = "wasi",
+)))]
+mod fallocate;
+#[cfg(target_os = "linux")]
+mod fanotify;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod fchmod;
 mod fcntl;
 mod file;
 #[cfg(not(target_os = "wasi"))]
 mod flock;
+mod fsync;
 mod futimens;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod getdents;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod inotify;
 mod invalid_offset;
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+mod linkat;
 mod long_paths;
 #[cfg(not(any(
     target_os = "dragonfly",
@@ -25,14 +62,27 @@ mod long_paths;
     target_os = "wasi"
 )))]
 mod makedev;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod memfd_secret;
 mod mkdirat;
+mod mkfifoat;
 mod mknodat;
 #[cfg(any(target_os = "android", target_os = "linux"))]
+mod mount;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod o_path;
+#[cfg(any(target_os = "android", target_os = "linux"))]
 mod openat;
 #[cfg(any(target_os = "android", target_os = "linux"))]
 mod openat2;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod pivot_root;
+#[cfg(target_os = "linux")]
+mod readahead;
 mod readdir;
 mod renameat;
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+mod seek;
 #[cfg(not(any(
     target_os = "illumos",
     target_os = "netbsd",
@@ -41,5 +91,9 @@ mod renameat;
 )))]
 // not implemented in libc for netbsd yet
 mod statfs;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod swap;
+#[cfg(not(any(target_os = "fuchsia", target_os = "redox", target_os = "wasi")))]
+mod truncate;
 mod utimensat;
 mod y2038;
@@ -0,0 +1,15 @@
+#[test]
+fn test_memfd_secret() {
+    use rustix::fs::{fcntl_getfd, fstat, ftruncate, memfd_secret, FdFlags, MemfdSecretFlags};
+
+    let fd = match memfd_secret(MemfdSecretFlags::CLOEXEC) {
+        Ok(fd) => fd,
+        // `memfd_secret` may be unsupported or disabled.
+        Err(rustix::io::Errno::NOSYS) => return,
+        Err(err) => panic!("memfd_secret failed: {:?}", err),
+    };
+    assert!(fcntl_getfd(&fd).unwrap().contains(FdFlags::CLOEXEC));
+
+    ftruncate(&fd, 4096).unwrap();
+    assert_eq!(fstat(&fd).unwrap().st_size, 4096);
+}
@@ -31,3 +31,32 @@ fn test_mkdirat_with_o_path() {
     assert_eq!(FileType::from_raw_mode(stat.st_mode), FileType::Directory);
     unlinkat(&dir, "foo", AtFlags::REMOVEDIR).unwrap();
 }
+
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+#[test]
+fn test_symlinkat() {
+    use rustix::fs::{cwd, mkdirat, openat, readlinkat, statat, symlinkat, AtFlags, Mode, OFlags};
+    use rustix::io;
+
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(cwd(), tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+    mkdirat(&dir, "sub", Mode::RWXU).unwrap();
+
+    // The target is stored verbatim, and needn't exist.
+    symlinkat("../nonexistent//./x", &dir, "sub/link").unwrap();
+    assert_eq!(
+        readlinkat(&dir, "sub/link", Vec::new()).unwrap().to_bytes(),
+        b"../nonexistent//./x"
+    );
+    assert_eq!(
+        statat(&dir, "sub/link", AtFlags::empty()).unwrap_err(),
+        io::Errno::NOENT
+    );
+
+    // A relative target is resolved relative to the symlink's directory.
+    mkdirat(&dir, "target", Mode::RWXU).unwrap();
+    symlinkat("../target", &dir, "sub/dirlink").unwrap();
+    statat(&dir, "sub/dirlink", AtFlags::empty()).unwrap();
+
+    assert_eq!(symlinkat("target", &dir, "sub/link"), Err(io::Errno::EXIST));
+}
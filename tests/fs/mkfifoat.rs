@@ -0,0 +1,34 @@
+#[cfg(not(any(
+    target_os = "ios",
+    target_os = "macos",
+    target_os = "redox",
+    target_os = "wasi",
+)))]
+#[test]
+fn test_mkfifoat() {
+    use rustix::fs::{cwd, mkfifoat, openat, statat, unlinkat, AtFlags, FileType, Mode, OFlags};
+    use rustix::io::{read, write};
+
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(cwd(), tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+
+    mkfifoat(&dir, "fifo", Mode::RUSR | Mode::WUSR).unwrap();
+    let stat = statat(&dir, "fifo", AtFlags::empty()).unwrap();
+    assert_eq!(FileType::from_raw_mode(stat.st_mode), FileType::Fifo);
+
+    // Open the read end non-blocking so that it doesn't wait for a writer.
+    let reader = openat(
+        &dir,
+        "fifo",
+        OFlags::RDONLY | OFlags::NONBLOCK,
+        Mode::empty(),
+    )
+    .unwrap();
+    let writer = openat(&dir, "fifo", OFlags::WRONLY, Mode::empty()).unwrap();
+    assert_eq!(write(&writer, b"hello").unwrap(), 5);
+    let mut buf = [0_u8; 8];
+    assert_eq!(read(&reader, &mut buf).unwrap(), 5);
+    assert_eq!(&buf[..5], b"hello");
+
+    unlinkat(&dir, "fifo", AtFlags::empty()).unwrap();
+}
@@ -24,4 +24,10 @@ fn test_mknodat() {
     let stat = statat(&dir, "foo", AtFlags::empty()).unwrap();
     assert_eq!(FileType::from_raw_mode(stat.st_mode), FileType::Fifo);
     unlinkat(&dir, "foo", AtFlags::empty()).unwrap();
+
+    // Like FIFOs, socket nodes don't require any special privileges.
+    mknodat(&dir, "foo", FileType::Socket, Mode::empty(), 0).unwrap();
+    let stat = statat(&dir, "foo", AtFlags::empty()).unwrap();
+    assert_eq!(FileType::from_raw_mode(stat.st_mode), FileType::Socket);
+    unlinkat(&dir, "foo", AtFlags::empty()).unwrap();
 }
@@ -0,0 +1,89 @@
+use rustix::fs::{
+    cwd, fspick, fstat, mount_setattr, open_tree, statat, AtFlags, FspickFlags, MountAttr,
+    MountAttrFlags, MountPropagationFlags, OpenTreeFlags,
+};
+use rustix::io;
+
+/// Call `mount_setattr` on `/`, returning `None` if it isn't supported.
+fn setattr(flags: AtFlags, attr: &MountAttr<'_>) -> Option<io::Result<()>> {
+    match mount_setattr(cwd(), "/", flags, attr) {
+        // `mount_setattr` was added in Linux 5.12, and may be disabled by
+        // seccomp.
+        Err(io::Errno::NOSYS) => None,
+        result => Some(result),
+    }
+}
+
+#[test]
+fn test_mount_setattr_multiple_propagations() {
+    // At most one propagation type may be set.
+    let attr = MountAttr {
+        propagation: MountPropagationFlags::PRIVATE | MountPropagationFlags::SHARED,
+        ..MountAttr::default()
+    };
+    if let Some(result) = setattr(AtFlags::RECURSIVE, &attr) {
+        assert_eq!(result, Err(io::Errno::INVAL));
+    }
+}
+
+#[test]
+fn test_mount_setattr_invalid_flags() {
+    if let Some(result) = setattr(AtFlags::REMOVEDIR, &MountAttr::default()) {
+        assert_eq!(result, Err(io::Errno::INVAL));
+    }
+}
+
+#[test]
+fn test_mount_setattr_idmap_without_userns() {
+    // `MOUNT_ATTR_IDMAP` requires a user namespace file descriptor.
+    let attr = MountAttr {
+        attr_set: MountAttrFlags::IDMAP,
+        ..MountAttr::default()
+    };
+    if let Some(result) = setattr(AtFlags::empty(), &attr) {
+        assert_eq!(result, Err(io::Errno::INVAL));
+    }
+}
+
+#[test]
+fn test_open_tree() {
+    let tree = match open_tree(cwd(), "/", OpenTreeFlags::CLOEXEC) {
+        Ok(tree) => tree,
+        // `open_tree` was added in Linux 5.2, and may be disabled by seccomp.
+        Err(io::Errno::NOSYS) => return,
+        Err(err) => panic!("open_tree failed: {:?}", err),
+    };
+    let root = statat(cwd(), "/", AtFlags::empty()).unwrap();
+    let stat = fstat(&tree).unwrap();
+    assert_eq!((stat.st_dev, stat.st_ino), (root.st_dev, root.st_ino));
+}
+
+#[test]
+fn test_open_tree_clone() {
+    let tmp = tempfile::tempdir().unwrap();
+    let flags = OpenTreeFlags::CLONE | OpenTreeFlags::CLOEXEC | OpenTreeFlags::RECURSIVE;
+    match open_tree(cwd(), tmp.path(), flags) {
+        // Closing the detached mount unmounts it.
+        Ok(tree) => drop(tree),
+        // Cloning requires `CAP_SYS_ADMIN`.
+        Err(io::Errno::NOSYS) | Err(io::Errno::PERM) => {}
+        Err(err) => panic!("open_tree failed: {:?}", err),
+    }
+}
+
+#[test]
+fn test_fspick() {
+    match fspick(cwd(), "/", FspickFlags::CLOEXEC) {
+        Ok(context) => drop(context),
+        // `fspick` was added in Linux 5.2, and requires `CAP_SYS_ADMIN`.
+        Err(io::Errno::NOSYS) | Err(io::Errno::PERM) => return,
+        Err(err) => panic!("fspick failed: {:?}", err),
+    }
+
+    // A directory which isn't the root of a mount can't be picked.
+    let tmp = tempfile::tempdir().unwrap();
+    assert_eq!(
+        fspick(cwd(), tmp.path(), FspickFlags::CLOEXEC).map(|_| ()),
+        Err(io::Errno::INVAL)
+    );
+}
@@ -0,0 +1,59 @@
+use rustix::fs::{
+    openat, readlinkat_empty, statat, statat_empty, symlinkat, AtFlags, FileType, Mode, OFlags,
+};
+use rustix::io;
+
+#[test]
+fn test_o_path_symlink() {
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(
+        rustix::fs::cwd(),
+        tmp.path(),
+        OFlags::RDONLY | OFlags::CLOEXEC,
+        Mode::empty(),
+    )
+    .unwrap();
+    symlinkat("target", &dir, "link").unwrap();
+
+    let link = openat(
+        &dir,
+        "link",
+        OFlags::PATH | OFlags::NOFOLLOW | OFlags::CLOEXEC,
+        Mode::empty(),
+    )
+    .unwrap();
+
+    let stat = statat_empty(&link).unwrap();
+    assert_eq!(
+        FileType::from_raw_mode(stat.st_mode as _),
+        FileType::Symlink
+    );
+    let expected = statat(&dir, "link", AtFlags::SYMLINK_NOFOLLOW).unwrap();
+    assert_eq!(stat.st_ino, expected.st_ino);
+
+    assert_eq!(
+        readlinkat_empty(&link, Vec::new()).unwrap().to_bytes(),
+        b"target"
+    );
+
+    // `O_PATH` handles can't be read from.
+    let mut buf = [0_u8; 1];
+    assert_eq!(io::read(&link, &mut buf), Err(io::Errno::BADF));
+}
+
+#[test]
+fn test_readlinkat_empty_not_symlink() {
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(
+        rustix::fs::cwd(),
+        tmp.path(),
+        OFlags::PATH | OFlags::CLOEXEC,
+        Mode::empty(),
+    )
+    .unwrap();
+    assert_eq!(
+        FileType::from_raw_mode(statat_empty(&dir).unwrap().st_mode as _),
+        FileType::Directory
+    );
+    assert_eq!(readlinkat_empty(&dir, Vec::new()), Err(io::Errno::NOENT));
+}
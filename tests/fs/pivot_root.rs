@@ -0,0 +1,19 @@
+#[test]
+fn test_pivot_root_not_mount_point() {
+    let tmp = tempfile::tempdir().unwrap();
+    let new_root = tmp.path().join("new_root");
+    let put_old = new_root.join("put_old");
+    std::fs::create_dir_all(&put_old).unwrap();
+
+    // `new_root` isn't a mount point, so this must fail. Depending on our
+    // capabilities and on where the temporary directory lives, the kernel
+    // reports `PERM`, `BUSY`, or `INVAL`.
+    let err = rustix::fs::pivot_root(&new_root, &put_old).unwrap_err();
+    assert!(
+        err == rustix::io::Errno::PERM
+            || err == rustix::io::Errno::BUSY
+            || err == rustix::io::Errno::INVAL,
+        "{:?}",
+        err
+    );
+}
@@ -0,0 +1,23 @@
+#[test]
+fn test_readahead() {
+    use rustix::fs::{cwd, openat, readahead, Mode, OFlags};
+    use rustix::io::{pipe, write};
+
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(cwd(), tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+    let file = openat(
+        &dir,
+        "file",
+        OFlags::RDWR | OFlags::CREATE | OFlags::TRUNC,
+        Mode::RUSR | Mode::WUSR,
+    )
+    .unwrap();
+    write(&file, &[0_u8; 8192]).unwrap();
+
+    readahead(&file, 0, 8192).unwrap();
+    readahead(&file, 4096, 4096).unwrap();
+
+    // Pipes don't support readahead.
+    let (reader, _writer) = pipe().unwrap();
+    assert_eq!(readahead(&reader, 0, 4096), Err(rustix::io::Errno::INVAL));
+}
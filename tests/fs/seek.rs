@@ -0,0 +1,81 @@
+#[test]
+fn test_seek() {
+    use rustix::fs::{cwd, openat, seek, tell, Mode, OFlags, SeekFrom};
+    use rustix::io::write;
+
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(cwd(), tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+    let file = openat(
+        &dir,
+        "file",
+        OFlags::RDWR | OFlags::CREATE | OFlags::TRUNC,
+        Mode::RUSR | Mode::WUSR,
+    )
+    .unwrap();
+
+    write(&file, b"hello, world").unwrap();
+    assert_eq!(tell(&file).unwrap(), 12);
+    assert_eq!(seek(&file, SeekFrom::Start(5)).unwrap(), 5);
+    assert_eq!(seek(&file, SeekFrom::Current(2)).unwrap(), 7);
+    assert_eq!(seek(&file, SeekFrom::Current(-7)).unwrap(), 0);
+    assert_eq!(seek(&file, SeekFrom::End(-5)).unwrap(), 7);
+    assert_eq!(
+        seek(&file, SeekFrom::Current(-8)),
+        Err(rustix::io::Errno::INVAL)
+    );
+
+    // `std::io::SeekFrom` values can be converted.
+    assert_eq!(seek(&file, std::io::SeekFrom::Start(1).into()).unwrap(), 1);
+}
+
+#[cfg(any(
+    target_os = "android",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "illumos",
+    target_os = "linux",
+    target_os = "solaris",
+))]
+#[test]
+fn test_seek_data_hole() {
+    use rustix::fs::{cwd, ftruncate, openat, seek, Mode, OFlags, SeekFrom};
+    use rustix::io::write;
+
+    const SIZE: u64 = 16 * 1024 * 1024;
+
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(cwd(), tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+    let file = openat(
+        &dir,
+        "file",
+        OFlags::RDWR | OFlags::CREATE | OFlags::TRUNC,
+        Mode::RUSR | Mode::WUSR,
+    )
+    .unwrap();
+
+    // Create a file with some data at the start, followed by a hole.
+    write(&file, &[1_u8; 4096]).unwrap();
+    ftruncate(&file, SIZE).unwrap();
+
+    assert_eq!(seek(&file, SeekFrom::Data(0)).unwrap(), 0);
+
+    // Filesystems that don't support holes report the whole file as data,
+    // with an implicit hole at the end.
+    let hole = seek(&file, SeekFrom::Hole(0)).unwrap();
+    assert!((4096..=SIZE).contains(&hole), "{}", hole);
+    if hole < SIZE {
+        assert_eq!(
+            seek(&file, SeekFrom::Data(hole)),
+            Err(rustix::io::Errno::NXIO)
+        );
+    }
+
+    assert_eq!(
+        seek(&file, SeekFrom::Data(SIZE)),
+        Err(rustix::io::Errno::NXIO)
+    );
+    assert_eq!(
+        seek(&file, SeekFrom::Hole(SIZE)),
+        Err(rustix::io::Errno::NXIO)
+    );
+}
@@ -0,0 +1,27 @@
+use rustix::fs::SwapFlags;
+
+#[test]
+fn test_swap_flags() {
+    let flags = SwapFlags::prefer(5) | SwapFlags::DISCARD;
+    assert_eq!(flags.bits(), 0x1_8005);
+    assert_eq!(flags.priority(), Some(5));
+    assert_eq!(SwapFlags::prefer(0x7fff).priority(), Some(0x7fff));
+    assert_eq!(SwapFlags::prefer(0xffff).priority(), Some(0x7fff));
+    assert_eq!(SwapFlags::DISCARD.priority(), None);
+}
+
+#[test]
+fn test_swapoff_not_swap() {
+    let tmp = tempfile::tempdir().unwrap();
+    let path = tmp.path().join("file");
+    std::fs::write(&path, b"not a swap file").unwrap();
+
+    // Depending on our capabilities, the kernel reports `PERM`, or `INVAL`
+    // because the file isn't in use for swap.
+    let err = rustix::fs::swapoff(&path).unwrap_err();
+    assert!(
+        err == rustix::io::Errno::PERM || err == rustix::io::Errno::INVAL,
+        "{:?}",
+        err
+    );
+}
@@ -0,0 +1,68 @@
+use rustix::fs::{cwd, fstat, ftruncate, openat, statat, truncate, AtFlags, Mode, OFlags};
+use rustix::io;
+
+#[test]
+fn test_truncate() {
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(cwd(), tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+    let _ = openat(
+        &dir,
+        "file",
+        OFlags::CREATE | OFlags::WRONLY | OFlags::CLOEXEC,
+        Mode::RUSR | Mode::WUSR,
+    )
+    .unwrap();
+    let path = tmp.path().join("file");
+
+    truncate(&path, 4096).unwrap();
+    assert_eq!(
+        statat(&dir, "file", AtFlags::empty()).unwrap().st_size,
+        4096
+    );
+
+    // Use a length which doesn't fit in 32 bits, to test that both halves
+    // are passed correctly on 32-bit platforms.
+    match truncate(&path, 0x1_0000_1000) {
+        Ok(()) => assert_eq!(
+            statat(&dir, "file", AtFlags::empty()).unwrap().st_size,
+            0x1_0000_1000
+        ),
+        // The filesystem may not support files this large.
+        Err(io::Errno::FBIG) | Err(io::Errno::INVAL) => {}
+        Err(err) => panic!("truncate failed: {:?}", err),
+    }
+
+    truncate(&path, 0).unwrap();
+    assert_eq!(statat(&dir, "file", AtFlags::empty()).unwrap().st_size, 0);
+
+    assert_eq!(
+        truncate(tmp.path().join("nonexistent"), 0),
+        Err(io::Errno::NOENT)
+    );
+}
+
+#[test]
+fn test_ftruncate() {
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(cwd(), tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+    let file = openat(
+        &dir,
+        "file",
+        OFlags::CREATE | OFlags::RDWR | OFlags::CLOEXEC,
+        Mode::RUSR | Mode::WUSR,
+    )
+    .unwrap();
+
+    ftruncate(&file, 100).unwrap();
+    assert_eq!(fstat(&file).unwrap().st_size, 100);
+
+    // A file opened read-only can't be truncated.
+    let read_only = openat(
+        &dir,
+        "file",
+        OFlags::RDONLY | OFlags::CLOEXEC,
+        Mode::empty(),
+    )
+    .unwrap();
+    assert_eq!(ftruncate(&read_only, 0), Err(io::Errno::INVAL));
+}
@@ -0,0 +1,49 @@
+use rustix::io::{pipe, Batch, Errno};
+
+#[test]
+fn test_batch() {
+    let (reader, writer) = pipe().unwrap();
+    let file = tempfile::tempfile().unwrap();
+
+    let mut buf = [0_u8; 16];
+    let mut batch = Batch::new();
+    assert!(batch.is_empty());
+    batch
+        .write(&writer, b"hello")
+        .fsync(&file)
+        .write(&writer, b", world")
+        .read(&reader, &mut buf);
+    assert_eq!(batch.len(), 4);
+
+    let results = batch.submit_and_wait();
+    assert_eq!(results, vec![Ok(5), Ok(0), Ok(7), Ok(12)]);
+    assert_eq!(&buf[..12], b"hello, world");
+}
+
+#[test]
+fn test_batch_independent_errors() {
+    let (reader, writer) = pipe().unwrap();
+
+    // Pipes don't support `fsync`, and the read end can't be written to,
+    // but the operations after those still run.
+    let mut buf = [0_u8; 16];
+    let mut batch = Batch::with_capacity(4);
+    batch
+        .fsync(&writer)
+        .write(&reader, b"x")
+        .write(&writer, b"y")
+        .read(&reader, &mut buf);
+
+    let results = batch.submit_and_wait();
+    assert_eq!(results.len(), 4);
+    assert_eq!(results[0], Err(Errno::INVAL));
+    assert_eq!(results[1], Err(Errno::BADF));
+    assert_eq!(results[2], Ok(1));
+    assert_eq!(results[3], Ok(1));
+    assert_eq!(buf[0], b'y');
+}
+
+#[test]
+fn test_batch_empty() {
+    assert!(Batch::default().submit_and_wait().is_empty());
+}
@@ -0,0 +1,27 @@
+#[test]
+fn test_fcntl_dupfd_cloexec() {
+    use rustix::fd::AsRawFd;
+    use rustix::io::{dup, fcntl_dupfd_cloexec, pipe};
+
+    let (reader, _writer) = pipe().unwrap();
+
+    let new = fcntl_dupfd_cloexec(&reader, 100).unwrap();
+    assert!(new.as_raw_fd() >= 100);
+
+    // A second duplicate gets a different fd, still at or above the minimum.
+    let another = fcntl_dupfd_cloexec(&reader, 100).unwrap();
+    assert!(another.as_raw_fd() > 100);
+    assert_ne!(another.as_raw_fd(), new.as_raw_fd());
+
+    // `dup` picks the lowest free fd, which is below the minimum here.
+    let plain = dup(&reader).unwrap();
+    assert!(plain.as_raw_fd() < 100);
+
+    #[cfg(feature = "fs")]
+    {
+        use rustix::fs::{fcntl_getfd, FdFlags};
+
+        assert!(fcntl_getfd(&new).unwrap().contains(FdFlags::CLOEXEC));
+        assert!(!fcntl_getfd(&plain).unwrap().contains(FdFlags::CLOEXEC));
+    }
+}
@@ -1,8 +1,8 @@
 #![cfg(any(target_os = "android", target_os = "linux"))]
 
-use rustix::fd::AsFd;
+use rustix::fd::{AsFd, IntoFd};
 use rustix::io::epoll::{self, Epoll};
-use rustix::io::{ioctl_fionbio, read, write, OwnedFd};
+use rustix::io::{ioctl_fionbio, pipe, read, write, OwnedFd};
 use rustix::net::{
     accept, bind_v4, connect_v4, getsockname, listen, socket, AddressFamily, Ipv4Addr, Protocol,
     SocketAddrAny, SocketAddrV4, SocketType,
@@ -101,3 +101,112 @@ fn test_epoll() {
         .unwrap();
     client.join().unwrap();
 }
+
+#[test]
+fn test_epoll_add_many() {
+    let epoll = Epoll::new(epoll::CreateFlags::CLOEXEC, epoll::Owning::<OwnedFd>::new()).unwrap();
+
+    let mut writers = Vec::new();
+    let mut readers = Vec::new();
+    for _ in 0..100 {
+        let (reader, writer) = pipe().unwrap();
+        readers.push((reader, epoll::EventFlags::IN));
+        writers.push(writer);
+    }
+    epoll.add_many(readers).unwrap();
+
+    for writer in &writers {
+        write(writer, b"x").unwrap();
+    }
+
+    let mut event_list = epoll::EventVec::with_capacity(128);
+    epoll.wait(&mut event_list, 0).unwrap();
+    assert_eq!(event_list.len(), 100);
+}
+
+#[test]
+fn test_epoll_add_many_rollback() {
+    let epoll = Epoll::new(epoll::CreateFlags::CLOEXEC, epoll::Owning::<OwnedFd>::new()).unwrap();
+
+    let (reader0, writer0) = pipe().unwrap();
+    let (reader1, writer1) = pipe().unwrap();
+
+    // Regular files don't support epoll, so adding one fails with `EPERM`.
+    let file = OwnedFd::from(tempfile::tempfile().unwrap().into_fd());
+
+    let err = epoll
+        .add_many(vec![
+            (reader0, epoll::EventFlags::IN),
+            (reader1, epoll::EventFlags::IN),
+            (file, epoll::EventFlags::IN),
+        ])
+        .unwrap_err();
+    assert_eq!(err, (2, rustix::io::Errno::PERM));
+
+    // The pipes added before the failure should have been removed.
+    write(&writer0, b"x").unwrap_err();
+    write(&writer1, b"x").unwrap_err();
+    let mut event_list = epoll::EventVec::with_capacity(4);
+    epoll.wait(&mut event_list, 0).unwrap();
+    assert!(event_list.is_empty());
+}
+
+#[cfg(feature = "time")]
+#[test]
+fn test_epoll_wait_timeout() {
+    use rustix::time::Timespec;
+    use std::time::{Duration, Instant};
+
+    let epoll = Epoll::new(epoll::CreateFlags::CLOEXEC, epoll::Owning::<OwnedFd>::new()).unwrap();
+    let mut event_list = epoll::EventVec::with_capacity(4);
+
+    let timeout = Timespec {
+        tv_sec: 0,
+        tv_nsec: 50_000_000,
+    };
+    let start = Instant::now();
+    let nfds = epoll
+        .wait_timeout(&mut event_list, Some(&timeout), None)
+        .unwrap();
+    let elapsed = start.elapsed();
+    assert_eq!(nfds, 0);
+    assert!(event_list.is_empty());
+    assert!(elapsed >= Duration::from_millis(50), "{:?}", elapsed);
+    assert!(elapsed < Duration::from_secs(5), "{:?}", elapsed);
+}
+
+#[cfg(all(feature = "process", feature = "time"))]
+#[test]
+fn test_epoll_wait_timeout_ready_sigmask() {
+    use rustix::process::{SigSet, Signal};
+    use rustix::time::Timespec;
+
+    let epoll = Epoll::new(epoll::CreateFlags::CLOEXEC, epoll::Owning::<OwnedFd>::new()).unwrap();
+    let (reader, writer) = pipe().unwrap();
+    let raw_reader = reader.as_raw_fd();
+    epoll.add(reader, epoll::EventFlags::IN).unwrap();
+    write(&writer, b"x").unwrap();
+
+    let mut sigmask = SigSet::new();
+    sigmask.add(Signal::Usr1);
+
+    // A ready event is returned without waiting for the timeout.
+    let mut event_list = epoll::EventVec::with_capacity(4);
+    let timeout = Timespec {
+        tv_sec: 60,
+        tv_nsec: 0,
+    };
+    let nfds = epoll
+        .wait_timeout(&mut event_list, Some(&timeout), Some(&sigmask))
+        .unwrap();
+    assert_eq!(nfds, 1);
+    let (event_flags, target) = (&event_list).into_iter().next().unwrap();
+    assert!(event_flags.contains(epoll::EventFlags::IN));
+    assert_eq!(target.as_raw_fd(), raw_reader);
+
+    // With no timeout, a ready event is also returned immediately.
+    let nfds = epoll
+        .wait_timeout(&mut event_list, None, Some(&sigmask))
+        .unwrap();
+    assert_eq!(nfds, 1);
+}
@@ -12,3 +12,32 @@ fn test_error() {
         windows_sys::Win32::Networking::WinSock::WSAEINVAL
     );
 }
+
+#[test]
+fn test_try_from_raw_os_error() {
+    use rustix::io::Errno;
+
+    assert_eq!(
+        Errno::try_from_raw_os_error(Errno::INVAL.raw_os_error()),
+        Some(Errno::INVAL)
+    );
+    assert_eq!(Errno::try_from_raw_os_error(0), None);
+    assert_eq!(Errno::try_from_raw_os_error(-1), None);
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    assert_eq!(Errno::try_from_raw_os_error(4096), None);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_io_error() {
+    use rustix::io::Errno;
+
+    let io_err = Errno::NOENT.io_error();
+    assert_eq!(io_err.raw_os_error(), Some(Errno::NOENT.raw_os_error()));
+    assert_eq!(io_err.kind(), std::io::ErrorKind::NotFound);
+    assert_eq!(Errno::from_io_error(&io_err), Some(Errno::NOENT));
+
+    // Errors which don't come from the OS have no `Errno`.
+    let io_err = std::io::Error::from(std::io::ErrorKind::Other);
+    assert_eq!(Errno::from_io_error(&io_err), None);
+}
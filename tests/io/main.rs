@@ -3,6 +3,12 @@
 #![cfg_attr(target_os = "wasi", feature(wasi_ext))]
 #![cfg_attr(io_lifetimes_use_std, feature(io_safety))]
 
+#[cfg(not(windows))]
+#[cfg(not(target_os = "wasi"))]
+mod batch;
+#[cfg(not(windows))]
+#[cfg(not(target_os = "wasi"))]
+mod dup;
 #[cfg(not(feature = "rustc-dep-of-std"))]
 #[cfg(not(windows))]
 #[cfg(not(target_os = "wasi"))]
@@ -21,6 +27,12 @@ mod error;
 mod eventfd;
 #[cfg(not(windows))]
 mod from_into;
+#[cfg(not(windows))]
+#[cfg(not(target_os = "wasi"))]
+mod nonblocking;
+#[cfg(all(feature = "process", feature = "thread"))]
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod owner;
 mod poll;
 #[cfg(all(feature = "procfs", any(target_os = "android", target_os = "linux")))]
 mod procfs;
@@ -30,3 +42,7 @@ mod procfs;
 mod read_write;
 #[cfg(any(target_os = "linux", target_os = "freebsd", target_os = "android"))]
 mod seals;
+#[cfg(not(windows))]
+#[cfg(not(target_os = "redox"))]
+#[cfg(not(target_os = "wasi"))]
+mod select;
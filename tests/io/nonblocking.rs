@@ -0,0 +1,50 @@
+#[test]
+fn test_set_nonblocking() {
+    use rustix::io::{pipe, read, set_nonblocking};
+
+    let (reader, _writer) = pipe().unwrap();
+
+    set_nonblocking(&reader, true).unwrap();
+    // Setting it again is a no-op.
+    set_nonblocking(&reader, true).unwrap();
+    let mut buf = [0_u8; 1];
+    assert_eq!(read(&reader, &mut buf), Err(rustix::io::Errno::AGAIN));
+
+    set_nonblocking(&reader, false).unwrap();
+
+    #[cfg(feature = "fs")]
+    {
+        use rustix::fs::OFlags;
+        use rustix::io::fcntl_getfl;
+
+        assert!(!fcntl_getfl(&reader).unwrap().contains(OFlags::NONBLOCK));
+    }
+}
+
+#[cfg(feature = "fs")]
+#[test]
+fn test_set_nonblocking_preserves_flags() {
+    use rustix::fs::{cwd, openat, Mode, OFlags};
+    use rustix::io::{fcntl_getfl, fcntl_setfl, set_nonblocking};
+
+    let tmp = tempfile::tempdir().unwrap();
+    let file = openat(
+        cwd(),
+        tmp.path().join("file"),
+        OFlags::WRONLY | OFlags::CREATE | OFlags::APPEND,
+        Mode::RUSR | Mode::WUSR,
+    )
+    .unwrap();
+
+    set_nonblocking(&file, true).unwrap();
+    let flags = fcntl_getfl(&file).unwrap();
+    assert!(flags.contains(OFlags::APPEND | OFlags::NONBLOCK));
+
+    set_nonblocking(&file, false).unwrap();
+    let flags = fcntl_getfl(&file).unwrap();
+    assert!(flags.contains(OFlags::APPEND));
+    assert!(!flags.contains(OFlags::NONBLOCK));
+
+    fcntl_setfl(&file, flags - OFlags::APPEND).unwrap();
+    assert!(!fcntl_getfl(&file).unwrap().contains(OFlags::APPEND));
+}
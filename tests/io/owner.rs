@@ -0,0 +1,34 @@
+use rustix::io::{fcntl_getown, fcntl_setown, fcntl_setsig, pipe, Owner};
+use rustix::process::{getpgid, getpid, Signal};
+use rustix::thread::gettid;
+
+#[test]
+fn test_fcntl_owner() {
+    let (reader, _writer) = pipe().unwrap();
+
+    // A new file descriptor has no owner.
+    assert_eq!(fcntl_getown(&reader).unwrap(), None);
+
+    let pid = getpid();
+    fcntl_setown(&reader, Owner::Pid(pid)).unwrap();
+    assert_eq!(fcntl_getown(&reader).unwrap(), Some(Owner::Pid(pid)));
+
+    let tid = gettid();
+    fcntl_setown(&reader, Owner::Thread(tid)).unwrap();
+    assert_eq!(fcntl_getown(&reader).unwrap(), Some(Owner::Thread(tid)));
+
+    let pgid = getpgid(None).unwrap().unwrap();
+    fcntl_setown(&reader, Owner::ProcessGroup(pgid)).unwrap();
+    assert_eq!(
+        fcntl_getown(&reader).unwrap(),
+        Some(Owner::ProcessGroup(pgid))
+    );
+}
+
+#[test]
+fn test_fcntl_setsig() {
+    let (reader, _writer) = pipe().unwrap();
+
+    fcntl_setsig(&reader, Some(Signal::Usr1)).unwrap();
+    fcntl_setsig(&reader, None).unwrap();
+}
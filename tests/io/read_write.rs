@@ -64,11 +64,49 @@ fn test_readwrite_p() {
     assert_eq!(&buf, b"world");
 }
 
+#[cfg(feature = "fs")]
+#[test]
+fn test_readwrite_p_offsets() {
+    use rustix::fs::{cwd, openat, seek, Mode, OFlags, SeekFrom};
+    use rustix::io::{pread, pwrite};
+
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(cwd(), tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+    let foo = openat(
+        &dir,
+        "foo",
+        OFlags::RDWR | OFlags::CREATE | OFlags::TRUNC,
+        Mode::RUSR | Mode::WUSR,
+    )
+    .unwrap();
+
+    // Use an offset which doesn't fit in 32 bits, to test that both halves
+    // are passed correctly on 32-bit platforms.
+    let offset = 0x1_0000_0100;
+    assert_eq!(pwrite(&foo, b"hello", offset).unwrap(), 5);
+
+    // A read past the end is short.
+    let mut buf = [0_u8; 8];
+    assert_eq!(pread(&foo, &mut buf, offset).unwrap(), 5);
+    assert_eq!(&buf[..5], b"hello");
+    assert_eq!(pread(&foo, &mut buf, offset + 5).unwrap(), 0);
+
+    // Nothing was written at the low half of the offset alone.
+    assert_eq!(pread(&foo, &mut buf[..5], 0x100).unwrap(), 5);
+    assert_eq!(&buf[..5], &[0; 5]);
+    assert_eq!(pwrite(&foo, b"world", 0x100).unwrap(), 5);
+    assert_eq!(pread(&foo, &mut buf[..5], 0x100).unwrap(), 5);
+    assert_eq!(&buf[..5], b"world");
+
+    // Positional I/O doesn't use or update the file offset.
+    assert_eq!(seek(&foo, SeekFrom::Current(0)).unwrap(), 0);
+}
+
 #[cfg(feature = "fs")]
 #[test]
 fn test_readwrite_v() {
-    use rustix::fs::{cwd, openat, seek, Mode, OFlags};
-    use rustix::io::{readv, writev, SeekFrom};
+    use rustix::fs::{cwd, openat, seek, Mode, OFlags, SeekFrom};
+    use rustix::io::{readv, writev};
 
     let tmp = tempfile::tempdir().unwrap();
     let dir = openat(cwd(), tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
@@ -90,12 +128,66 @@ fn test_readwrite_v() {
     assert_eq!(&buf, b"world");
 }
 
+#[cfg(feature = "fs")]
+#[test]
+fn test_readwrite_v_multiple() {
+    use rustix::fs::{cwd, openat, seek, Mode, OFlags, SeekFrom};
+    use rustix::io::{readv, writev};
+
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(cwd(), tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+    let foo = openat(
+        &dir,
+        "foo",
+        OFlags::RDWR | OFlags::CREATE | OFlags::TRUNC,
+        Mode::RUSR | Mode::WUSR,
+    )
+    .unwrap();
+
+    let nwritten = writev(&foo, &[IoSlice::new(b"hello"), IoSlice::new(b" world")]).unwrap();
+    assert_eq!(nwritten, 11);
+    seek(&foo, SeekFrom::Start(0)).unwrap();
+    let mut hello = [0_u8; 5];
+    let mut world = [0_u8; 6];
+    let nread = readv(
+        &foo,
+        &mut [IoSliceMut::new(&mut hello), IoSliceMut::new(&mut world)],
+    )
+    .unwrap();
+    assert_eq!(nread, 11);
+    assert_eq!(&hello, b"hello");
+    assert_eq!(&world, b" world");
+}
+
+/// More than `IOV_MAX` buffers is an error, rather than a short write.
+#[cfg(feature = "fs")]
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+fn test_writev_iov_max() {
+    use rustix::fs::{cwd, openat, Mode, OFlags};
+    use rustix::io::writev;
+
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(cwd(), tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+    let foo = openat(
+        &dir,
+        "foo",
+        OFlags::WRONLY | OFlags::CREATE | OFlags::TRUNC,
+        Mode::RUSR | Mode::WUSR,
+    )
+    .unwrap();
+
+    let iov_max = libc::UIO_MAXIOV as usize;
+    let bufs = vec![IoSlice::new(b"x"); iov_max + 1];
+    assert_eq!(writev(&foo, &bufs), Err(rustix::io::Errno::INVAL));
+    assert_eq!(writev(&foo, &bufs[..iov_max]), Ok(iov_max));
+}
+
 #[cfg(feature = "fs")]
 #[test]
 fn test_readwrite() {
-    use rustix::fs::{cwd, openat, seek, Mode, OFlags};
+    use rustix::fs::{cwd, openat, seek, Mode, OFlags, SeekFrom};
     use rustix::io::{read, write};
-    use std::io::SeekFrom;
 
     let tmp = tempfile::tempdir().unwrap();
     let dir = openat(cwd(), tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
@@ -116,3 +208,34 @@ fn test_readwrite() {
     read(&foo, &mut buf).unwrap();
     assert_eq!(&buf, b"world");
 }
+
+#[cfg(feature = "fs")]
+#[test]
+fn test_read_uninit() {
+    use rustix::fs::{cwd, openat, seek, Mode, OFlags, SeekFrom};
+    use rustix::io::{read_uninit, write};
+    use std::mem::MaybeUninit;
+
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(cwd(), tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+    let foo = openat(
+        &dir,
+        "foo",
+        OFlags::RDWR | OFlags::CREATE | OFlags::TRUNC,
+        Mode::RUSR | Mode::WUSR,
+    )
+    .unwrap();
+
+    write(&foo, b"hello").unwrap();
+    seek(&foo, SeekFrom::Start(0)).unwrap();
+
+    let mut buf = [MaybeUninit::<u8>::uninit(); 8];
+    let (init, uninit) = read_uninit(&foo, &mut buf).unwrap();
+    assert_eq!(init, b"hello");
+    assert_eq!(uninit.len(), 3);
+
+    // At the end of the file, nothing is initialized.
+    let (init, uninit) = read_uninit(&foo, &mut buf).unwrap();
+    assert!(init.is_empty());
+    assert_eq!(uninit.len(), 8);
+}
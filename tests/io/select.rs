@@ -0,0 +1,123 @@
+#[cfg(feature = "process")]
+use rustix::io::pselect;
+use rustix::io::{pipe, select, FdSet, Timeval};
+#[cfg(feature = "process")]
+use rustix::process::{SigSet, Signal};
+
+#[test]
+fn test_fd_set() {
+    let (reader, writer) = pipe().unwrap();
+
+    let mut set = FdSet::new();
+    assert!(!set.contains(&reader));
+    set.insert(&reader);
+    set.insert(&writer);
+    assert!(set.contains(&reader));
+    assert!(set.contains(&writer));
+    set.remove(&writer);
+    assert!(set.contains(&reader));
+    assert!(!set.contains(&writer));
+    set.clear();
+    assert!(!set.contains(&reader));
+    assert_eq!(set, FdSet::default());
+}
+
+#[test]
+fn test_select_nfds_out_of_range() {
+    assert_eq!(
+        select(FdSet::SETSIZE + 1, None, None, None, None),
+        Err(rustix::io::Errno::INVAL)
+    );
+    #[cfg(feature = "process")]
+    assert_eq!(
+        pselect(FdSet::SETSIZE + 1, None, None, None, None, None),
+        Err(rustix::io::Errno::INVAL)
+    );
+}
+
+#[test]
+fn test_select() {
+    use rustix::fd::AsRawFd;
+    use rustix::io::write;
+
+    let (reader, writer) = pipe().unwrap();
+    let nfds = reader.as_raw_fd().max(writer.as_raw_fd()) as usize + 1;
+    let zero = Timeval {
+        tv_sec: 0,
+        tv_usec: 0,
+    };
+
+    // Nothing has been written, so the reader isn't ready.
+    let mut readfds = FdSet::new();
+    readfds.insert(&reader);
+    assert_eq!(
+        select(nfds, Some(&mut readfds), None, None, Some(&zero)).unwrap(),
+        0
+    );
+    assert!(!readfds.contains(&reader));
+
+    // The writer is ready.
+    let mut readfds = FdSet::new();
+    readfds.insert(&reader);
+    let mut writefds = FdSet::new();
+    writefds.insert(&writer);
+    assert_eq!(
+        select(
+            nfds,
+            Some(&mut readfds),
+            Some(&mut writefds),
+            None,
+            Some(&zero)
+        )
+        .unwrap(),
+        1
+    );
+    assert!(!readfds.contains(&reader));
+    assert!(writefds.contains(&writer));
+
+    // Once something has been written, the reader is ready.
+    write(&writer, b"x").unwrap();
+    let mut readfds = FdSet::new();
+    readfds.insert(&reader);
+    assert_eq!(
+        select(nfds, Some(&mut readfds), None, None, None).unwrap(),
+        1
+    );
+    assert!(readfds.contains(&reader));
+}
+
+#[cfg(feature = "process")]
+#[test]
+fn test_pselect_timeout() {
+    use rustix::fd::AsRawFd;
+    use rustix::io::Timespec;
+    use std::time::{Duration, Instant};
+
+    let (reader, _writer) = pipe().unwrap();
+    let nfds = reader.as_raw_fd() as usize + 1;
+
+    let mut sigmask = SigSet::new();
+    sigmask.add(Signal::Usr1);
+
+    let mut readfds = FdSet::new();
+    readfds.insert(&reader);
+    let timeout = Timespec {
+        tv_sec: 0,
+        tv_nsec: 50_000_000,
+    };
+    let start = Instant::now();
+    let nready = pselect(
+        nfds,
+        Some(&mut readfds),
+        None,
+        None,
+        Some(&timeout),
+        Some(&sigmask),
+    )
+    .unwrap();
+    let elapsed = start.elapsed();
+    assert_eq!(nready, 0);
+    assert!(!readfds.contains(&reader));
+    assert!(elapsed >= Duration::from_millis(50), "{:?}", elapsed);
+    assert!(elapsed < Duration::from_secs(5), "{:?}", elapsed);
+}
@@ -0,0 +1,157 @@
+//! Tests for [`rustix::io_uring`].
+
+#![cfg(feature = "io_uring")]
+#![cfg(feature = "mm")]
+#![cfg(any(target_os = "android", target_os = "linux"))]
+#![cfg_attr(io_lifetimes_use_std, feature(io_safety))]
+
+use rustix::io::{self, OwnedFd};
+use rustix::io_uring::{
+    io_uring_cqe, io_uring_enter, io_uring_params, io_uring_register, io_uring_register_probe,
+    io_uring_setup, io_uring_sqe, io_uring_user_data, supports_opcode, IoringEnterFlags, IoringOp,
+    IoringProbe, IoringRegisterOp, IORING_OFF_CQ_RING, IORING_OFF_SQES, IORING_OFF_SQ_RING,
+};
+use rustix::mm::{mmap, munmap, MapFlags, ProtFlags};
+use std::ffi::c_void;
+use std::mem::size_of;
+use std::ptr::null_mut;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+/// Create an io_uring instance, or return `None` if io_uring is disabled,
+/// such as by seccomp or the `kernel.io_uring_disabled` sysctl.
+fn setup(params: &mut io_uring_params) -> Option<OwnedFd> {
+    match io_uring_setup(4, params) {
+        Ok(fd) => Some(fd),
+        Err(io::Errno::NOSYS) | Err(io::Errno::PERM) => None,
+        Err(err) => panic!("io_uring_setup failed: {:?}", err),
+    }
+}
+
+#[test]
+fn test_io_uring_setup() {
+    let mut params = io_uring_params::default();
+    let _fd = match setup(&mut params) {
+        Some(fd) => fd,
+        None => return,
+    };
+    assert!(params.sq_entries >= 4);
+    assert!(params.cq_entries >= params.sq_entries);
+}
+
+#[test]
+fn test_io_uring_register_personality() {
+    let mut params = io_uring_params::default();
+    let fd = match setup(&mut params) {
+        Some(fd) => fd,
+        None => return,
+    };
+
+    // `IORING_REGISTER_PERSONALITY` returns the ID of the new personality.
+    let id = unsafe {
+        io_uring_register(&fd, IoringRegisterOp::RegisterPersonality, null_mut(), 0).unwrap()
+    };
+    assert_ne!(id, 0);
+    unsafe {
+        io_uring_register(&fd, IoringRegisterOp::UnregisterPersonality, null_mut(), id).unwrap()
+    };
+}
+
+#[test]
+fn test_io_uring_register_probe() {
+    let mut params = io_uring_params::default();
+    let fd = match setup(&mut params) {
+        Some(fd) => fd,
+        None => return,
+    };
+
+    let mut probe = IoringProbe::new();
+    match io_uring_register_probe(&fd, &mut probe) {
+        Ok(()) => {
+            // `IORING_OP_NOP` has been supported since io_uring was added.
+            assert!(supports_opcode(&probe, IoringOp::Nop));
+            assert!(probe.supports(IoringOp::Nop));
+            assert!(probe.last_op() >= IoringOp::Nop as u8);
+
+            // Probing again resets the probe first, which Linux requires.
+            io_uring_register_probe(&fd, &mut probe).unwrap();
+            assert!(probe.supports(IoringOp::Nop));
+        }
+        // `IORING_REGISTER_PROBE` was added in Linux 5.6.
+        Err(io::Errno::INVAL) => assert!(!probe.supports(IoringOp::Nop)),
+        Err(err) => panic!("io_uring_register_probe failed: {:?}", err),
+    }
+}
+
+#[test]
+fn test_io_uring_nop() {
+    let mut params = io_uring_params::default();
+    let fd = match setup(&mut params) {
+        Some(fd) => fd,
+        None => return,
+    };
+
+    let sq_len = params.sq_off.array as usize + params.sq_entries as usize * size_of::<u32>();
+    let cq_len =
+        params.cq_off.cqes as usize + params.cq_entries as usize * size_of::<io_uring_cqe>();
+    let sqes_len = params.sq_entries as usize * size_of::<io_uring_sqe>();
+    let map = |len, offset| unsafe {
+        mmap(
+            null_mut(),
+            len,
+            ProtFlags::READ | ProtFlags::WRITE,
+            MapFlags::SHARED | MapFlags::POPULATE,
+            &fd,
+            offset,
+        )
+        .unwrap()
+    };
+    let sq = map(sq_len, IORING_OFF_SQ_RING);
+    let cq = map(cq_len, IORING_OFF_CQ_RING);
+    let sqes = map(sqes_len, IORING_OFF_SQES);
+
+    unsafe {
+        let field = |ring: *mut c_void, offset: u32| {
+            &*ring.cast::<u8>().add(offset as usize).cast::<AtomicU32>()
+        };
+
+        // Submit a single `IORING_OP_NOP`.
+        let sq_tail = field(sq, params.sq_off.tail);
+        let sq_mask = field(sq, params.sq_off.ring_mask).load(Ordering::Relaxed);
+        let tail = sq_tail.load(Ordering::Relaxed);
+        let index = tail & sq_mask;
+        let sqe = io_uring_sqe {
+            opcode: IoringOp::Nop,
+            user_data: io_uring_user_data::from_u64(0x1234),
+            ..Default::default()
+        };
+        sqes.cast::<io_uring_sqe>().add(index as usize).write(sqe);
+        field(sq, params.sq_off.array + index * 4).store(index, Ordering::Relaxed);
+        sq_tail.store(tail.wrapping_add(1), Ordering::Release);
+
+        let submitted =
+            io_uring_enter(&fd, 1, 1, IoringEnterFlags::GETEVENTS, null_mut(), 0).unwrap();
+        assert_eq!(submitted, 1);
+
+        // Reap its completion.
+        let cq_head = field(cq, params.cq_off.head);
+        let cq_mask = field(cq, params.cq_off.ring_mask).load(Ordering::Relaxed);
+        let head = cq_head.load(Ordering::Relaxed);
+        assert_eq!(
+            field(cq, params.cq_off.tail).load(Ordering::Acquire),
+            head.wrapping_add(1)
+        );
+        let cqe = cq
+            .cast::<u8>()
+            .add(params.cq_off.cqes as usize)
+            .cast::<io_uring_cqe>()
+            .add((head & cq_mask) as usize)
+            .read();
+        assert_eq!(cqe.user_data.u64_(), 0x1234);
+        assert_eq!(cqe.res, 0);
+        cq_head.store(head.wrapping_add(1), Ordering::Release);
+
+        munmap(sqes, sqes_len).unwrap();
+        munmap(cq, cq_len).unwrap();
+        munmap(sq, sq_len).unwrap();
+    }
+}
@@ -4,10 +4,38 @@
 #![cfg_attr(target_os = "wasi", feature(wasi_ext))]
 #![cfg_attr(io_lifetimes_use_std, feature(io_safety))]
 
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod mempolicy;
+#[cfg(feature = "param")]
+#[cfg(any(
+    target_os = "android",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "ios",
+    target_os = "linux",
+    target_os = "macos",
+    target_os = "netbsd",
+    target_os = "openbsd",
+))]
+mod mincore;
 #[cfg(not(windows))]
 #[cfg(not(target_os = "wasi"))]
 mod mlock;
 #[cfg(not(windows))]
 mod mmap;
+// The libc backend only supports pkeys on some architectures.
+#[cfg(all(
+    any(target_os = "android", target_os = "linux"),
+    any(
+        target_arch = "aarch64",
+        target_arch = "arm",
+        target_arch = "mips",
+        target_arch = "mips64",
+        target_arch = "riscv64",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    )
+))]
+mod pkey;
 #[cfg(not(windows))]
 mod prot;
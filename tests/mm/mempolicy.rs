@@ -0,0 +1,84 @@
+use rustix::io;
+use rustix::mm::{
+    get_mempolicy, get_mempolicy_node, mbind, numa_node, set_mempolicy, GetMempolicyFlags,
+    MbindFlags, MempolicyMode, NodeMask,
+};
+
+#[test]
+fn test_node_mask() {
+    let mut mask = NodeMask::new();
+    assert_eq!(mask.count(), 0);
+    mask.set(0);
+    mask.set(NodeMask::MAX_NODE - 1);
+    assert!(mask.is_set(0));
+    assert!(!mask.is_set(1));
+    assert!(mask.is_set(NodeMask::MAX_NODE - 1));
+    assert_eq!(mask.count(), 2);
+    mask.unset(0);
+    assert!(!mask.is_set(0));
+    mask.clear();
+    assert_eq!(mask, NodeMask::default());
+}
+
+#[test]
+fn test_mempolicy() {
+    let (mode, _) = match get_mempolicy(None, GetMempolicyFlags::empty()) {
+        Ok(policy) => policy,
+        // The kernel may be built without NUMA support.
+        Err(io::Errno::NOSYS) => return,
+        Err(err) => panic!("get_mempolicy failed: {:?}", err),
+    };
+    assert_eq!(mode, MempolicyMode::Default);
+
+    let (_, allowed) = get_mempolicy(None, GetMempolicyFlags::MEMS_ALLOWED).unwrap();
+    assert!(allowed.is_set(numa_node().unwrap() as usize));
+
+    let mut mask = NodeMask::new();
+    mask.set(numa_node().unwrap() as usize);
+    set_mempolicy(MempolicyMode::Bind, &mask).unwrap();
+    assert_eq!(
+        get_mempolicy(None, GetMempolicyFlags::empty()).unwrap(),
+        (MempolicyMode::Bind, mask)
+    );
+
+    // `MPOL_PREFERRED_MANY` requires Linux 5.15.
+    match set_mempolicy(MempolicyMode::PreferredMany, &mask) {
+        Ok(()) => assert_eq!(
+            get_mempolicy(None, GetMempolicyFlags::empty()).unwrap(),
+            (MempolicyMode::PreferredMany, mask)
+        ),
+        Err(io::Errno::INVAL) => {}
+        Err(err) => panic!("set_mempolicy failed: {:?}", err),
+    }
+
+    set_mempolicy(MempolicyMode::Default, &NodeMask::new()).unwrap();
+    assert_eq!(
+        get_mempolicy(None, GetMempolicyFlags::empty()).unwrap().0,
+        MempolicyMode::Default
+    );
+}
+
+#[test]
+fn test_mbind() {
+    let node = match numa_node() {
+        Ok(node) => node,
+        Err(io::Errno::NOSYS) => return,
+        Err(err) => panic!("getcpu failed: {:?}", err),
+    };
+
+    let mut buf = vec![0_u8; 4096 * 4];
+    let page = ((buf.as_mut_ptr() as usize + 4095) & !4095) as *mut _;
+
+    let mut mask = NodeMask::new();
+    mask.set(node as usize);
+    match unsafe { mbind(page, 4096, MempolicyMode::Bind, &mask, MbindFlags::empty()) } {
+        Ok(()) => {}
+        Err(io::Errno::NOSYS) => return,
+        Err(err) => panic!("mbind failed: {:?}", err),
+    }
+
+    let (mode, policy_mask) = get_mempolicy(Some(page), GetMempolicyFlags::ADDR).unwrap();
+    assert_eq!(mode, MempolicyMode::Bind);
+    assert_eq!(policy_mask, mask);
+    assert_eq!(get_mempolicy_node(page).unwrap(), node);
+}
@@ -0,0 +1,38 @@
+use rustix::io;
+use rustix::mm::{mincore, mmap_anonymous, munmap, MapFlags, ProtFlags};
+use rustix::param::page_size;
+use std::ptr::null_mut;
+
+#[test]
+fn test_mincore() {
+    let page_size = page_size();
+    unsafe {
+        let addr = mmap_anonymous(
+            null_mut(),
+            page_size * 2,
+            ProtFlags::READ | ProtFlags::WRITE,
+            MapFlags::PRIVATE,
+        )
+        .unwrap();
+
+        // Touch the first page so that it's resident.
+        *addr.cast::<u8>() = 1;
+
+        let mut vec = [0_u8; 2];
+        mincore(addr, page_size * 2, &mut vec).unwrap();
+        assert_eq!(vec[0] & 1, 1);
+
+        // A partial page counts as a whole page.
+        let mut vec = [0_u8; 2];
+        mincore(addr, page_size + 1, &mut vec).unwrap();
+        assert_eq!(vec[0] & 1, 1);
+
+        let mut short = [0_u8; 1];
+        assert_eq!(
+            mincore(addr, page_size + 1, &mut short),
+            Err(io::Errno::INVAL)
+        );
+
+        munmap(addr, page_size * 2).unwrap();
+    }
+}
@@ -0,0 +1,43 @@
+use rustix::io;
+use rustix::mm::{
+    mmap_anonymous, munmap, pkey_alloc, pkey_free, pkey_mprotect, MapFlags, PkeyAccessRights,
+    ProtFlags,
+};
+use std::ptr::null_mut;
+
+#[test]
+fn test_pkey() {
+    let pkey = match pkey_alloc(0, PkeyAccessRights::empty()) {
+        Ok(pkey) => pkey,
+        // Protection keys may not be supported by the CPU or kernel, or all
+        // the keys may already be in use.
+        Err(io::Errno::NOSYS) | Err(io::Errno::INVAL) | Err(io::Errno::NOSPC) => return,
+        Err(err) => panic!("pkey_alloc failed: {:?}", err),
+    };
+
+    unsafe {
+        let addr = mmap_anonymous(
+            null_mut(),
+            8192,
+            ProtFlags::READ | ProtFlags::WRITE,
+            MapFlags::PRIVATE,
+        )
+        .unwrap();
+
+        pkey_mprotect(addr, 8192, ProtFlags::READ | ProtFlags::WRITE, pkey).unwrap();
+        *addr.cast::<u8>() = 1;
+        assert_eq!(*addr.cast::<u8>(), 1);
+
+        munmap(addr, 8192).unwrap();
+    }
+
+    pkey_free(pkey).unwrap();
+}
+
+#[test]
+fn test_pkey_alloc_invalid_flags() {
+    match pkey_alloc(!0, PkeyAccessRights::empty()) {
+        Err(io::Errno::NOSYS) | Err(io::Errno::INVAL) => (),
+        other => panic!("unexpected result: {:?}", other),
+    }
+}
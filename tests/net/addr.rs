@@ -90,3 +90,94 @@ fn test_unix_addr() {
             .is_none());
     }
 }
+
+#[test]
+fn test_raw_round_trip() {
+    #[cfg(not(windows))]
+    use rustix::net::SocketAddrUnix;
+    use rustix::net::{
+        Ipv4Addr, Ipv6Addr, SocketAddrAny, SocketAddrStorage, SocketAddrV4, SocketAddrV6,
+    };
+
+    let mut storage: SocketAddrStorage = unsafe { std::mem::zeroed() };
+
+    let orig = SocketAddrAny::V4(SocketAddrV4::new(Ipv4Addr::new(2, 3, 5, 6), 33));
+    let len = orig.write_to(&mut storage);
+    assert_eq!(SocketAddrAny::from_raw(&storage, len).unwrap(), orig);
+
+    // Too short for the family, or longer than the storage.
+    assert_eq!(
+        SocketAddrAny::from_raw(&storage, len - 1),
+        Err(rustix::io::Errno::INVAL)
+    );
+    assert_eq!(
+        SocketAddrAny::from_raw(&storage, std::mem::size_of::<SocketAddrStorage>() + 1),
+        Err(rustix::io::Errno::INVAL)
+    );
+    assert_eq!(
+        SocketAddrAny::from_raw(&storage, 0),
+        Err(rustix::io::Errno::INVAL)
+    );
+
+    let orig = SocketAddrAny::V6(SocketAddrV6::new(
+        Ipv6Addr::new(2, 3, 5, 6, 8, 9, 11, 12),
+        33,
+        34,
+        36,
+    ));
+    let len = orig.write_to(&mut storage);
+    assert_eq!(SocketAddrAny::from_raw(&storage, len).unwrap(), orig);
+
+    #[cfg(not(windows))]
+    {
+        let orig = SocketAddrAny::Unix(SocketAddrUnix::new("/path/to/socket").unwrap());
+        let len = orig.write_to(&mut storage);
+        assert_eq!(SocketAddrAny::from_raw(&storage, len).unwrap(), orig);
+    }
+
+    // An unsupported address family.
+    let storage: SocketAddrStorage = unsafe { std::mem::zeroed() };
+    let len = std::mem::size_of::<SocketAddrStorage>();
+    assert_eq!(
+        SocketAddrAny::from_raw(&storage, len),
+        Err(rustix::io::Errno::INVAL)
+    );
+}
+
+#[cfg(not(windows))]
+#[test]
+fn test_raw_malformed_unix() {
+    use rustix::net::{SocketAddrAny, SocketAddrStorage, SocketAddrUnix};
+
+    let mut storage: SocketAddrStorage = unsafe { std::mem::zeroed() };
+    let orig = SocketAddrAny::Unix(SocketAddrUnix::new("/path/to/socket").unwrap());
+    let full_len = orig.write_to(&mut storage);
+    // The path starts right after the family.
+    let offsetof_sun_path = full_len - b"/path/to/socket\0".len();
+
+    let sun_path = |storage: &mut SocketAddrStorage| unsafe {
+        std::slice::from_raw_parts_mut(
+            (storage as *mut SocketAddrStorage)
+                .cast::<u8>()
+                .add(offsetof_sun_path),
+            std::mem::size_of::<SocketAddrStorage>() - offsetof_sun_path,
+        )
+    };
+
+    // A path with an interior NUL. FreeBSD truncates paths at the first NUL.
+    sun_path(&mut storage)[..4].copy_from_slice(b"a\0b\0");
+    #[cfg(not(target_os = "freebsd"))]
+    assert_eq!(
+        SocketAddrAny::from_raw(&storage, offsetof_sun_path + 4),
+        Err(rustix::io::Errno::INVAL)
+    );
+
+    // A path with no NUL terminator.
+    for b in sun_path(&mut storage) {
+        *b = b'a';
+    }
+    assert_eq!(
+        SocketAddrAny::from_raw(&storage, offsetof_sun_path + 4),
+        Err(rustix::io::Errno::INVAL)
+    );
+}
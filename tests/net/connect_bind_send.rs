@@ -485,3 +485,61 @@ fn net_v6_acceptfrom() -> std::io::Result<()> {
 
     Ok(())
 }
+
+/// Test `recv` with `RecvFlags::PEEK`.
+#[test]
+fn net_v4_recv_peek() -> std::io::Result<()> {
+    let localhost = IpAddr::V4(Ipv4Addr::LOCALHOST);
+    let addr = SocketAddr::new(localhost, 0);
+    let receiver =
+        rustix::net::socket(AddressFamily::INET, SocketType::DGRAM, Protocol::default())?;
+    rustix::net::bind(&receiver, &addr).expect("bind");
+
+    let local_addr = rustix::net::getsockname(&receiver)?;
+    let sender = rustix::net::socket(AddressFamily::INET, SocketType::DGRAM, Protocol::default())?;
+    rustix::net::connect_any(&sender, &local_addr).expect("connect");
+    rustix::net::send(&sender, b"\x05hello", SendFlags::empty()).expect("send");
+
+    // Peek at the length prefix, leaving the message in the queue.
+    let mut prefix = [0_u8; 1];
+    let n = rustix::net::recv(&receiver, &mut prefix, RecvFlags::PEEK).expect("recv");
+    assert_eq!(n, 1);
+    assert_eq!(prefix[0], 5);
+
+    let mut response = [0_u8; 16];
+    let n = rustix::net::recv(&receiver, &mut response, RecvFlags::empty()).expect("recv");
+    assert_eq!(&response[..n], b"\x05hello");
+
+    #[cfg(not(windows))]
+    assert_eq!(
+        rustix::net::recv(&receiver, &mut response, RecvFlags::DONTWAIT),
+        Err(rustix::io::Errno::AGAIN)
+    );
+
+    Ok(())
+}
+
+/// Test `recv` with `RecvFlags::TRUNC`.
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+fn net_v4_recv_trunc() -> std::io::Result<()> {
+    let localhost = IpAddr::V4(Ipv4Addr::LOCALHOST);
+    let addr = SocketAddr::new(localhost, 0);
+    let receiver =
+        rustix::net::socket(AddressFamily::INET, SocketType::DGRAM, Protocol::default())?;
+    rustix::net::bind(&receiver, &addr).expect("bind");
+
+    let local_addr = rustix::net::getsockname(&receiver)?;
+    let sender = rustix::net::socket(AddressFamily::INET, SocketType::DGRAM, Protocol::default())?;
+    rustix::net::connect_any(&sender, &local_addr).expect("connect");
+    rustix::net::send(&sender, b"Hello, World!!!", SendFlags::empty()).expect("send");
+
+    // The real length of the datagram is returned, though only part of it
+    // fits in the buffer.
+    let mut response = [0_u8; 5];
+    let n = rustix::net::recv(&receiver, &mut response, RecvFlags::TRUNC).expect("recv");
+    assert_eq!(n, 15);
+    assert_eq!(&response, b"Hello");
+
+    Ok(())
+}
@@ -0,0 +1,81 @@
+use rustix::io::{ioctl_fionbio, poll, PollFd, PollFlags};
+use rustix::net::{
+    bind_v4, connect_result, connect_v4, getsockname, listen, socket, AddressFamily, Ipv4Addr,
+    Protocol, SocketAddrAny, SocketAddrV4, SocketType,
+};
+
+/// Start a nonblocking connect to `addr` and wait for it to complete.
+fn nonblocking_connect(addr: &SocketAddrV4) -> rustix::io::Result<()> {
+    let client = socket(AddressFamily::INET, SocketType::STREAM, Protocol::TCP).unwrap();
+    ioctl_fionbio(&client, true).unwrap();
+
+    match connect_v4(&client, addr) {
+        Ok(()) => return connect_result(&client),
+        Err(rustix::io::Errno::INPROGRESS) => (),
+        Err(err) => return Err(err),
+    }
+
+    let mut fds = [PollFd::new(&client, PollFlags::OUT)];
+    assert_eq!(poll(&mut fds, 10_000).unwrap(), 1);
+    connect_result(&client)
+}
+
+#[test]
+fn test_connect_result_refused() {
+    // Bind a socket but don't listen on it, so that connections to its port
+    // are refused.
+    let unlistened = socket(AddressFamily::INET, SocketType::STREAM, Protocol::TCP).unwrap();
+    bind_v4(&unlistened, &SocketAddrV4::new(Ipv4Addr::LOCALHOST, 0)).unwrap();
+    let addr = match getsockname(&unlistened).unwrap() {
+        SocketAddrAny::V4(addr) => addr,
+        _ => panic!(),
+    };
+
+    assert_eq!(
+        nonblocking_connect(&addr),
+        Err(rustix::io::Errno::CONNREFUSED)
+    );
+}
+
+#[test]
+fn test_connect_result_ok() {
+    let listener = socket(AddressFamily::INET, SocketType::STREAM, Protocol::TCP).unwrap();
+    bind_v4(&listener, &SocketAddrV4::new(Ipv4Addr::LOCALHOST, 0)).unwrap();
+    listen(&listener, 1).unwrap();
+    let addr = match getsockname(&listener).unwrap() {
+        SocketAddrAny::V4(addr) => addr,
+        _ => panic!(),
+    };
+
+    nonblocking_connect(&addr).unwrap();
+}
+
+#[test]
+fn test_connect_any_nonblocking() {
+    use rustix::net::connect_any;
+    use rustix::net::sockopt::get_socket_error;
+
+    let unlistened = socket(AddressFamily::INET, SocketType::STREAM, Protocol::TCP).unwrap();
+    bind_v4(&unlistened, &SocketAddrV4::new(Ipv4Addr::LOCALHOST, 0)).unwrap();
+    let addr = getsockname(&unlistened).unwrap();
+
+    let client = socket(AddressFamily::INET, SocketType::STREAM, Protocol::TCP).unwrap();
+    ioctl_fionbio(&client, true).unwrap();
+
+    match connect_any(&client, &addr) {
+        Err(rustix::io::Errno::INPROGRESS) => {
+            let mut fds = [PollFd::new(&client, PollFlags::OUT)];
+            assert_eq!(poll(&mut fds, 10_000).unwrap(), 1);
+            assert_eq!(
+                get_socket_error(&client).unwrap(),
+                Err(rustix::io::Errno::CONNREFUSED)
+            );
+        }
+        // Some platforms report the refusal immediately.
+        Err(rustix::io::Errno::CONNREFUSED) => (),
+        other => panic!("unexpected connect result: {:?}", other),
+    }
+
+    // Once retrieved, the pending error is cleared.
+    assert_eq!(get_socket_error(&client).unwrap(), Ok(()));
+}
@@ -0,0 +1,49 @@
+use rustix::net::sockopt::{get_ip_recverr, set_ip_recverr};
+use rustix::net::{
+    bind_v4, connect_v4, drain_error_queue, getsockname, send, socket, AddressFamily, Ipv4Addr,
+    Protocol, SendFlags, SockExtendedErr, SocketAddrAny, SocketAddrV4, SocketType,
+};
+
+#[test]
+fn test_drain_error_queue() {
+    // Bind a socket to find a free port, and then close it so that datagrams
+    // sent to that port are answered with ICMP port-unreachable errors.
+    let closed = socket(AddressFamily::INET, SocketType::DGRAM, Protocol::UDP).unwrap();
+    bind_v4(&closed, &SocketAddrV4::new(Ipv4Addr::LOCALHOST, 0)).unwrap();
+    let addr = match getsockname(&closed).unwrap() {
+        SocketAddrAny::V4(addr) => addr,
+        _ => panic!(),
+    };
+    drop(closed);
+
+    let sock = socket(AddressFamily::INET, SocketType::DGRAM, Protocol::UDP).unwrap();
+    assert!(!get_ip_recverr(&sock).unwrap());
+    set_ip_recverr(&sock, true).unwrap();
+    assert!(get_ip_recverr(&sock).unwrap());
+
+    // The queue starts out empty.
+    assert_eq!(drain_error_queue(&sock).unwrap(), Vec::new());
+
+    connect_v4(&sock, &addr).unwrap();
+    send(&sock, b"hello", SendFlags::empty()).unwrap();
+
+    // The ICMP error is delivered asynchronously, so wait for it.
+    let mut errors = Vec::new();
+    for _ in 0..100 {
+        errors = drain_error_queue(&sock).unwrap();
+        if !errors.is_empty() {
+            break;
+        }
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    }
+
+    assert_eq!(errors.len(), 1, "{:?}", errors);
+    assert_eq!(errors[0].errno, rustix::io::Errno::CONNREFUSED);
+    assert_eq!(errors[0].origin, SockExtendedErr::ORIGIN_ICMP);
+    // ICMP destination unreachable, port unreachable.
+    assert_eq!(errors[0].type_, 3);
+    assert_eq!(errors[0].code, 3);
+
+    // Draining removed the error from the queue.
+    assert_eq!(drain_error_queue(&sock).unwrap(), Vec::new());
+}
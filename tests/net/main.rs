@@ -7,8 +7,25 @@
 
 mod addr;
 mod connect_bind_send;
+#[cfg(not(windows))]
+mod connect_result;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod error_queue;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod mmsg;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod netdevice;
 mod poll;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod recvmsg;
+#[cfg(all(
+    feature = "fs",
+    not(any(windows, target_os = "ios", target_os = "macos"))
+))]
+mod socket_with;
 mod sockopt;
+#[cfg(not(any(windows, target_os = "ios", target_os = "macos")))]
+mod tcp_pair;
 #[cfg(unix)]
 mod unix;
 mod v4;
@@ -0,0 +1,161 @@
+use rustix::io::{IoSlice, IoSliceMut};
+use rustix::net::{
+    bind_v4, connect_v4, getsockname, recvmmsg, sendmmsg, socket, AddressFamily, Ipv4Addr, MMsgHdr,
+    Protocol, RecvFlags, SendFlags, SocketAddrAny, SocketAddrStorage, SocketAddrV4, SocketType,
+};
+
+fn bound_udp_socket() -> (rustix::io::OwnedFd, SocketAddrV4) {
+    let s = socket(AddressFamily::INET, SocketType::DGRAM, Protocol::default()).unwrap();
+    bind_v4(&s, &SocketAddrV4::new(Ipv4Addr::LOCALHOST, 0)).unwrap();
+    let addr = match getsockname(&s).unwrap() {
+        SocketAddrAny::V4(addr) => addr,
+        _ => panic!(),
+    };
+    (s, addr)
+}
+
+#[test]
+fn test_sendmmsg_recvmmsg() {
+    let (receiver, receiver_addr) = bound_udp_socket();
+    let (sender, sender_addr) = bound_udp_socket();
+    connect_v4(&sender, &receiver_addr).unwrap();
+
+    let a = [IoSlice::new(b"hello"), IoSlice::new(b", world")];
+    let b = [IoSlice::new(b"goodnight")];
+    let mut msgs = [MMsgHdr::new_send(&a), MMsgHdr::new_send(&b)];
+    assert_eq!(sendmmsg(&sender, &mut msgs, SendFlags::empty()).unwrap(), 2);
+    assert_eq!(msgs[0].msg_len(), 12);
+    assert_eq!(msgs[1].msg_len(), 9);
+
+    let mut buf0 = [0_u8; 16];
+    let mut buf1 = [0_u8; 4];
+    let mut buf2 = [0_u8; 16];
+    let mut bufs0 = [IoSliceMut::new(&mut buf0)];
+    let mut bufs1 = [IoSliceMut::new(&mut buf1)];
+    let mut bufs2 = [IoSliceMut::new(&mut buf2)];
+    let mut addr0 = unsafe { std::mem::zeroed::<SocketAddrStorage>() };
+    let mut msgs = [
+        MMsgHdr::new_recv(&mut bufs0, Some(&mut addr0)),
+        MMsgHdr::new_recv(&mut bufs1, None),
+        MMsgHdr::new_recv(&mut bufs2, None),
+    ];
+    let n = recvmmsg(&receiver, &mut msgs, RecvFlags::WAITFORONE, None).unwrap();
+    assert_eq!(n, 2);
+    assert_eq!(msgs[0].msg_len(), 12);
+    assert_eq!(msgs[0].addr(), Some(SocketAddrAny::V4(sender_addr)));
+    assert!(!msgs[0].flags().contains(RecvFlags::TRUNC));
+    // The second message is truncated to fit its buffer.
+    assert_eq!(msgs[1].msg_len(), 4);
+    assert_eq!(msgs[1].addr(), None);
+    assert!(msgs[1].flags().contains(RecvFlags::TRUNC));
+
+    assert_eq!(&buf0[..12], b"hello, world");
+    assert_eq!(&buf1, b"good");
+}
+
+#[test]
+fn test_sendmmsg_to() {
+    let (receiver, receiver_addr) = bound_udp_socket();
+    let (sender, _sender_addr) = bound_udp_socket();
+
+    let data = [IoSlice::new(b"datagram")];
+    let mut storage = unsafe { std::mem::zeroed::<SocketAddrStorage>() };
+    let mut msgs = [MMsgHdr::new_sendto(
+        &data,
+        &SocketAddrAny::V4(receiver_addr),
+        &mut storage,
+    )];
+    assert_eq!(sendmmsg(&sender, &mut msgs, SendFlags::empty()).unwrap(), 1);
+
+    let mut buf = [0_u8; 16];
+    let nread = rustix::net::recv(&receiver, &mut buf, RecvFlags::empty()).unwrap();
+    assert_eq!(&buf[..nread], b"datagram");
+}
+
+#[test]
+fn test_recvmmsg_nonblocking() {
+    let (receiver, _receiver_addr) = bound_udp_socket();
+
+    let mut buf = [0_u8; 16];
+    let mut bufs = [IoSliceMut::new(&mut buf)];
+    let mut msgs = [MMsgHdr::new_recv(&mut bufs, None)];
+    assert_eq!(
+        recvmmsg(&receiver, &mut msgs, RecvFlags::DONTWAIT, None),
+        Err(rustix::io::Errno::AGAIN)
+    );
+    assert_eq!(
+        recvmmsg(&receiver, &mut [], RecvFlags::DONTWAIT, None),
+        Ok(0)
+    );
+}
+
+#[cfg(feature = "time")]
+#[test]
+fn test_recvmmsg_timeout() {
+    let (receiver, receiver_addr) = bound_udp_socket();
+    let (sender, _sender_addr) = bound_udp_socket();
+    connect_v4(&sender, &receiver_addr).unwrap();
+    rustix::net::send(&sender, b"first", SendFlags::empty()).unwrap();
+    rustix::net::send(&sender, b"second", SendFlags::empty()).unwrap();
+
+    // The timeout is checked after each message, so a zero timeout returns
+    // after the first one.
+    let timeout = rustix::time::Timespec {
+        tv_sec: 0,
+        tv_nsec: 0,
+    };
+    let mut buf0 = [0_u8; 16];
+    let mut buf1 = [0_u8; 16];
+    let mut bufs0 = [IoSliceMut::new(&mut buf0)];
+    let mut bufs1 = [IoSliceMut::new(&mut buf1)];
+    let mut msgs = [
+        MMsgHdr::new_recv(&mut bufs0, None),
+        MMsgHdr::new_recv(&mut bufs1, None),
+    ];
+    let n = recvmmsg(&receiver, &mut msgs, RecvFlags::empty(), Some(&timeout)).unwrap();
+    assert_eq!(n, 1);
+    assert_eq!(msgs[0].msg_len(), 5);
+}
+
+#[cfg(unix)]
+#[test]
+fn test_recvmmsg_reuse() {
+    use rustix::net::{bind_unix, sendto_unix, SocketAddrUnix};
+
+    let tmp = tempfile::tempdir().unwrap();
+    let bound_unix_socket = |name: &str| {
+        let s = socket(AddressFamily::UNIX, SocketType::DGRAM, Protocol::default()).unwrap();
+        let addr = SocketAddrUnix::new(tmp.path().join(name)).unwrap();
+        bind_unix(&s, &addr).unwrap();
+        (s, addr)
+    };
+    let (receiver, receiver_addr) = bound_unix_socket("receiver");
+    let (short, short_addr) = bound_unix_socket("a");
+    let (long, long_addr) = bound_unix_socket("a-much-longer-name");
+
+    let mut buf0 = [0_u8; 16];
+    let mut buf1 = [0_u8; 16];
+    let mut bufs0 = [IoSliceMut::new(&mut buf0)];
+    let mut bufs1 = [IoSliceMut::new(&mut buf1)];
+    let mut addr0 = unsafe { std::mem::zeroed::<SocketAddrStorage>() };
+    let mut addr1 = unsafe { std::mem::zeroed::<SocketAddrStorage>() };
+    let mut msgs = [
+        MMsgHdr::new_recv(&mut bufs0, Some(&mut addr0)),
+        MMsgHdr::new_recv(&mut bufs1, Some(&mut addr1)),
+    ];
+    // Nothing has been received yet.
+    assert_eq!(msgs[0].addr(), None);
+
+    // The shorter address from the first call doesn't truncate the longer
+    // address from the second call.
+    for (sender, sender_addr) in &[(&short, &short_addr), (&long, &long_addr)] {
+        sendto_unix(sender, b"x", SendFlags::empty(), &receiver_addr).unwrap();
+        let n = recvmmsg(&receiver, &mut msgs, RecvFlags::WAITFORONE, None).unwrap();
+        assert_eq!(n, 1);
+        assert_eq!(
+            msgs[0].addr(),
+            Some(SocketAddrAny::Unix((*sender_addr).clone()))
+        );
+        assert_eq!(msgs[1].addr(), None);
+    }
+}
@@ -0,0 +1,20 @@
+use rustix::io::Errno;
+use rustix::net::{if_indextoname, if_nametoindex};
+use rustix::zstr;
+
+#[test]
+fn test_if_nametoindex_loopback() {
+    let index = if_nametoindex(zstr!("lo")).unwrap();
+    assert_ne!(index, 0);
+    assert_eq!(if_indextoname(index).unwrap().as_c_str(), zstr!("lo"));
+}
+
+#[test]
+fn test_if_nametoindex_unknown() {
+    assert_eq!(if_nametoindex(zstr!("no-such-if0")), Err(Errno::NODEV));
+    assert_eq!(
+        if_nametoindex(zstr!("a-name-which-is-far-too-long")),
+        Err(Errno::NODEV)
+    );
+    assert_eq!(if_indextoname(0), Err(Errno::NXIO));
+}
@@ -0,0 +1,51 @@
+use rustix::io::IoSliceMut;
+use rustix::net::sockopt::{set_socket_timestamp, set_socket_timestampns};
+use rustix::net::{
+    bind_v4, connect_v4, getsockname, recvmsg, send, socket, AddressFamily, ControlMessage,
+    Ipv4Addr, Protocol, RecvFlags, SendFlags, SocketAddrAny, SocketAddrV4, SocketType,
+};
+
+#[test]
+fn test_recvmsg_timestamps() {
+    let sock = socket(AddressFamily::INET, SocketType::DGRAM, Protocol::UDP).unwrap();
+    bind_v4(&sock, &SocketAddrV4::new(Ipv4Addr::LOCALHOST, 0)).unwrap();
+    let addr = match getsockname(&sock).unwrap() {
+        SocketAddrAny::V4(addr) => addr,
+        _ => panic!(),
+    };
+    connect_v4(&sock, &addr).unwrap();
+
+    // Without timestamps enabled, there are no control messages.
+    send(&sock, b"hello", SendFlags::empty()).unwrap();
+    let mut buf = [0_u8; 16];
+    let msg = recvmsg(&sock, &mut [IoSliceMut::new(&mut buf)], RecvFlags::empty()).unwrap();
+    assert_eq!(msg.bytes, 5);
+    assert_eq!(&buf[..5], b"hello");
+    assert!(msg.control_messages.is_empty());
+
+    set_socket_timestamp(&sock, true).unwrap();
+    send(&sock, b"world", SendFlags::empty()).unwrap();
+    let msg = recvmsg(&sock, &mut [IoSliceMut::new(&mut buf)], RecvFlags::empty()).unwrap();
+    assert_eq!(msg.bytes, 5);
+    assert_eq!(&buf[..5], b"world");
+    match msg.control_messages[..] {
+        [ControlMessage::ScmTimestamp(tv)] => {
+            assert!(tv.tv_sec > 0);
+            assert!(tv.tv_usec >= 0 && tv.tv_usec < 1_000_000);
+        }
+        _ => panic!("{:?}", msg.control_messages),
+    }
+    set_socket_timestamp(&sock, false).unwrap();
+
+    set_socket_timestampns(&sock, true).unwrap();
+    send(&sock, b"again", SendFlags::empty()).unwrap();
+    let msg = recvmsg(&sock, &mut [IoSliceMut::new(&mut buf)], RecvFlags::empty()).unwrap();
+    assert_eq!(msg.bytes, 5);
+    match msg.control_messages[..] {
+        [ControlMessage::ScmTimestampNs(ts)] => {
+            assert!(ts.tv_sec > 0);
+            assert!(ts.tv_nsec >= 0 && ts.tv_nsec < 1_000_000_000);
+        }
+        _ => panic!("{:?}", msg.control_messages),
+    }
+}
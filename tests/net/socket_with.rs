@@ -0,0 +1,37 @@
+use rustix::fs::{fcntl_getfd, fcntl_getfl, FdFlags, OFlags};
+use rustix::net::{socket, socket_with, AddressFamily, Protocol, SocketFlags, SocketType};
+
+#[test]
+fn test_socket_with_flags() {
+    let plain = socket(AddressFamily::INET, SocketType::STREAM, Protocol::default()).unwrap();
+    assert!(!fcntl_getfd(&plain).unwrap().contains(FdFlags::CLOEXEC));
+    assert!(!fcntl_getfl(&plain).unwrap().contains(OFlags::NONBLOCK));
+
+    let cloexec = socket_with(
+        AddressFamily::INET,
+        SocketType::STREAM,
+        SocketFlags::CLOEXEC,
+        Protocol::default(),
+    )
+    .unwrap();
+    assert!(fcntl_getfd(&cloexec).unwrap().contains(FdFlags::CLOEXEC));
+    assert!(!fcntl_getfl(&cloexec).unwrap().contains(OFlags::NONBLOCK));
+
+    let both = socket_with(
+        AddressFamily::INET,
+        SocketType::DGRAM,
+        SocketFlags::CLOEXEC | SocketFlags::NONBLOCK,
+        Protocol::default(),
+    )
+    .unwrap();
+    assert!(fcntl_getfd(&both).unwrap().contains(FdFlags::CLOEXEC));
+    assert!(fcntl_getfl(&both).unwrap().contains(OFlags::NONBLOCK));
+
+    // A non-blocking socket with nothing to receive fails rather than
+    // blocking.
+    let mut buf = [0_u8; 4];
+    assert_eq!(
+        rustix::net::recv(&both, &mut buf, rustix::net::RecvFlags::empty()),
+        Err(rustix::io::Errno::AGAIN)
+    );
+}
@@ -156,3 +156,301 @@ fn test_sockopts() {
     // Check that the nodelay flag is set.
     assert_eq!(rustix::net::sockopt::get_tcp_nodelay(&s).unwrap(), true);
 }
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+fn test_sockopt_peercred() {
+    use rustix::net::{socketpair, AddressFamily, Protocol, SocketFlags, SocketType};
+
+    let (a, b) = socketpair(
+        AddressFamily::UNIX,
+        SocketType::STREAM,
+        SocketFlags::CLOEXEC,
+        Protocol::default(),
+    )
+    .unwrap();
+
+    for s in &[a, b] {
+        let cred = rustix::net::sockopt::get_socket_peercred(s).unwrap();
+        assert_eq!(cred.pid.unwrap().as_raw_nonzero().get() as i32, unsafe {
+            libc::getpid()
+        });
+        assert_eq!(cred.uid.as_raw(), unsafe { libc::geteuid() });
+        assert_eq!(cred.gid.as_raw(), unsafe { libc::getegid() });
+    }
+
+    // `SO_PEERCRED` is only meaningful for Unix-domain sockets.
+    let s =
+        rustix::net::socket(AddressFamily::INET, SocketType::STREAM, Protocol::default()).unwrap();
+    let cred = rustix::net::sockopt::get_socket_peercred(&s).unwrap();
+    assert_eq!(cred.pid, None);
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+fn test_sockopt_bindtodevice() {
+    use rustix::net::sockopt::{get_socket_bindtodevice, set_socket_bindtodevice};
+    use rustix::net::{AddressFamily, Protocol, SocketType};
+
+    let s =
+        rustix::net::socket(AddressFamily::INET, SocketType::DGRAM, Protocol::default()).unwrap();
+
+    assert_eq!(get_socket_bindtodevice(&s).unwrap().to_bytes(), b"");
+
+    match set_socket_bindtodevice(&s, rustix::zstr!("lo")) {
+        Ok(()) => {
+            assert_eq!(get_socket_bindtodevice(&s).unwrap().to_bytes(), b"lo");
+
+            // An empty name removes the binding.
+            set_socket_bindtodevice(&s, rustix::zstr!("")).unwrap();
+            assert_eq!(get_socket_bindtodevice(&s).unwrap().to_bytes(), b"");
+        }
+        // Binding to a device may require `CAP_NET_RAW`.
+        Err(rustix::io::Errno::PERM) => {}
+        Err(err) => panic!("set_socket_bindtodevice failed: {:?}", err),
+    }
+
+    assert!(matches!(
+        set_socket_bindtodevice(&s, rustix::zstr!("nonexistent0")),
+        Err(rustix::io::Errno::NODEV) | Err(rustix::io::Errno::PERM)
+    ));
+}
+
+#[test]
+fn test_sockopt_keepalive() {
+    use rustix::net::sockopt::{get_socket_keepalive, set_socket_keepalive};
+    use rustix::net::{AddressFamily, Protocol, SocketType};
+
+    let s =
+        rustix::net::socket(AddressFamily::INET, SocketType::STREAM, Protocol::default()).unwrap();
+
+    assert!(!get_socket_keepalive(&s).unwrap());
+    set_socket_keepalive(&s, true).unwrap();
+    assert!(get_socket_keepalive(&s).unwrap());
+    set_socket_keepalive(&s, false).unwrap();
+    assert!(!get_socket_keepalive(&s).unwrap());
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+fn test_sockopt_tcp_keepalive() {
+    use rustix::net::sockopt::{
+        get_tcp_keepcnt, get_tcp_keepidle, get_tcp_keepintvl, set_tcp_keepcnt, set_tcp_keepidle,
+        set_tcp_keepintvl,
+    };
+    use rustix::net::{AddressFamily, Protocol, SocketType};
+    use std::time::Duration;
+
+    let s =
+        rustix::net::socket(AddressFamily::INET, SocketType::STREAM, Protocol::default()).unwrap();
+
+    set_tcp_keepidle(&s, Duration::from_secs(30)).unwrap();
+    assert_eq!(get_tcp_keepidle(&s).unwrap(), Duration::from_secs(30));
+
+    // Sub-second values are rounded up.
+    set_tcp_keepidle(&s, Duration::from_millis(1500)).unwrap();
+    assert_eq!(get_tcp_keepidle(&s).unwrap(), Duration::from_secs(2));
+    set_tcp_keepintvl(&s, Duration::from_millis(1)).unwrap();
+    assert_eq!(get_tcp_keepintvl(&s).unwrap(), Duration::from_secs(1));
+
+    set_tcp_keepintvl(&s, Duration::from_secs(7)).unwrap();
+    assert_eq!(get_tcp_keepintvl(&s).unwrap(), Duration::from_secs(7));
+
+    set_tcp_keepcnt(&s, 4).unwrap();
+    assert_eq!(get_tcp_keepcnt(&s).unwrap(), 4);
+
+    assert_eq!(
+        set_tcp_keepidle(&s, Duration::from_secs(0)),
+        Err(rustix::io::Errno::INVAL)
+    );
+    assert_eq!(
+        set_tcp_keepintvl(&s, Duration::from_secs(u64::MAX)),
+        Err(rustix::io::Errno::INVAL)
+    );
+    assert_eq!(set_tcp_keepcnt(&s, 0), Err(rustix::io::Errno::INVAL));
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+fn test_sockopt_tcp_user_timeout() {
+    use rustix::net::sockopt::{get_tcp_user_timeout, set_tcp_user_timeout};
+    use rustix::net::{AddressFamily, Protocol, SocketType};
+    use std::time::Duration;
+
+    let s =
+        rustix::net::socket(AddressFamily::INET, SocketType::STREAM, Protocol::default()).unwrap();
+
+    assert_eq!(get_tcp_user_timeout(&s).unwrap(), Duration::from_secs(0));
+
+    set_tcp_user_timeout(&s, Duration::from_secs(10)).unwrap();
+    assert_eq!(get_tcp_user_timeout(&s).unwrap(), Duration::from_secs(10));
+
+    // Sub-millisecond values are rounded up.
+    set_tcp_user_timeout(&s, Duration::from_micros(2500)).unwrap();
+    assert_eq!(get_tcp_user_timeout(&s).unwrap(), Duration::from_millis(3));
+
+    assert_eq!(
+        set_tcp_user_timeout(&s, Duration::from_secs(u64::MAX)),
+        Err(rustix::io::Errno::INVAL)
+    );
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+fn test_sockopt_tcp_congestion() {
+    use rustix::net::sockopt::{get_tcp_congestion, set_tcp_congestion};
+    use rustix::net::{AddressFamily, Protocol, SocketType};
+
+    let s =
+        rustix::net::socket(AddressFamily::INET, SocketType::STREAM, Protocol::default()).unwrap();
+
+    let name = get_tcp_congestion(&s).unwrap();
+    assert!(!name.to_bytes().is_empty());
+
+    // Setting the current algorithm again always succeeds.
+    set_tcp_congestion(&s, &name).unwrap();
+    assert_eq!(get_tcp_congestion(&s).unwrap(), name);
+
+    assert_eq!(
+        set_tcp_congestion(&s, rustix::zstr!("nonexistent")),
+        Err(rustix::io::Errno::NOENT)
+    );
+}
+
+#[test]
+fn test_sockopt_buffer_size() {
+    use rustix::net::sockopt::{
+        get_socket_recv_buffer_size, get_socket_send_buffer_size, set_socket_recv_buffer_size,
+        set_socket_send_buffer_size,
+    };
+    use rustix::net::{AddressFamily, Protocol, SocketType};
+
+    let s =
+        rustix::net::socket(AddressFamily::INET, SocketType::STREAM, Protocol::default()).unwrap();
+
+    assert_ne!(get_socket_recv_buffer_size(&s).unwrap(), 0);
+    assert_ne!(get_socket_send_buffer_size(&s).unwrap(), 0);
+
+    // Linux doubles the requested size; other platforms may not.
+    set_socket_recv_buffer_size(&s, 8192).unwrap();
+    let size = get_socket_recv_buffer_size(&s).unwrap();
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    assert_eq!(size, 16384);
+    #[cfg(not(any(target_os = "android", target_os = "linux")))]
+    assert!(size >= 8192);
+
+    set_socket_send_buffer_size(&s, 8192).unwrap();
+    let size = get_socket_send_buffer_size(&s).unwrap();
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    assert_eq!(size, 16384);
+    #[cfg(not(any(target_os = "android", target_os = "linux")))]
+    assert!(size >= 8192);
+}
+
+#[cfg(not(windows))]
+#[test]
+fn test_sockopt_ip_tos() {
+    use rustix::net::sockopt::{get_ip_tos, set_ip_tos};
+    use rustix::net::{AddressFamily, Protocol, SocketType};
+
+    let s =
+        rustix::net::socket(AddressFamily::INET, SocketType::DGRAM, Protocol::default()).unwrap();
+
+    assert_eq!(get_ip_tos(&s).unwrap(), 0);
+
+    // DSCP 46 (Expedited Forwarding) goes in the upper six bits.
+    set_ip_tos(&s, 46 << 2).unwrap();
+    assert_eq!(get_ip_tos(&s).unwrap(), 0xb8);
+    assert_eq!(get_ip_tos(&s).unwrap() >> 2, 46);
+}
+
+#[cfg(any(
+    target_os = "android",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "illumos",
+    target_os = "ios",
+    target_os = "linux",
+    target_os = "macos",
+    target_os = "netbsd",
+    target_os = "openbsd",
+    target_os = "solaris",
+))]
+#[test]
+fn test_sockopt_ipv6_tclass() {
+    use rustix::net::sockopt::{get_ipv6_tclass, set_ipv6_tclass};
+    use rustix::net::{AddressFamily, Protocol, SocketType};
+
+    let s = match rustix::net::socket(AddressFamily::INET6, SocketType::DGRAM, Protocol::default())
+    {
+        Ok(s) => s,
+        // IPv6 may be disabled.
+        Err(rustix::io::Errno::AFNOSUPPORT) => return,
+        Err(err) => panic!("socket failed: {:?}", err),
+    };
+
+    set_ipv6_tclass(&s, 46 << 2).unwrap();
+    assert_eq!(get_ipv6_tclass(&s).unwrap(), 0xb8);
+
+    assert_eq!(set_ipv6_tclass(&s, 256), Err(rustix::io::Errno::INVAL));
+    assert_eq!(set_ipv6_tclass(&s, u32::MAX), Err(rustix::io::Errno::INVAL));
+}
+
+#[test]
+fn test_sockopt_ipv6_v6only() {
+    use rustix::net::sockopt::{get_ipv6_v6only, set_ipv6_v6only};
+    use rustix::net::{AddressFamily, Ipv6Addr, Protocol, SocketAddrV6, SocketType};
+
+    let s = match rustix::net::socket(
+        AddressFamily::INET6,
+        SocketType::STREAM,
+        Protocol::default(),
+    ) {
+        Ok(s) => s,
+        // IPv6 may be disabled.
+        Err(rustix::io::Errno::AFNOSUPPORT) => return,
+        Err(err) => panic!("socket failed: {:?}", err),
+    };
+
+    set_ipv6_v6only(&s, true).unwrap();
+    assert!(get_ipv6_v6only(&s).unwrap());
+    set_ipv6_v6only(&s, false).unwrap();
+    assert!(!get_ipv6_v6only(&s).unwrap());
+
+    match rustix::net::bind_v6(&s, &SocketAddrV6::new(Ipv6Addr::LOCALHOST, 0, 0, 0)) {
+        Ok(()) => (),
+        // The loopback device may not have an IPv6 address.
+        Err(rustix::io::Errno::ADDRNOTAVAIL) => return,
+        Err(err) => panic!("bind failed: {:?}", err),
+    }
+
+    // Linux doesn't allow changing it once the socket is bound.
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    assert_eq!(set_ipv6_v6only(&s, true), Err(rustix::io::Errno::INVAL));
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+fn test_sockopts_timestamp() {
+    use rustix::net::sockopt::{
+        get_socket_timestamp, get_socket_timestampns, set_socket_timestamp, set_socket_timestampns,
+    };
+    use rustix::net::{AddressFamily, Protocol, SocketType};
+
+    let s =
+        rustix::net::socket(AddressFamily::INET, SocketType::DGRAM, Protocol::default()).unwrap();
+
+    assert!(!get_socket_timestamp(&s).unwrap());
+    assert!(!get_socket_timestampns(&s).unwrap());
+
+    set_socket_timestamp(&s, true).unwrap();
+    assert!(get_socket_timestamp(&s).unwrap());
+    assert!(!get_socket_timestampns(&s).unwrap());
+    set_socket_timestamp(&s, false).unwrap();
+    assert!(!get_socket_timestamp(&s).unwrap());
+
+    set_socket_timestampns(&s, true).unwrap();
+    assert!(get_socket_timestampns(&s).unwrap());
+    set_socket_timestampns(&s, false).unwrap();
+    assert!(!get_socket_timestampns(&s).unwrap());
+}
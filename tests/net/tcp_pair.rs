@@ -0,0 +1,50 @@
+use rustix::io::{read, write};
+use rustix::net::{getpeername, getsockname, tcp_pair};
+
+#[test]
+fn test_tcp_pair() {
+    let (client, server) = tcp_pair().unwrap();
+
+    assert_eq!(
+        getpeername(&client).unwrap(),
+        Some(getsockname(&server).unwrap())
+    );
+    assert_eq!(
+        getpeername(&server).unwrap(),
+        Some(getsockname(&client).unwrap())
+    );
+
+    #[cfg(feature = "fs")]
+    {
+        use rustix::fs::{fcntl_getfd, FdFlags};
+
+        assert!(fcntl_getfd(&client).unwrap().contains(FdFlags::CLOEXEC));
+        assert!(fcntl_getfd(&server).unwrap().contains(FdFlags::CLOEXEC));
+    }
+
+    let mut buf = [0_u8; 16];
+
+    assert_eq!(write(&client, b"ping").unwrap(), 4);
+    let n = read(&server, &mut buf).unwrap();
+    assert_eq!(&buf[..n], b"ping");
+
+    assert_eq!(write(&server, b"pong").unwrap(), 4);
+    let n = read(&client, &mut buf).unwrap();
+    assert_eq!(&buf[..n], b"pong");
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+fn test_tcp_info() {
+    use rustix::net::sockopt::{get_tcp_info, TcpState};
+
+    let (client, server) = tcp_pair().unwrap();
+    write(&client, b"hello").unwrap();
+
+    let info = get_tcp_info(&client).unwrap();
+    assert_eq!(info.state, TcpState::ESTABLISHED);
+    assert_ne!(info.snd_cwnd, 0);
+
+    let info = get_tcp_info(&server).unwrap();
+    assert_eq!(info.state, TcpState::ESTABLISHED);
+}
@@ -2,8 +2,8 @@
     all(target_os = "android", target_pointer_width = "64"),
     target_os = "linux"
 ))]
-use rustix::process::linux_hwcap;
-use rustix::process::{clock_ticks_per_second, page_size};
+use rustix::param::{auxv, linux_execfn, linux_hwcap, AuxvKind};
+use rustix::param::{clock_ticks_per_second, page_size};
 
 #[test]
 fn test_page_size() {
@@ -39,3 +39,29 @@ fn test_linux_hwcap() {
         assert_eq!(hwcap2, unsafe { libc_getauxval(libc::AT_HWCAP2) } as usize);
     }
 }
+
+#[test]
+#[cfg(any(
+    all(target_os = "android", target_pointer_width = "64"),
+    target_os = "linux"
+))]
+fn test_auxv() {
+    assert_eq!(auxv(AuxvKind::Pagesz), page_size());
+    assert_eq!(auxv(AuxvKind::Clktck) as u64, clock_ticks_per_second());
+    assert_eq!(
+        (auxv(AuxvKind::Hwcap), auxv(AuxvKind::Hwcap2)),
+        linux_hwcap()
+    );
+    assert_eq!(auxv(AuxvKind::Execfn), linux_execfn().as_ptr() as usize);
+    assert_ne!(auxv(AuxvKind::Phdr), 0);
+    assert_ne!(auxv(AuxvKind::Phnum), 0);
+
+    weak!(fn getauxval(libc::c_ulong) -> libc::c_ulong);
+
+    if let Some(libc_getauxval) = getauxval.get() {
+        assert_eq!(
+            auxv(AuxvKind::SysinfoEhdr),
+            unsafe { libc_getauxval(libc::AT_SYSINFO_EHDR) } as usize
+        );
+    }
+}
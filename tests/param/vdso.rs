@@ -0,0 +1,20 @@
+use rustix::param::vdso_sym;
+use rustix::zstr;
+
+#[test]
+fn test_vdso_sym() {
+    #[cfg(target_arch = "x86_64")]
+    let ptr = vdso_sym(zstr!("LINUX_2.6"), zstr!("__vdso_clock_gettime"));
+    #[cfg(target_arch = "aarch64")]
+    let ptr = vdso_sym(zstr!("LINUX_2.6.39"), zstr!("__kernel_clock_gettime"));
+    #[cfg(target_arch = "riscv64")]
+    let ptr = vdso_sym(zstr!("LINUX_4.15"), zstr!("__vdso_clock_gettime"));
+    #[cfg(any(
+        target_arch = "x86_64",
+        target_arch = "aarch64",
+        target_arch = "riscv64"
+    ))]
+    assert!(!ptr.is_null());
+
+    assert!(vdso_sym(zstr!("LINUX_2.6"), zstr!("__rustix_no_such_symbol")).is_null());
+}
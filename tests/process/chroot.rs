@@ -0,0 +1,7 @@
+#[test]
+fn test_chroot_nonexistent() {
+    assert_eq!(
+        rustix::process::chroot("/no/such/directory").unwrap_err(),
+        rustix::io::Errno::NOENT
+    );
+}
@@ -0,0 +1,36 @@
+use rustix::process::gethostname;
+
+#[test]
+fn test_gethostname() {
+    let mut buf = [0_u8; 256];
+    let len = gethostname(&mut buf).unwrap();
+    assert_eq!(&buf[..len], rustix::process::uname().nodename().to_bytes());
+
+    let mut libc_buf = [0_u8; 256];
+    assert_eq!(
+        unsafe { libc::gethostname(libc_buf.as_mut_ptr().cast(), libc_buf.len()) },
+        0
+    );
+    assert_eq!(&buf[..len], &libc_buf[..len]);
+    assert_eq!(libc_buf[len], 0);
+
+    if len > 0 {
+        let mut small = vec![0_u8; len - 1];
+        assert_eq!(gethostname(&mut small), Err(rustix::io::Errno::NAMETOOLONG));
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+fn test_sethostname_too_long() {
+    use rustix::process::sethostname;
+
+    // `HOST_NAME_MAX` is 64 on Linux. The kernel checks for `CAP_SYS_ADMIN`
+    // before checking the length, so this fails with `PERM` when
+    // unprivileged.
+    let name = [b'x'; 65];
+    match sethostname(&name) {
+        Err(rustix::io::Errno::INVAL) | Err(rustix::io::Errno::PERM) => (),
+        otherwise => panic!("unexpected result: {:?}", otherwise),
+    }
+}
@@ -63,3 +63,55 @@ fn test_getppid() {
         }
     }
 }
+
+#[test]
+fn test_getpgid() {
+    let pgid = process::getpgid(None).unwrap();
+    assert_eq!(pgid, process::getpgid(Some(process::getpid())).unwrap());
+    unsafe {
+        assert_eq!(process::Pid::as_raw(pgid), libc::getpgid(0) as _);
+    }
+}
+
+#[test]
+fn test_getsid() {
+    let sid = process::getsid(None).unwrap();
+    assert_eq!(sid, process::getsid(Some(process::getpid())).unwrap());
+    unsafe {
+        assert_eq!(process::Pid::as_raw(sid), libc::getsid(0) as _);
+    }
+}
+
+// This must execute serially with the tests in `wait.rs` so that they don't
+// reap our child before we do.
+#[test]
+#[serial_test::serial]
+fn test_setpgid_setsid() {
+    unsafe {
+        match libc::fork() {
+            -1 => panic!("fork failed"),
+            0 => {
+                // In the child, only make syscalls, and report the outcome
+                // in the exit status.
+                let pid = process::getpid();
+                let code = if process::setpgid(None, None).is_err() {
+                    1
+                } else if process::getpgid(None) != Ok(Some(pid)) {
+                    2
+                } else if process::setsid() != Err(rustix::io::Errno::PERM) {
+                    // We're a process group leader now, so this fails.
+                    3
+                } else {
+                    0
+                };
+                libc::_exit(code)
+            }
+            child => {
+                let mut status = 0;
+                assert_eq!(libc::waitpid(child, &mut status, 0), child);
+                assert!(libc::WIFEXITED(status));
+                assert_eq!(libc::WEXITSTATUS(status), 0);
+            }
+        }
+    }
+}
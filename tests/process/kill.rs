@@ -0,0 +1,52 @@
+use rustix::process::{self, Signal};
+use serial_test::serial;
+use std::os::unix::process::ExitStatusExt;
+use std::process::{Command, Stdio};
+
+#[test]
+fn test_test_kill_process() {
+    process::test_kill_process(process::getpid()).unwrap();
+    process::test_kill_current_process_group().unwrap();
+}
+
+#[test]
+fn test_test_kill_process_nonexistent() {
+    // This is above Linux's `PID_MAX_LIMIT`, so no process can have it.
+    let pid = unsafe { process::Pid::from_raw(0x7fff_ffff) }.unwrap();
+    assert_eq!(
+        process::test_kill_process(pid).unwrap_err(),
+        rustix::io::Errno::SRCH
+    );
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+fn test_signal_rt() {
+    assert_eq!(Signal::rt(0), Some(Signal::Rtmin));
+    assert_eq!(Signal::rt(5), Some(Signal::Rt5));
+    assert_eq!(Signal::rt(32), Some(Signal::Rt32));
+    assert_eq!(Signal::rt(33), None);
+    assert_eq!(Signal::Rt5 as i32, Signal::Rtmin as i32 + 5);
+    assert_eq!(Signal::from_raw(Signal::Rt32 as i32), Some(Signal::Rt32));
+}
+
+// This must execute serially with the tests in `wait.rs` so that they don't
+// reap our child before we do.
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+#[serial]
+fn test_kill_process_rt() {
+    let mut child = Command::new("sleep")
+        .arg("60")
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .expect("failed to execute child");
+    let pid = unsafe { process::Pid::from_raw(child.id() as _) }.unwrap();
+
+    // The default action for real-time signals is to terminate the process.
+    let sig = Signal::rt(5).unwrap();
+    process::kill_process(pid, sig).unwrap();
+    let status = child.wait().unwrap();
+    assert_eq!(status.signal(), Some(sig as i32));
+}
@@ -0,0 +1,17 @@
+use rustix::io;
+use rustix::process::{klogctl, KlogAction};
+
+#[test]
+fn test_klogctl() {
+    // Reading the log buffer may be restricted by `kernel.dmesg_restrict`.
+    let size = match klogctl(KlogAction::SizeBuffer, &mut []) {
+        Ok(size) => size,
+        Err(io::Errno::PERM) => return,
+        Err(err) => panic!("klogctl failed: {:?}", err),
+    };
+    assert!(size > 0);
+
+    let mut buf = vec![0_u8; 4096];
+    let n = klogctl(KlogAction::ReadAll, &mut buf).unwrap();
+    assert!(n <= buf.len());
+}
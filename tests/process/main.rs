@@ -5,22 +5,42 @@
 #![cfg_attr(target_os = "wasi", feature(wasi_ext))]
 #![cfg_attr(io_lifetimes_use_std, feature(io_safety))]
 
-#[cfg(not(target_os = "wasi"))]
-#[macro_use]
-mod weak;
-
-#[cfg(not(target_os = "wasi"))]
-mod auxv;
+#[cfg(not(any(target_os = "fuchsia", target_os = "wasi")))]
+mod chroot;
 mod cpu_set;
+#[cfg(not(target_os = "wasi"))] // WASI doesn't have uname.
+mod hostname;
 #[cfg(not(target_os = "wasi"))] // WASI doesn't have get[gpu]id.
 mod id;
+#[cfg(not(target_os = "wasi"))] // WASI doesn't have kill.
+mod kill;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod klogctl;
 #[cfg(any(target_os = "android", target_os = "linux"))]
 mod membarrier;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod personality;
+#[cfg(feature = "fs")]
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod pidfd;
 #[cfg(not(any(target_os = "fuchsia", target_os = "wasi")))] // WASI doesn't have [gs]etpriority.
 mod priority;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod process_vm;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod ptrace;
 #[cfg(not(any(target_os = "fuchsia", target_os = "redox", target_os = "wasi")))]
 mod rlimit;
+#[cfg(not(any(target_os = "fuchsia", target_os = "redox", target_os = "wasi")))]
+mod rusage;
 mod sched_yield;
+#[cfg(not(target_os = "wasi"))]
+mod signal;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod sysinfo;
+#[cfg(feature = "fs")]
+#[cfg(not(target_os = "wasi"))]
+mod umask;
 #[cfg(not(target_os = "wasi"))] // WASI doesn't have uname.
 mod uname;
 #[cfg(not(target_os = "wasi"))] // WASI doesn't have waitpid.
@@ -0,0 +1,21 @@
+use rustix::io;
+use rustix::process::{personality, personality_query, Personality};
+
+#[test]
+fn test_personality() {
+    let prev = personality_query().unwrap();
+    assert_eq!(personality_query().unwrap(), prev);
+
+    match personality(prev | Personality::ADDR_NO_RANDOMIZE) {
+        Ok(old) => assert_eq!(old, prev),
+        // Container seccomp profiles often only allow querying.
+        Err(io::Errno::PERM) => return,
+        Err(err) => panic!("personality failed: {:?}", err),
+    }
+    assert!(personality_query()
+        .unwrap()
+        .contains(Personality::ADDR_NO_RANDOMIZE));
+
+    personality(prev).unwrap();
+    assert_eq!(personality_query().unwrap(), prev);
+}
@@ -0,0 +1,29 @@
+use rustix::fd::AsRawFd;
+use rustix::fs::fstat;
+use rustix::io;
+use rustix::process::{getpid, pidfd_getfd, pidfd_open, PidfdFlags, PidfdGetfdFlags};
+
+#[test]
+fn test_pidfd_getfd() {
+    let pidfd = match pidfd_open(getpid(), PidfdFlags::empty()) {
+        Ok(pidfd) => pidfd,
+        Err(io::Errno::NOSYS) => return,
+        Err(err) => panic!("pidfd_open failed: {:?}", err),
+    };
+
+    let file = tempfile::tempfile().unwrap();
+    let fd = match pidfd_getfd(&pidfd, file.as_raw_fd(), PidfdGetfdFlags::empty()) {
+        Ok(fd) => fd,
+        // `pidfd_getfd` is newer than `pidfd_open`, and may be restricted by
+        // ptrace policy.
+        Err(io::Errno::NOSYS) | Err(io::Errno::PERM) => return,
+        Err(err) => panic!("pidfd_getfd failed: {:?}", err),
+    };
+    assert_ne!(fd.as_raw_fd(), file.as_raw_fd());
+    assert_eq!(fstat(&fd).unwrap().st_ino, fstat(&file).unwrap().st_ino);
+
+    assert_eq!(
+        pidfd_getfd(&pidfd, -1, PidfdGetfdFlags::empty()).map(|_| ()),
+        Err(io::Errno::BADF)
+    );
+}
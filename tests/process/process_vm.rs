@@ -0,0 +1,82 @@
+use rustix::io::{self, IoSlice, IoSliceMut};
+use rustix::process::{getpid, process_vm_readv, process_vm_writev, RemoteIoVec};
+
+#[test]
+fn test_process_vm_readv() {
+    let data = *b"hello world";
+    let mut a = [0_u8; 5];
+    let mut b = [0_u8; 6];
+    let remote = [RemoteIoVec {
+        base: data.as_ptr() as usize,
+        len: data.len(),
+    }];
+    let n = match process_vm_readv(
+        getpid(),
+        &mut [IoSliceMut::new(&mut a), IoSliceMut::new(&mut b)],
+        &remote,
+    ) {
+        Ok(n) => n,
+        // `process_vm_readv` may be disabled by seccomp.
+        Err(io::Errno::NOSYS) | Err(io::Errno::PERM) => return,
+        Err(err) => panic!("process_vm_readv failed: {:?}", err),
+    };
+    assert_eq!(n, data.len());
+    assert_eq!(&a, b"hello");
+    assert_eq!(&b, b" world");
+}
+
+#[test]
+fn test_process_vm_readv_partial() {
+    let data = *b"hello";
+    let mut buf = [0_u8; 16];
+    let remote = [
+        RemoteIoVec {
+            base: data.as_ptr() as usize,
+            len: data.len(),
+        },
+        // Nothing is mapped at address zero.
+        RemoteIoVec { base: 0, len: 8 },
+    ];
+    match process_vm_readv(getpid(), &mut [IoSliceMut::new(&mut buf)], &remote) {
+        Ok(n) => {
+            assert_eq!(n, data.len());
+            assert_eq!(&buf[..n], b"hello");
+        }
+        Err(io::Errno::NOSYS) | Err(io::Errno::PERM) => (),
+        Err(err) => panic!("process_vm_readv failed: {:?}", err),
+    }
+
+    // If nothing can be read, the error is reported.
+    match process_vm_readv(
+        getpid(),
+        &mut [IoSliceMut::new(&mut buf)],
+        &[RemoteIoVec { base: 0, len: 8 }],
+    ) {
+        Err(io::Errno::FAULT) | Err(io::Errno::NOSYS) | Err(io::Errno::PERM) => (),
+        otherwise => panic!("unexpected result: {:?}", otherwise),
+    }
+}
+
+#[test]
+fn test_process_vm_writev() {
+    let mut target = [0_u8; 11];
+    let remote = [RemoteIoVec {
+        base: target.as_mut_ptr() as usize,
+        len: target.len(),
+    }];
+    // Safety: `target` is valid for writes, and isn't accessed during the
+    // call.
+    let n = match unsafe {
+        process_vm_writev(
+            getpid(),
+            &[IoSlice::new(b"hello"), IoSlice::new(b" world")],
+            &remote,
+        )
+    } {
+        Ok(n) => n,
+        Err(io::Errno::NOSYS) | Err(io::Errno::PERM) => return,
+        Err(err) => panic!("process_vm_writev failed: {:?}", err),
+    };
+    assert_eq!(n, target.len());
+    assert_eq!(&target, b"hello world");
+}
@@ -0,0 +1,129 @@
+use libc::{kill, SIGKILL, SIGSTOP};
+use rustix::io;
+use rustix::process::{self, ptrace, Pid, WaitOptions};
+use serial_test::serial;
+use std::os::unix::process::ExitStatusExt;
+use std::process::{Command, Stdio};
+
+// These tests must execute serially with the `wait` tests, so that
+// `test_wait` doesn't reap the children spawned here.
+
+static MAGIC: usize = 0x1234_5678;
+
+/// Fork a child which asks to be traced and then stops itself, and wait for
+/// it to stop.
+fn fork_tracee() -> Pid {
+    let pid = unsafe { libc::fork() };
+    assert!(pid >= 0, "fork failed");
+    if pid == 0 {
+        unsafe {
+            if ptrace::traceme().is_err() {
+                libc::_exit(1);
+            }
+            kill(libc::getpid(), SIGSTOP);
+            libc::_exit(0);
+        }
+    }
+
+    let pid = unsafe { Pid::from_raw(pid as _) }.unwrap();
+    let status = process::waitpid(Some(pid), WaitOptions::empty())
+        .expect("failed to wait")
+        .unwrap();
+    assert!(status.stopped());
+    assert_eq!(status.stopping_signal(), Some(SIGSTOP as _));
+    pid
+}
+
+fn finish_tracee(pid: Pid) {
+    ptrace::cont(pid, None).unwrap();
+    let status = process::waitpid(Some(pid), WaitOptions::empty())
+        .expect("failed to wait")
+        .unwrap();
+    assert_eq!(status.exit_status(), Some(0));
+}
+
+#[test]
+#[serial]
+fn test_ptrace_traceme_peek() {
+    let pid = fork_tracee();
+
+    // The child is a copy of this process, so `MAGIC` is at the same address.
+    let word = ptrace::peek(pid, &MAGIC as *const usize as usize).unwrap();
+    assert_eq!(word, MAGIC);
+
+    // Nothing is mapped at address zero.
+    assert_eq!(ptrace::peek(pid, 0), Err(io::Errno::IO));
+
+    finish_tracee(pid);
+}
+
+#[cfg(any(
+    target_arch = "aarch64",
+    target_arch = "arm",
+    target_arch = "riscv64",
+    target_arch = "x86",
+    target_arch = "x86_64",
+))]
+#[test]
+#[serial]
+fn test_ptrace_getregs_setregs() {
+    let pid = fork_tracee();
+
+    let regs = ptrace::getregs(pid).unwrap();
+    unsafe { ptrace::setregs(pid, &regs) }.unwrap();
+    assert_eq!(ptrace::getregs(pid).unwrap(), regs);
+
+    finish_tracee(pid);
+}
+
+#[test]
+#[serial]
+fn test_ptrace_attach_detach() {
+    let mut child = Command::new("yes")
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .expect("failed to execute child");
+    let pid = unsafe { Pid::from_raw(child.id() as _) }.unwrap();
+
+    match ptrace::attach(pid) {
+        Ok(()) => {
+            let status = process::waitpid(Some(pid), WaitOptions::empty())
+                .expect("failed to wait")
+                .unwrap();
+            assert!(status.stopped());
+            ptrace::detach(pid, None).unwrap();
+        }
+        // Attaching may be forbidden by Yama or seccomp.
+        Err(io::Errno::PERM) | Err(io::Errno::NOSYS) => {}
+        Err(err) => panic!("ptrace attach failed: {:?}", err),
+    }
+
+    unsafe { kill(child.id() as _, SIGKILL) };
+    let status = child.wait().expect("failed to wait");
+    assert_eq!(status.signal(), Some(SIGKILL));
+}
+
+#[test]
+#[serial]
+fn test_ptrace_seize() {
+    let mut child = Command::new("yes")
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .expect("failed to execute child");
+    let pid = unsafe { Pid::from_raw(child.id() as _) }.unwrap();
+
+    match ptrace::seize(pid, ptrace::PtraceOptions::EXITKILL) {
+        // A seized tracee isn't stopped, so requests which need a stopped
+        // tracee fail.
+        Ok(()) => assert_eq!(ptrace::cont(pid, None), Err(io::Errno::SRCH)),
+        // Attaching may be forbidden by Yama or seccomp.
+        Err(io::Errno::PERM) | Err(io::Errno::NOSYS) => {}
+        Err(err) => panic!("ptrace seize failed: {:?}", err),
+    }
+
+    unsafe { kill(child.id() as _, SIGKILL) };
+    let status = child.wait().expect("failed to wait");
+    assert_eq!(status.signal(), Some(SIGKILL));
+}
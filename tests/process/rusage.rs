@@ -0,0 +1,39 @@
+use rustix::process::{getrusage, RusageWho};
+
+#[test]
+fn test_getrusage() {
+    let usage = getrusage(RusageWho::Self_);
+    assert!(usage.ru_utime.tv_usec < 1_000_000);
+    assert!(usage.ru_stime.tv_usec < 1_000_000);
+    assert!(usage.ru_maxrss > 0);
+    assert!(usage.ru_minflt > 0);
+
+    let _ = getrusage(RusageWho::Children);
+}
+
+#[test]
+fn test_getrusage_matches_libc() {
+    let usage = getrusage(RusageWho::Self_);
+    let mut libc_usage = unsafe { core::mem::zeroed::<libc::rusage>() };
+    assert_eq!(
+        unsafe { libc::getrusage(libc::RUSAGE_SELF, &mut libc_usage) },
+        0
+    );
+
+    // These counters only go up, so our earlier call can't see more.
+    assert!(usage.ru_minflt <= libc_usage.ru_minflt as u64);
+    assert!(usage.ru_maxrss <= libc_usage.ru_maxrss as u64);
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+fn test_getrusage_thread() {
+    let thread = std::thread::spawn(|| getrusage(RusageWho::Thread))
+        .join()
+        .unwrap();
+
+    // The process-wide statistics include those of the thread, which has
+    // exited by now, so a later call for the process can't see less.
+    let process = getrusage(RusageWho::Self_);
+    assert!(thread.ru_minflt <= process.ru_minflt);
+}
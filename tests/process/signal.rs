@@ -0,0 +1,22 @@
+use rustix::process::{SigSet, Signal};
+
+#[test]
+fn test_sig_set() {
+    let mut set = SigSet::new();
+    assert!(!set.contains(Signal::Usr1));
+    assert!(!set.contains(Signal::Term));
+
+    set.add(Signal::Usr1);
+    set.add(Signal::Term);
+    assert!(set.contains(Signal::Usr1));
+    assert!(set.contains(Signal::Term));
+    assert!(!set.contains(Signal::Usr2));
+
+    set.remove(Signal::Usr1);
+    assert!(!set.contains(Signal::Usr1));
+    assert!(set.contains(Signal::Term));
+    assert_ne!(set, SigSet::new());
+
+    set.remove(Signal::Term);
+    assert_eq!(set, SigSet::default());
+}
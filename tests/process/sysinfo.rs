@@ -0,0 +1,20 @@
+use rustix::process::sysinfo;
+
+#[test]
+fn test_sysinfo() {
+    let info = sysinfo().unwrap();
+    let libc_info = unsafe {
+        let mut libc_info = std::mem::zeroed::<libc::sysinfo>();
+        assert_eq!(libc::sysinfo(&mut libc_info), 0);
+        libc_info
+    };
+
+    assert_ne!(info.mem_unit, 0);
+    assert_eq!(info.mem_unit, libc_info.mem_unit);
+    assert_eq!(info.totalram, libc_info.totalram as u64);
+    assert_eq!(info.totalswap, libc_info.totalswap as u64);
+    assert!(info.freeram <= info.totalram);
+    assert!(info.freeswap <= info.totalswap);
+    assert!(info.procs > 0);
+    assert!(info.uptime >= 0);
+}
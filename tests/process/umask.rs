@@ -0,0 +1,36 @@
+use rustix::fs::Mode;
+use rustix::process::umask;
+use serial_test::serial;
+
+// The umask is process-wide, so change it in a child process to avoid
+// affecting other tests that create files. This must execute serially with
+// the tests in `wait.rs` so that they don't reap our child before we do.
+#[test]
+#[serial]
+fn test_umask() {
+    unsafe {
+        match libc::fork() {
+            -1 => panic!("fork failed"),
+            0 => {
+                // In the child, only make syscalls, and report the outcome
+                // in the exit status.
+                let mask = Mode::RWXG | Mode::RWXO;
+                let old = umask(mask);
+                let code = if umask(old) != mask {
+                    1
+                } else if umask(old) != old {
+                    2
+                } else {
+                    0
+                };
+                libc::_exit(code)
+            }
+            child => {
+                let mut status = 0;
+                assert_eq!(libc::waitpid(child, &mut status, 0), child);
+                assert!(libc::WIFEXITED(status));
+                assert_eq!(libc::WEXITSTATUS(status), 0);
+            }
+        }
+    }
+}
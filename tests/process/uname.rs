@@ -11,3 +11,40 @@ fn test_uname() {
     #[cfg(any(target_os = "android", target_os = "linux"))]
     assert!(!name.domainname().to_bytes().is_empty());
 }
+
+#[test]
+fn test_uname_matches_libc() {
+    use std::ffi::CStr;
+
+    let name = rustix::process::uname();
+    let libc_name = unsafe {
+        let mut libc_name = std::mem::zeroed::<libc::utsname>();
+        assert_eq!(libc::uname(&mut libc_name), 0);
+        libc_name
+    };
+
+    // Each field is trimmed at its NUL terminator.
+    unsafe {
+        assert_eq!(
+            name.sysname().to_bytes(),
+            CStr::from_ptr(libc_name.sysname.as_ptr()).to_bytes()
+        );
+        assert_eq!(
+            name.release().to_bytes(),
+            CStr::from_ptr(libc_name.release.as_ptr()).to_bytes()
+        );
+        assert_eq!(
+            name.version().to_bytes(),
+            CStr::from_ptr(libc_name.version.as_ptr()).to_bytes()
+        );
+        assert_eq!(
+            name.machine().to_bytes(),
+            CStr::from_ptr(libc_name.machine.as_ptr()).to_bytes()
+        );
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        assert_eq!(
+            name.domainname().to_bytes(),
+            CStr::from_ptr(libc_name.domainname.as_ptr()).to_bytes()
+        );
+    }
+}
@@ -23,3 +23,33 @@ fn test_waitpid() {
         .unwrap();
     assert!(status.stopped());
 }
+
+#[cfg(not(any(
+    target_os = "fuchsia",
+    target_os = "illumos",
+    target_os = "redox",
+    target_os = "wasi"
+)))]
+#[test]
+#[serial]
+fn test_wait4() {
+    let child = Command::new("yes")
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .expect("failed to execute child");
+    let pid = unsafe { process::Pid::from_raw(child.id() as _) };
+
+    // The child is still running, so `NOHANG` finds nothing to reap.
+    assert!(process::wait4(pid, process::WaitOptions::NOHANG)
+        .expect("failed to wait")
+        .is_none());
+
+    unsafe { kill(child.id() as _, libc::SIGKILL) };
+    let (status, usage) = process::wait4(pid, process::WaitOptions::empty())
+        .expect("failed to wait")
+        .unwrap();
+    assert_eq!(status.terminating_signal(), Some(libc::SIGKILL as _));
+    assert!(usage.ru_utime.tv_usec < 1_000_000);
+    assert!(usage.ru_maxrss > 0);
+}
@@ -39,3 +39,35 @@ fn test_changing_working_directory() {
         "The cwd wasn't changed back to the its original position"
     );
 }
+
+#[test]
+fn test_getcwd_raw() {
+    let cwd = rustix::process::getcwd(Vec::new()).expect("get the cwd");
+
+    let mut buf = [0_u8; 4096];
+    let raw = rustix::process::getcwd_raw(&mut buf).expect("get the cwd");
+    assert_eq!(raw, &*cwd);
+
+    let mut small = [0_u8; 1];
+    assert_eq!(
+        rustix::process::getcwd_raw(&mut small),
+        Err(rustix::io::Errno::RANGE)
+    );
+}
+
+#[cfg(not(any(target_os = "fuchsia", target_os = "macos")))]
+#[test]
+fn test_fchdir_not_dir() {
+    let tmpdir = tmpdir();
+    let file = rustix::fs::openat(
+        rustix::fs::cwd(),
+        tmpdir.path().join("file"),
+        OFlags::WRONLY | OFlags::CREATE | OFlags::CLOEXEC,
+        Mode::RUSR | Mode::WUSR,
+    )
+    .unwrap();
+    assert_eq!(
+        rustix::process::fchdir(&file),
+        Err(rustix::io::Errno::NOTDIR)
+    );
+}
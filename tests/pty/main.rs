@@ -0,0 +1,7 @@
+//! Tests for [`rustix::pty`].
+
+#![cfg(feature = "pty")]
+#![cfg(any(target_os = "android", target_os = "linux"))]
+#![cfg_attr(io_lifetimes_use_std, feature(io_safety))]
+
+mod openpt;
@@ -0,0 +1,52 @@
+use rustix::fs::{cwd, openat, Mode, OFlags};
+use rustix::io;
+use rustix::pty::{grantpt, openpt, openpt_unlocked, ptsname, unlockpt, OpenptFlags};
+
+/// Return `None` if pseudoterminals aren't available, such as in some
+/// containers.
+fn available<T>(result: io::Result<T>) -> Option<T> {
+    match result {
+        Ok(t) => Some(t),
+        Err(io::Errno::NOENT) | Err(io::Errno::ACCESS) => None,
+        Err(err) => panic!("failed to open a pseudoterminal: {:?}", err),
+    }
+}
+
+#[test]
+fn test_openpt() {
+    let master = match available(openpt(
+        OpenptFlags::RDWR | OpenptFlags::NOCTTY | OpenptFlags::CLOEXEC,
+    )) {
+        Some(master) => master,
+        None => return,
+    };
+    grantpt(&master).unwrap();
+    unlockpt(&master).unwrap();
+
+    let name = ptsname(&master, Vec::new()).unwrap();
+    assert!(name.to_bytes().starts_with(b"/dev/pts/"));
+
+    // Writes to the slave are readable from the master.
+    let slave = openat(cwd(), &name, OFlags::RDWR | OFlags::NOCTTY, Mode::empty()).unwrap();
+    assert_eq!(io::write(&slave, b"x").unwrap(), 1);
+    let mut buf = [0_u8; 1];
+    assert_eq!(io::read(&master, &mut buf).unwrap(), 1);
+    assert_eq!(&buf, b"x");
+}
+
+#[test]
+fn test_openpt_unlocked() {
+    let (_master, name) = match available(openpt_unlocked(OpenptFlags::RDWR | OpenptFlags::NOCTTY))
+    {
+        Some(pair) => pair,
+        None => return,
+    };
+    openat(cwd(), &name, OFlags::RDWR | OFlags::NOCTTY, Mode::empty()).unwrap();
+}
+
+#[test]
+fn test_pty_not_a_master() {
+    let file = tempfile::tempfile().unwrap();
+    assert_eq!(grantpt(&file), Err(io::Errno::NOTTY));
+    assert_eq!(ptsname(&file, Vec::new()).unwrap_err(), io::Errno::NOTTY);
+}
@@ -0,0 +1,32 @@
+use rustix::fd::FromRawFd;
+use rustix::io::{Errno, OwnedFd};
+use rustix::process::{waitpid, Signal, WaitOptions};
+use rustix::runtime::{clone3, exit_group, CloneArgs, CloneFlags, Fork};
+
+#[test]
+fn test_clone3_pidfd() {
+    let mut pidfd = -1;
+    let mut args = CloneArgs {
+        flags: CloneFlags::PIDFD,
+        pidfd: Some(&mut pidfd),
+        child_tid: core::ptr::null_mut(),
+        parent_tid: core::ptr::null_mut(),
+        exit_signal: Some(Signal::Child),
+        stack: core::ptr::null_mut(),
+        stack_size: 0,
+        tls: core::ptr::null_mut(),
+    };
+    match unsafe { clone3(&mut args) } {
+        // Only call async-signal-safe functions in the child.
+        Ok(Fork::Child) => exit_group(7),
+        Ok(Fork::Parent(pid)) => {
+            assert!(pidfd >= 0);
+            let _pidfd = unsafe { OwnedFd::from_raw_fd(pidfd) };
+            let status = waitpid(Some(pid), WaitOptions::empty()).unwrap().unwrap();
+            assert_eq!(status.exit_status(), Some(7));
+        }
+        // Linux before 5.3, or a seccomp filter, may not support `clone3`.
+        Err(Errno::NOSYS) | Err(Errno::PERM) => {}
+        Err(err) => panic!("{:?}", err),
+    }
+}
@@ -0,0 +1,43 @@
+use rustix::fs::{cwd, openat, AtFlags, Mode, OFlags};
+use rustix::io::Errno;
+use rustix::runtime::{execve, execveat};
+use rustix::zstr;
+
+#[test]
+fn test_execve_nonexistent() {
+    let argv = [b"nonexistent\0".as_ptr(), core::ptr::null()];
+    let envp = [core::ptr::null()];
+    let err = unsafe { execve(zstr!("/no/such/executable"), &argv, &envp) }.unwrap_err();
+    assert_eq!(err, Errno::NOENT);
+}
+
+#[test]
+fn test_execve_unterminated() {
+    let argv = [b"true\0".as_ptr()];
+    let envp = [core::ptr::null()];
+    let err = unsafe { execve(zstr!("/bin/true"), &argv, &envp) }.unwrap_err();
+    assert_eq!(err, Errno::INVAL);
+
+    let argv = [b"true\0".as_ptr(), core::ptr::null()];
+    let err = unsafe { execve(zstr!("/bin/true"), &argv, &[]) }.unwrap_err();
+    assert_eq!(err, Errno::INVAL);
+}
+
+#[test]
+fn test_execveat_empty_path_not_executable() {
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(cwd(), tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+    let file = openat(
+        &dir,
+        "file",
+        OFlags::CREATE | OFlags::WRONLY,
+        Mode::RUSR | Mode::WUSR,
+    )
+    .unwrap();
+
+    // The file isn't executable, so this fails rather than replacing us.
+    let argv = [b"file\0".as_ptr(), core::ptr::null()];
+    let envp = [core::ptr::null()];
+    let err = unsafe { execveat(&file, zstr!(""), &argv, &envp, AtFlags::EMPTY_PATH) }.unwrap_err();
+    assert_eq!(err, Errno::ACCESS);
+}
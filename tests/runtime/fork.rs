@@ -0,0 +1,28 @@
+use rustix::process::{getpid, waitpid, WaitOptions};
+use rustix::runtime::{exit_group, fork, Fork};
+
+#[test]
+fn test_fork_getpid() {
+    let parent = getpid();
+    match unsafe { fork() }.unwrap() {
+        // `getpid` isn't cached, so the child sees its own pid.
+        Fork::Child => exit_group(if getpid() != parent { 0 } else { 1 }),
+        Fork::Parent(pid) => {
+            assert_ne!(pid, parent);
+            let status = waitpid(Some(pid), WaitOptions::empty()).unwrap().unwrap();
+            assert_eq!(status.exit_status(), Some(0));
+        }
+    }
+}
+
+#[test]
+fn test_fork() {
+    match unsafe { fork() }.unwrap() {
+        // Only call async-signal-safe functions in the child.
+        Fork::Child => exit_group(42),
+        Fork::Parent(pid) => {
+            let status = waitpid(Some(pid), WaitOptions::empty()).unwrap().unwrap();
+            assert_eq!(status.exit_status(), Some(42));
+        }
+    }
+}
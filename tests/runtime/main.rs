@@ -0,0 +1,23 @@
+//! Tests for [`rustix::runtime`].
+
+#![cfg(feature = "runtime")]
+#![cfg(not(windows))]
+#![cfg_attr(io_lifetimes_use_std, feature(io_safety))]
+
+#[cfg(feature = "process")]
+#[cfg(linux_raw)]
+mod clone3;
+#[cfg(feature = "fs")]
+#[cfg(linux_raw)]
+mod exec;
+#[cfg(feature = "process")]
+#[cfg(linux_raw)]
+mod fork;
+#[cfg(feature = "process")]
+#[cfg(linux_raw)]
+mod sigaction;
+#[cfg(linux_raw)]
+mod sigaltstack;
+#[cfg(feature = "process")]
+#[cfg(linux_raw)]
+mod tid;
@@ -0,0 +1,49 @@
+use rustix::process::{getpid, kill_process, SigSet, Signal};
+use rustix::runtime::{sigaction, SigHandler, Sigaction, SigactionFlags};
+
+#[test]
+fn test_sigaction_ignore() {
+    let ignore = Sigaction {
+        handler: SigHandler::Ignore,
+        mask: SigSet::new(),
+        flags: SigactionFlags::empty(),
+        restorer: None,
+    };
+    let old = unsafe { sigaction(Signal::Usr2, Some(&ignore)) }.unwrap();
+    assert!(matches!(old.handler, SigHandler::Default));
+
+    // The signal is ignored, so sending it to ourselves is harmless.
+    kill_process(getpid(), Signal::Usr2).unwrap();
+
+    let current = unsafe { sigaction(Signal::Usr2, Some(&old)) }.unwrap();
+    assert!(matches!(current.handler, SigHandler::Ignore));
+}
+
+#[test]
+fn test_sigaction_handler() {
+    unsafe extern "C" fn handler(_sig: i32) {}
+
+    let mut mask = SigSet::new();
+    mask.add(Signal::Term);
+    let action = Sigaction {
+        handler: SigHandler::Handler(handler),
+        mask,
+        flags: SigactionFlags::RESTART,
+        restorer: None,
+    };
+    let old = unsafe { sigaction(Signal::Usr1, Some(&action)) }.unwrap();
+
+    // Query without changing anything, and check that we read back what we
+    // installed.
+    let current = unsafe { sigaction(Signal::Usr1, None) }.unwrap();
+    match current.handler {
+        SigHandler::Handler(h) => {
+            assert_eq!(h as usize, handler as unsafe extern "C" fn(i32) as usize)
+        }
+        other => panic!("unexpected handler: {:?}", other),
+    }
+    assert!(current.mask.contains(Signal::Term));
+    assert!(current.flags.contains(SigactionFlags::RESTART));
+
+    unsafe { sigaction(Signal::Usr1, Some(&old)) }.unwrap();
+}
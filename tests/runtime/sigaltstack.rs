@@ -0,0 +1,56 @@
+use rustix::runtime::{sigaltstack, Stack, StackFlags};
+
+#[test]
+fn test_sigaltstack() {
+    // Use a fresh thread, so that we don't leave an alternate stack installed
+    // on a thread which outlives the buffer. Note that std may have already
+    // installed its own alternate stack on it.
+    std::thread::spawn(|| {
+        let old = unsafe { sigaltstack(None) }.unwrap();
+
+        let mut buf = vec![0_u8; 64 * 1024];
+        let new = Stack {
+            ss_sp: buf.as_mut_ptr().cast(),
+            ss_flags: StackFlags::empty(),
+            ss_size: buf.len(),
+        };
+        unsafe { sigaltstack(Some(&new)) }.unwrap();
+
+        let current = unsafe { sigaltstack(None) }.unwrap();
+        assert_eq!(current.ss_sp, new.ss_sp);
+        assert_eq!(current.ss_size, new.ss_size);
+        assert!(!current.ss_flags.contains(StackFlags::DISABLE));
+        assert!(!current.ss_flags.contains(StackFlags::ONSTACK));
+
+        let disable = Stack {
+            ss_sp: core::ptr::null_mut(),
+            ss_flags: StackFlags::DISABLE,
+            ss_size: 0,
+        };
+        unsafe { sigaltstack(Some(&disable)) }.unwrap();
+        let current = unsafe { sigaltstack(None) }.unwrap();
+        assert!(current.ss_flags.contains(StackFlags::DISABLE));
+
+        unsafe { sigaltstack(Some(&old)) }.unwrap();
+    })
+    .join()
+    .unwrap();
+}
+
+#[test]
+fn test_sigaltstack_too_small() {
+    std::thread::spawn(|| {
+        let mut buf = [0_u8; 16];
+        let new = Stack {
+            ss_sp: buf.as_mut_ptr().cast(),
+            ss_flags: StackFlags::empty(),
+            ss_size: buf.len(),
+        };
+        assert_eq!(
+            unsafe { sigaltstack(Some(&new)) }.unwrap_err(),
+            rustix::io::Errno::NOMEM
+        );
+    })
+    .join()
+    .unwrap();
+}
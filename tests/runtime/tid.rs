@@ -0,0 +1,19 @@
+use rustix::process::{getpid, waitpid, WaitOptions};
+use rustix::runtime::{exit_group, fork, set_tid_address, Fork};
+
+#[test]
+fn test_set_tid_address() {
+    // Do this in a child process, so that we don't clobber the pointer the
+    // threading library set up for this thread.
+    match unsafe { fork() }.unwrap() {
+        Fork::Child => {
+            // The child is single-threaded, so its tid is its pid.
+            let tid = unsafe { set_tid_address(core::ptr::null_mut()) };
+            exit_group(if tid == getpid() { 0 } else { 1 })
+        }
+        Fork::Parent(pid) => {
+            let status = waitpid(Some(pid), WaitOptions::empty()).unwrap().unwrap();
+            assert_eq!(status.exit_status(), Some(0));
+        }
+    }
+}
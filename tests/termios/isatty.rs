@@ -67,3 +67,29 @@ fn stdio_descriptors() {
         );
     }
 }
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg(feature = "fs")]
+#[test]
+fn pty_winsize() {
+    use rustix::termios::{tcsetwinsize, Winsize};
+
+    let pty = match crate::open_ptmx() {
+        Some(pty) => pty,
+        None => return,
+    };
+    assert!(isatty(&pty));
+
+    let winsize = Winsize {
+        ws_row: 24,
+        ws_col: 80,
+        ws_xpixel: 640,
+        ws_ypixel: 480,
+    };
+    tcsetwinsize(&pty, winsize).unwrap();
+    let new = tcgetwinsize(&pty).unwrap();
+    assert_eq!(new.ws_row, 24);
+    assert_eq!(new.ws_col, 80);
+    assert_eq!(new.ws_xpixel, 640);
+    assert_eq!(new.ws_ypixel, 480);
+}
@@ -6,3 +6,31 @@
 
 #[cfg(not(windows))]
 mod isatty;
+#[cfg(not(any(windows, target_os = "wasi")))]
+#[cfg(feature = "process")]
+mod pgrp;
+#[cfg(not(any(windows, target_os = "wasi")))]
+mod tc;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg(feature = "fs")]
+mod termios;
+
+/// Open a pseudoterminal master, or return `None` if `/dev/ptmx` isn't
+/// available, such as in some containers.
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg(feature = "fs")]
+fn open_ptmx() -> Option<rustix::io::OwnedFd> {
+    use rustix::fs::{cwd, openat, Mode, OFlags};
+    use rustix::io;
+
+    match openat(
+        cwd(),
+        "/dev/ptmx",
+        OFlags::RDWR | OFlags::NOCTTY | OFlags::CLOEXEC,
+        Mode::empty(),
+    ) {
+        Ok(pty) => Some(pty),
+        Err(io::Errno::NOENT) | Err(io::Errno::ACCESS) => None,
+        Err(err) => panic!("failed to open /dev/ptmx: {:?}", err),
+    }
+}
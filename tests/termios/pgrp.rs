@@ -0,0 +1,74 @@
+use rustix::io;
+use rustix::process::getpid;
+use rustix::termios::{ioctl_tiocsctty, tcgetpgrp, tcsetpgrp};
+
+#[test]
+fn test_tcgetpgrp_not_a_tty() {
+    let file = tempfile::tempfile().unwrap();
+    assert_eq!(tcgetpgrp(&file), Err(io::Errno::NOTTY));
+}
+
+#[test]
+fn test_tcsetpgrp_not_a_tty() {
+    let file = tempfile::tempfile().unwrap();
+    assert_eq!(tcsetpgrp(&file, getpid()), Err(io::Errno::NOTTY));
+}
+
+#[test]
+fn test_ioctl_tiocsctty_not_a_tty() {
+    let file = tempfile::tempfile().unwrap();
+    assert_eq!(ioctl_tiocsctty(&file), Err(io::Errno::NOTTY));
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg(feature = "pty")]
+#[test]
+fn test_pty_controlling_terminal() {
+    use rustix::fs::{cwd, openat, Mode, OFlags};
+    use rustix::process::setsid;
+    use rustix::pty::{grantpt, ptsname, unlockpt};
+
+    let master = match crate::open_ptmx() {
+        Some(master) => master,
+        None => return,
+    };
+    grantpt(&master).unwrap();
+    unlockpt(&master).unwrap();
+    let name = ptsname(&master, Vec::new()).unwrap();
+
+    unsafe {
+        match libc::fork() {
+            -1 => panic!("fork failed"),
+            0 => {
+                // In the child, only make syscalls, and report the outcome
+                // in the exit status.
+                let code = if setsid().is_err() {
+                    1
+                } else {
+                    match openat(cwd(), &name, OFlags::RDWR | OFlags::NOCTTY, Mode::empty()) {
+                        Err(_) => 2,
+                        Ok(slave) => {
+                            let pid = getpid();
+                            if ioctl_tiocsctty(&slave).is_err() {
+                                3
+                            } else if tcsetpgrp(&slave, pid).is_err() {
+                                4
+                            } else if tcgetpgrp(&slave) != Ok(pid) {
+                                5
+                            } else {
+                                0
+                            }
+                        }
+                    }
+                };
+                libc::_exit(code)
+            }
+            child => {
+                let mut status = 0;
+                assert_eq!(libc::waitpid(child, &mut status, 0), child);
+                assert!(libc::WIFEXITED(status));
+                assert_eq!(libc::WEXITSTATUS(status), 0);
+            }
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use rustix::io;
+use rustix::termios::{tcdrain, tcflow, tcflush, tcsendbreak, Action, QueueSelector};
+
+#[test]
+fn test_tc_not_a_tty() {
+    let file = tempfile::tempfile().unwrap();
+    assert_eq!(tcdrain(&file), Err(io::Errno::NOTTY));
+    assert_eq!(tcsendbreak(&file), Err(io::Errno::NOTTY));
+    assert_eq!(
+        tcflush(&file, QueueSelector::IOFlush),
+        Err(io::Errno::NOTTY)
+    );
+    assert_eq!(tcflow(&file, Action::OOn), Err(io::Errno::NOTTY));
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg(feature = "fs")]
+#[test]
+fn test_tc_pty() {
+    let pty = match crate::open_ptmx() {
+        Some(pty) => pty,
+        None => return,
+    };
+
+    tcflush(&pty, QueueSelector::IFlush).unwrap();
+    tcflush(&pty, QueueSelector::OFlush).unwrap();
+    tcflush(&pty, QueueSelector::IOFlush).unwrap();
+    tcflow(&pty, Action::OOff).unwrap();
+    tcflow(&pty, Action::OOn).unwrap();
+    tcdrain(&pty).unwrap();
+}
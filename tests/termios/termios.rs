@@ -0,0 +1,31 @@
+use rustix::io;
+use rustix::termios::{
+    cfgetispeed, cfgetospeed, cfmakeraw, cfsetspeed, tcgetattr, tcsetattr, OptionalActions, B9600,
+    ECHO, ICANON, VMIN,
+};
+
+#[test]
+fn test_tcgetattr_not_a_tty() {
+    let file = tempfile::tempfile().unwrap();
+    assert_eq!(tcgetattr(&file).unwrap_err(), io::Errno::NOTTY);
+}
+
+#[test]
+fn test_termios_raw_round_trip() {
+    let pty = match crate::open_ptmx() {
+        Some(pty) => pty,
+        None => return,
+    };
+
+    let mut termios = tcgetattr(&pty).unwrap();
+    cfmakeraw(&mut termios);
+    cfsetspeed(&mut termios, B9600).unwrap();
+    assert_eq!(cfgetospeed(&termios), B9600);
+    assert_eq!(cfgetispeed(&termios), B9600);
+    tcsetattr(&pty, OptionalActions::Now, &termios).unwrap();
+
+    let new = tcgetattr(&pty).unwrap();
+    assert_eq!(new.c_lflag & (ECHO | ICANON), 0);
+    assert_eq!(new.c_cc[VMIN], 1);
+    assert_eq!(cfgetospeed(&new), B9600);
+}
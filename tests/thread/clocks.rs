@@ -156,3 +156,56 @@ fn test_zero_nanosleep_relative() {
         otherwise => panic!("unexpected resut: {:?}", otherwise),
     }
 }
+
+#[cfg(feature = "time")]
+#[cfg(not(any(
+    target_os = "emscripten",
+    target_os = "freebsd",
+    target_os = "ios",
+    target_os = "macos",
+    target_os = "openbsd",
+    target_os = "redox",
+    target_os = "wasi",
+)))]
+#[test]
+fn test_nanosleep_absolute_deadline() {
+    use rustix::time::clock_gettime;
+
+    let now = clock_gettime(ClockId::Monotonic);
+    let mut deadline = Timespec {
+        tv_sec: now.tv_sec,
+        tv_nsec: now.tv_nsec + 20_000_000,
+    };
+    if deadline.tv_nsec >= 1_000_000_000 {
+        deadline.tv_sec += 1;
+        deadline.tv_nsec -= 1_000_000_000;
+    }
+
+    clock_nanosleep_absolute(ClockId::Monotonic, &deadline).unwrap();
+
+    let after = clock_gettime(ClockId::Monotonic);
+    assert!((after.tv_sec, after.tv_nsec) >= (deadline.tv_sec, deadline.tv_nsec));
+}
+
+#[cfg(not(any(
+    target_os = "emscripten",
+    target_os = "freebsd",
+    target_os = "ios",
+    target_os = "macos",
+    target_os = "openbsd",
+    target_os = "redox",
+    target_os = "wasi",
+)))]
+#[test]
+fn test_nanosleep_relative() {
+    match clock_nanosleep_relative(
+        ClockId::Monotonic,
+        &Timespec {
+            tv_sec: 0,
+            tv_nsec: 1_000_000,
+        },
+    ) {
+        NanosleepRelativeResult::Ok => (),
+        otherwise => panic!("unexpected resut: {:?}", otherwise),
+    }
+}
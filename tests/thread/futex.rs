@@ -0,0 +1,122 @@
+use core::ptr::{null, null_mut};
+use rustix::io::Errno;
+use rustix::thread::{futex, FutexFlags, FutexOperation, Timespec};
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
+
+#[test]
+fn test_futex_wait_mismatch() {
+    let mut word = 1_u32;
+
+    // The value doesn't match, so this returns immediately.
+    let err = unsafe {
+        futex(
+            &mut word,
+            FutexOperation::Wait,
+            FutexFlags::PRIVATE,
+            0,
+            null(),
+            null_mut(),
+            0,
+        )
+    }
+    .unwrap_err();
+    assert_eq!(err, Errno::AGAIN);
+}
+
+#[test]
+fn test_futex_wait_timeout() {
+    let mut word = 0_u32;
+    let timeout = Timespec {
+        tv_sec: 0,
+        tv_nsec: 1_000_000,
+    };
+    let err = unsafe {
+        futex(
+            &mut word,
+            FutexOperation::Wait,
+            FutexFlags::PRIVATE,
+            0,
+            &timeout,
+            null_mut(),
+            0,
+        )
+    }
+    .unwrap_err();
+    assert_eq!(err, Errno::TIMEDOUT);
+}
+
+#[test]
+fn test_futex_wake() {
+    let mut word = 0_u32;
+
+    // There are no waiters, so nothing is woken.
+    let woken = unsafe {
+        futex(
+            &mut word,
+            FutexOperation::Wake,
+            FutexFlags::PRIVATE,
+            1,
+            null(),
+            null_mut(),
+            0,
+        )
+    }
+    .unwrap();
+    assert_eq!(woken, 0);
+
+    let woken = unsafe {
+        futex(
+            &mut word,
+            FutexOperation::WakeBitset,
+            FutexFlags::PRIVATE,
+            1,
+            null(),
+            null_mut(),
+            !0,
+        )
+    }
+    .unwrap();
+    assert_eq!(woken, 0);
+}
+
+#[test]
+fn test_futex_wait_wake_threads() {
+    let word = Arc::new(AtomicU32::new(0));
+    let waiter = {
+        let word = Arc::clone(&word);
+        std::thread::spawn(move || {
+            while word.load(Ordering::SeqCst) == 0 {
+                match unsafe {
+                    futex(
+                        &*word as *const AtomicU32 as *mut u32,
+                        FutexOperation::WaitBitset,
+                        FutexFlags::PRIVATE,
+                        0,
+                        null(),
+                        null_mut(),
+                        !0,
+                    )
+                } {
+                    Ok(_) | Err(Errno::AGAIN) | Err(Errno::INTR) => {}
+                    Err(err) => panic!("{:?}", err),
+                }
+            }
+        })
+    };
+
+    word.store(1, Ordering::SeqCst);
+    unsafe {
+        futex(
+            &*word as *const AtomicU32 as *mut u32,
+            FutexOperation::WakeBitset,
+            FutexFlags::PRIVATE,
+            1,
+            null(),
+            null_mut(),
+            !0,
+        )
+    }
+    .unwrap();
+    waiter.join().unwrap();
+}
@@ -0,0 +1,19 @@
+use rustix::process::{getpid, Pid, Signal};
+use rustix::thread::{gettid, tgkill};
+
+#[test]
+fn test_tgkill() {
+    // `SIGWINCH` is ignored by default, so sending it to ourselves is
+    // harmless.
+    tgkill(getpid(), gettid(), Signal::Winch).unwrap();
+}
+
+#[test]
+fn test_tgkill_nonexistent_thread() {
+    // This is above Linux's `PID_MAX_LIMIT`, so no thread can have it.
+    let tid = unsafe { Pid::from_raw(0x7fff_ffff) }.unwrap();
+    assert_eq!(
+        tgkill(getpid(), tid, Signal::Winch).unwrap_err(),
+        rustix::io::Errno::SRCH
+    );
+}
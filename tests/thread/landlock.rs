@@ -0,0 +1,89 @@
+use rustix::fd::AsFd;
+use rustix::fs::{cwd, mkdirat, openat, Mode, OFlags};
+use rustix::io;
+use rustix::thread::landlock::{
+    abi_version, add_rule, create_ruleset, restrict_self, AccessFs, CreateRulesetFlags,
+    PathBeneathAttr, RestrictSelfFlags, RuleType, RulesetAttr,
+};
+use rustix::thread::set_no_new_privs;
+
+/// Test whether the kernel supports Landlock.
+fn landlock_supported() -> bool {
+    match abi_version() {
+        Ok(version) => {
+            assert!(version >= 1);
+            true
+        }
+        Err(io::Errno::NOSYS) | Err(io::Errno::OPNOTSUPP) => false,
+        Err(err) => panic!("{:?}", err),
+    }
+}
+
+#[test]
+fn test_landlock_restrict_self() {
+    if !landlock_supported() {
+        return;
+    }
+
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(cwd(), tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+    for name in &["allowed", "denied"] {
+        mkdirat(&dir, *name, Mode::RWXU).unwrap();
+        openat(
+            &dir,
+            format!("{}/file", name),
+            OFlags::CREATE | OFlags::WRONLY,
+            Mode::RUSR | Mode::WUSR,
+        )
+        .unwrap();
+    }
+
+    // Rulesets apply to a single thread, so restrict a new thread, so that
+    // it doesn't affect the test harness.
+    std::thread::spawn(move || {
+        let attr = RulesetAttr {
+            handled_access_fs: AccessFs::READ_FILE,
+        };
+        let ruleset = create_ruleset(&attr, CreateRulesetFlags::empty()).unwrap();
+
+        let allowed = openat(&dir, "allowed", OFlags::PATH, Mode::empty()).unwrap();
+        let rule = PathBeneathAttr {
+            allowed_access: AccessFs::READ_FILE,
+            parent_fd: allowed.as_fd(),
+        };
+        add_rule(&ruleset, RuleType::PathBeneath, &rule).unwrap();
+
+        set_no_new_privs().unwrap();
+        restrict_self(&ruleset, RestrictSelfFlags::empty()).unwrap();
+
+        openat(&dir, "allowed/file", OFlags::RDONLY, Mode::empty()).unwrap();
+        assert_eq!(
+            openat(&dir, "denied/file", OFlags::RDONLY, Mode::empty()).map(|_| ()),
+            Err(io::Errno::ACCESS)
+        );
+    })
+    .join()
+    .unwrap();
+}
+
+#[test]
+fn test_landlock_unhandled_access() {
+    if !landlock_supported() {
+        return;
+    }
+
+    let attr = RulesetAttr {
+        handled_access_fs: AccessFs::READ_FILE,
+    };
+    let ruleset = create_ruleset(&attr, CreateRulesetFlags::empty()).unwrap();
+
+    // Rules may only allow access which the ruleset handles.
+    let rule = PathBeneathAttr {
+        allowed_access: AccessFs::WRITE_FILE,
+        parent_fd: cwd(),
+    };
+    assert_eq!(
+        add_rule(&ruleset, RuleType::PathBeneath, &rule),
+        Err(io::Errno::INVAL)
+    );
+}
@@ -5,5 +5,15 @@
 
 #[cfg(not(any(target_os = "redox")))]
 mod clocks;
+#[cfg(linux_raw)]
+mod futex;
 #[cfg(any(target_os = "android", target_os = "linux"))]
 mod id;
+#[cfg(feature = "process")]
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod kill;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg(feature = "fs")]
+mod landlock;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod seccomp;
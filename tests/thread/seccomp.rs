@@ -0,0 +1,147 @@
+use rustix::io;
+use rustix::process::{getppid, Pid};
+use rustix::thread::{
+    no_new_privs, seccomp_notif_id_valid, seccomp_notif_recv, seccomp_notif_send,
+    seccomp_set_mode_filter, set_no_new_privs, SeccompFilterFlags, SeccompNotifResp,
+    SeccompNotifRespFlags, SockFilter, SockFprog,
+};
+use std::os::unix::process::CommandExt;
+use std::process::Command;
+use std::sync::mpsc;
+
+/// `BPF_LD | BPF_W | BPF_ABS`
+const BPF_LD_W_ABS: u16 = 0x20;
+
+/// `BPF_JMP | BPF_JEQ | BPF_K`
+const BPF_JEQ_K: u16 = 0x15;
+
+/// `BPF_RET | BPF_K`
+const BPF_RET_K: u16 = 0x06;
+
+/// `SECCOMP_RET_ALLOW`
+const SECCOMP_RET_ALLOW: u32 = 0x7fff_0000;
+
+/// `SECCOMP_RET_USER_NOTIF`
+const SECCOMP_RET_USER_NOTIF: u32 = 0x7fc0_0000;
+
+#[test]
+fn test_seccomp_empty_program() {
+    let prog = SockFprog::new(&[]);
+    assert_eq!(
+        seccomp_set_mode_filter(SeccompFilterFlags::empty(), &prog).map(|_| ()),
+        Err(io::Errno::INVAL)
+    );
+}
+
+#[test]
+fn test_seccomp_allow_all() {
+    let filter = [SockFilter {
+        code: BPF_RET_K,
+        jt: 0,
+        jf: 0,
+        k: SECCOMP_RET_ALLOW,
+    }];
+
+    // Install the filter in a child process, so that it doesn't affect the
+    // test harness.
+    let mut command = Command::new("true");
+    unsafe {
+        command.pre_exec(move || {
+            set_no_new_privs()?;
+            if !no_new_privs()? {
+                return Err(io::Errno::PERM.into());
+            }
+            let prog = SockFprog::new(&filter);
+            match seccomp_set_mode_filter(SeccompFilterFlags::empty(), &prog) {
+                Ok(None) => Ok(()),
+                // The kernel may be built without seccomp filter support.
+                Err(io::Errno::NOSYS) | Err(io::Errno::INVAL) => Ok(()),
+                Ok(Some(_)) => Err(io::Errno::INVAL.into()),
+                Err(err) => Err(err.into()),
+            }
+        });
+    }
+    assert!(command.status().unwrap().success());
+}
+
+#[test]
+fn test_seccomp_notif_not_listener() {
+    let (reader, _writer) = io::pipe().unwrap();
+    assert!(seccomp_notif_recv(&reader).is_err());
+    assert!(seccomp_notif_id_valid(&reader, 0).is_err());
+}
+
+#[test]
+fn test_seccomp_notif() {
+    // Send `getppid` to the listener, and allow everything else.
+    let filter = [
+        SockFilter {
+            code: BPF_LD_W_ABS,
+            jt: 0,
+            jf: 0,
+            k: 0,
+        },
+        SockFilter {
+            code: BPF_JEQ_K,
+            jt: 0,
+            jf: 1,
+            k: libc::SYS_getppid as u32,
+        },
+        SockFilter {
+            code: BPF_RET_K,
+            jt: 0,
+            jf: 0,
+            k: SECCOMP_RET_USER_NOTIF,
+        },
+        SockFilter {
+            code: BPF_RET_K,
+            jt: 0,
+            jf: 0,
+            k: SECCOMP_RET_ALLOW,
+        },
+    ];
+
+    // Filters apply to a single thread, so install it in a new thread, which
+    // sends the listener back to this one.
+    let (sender, receiver) = mpsc::channel();
+    let thread = std::thread::spawn(move || {
+        set_no_new_privs().unwrap();
+        let prog = SockFprog::new(&filter);
+        match seccomp_set_mode_filter(SeccompFilterFlags::NEW_LISTENER, &prog) {
+            Ok(listener) => {
+                sender.send(Some(listener.unwrap())).unwrap();
+                Some(getppid())
+            }
+            // The kernel may be older than Linux 5.0, or built without
+            // seccomp filter support.
+            Err(io::Errno::NOSYS) | Err(io::Errno::INVAL) => {
+                sender.send(None).unwrap();
+                None
+            }
+            Err(err) => panic!("{:?}", err),
+        }
+    });
+
+    if let Some(listener) = receiver.recv().unwrap() {
+        let notif = seccomp_notif_recv(&listener).unwrap();
+        assert_eq!(notif.data.nr, libc::SYS_getppid as i32);
+        seccomp_notif_id_valid(&listener, notif.id).unwrap();
+
+        let resp = SeccompNotifResp {
+            id: notif.id,
+            val: 42,
+            error: 0,
+            flags: SeccompNotifRespFlags::empty(),
+        };
+        seccomp_notif_send(&listener, &resp).unwrap();
+        assert_eq!(
+            seccomp_notif_id_valid(&listener, notif.id),
+            Err(io::Errno::NOENT)
+        );
+
+        let ppid = thread.join().unwrap().unwrap();
+        assert_eq!(ppid, Some(unsafe { Pid::from_raw(42) }.unwrap()));
+    } else {
+        assert_eq!(thread.join().unwrap(), None);
+    }
+}
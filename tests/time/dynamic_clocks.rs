@@ -20,3 +20,22 @@ fn test_dynamic_clocks() {
 fn test_conditional_clocks() {
     let _ = clock_gettime_dynamic(DynamicClockId::Tai);
 }
+
+#[test]
+fn test_getres_dynamic() {
+    use rustix::time::clock_getres_dynamic;
+
+    let res = clock_getres_dynamic(DynamicClockId::Known(ClockId::Monotonic)).unwrap();
+    assert!(res.tv_sec > 0 || res.tv_nsec > 0);
+
+    let file = std::fs::File::open("Cargo.toml").unwrap();
+    clock_getres_dynamic(DynamicClockId::Dynamic(file.as_fd())).unwrap_err();
+}
+
+#[test]
+fn test_dynamic_constructor() {
+    let file = std::fs::File::open("Cargo.toml").unwrap();
+    let id = ClockId::dynamic(file.as_fd());
+    assert!(matches!(id, DynamicClockId::Dynamic(_)));
+    assert_eq!(clock_gettime_dynamic(id), Err(rustix::io::Errno::INVAL));
+}
@@ -0,0 +1,24 @@
+use rustix::time::{clock_gettime, gettimeofday, ClockId};
+
+#[test]
+fn test_gettimeofday() {
+    let before = clock_gettime(ClockId::Realtime);
+    let tv = gettimeofday();
+    let after = clock_gettime(ClockId::Realtime);
+
+    assert!((0..1_000_000).contains(&tv.tv_usec));
+    assert!(tv.tv_sec >= before.tv_sec);
+    assert!(tv.tv_sec <= after.tv_sec);
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+fn test_settimeofday_invalid() {
+    use rustix::time::{settimeofday, Timeval};
+
+    let tv = Timeval {
+        tv_sec: 0,
+        tv_usec: 1_000_000,
+    };
+    assert_eq!(settimeofday(&tv), Err(rustix::io::Errno::INVAL));
+}